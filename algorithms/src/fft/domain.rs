@@ -30,6 +30,7 @@ use crate::{
     cfg_iter,
     cfg_iter_mut,
     fft::{DomainCoeff, SparsePolynomial},
+    prover_workspace,
 };
 use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 #[cfg(not(feature = "serial"))]
@@ -159,11 +160,18 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT.
     pub fn fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
-        let mut coeffs = coeffs.to_vec();
+        let mut coeffs = self.copy_to_workspace(coeffs);
         self.fft_in_place(&mut coeffs);
         coeffs
     }
 
+    /// Returns a copy of the given values, in a buffer from the prover workspace that fits the domain.
+    fn copy_to_workspace<T: DomainCoeff<F>>(&self, values: &[T]) -> Vec<T> {
+        let mut buffer = prover_workspace::take(self.size().max(values.len()));
+        buffer.extend_from_slice(values);
+        buffer
+    }
+
     /// Compute an FFT, modifying the vector in place.
     pub fn fft_in_place<T: DomainCoeff<F>>(&self, coeffs: &mut Vec<T>) {
         execute_with_max_available_threads(|| {
@@ -174,7 +182,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT.
     pub fn ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
-        let mut evals = evals.to_vec();
+        let mut evals = self.copy_to_workspace(evals);
         self.ifft_in_place(&mut evals);
         evals
     }
@@ -190,7 +198,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an FFT over a coset of the domain.
     pub fn coset_fft<T: DomainCoeff<F>>(&self, coeffs: &[T]) -> Vec<T> {
-        let mut coeffs = coeffs.to_vec();
+        let mut coeffs = self.copy_to_workspace(coeffs);
         self.coset_fft_in_place(&mut coeffs);
         coeffs
     }
@@ -206,7 +214,7 @@ impl<F: FftField> EvaluationDomain<F> {
 
     /// Compute an IFFT over a coset of the domain.
     pub fn coset_ifft<T: DomainCoeff<F>>(&self, evals: &[T]) -> Vec<T> {
-        let mut evals = evals.to_vec();
+        let mut evals = self.copy_to_workspace(evals);
         self.coset_ifft_in_place(&mut evals);
         evals
     }
@@ -274,7 +282,8 @@ impl<F: FftField> EvaluationDomain<F> {
             let mut l = (t_size - one) * self.size_inv;
             let mut r = one;
             let mut u = vec![F::zero(); size];
-            let mut ls = vec![F::zero(); size];
+            let mut ls = prover_workspace::take::<F>(size);
+            ls.resize(size, F::zero());
             for i in 0..size {
                 u[i] = tau - r;
                 ls[i] = l;
@@ -283,9 +292,10 @@ impl<F: FftField> EvaluationDomain<F> {
             }
 
             batch_inversion(u.as_mut_slice());
-            cfg_iter_mut!(u).zip_eq(ls).for_each(|(tau_minus_r, l)| {
-                *tau_minus_r = l * *tau_minus_r;
+            cfg_iter_mut!(u).zip_eq(&ls).for_each(|(tau_minus_r, l)| {
+                *tau_minus_r = *l * *tau_minus_r;
             });
+            prover_workspace::recycle(ls);
             u
         }
     }
@@ -373,7 +383,7 @@ impl<F: FftField> EvaluationDomain<F> {
     pub(crate) fn in_order_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
         // SNP TODO: how to set threshold and check that the type is Fr
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if self.size >= 32 && std::mem::size_of::<T>() == 32 && crate::gpu::is_gpu_enabled() {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -402,7 +412,7 @@ impl<F: FftField> EvaluationDomain<F> {
     pub(crate) fn in_order_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
         // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if self.size >= 32 && std::mem::size_of::<T>() == 32 && crate::gpu::is_gpu_enabled() {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -423,7 +433,7 @@ impl<F: FftField> EvaluationDomain<F> {
     pub(crate) fn in_order_coset_ifft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T]) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
         // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if self.size >= 32 && std::mem::size_of::<T>() == 32 && crate::gpu::is_gpu_enabled() {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -450,7 +460,7 @@ impl<F: FftField> EvaluationDomain<F> {
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
         // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if self.size >= 32 && std::mem::size_of::<T>() == 32 && crate::gpu::is_gpu_enabled() {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -481,7 +491,7 @@ impl<F: FftField> EvaluationDomain<F> {
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
         // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if self.size >= 32 && std::mem::size_of::<T>() == 32 && crate::gpu::is_gpu_enabled() {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -515,7 +525,7 @@ impl<F: FftField> EvaluationDomain<F> {
     ) {
         #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
         // SNP TODO: how to set threshold
-        if self.size >= 32 && std::mem::size_of::<T>() == 32 {
+        if self.size >= 32 && std::mem::size_of::<T>() == 32 && crate::gpu::is_gpu_enabled() {
             let result = snarkvm_algorithms_cuda::NTT(
                 self.size as usize,
                 x_s,
@@ -740,7 +750,8 @@ impl<F: FftField> EvaluationDomain<F> {
 
         let compaction_max_size =
             core::cmp::min(roots_cache.len() / 2, roots_cache.len() / MIN_NUM_CHUNKS_FOR_COMPACTION);
-        let mut compacted_roots = vec![F::default(); compaction_max_size];
+        let mut compacted_roots = prover_workspace::take::<F>(compaction_max_size);
+        compacted_roots.resize(compaction_max_size, F::default());
 
         #[cfg(not(feature = "serial"))]
         let max_threads = snarkvm_utilities::parallel::max_available_threads();
@@ -769,6 +780,9 @@ impl<F: FftField> EvaluationDomain<F> {
 
             gap *= 2;
         }
+
+        // Return the compacted roots to the workspace, for reuse by the next FFT.
+        prover_workspace::recycle(compacted_roots);
     }
 }
 
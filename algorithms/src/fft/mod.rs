@@ -40,6 +40,7 @@ pub trait DomainCoeff<F: FftField>:
     + core::ops::SubAssign
     + snarkvm_fields::Zero
     + core::ops::MulAssign<F>
+    + 'static
 {
 }
 
@@ -54,6 +55,7 @@ where
         + core::ops::SubAssign
         + core::ops::MulAssign<F>
         + core::ops::Add<Output = Self>
-        + core::ops::Sub<Output = Self>,
+        + core::ops::Sub<Output = Self>
+        + 'static,
 {
 }
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// The environment variable that toggles GPU acceleration at runtime.
+/// Setting it to `0` or `false` forces the prover to run MSMs and FFTs on the CPU.
+pub const GPU_ENV_VAR: &str = "SNARKVM_GPU";
+
+/// The GPU acceleration state has not been initialized yet.
+const UNINITIALIZED: u8 = 0;
+/// GPU acceleration is enabled.
+const ENABLED: u8 = 1;
+/// GPU acceleration is disabled.
+const DISABLED: u8 = 2;
+
+/// The GPU acceleration state.
+static GPU_STATE: AtomicU8 = AtomicU8::new(UNINITIALIZED);
+
+/// Returns `true` if MSMs and FFTs may be offloaded to the GPU.
+///
+/// This is always `false` unless snarkVM is compiled with the `cuda` feature.
+/// Otherwise, GPU acceleration is enabled by default, and may be disabled with the
+/// `SNARKVM_GPU` environment variable or with `set_gpu_enabled`.
+/// Note: If a GPU operation fails, the prover automatically falls back to the CPU.
+pub fn is_gpu_enabled() -> bool {
+    match GPU_STATE.load(Ordering::Relaxed) {
+        ENABLED => true,
+        DISABLED => false,
+        _ => {
+            let is_enabled = cfg!(all(feature = "cuda", target_arch = "x86_64"))
+                && !matches!(std::env::var(GPU_ENV_VAR).as_deref(), Ok("0") | Ok("false"));
+            set_gpu_enabled(is_enabled);
+            is_enabled
+        }
+    }
+}
+
+/// Enables or disables offloading MSMs and FFTs to the GPU.
+/// This takes precedence over the `SNARKVM_GPU` environment variable,
+/// and has no effect unless snarkVM is compiled with the `cuda` feature.
+pub fn set_gpu_enabled(is_enabled: bool) {
+    let is_enabled = is_enabled && cfg!(all(feature = "cuda", target_arch = "x86_64"));
+    GPU_STATE.store(if is_enabled { ENABLED } else { DISABLED }, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_gpu_enabled() {
+        set_gpu_enabled(false);
+        assert!(!is_gpu_enabled());
+
+        set_gpu_enabled(true);
+        assert_eq!(is_gpu_enabled(), cfg!(all(feature = "cuda", target_arch = "x86_64")));
+    }
+}
@@ -42,6 +42,12 @@ pub mod r1cs;
 #[cfg(feature = "snark")]
 pub mod snark;
 
+pub mod gpu;
+
+pub mod prover_config;
+
+pub mod prover_workspace;
+
 pub mod srs;
 
 pub mod errors;
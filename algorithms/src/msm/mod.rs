@@ -29,3 +29,15 @@ fn ln_without_floats(a: usize) -> usize {
     // log2(a) * ln(2)
     (crate::fft::domain::log2(a) * 69 / 100) as usize
 }
+
+/// Returns the window size `c` for a variable-base MSM over the given number of scalars.
+/// This is the window size in the prover configuration, if one is set, and is otherwise chosen empirically.
+fn window_size(num_scalars: usize) -> usize {
+    match crate::prover_config::ProverConfig::current().msm_window_size() {
+        Some(c) => c,
+        None => match num_scalars < 32 {
+            true => 1,
+            false => ln_without_floats(num_scalars) + 2,
+        },
+    }
+}
@@ -384,11 +384,8 @@ pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField
         debug_assert!(bits.iter_mut().all(|b| b.next().is_none()));
         sum
     } else {
-        // Determine the bucket size `c` (chosen empirically).
-        let c = match scalars.len() < 32 {
-            true => 1,
-            false => crate::msm::ln_without_floats(scalars.len()) + 2,
-        };
+        // Determine the bucket size `c`.
+        let c = crate::msm::window_size(scalars.len());
 
         let num_bits = <G::ScalarField as PrimeField>::size_in_bits();
 
@@ -18,20 +18,51 @@ pub mod standard;
 #[cfg(target_arch = "x86_64")]
 pub mod prefetch;
 
+use crate::prover_config::ProverConfig;
 use snarkvm_curves::{bls12_377::G1Affine, traits::AffineCurve};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::cfg_chunks;
 
 use core::any::TypeId;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 pub struct VariableBase;
 
 impl VariableBase {
     pub fn msm<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
+        // If a chunk size is set in the prover configuration, split the MSM into chunks, and process them in parallel.
+        match ProverConfig::current().msm_chunk_size() {
+            Some(chunk_size) => Self::msm_chunked(bases, scalars, chunk_size),
+            None => Self::msm_chunk(bases, scalars),
+        }
+    }
+
+    /// Performs a variable base MSM, by splitting the bases and scalars into chunks of the given size,
+    /// and processing the chunks in parallel.
+    fn msm_chunked<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInteger],
+        chunk_size: usize,
+    ) -> G::Projective {
+        let num_terms = bases.len().min(scalars.len());
+        match num_terms > chunk_size {
+            true => cfg_chunks!(bases[..num_terms], chunk_size)
+                .zip(cfg_chunks!(scalars[..num_terms], chunk_size))
+                .map(|(bases, scalars)| Self::msm_chunk(bases, scalars))
+                .sum(),
+            false => Self::msm_chunk(bases, scalars),
+        }
+    }
+
+    /// Performs a variable base MSM over the given bases and scalars.
+    fn msm_chunk<G: AffineCurve>(bases: &[G], scalars: &[<G::ScalarField as PrimeField>::BigInteger]) -> G::Projective {
         // For BLS12-377, we perform variable base MSM using a batched addition technique.
         if TypeId::of::<G>() == TypeId::of::<G1Affine>() {
             #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
             // TODO SNP: where to set the threshold
-            if scalars.len() > 1024 {
+            if scalars.len() > 1024 && crate::gpu::is_gpu_enabled() {
                 let result = snarkvm_algorithms_cuda::msm::<G, G::Projective, <G::ScalarField as PrimeField>::BigInteger>(
                     bases, scalars,
                 );
@@ -105,6 +136,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_msm_chunked() {
+        use snarkvm_curves::ProjectiveCurve;
+        let mut rng = TestRng::default();
+        let (bases, scalars) = create_scalar_bases::<G1Affine, Fr>(&mut rng, 1000);
+        let expected = VariableBase::msm_naive(bases.as_slice(), scalars.as_slice()).to_affine();
+        for chunk_size in [1, 7, 128, 999, 1000, 1024] {
+            let candidate = VariableBase::msm_chunked(bases.as_slice(), scalars.as_slice(), chunk_size).to_affine();
+            assert_eq!(expected, candidate, "Chunk size: {chunk_size}");
+        }
+    }
+
     #[cfg(all(feature = "cuda", target_arch = "x86_64"))]
     #[test]
     fn test_msm_cuda() {
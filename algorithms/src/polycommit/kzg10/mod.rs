@@ -23,6 +23,7 @@ use crate::{
     fft::{DensePolynomial, Polynomial},
     msm::VariableBase,
     polycommit::PCError,
+    prover_workspace,
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, PrimeField, Zero};
@@ -117,6 +118,7 @@ impl<E: PairingEngine> KZG10<E> {
                 let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
                 let commitment = VariableBase::msm(bases, &plain_coeffs);
                 end_timer!(msm_time);
+                prover_workspace::recycle(plain_coeffs);
 
                 commitment
             }
@@ -173,10 +175,11 @@ impl<E: PairingEngine> KZG10<E> {
             hiding_bound,
         ));
 
-        let evaluations = evaluations.iter().map(|e| e.to_bigint()).collect::<Vec<_>>();
+        let evaluations = convert_to_bigints(evaluations);
         let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
         let mut commitment = VariableBase::msm(&lagrange_basis.lagrange_basis_at_beta_g, &evaluations);
         end_timer!(msm_time);
+        prover_workspace::recycle(evaluations);
 
         let mut randomness = KZGRandomness::empty();
         if let Some(hiding_degree) = hiding_bound {
@@ -249,6 +252,7 @@ impl<E: PairingEngine> KZG10<E> {
         let witness_comm_time = start_timer!(|| "Computing commitment to witness polynomial");
         let mut w = VariableBase::msm(bases, &witness_coeffs);
         end_timer!(witness_comm_time);
+        prover_workspace::recycle(witness_coeffs);
 
         let random_v = if let Some(hiding_witness_polynomial) = hiding_witness_polynomial {
             let blinding_p = &randomness.blinding_polynomial;
@@ -465,9 +469,12 @@ fn skip_leading_zeros_and_convert_to_bigints<F: PrimeField>(p: &DensePolynomial<
     }
 }
 
+/// Returns the given coefficients as bigints, in a buffer from the prover workspace.
 fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
     let to_bigint_time = start_timer!(|| "Converting polynomial coeffs to bigints");
-    let coeffs = cfg_iter!(p).map(|s| s.to_bigint()).collect::<Vec<_>>();
+    let mut coeffs = prover_workspace::take(p.len());
+    coeffs.resize(p.len(), F::BigInteger::default());
+    cfg_iter_mut!(coeffs).zip(p).for_each(|(coeff, s)| *coeff = s.to_bigint());
     end_timer!(to_bigint_time);
     coeffs
 }
@@ -15,7 +15,7 @@
 use super::{LabeledPolynomial, PolynomialInfo};
 use crate::{crypto_hash::sha256::sha256, fft::EvaluationDomain, polycommit::kzg10};
 use snarkvm_curves::PairingEngine;
-use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField};
+use snarkvm_fields::{ConstraintFieldError, Field, PrimeField, ToConstraintField, Zero};
 use snarkvm_utilities::{error, serialize::*, FromBytes, ToBytes};
 
 use hashbrown::HashMap;
@@ -395,6 +395,44 @@ impl<E: PairingEngine> BatchProof<E> {
     }
 }
 
+/// The accumulated group elements of a deferred batch check, which can be combined
+/// with the pairing checks of other proofs before computing a single product of pairings.
+#[derive(Clone, Debug)]
+pub struct PairingCheck<E: PairingEngine> {
+    /// The combined commitments, indexed by their degree bound.
+    pub(crate) combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
+    /// The combined witness.
+    pub(crate) combined_witness: E::G1Projective,
+    /// The combined adjusted witness.
+    pub(crate) combined_adjusted_witness: E::G1Projective,
+}
+
+impl<E: PairingEngine> PairingCheck<E> {
+    /// Initializes an empty pairing check.
+    pub fn new() -> Self {
+        Self {
+            combined_comms: BTreeMap::new(),
+            combined_witness: E::G1Projective::zero(),
+            combined_adjusted_witness: E::G1Projective::zero(),
+        }
+    }
+
+    /// Adds the given pairing check, scaled by the given randomizer, into this pairing check.
+    pub fn accumulate(&mut self, other: &Self, randomizer: E::Fr) {
+        for (degree_bound, comm) in &other.combined_comms {
+            *self.combined_comms.entry(*degree_bound).or_insert_with(E::G1Projective::zero) += *comm * randomizer;
+        }
+        self.combined_witness += other.combined_witness * randomizer;
+        self.combined_adjusted_witness += other.combined_adjusted_witness * randomizer;
+    }
+}
+
+impl<E: PairingEngine> Default for PairingCheck<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Labels a `LabeledPolynomial` or a `LabeledCommitment`.
 pub type PolynomialLabel = String;
 
@@ -13,9 +13,10 @@
 // limitations under the License.
 
 use crate::{
-    fft::DensePolynomial,
+    fft::{DensePolynomial, Polynomial},
     msm::variable_base::VariableBase,
     polycommit::{kzg10, optional_rng::OptionalRng, PCError},
+    prover_workspace,
     srs::{UniversalProver, UniversalVerifier},
     AlgebraicSponge,
 };
@@ -26,9 +27,9 @@ use snarkvm_fields::{One, Zero};
 
 use anyhow::{bail, ensure, Result};
 use core::{convert::TryInto, marker::PhantomData, ops::Mul};
-use rand_core::{RngCore, SeedableRng};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
 use std::{
-    borrow::Borrow,
+    borrow::{Borrow, Cow},
     collections::{BTreeMap, BTreeSet},
 };
 
@@ -348,6 +349,23 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::batch_check_deferred(vk, commitments, query_set, values, proof, fs_rng)?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Performs the batch check up to (but excluding) the final product of pairings,
+    /// and returns the accumulated group elements of the pairing check.
+    pub fn batch_check_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        values: &Evaluations<E::Fr>,
+        proof: &BatchProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -368,9 +386,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 
         let mut randomizer = E::Fr::one();
 
-        let mut combined_comms = BTreeMap::new();
-        let mut combined_witness = E::G1Projective::zero();
-        let mut combined_adjusted_witness = E::G1Projective::zero();
+        let mut pairing_check = PairingCheck::new();
 
         ensure!(query_to_labels_map.len() == proof.0.len());
         for ((_query_name, (query, labels)), p) in query_to_labels_map.into_iter().zip_eq(&proof.0) {
@@ -389,9 +405,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             }
 
             Self::accumulate_elems(
-                &mut combined_comms,
-                &mut combined_witness,
-                &mut combined_adjusted_witness,
+                &mut pairing_check,
                 vk,
                 comms_to_combine.into_iter(),
                 *query,
@@ -404,9 +418,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             randomizer = fs_rng.squeeze_short_nonnative_field_element::<E::Fr>();
         }
 
-        let result = Self::check_elems(vk, combined_comms, combined_witness, combined_adjusted_witness);
         end_timer!(batch_check_time);
-        result.map_err(Into::into)
+        Ok(pairing_check)
     }
 
     pub fn open_combinations<'a>(
@@ -468,6 +481,14 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         let proof =
             Self::batch_open(universal_prover, ck, lc_polynomials.iter(), query_set, lc_randomness.iter(), fs_rng)?;
 
+        // Return the coefficients of the prover polynomials to the prover workspace, for reuse by later proofs.
+        let polynomials = label_map.into_values().map(|(polynomial, _)| polynomial);
+        for polynomial in polynomials.chain(lc_polynomials) {
+            if let Polynomial::Dense(Cow::Owned(polynomial)) = polynomial.polynomial {
+                prover_workspace::recycle(polynomial.coeffs);
+            }
+        }
+
         Ok(BatchLCProof { proof })
     }
 
@@ -482,6 +503,32 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
         proof: &BatchLCProof<E>,
         fs_rng: &mut S,
     ) -> Result<bool>
+    where
+        Commitment<E>: 'a,
+    {
+        let pairing_check = Self::check_combinations_deferred(
+            vk,
+            linear_combinations,
+            commitments,
+            query_set,
+            evaluations,
+            proof,
+            fs_rng,
+        )?;
+        Self::check_pairing(vk, pairing_check)
+    }
+
+    /// Checks the linear combinations up to (but excluding) the final product of pairings,
+    /// and returns the accumulated group elements of the pairing check.
+    pub fn check_combinations_deferred<'a>(
+        vk: &UniversalVerifier<E>,
+        linear_combinations: impl IntoIterator<Item = &'a LinearCombination<E::Fr>>,
+        commitments: impl IntoIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
+        query_set: &QuerySet<E::Fr>,
+        evaluations: &Evaluations<E::Fr>,
+        proof: &BatchLCProof<E>,
+        fs_rng: &mut S,
+    ) -> Result<PairingCheck<E>>
     where
         Commitment<E>: 'a,
     {
@@ -539,7 +586,30 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             .collect::<Vec<_>>();
         end_timer!(combined_comms_norm_time);
 
-        Self::batch_check(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+        Self::batch_check_deferred(vk, &lc_commitments, query_set, &evaluations, proof, fs_rng)
+    }
+
+    /// Returns `true` if all of the given pairing checks are satisfied.
+    ///
+    /// The pairing checks are combined with random coefficients sampled from `rng`,
+    /// so that only a single product of pairings is computed for the entire batch.
+    pub fn check_pairings<R: RngCore + CryptoRng>(
+        vk: &UniversalVerifier<E>,
+        pairing_checks: impl IntoIterator<Item = PairingCheck<E>>,
+        rng: &mut R,
+    ) -> Result<bool> {
+        let combine_time = start_timer!(|| "Combining pairing checks");
+        let mut combined_check = PairingCheck::new();
+        for (i, pairing_check) in pairing_checks.into_iter().enumerate() {
+            // The first pairing check does not need to be randomized.
+            let randomizer = match i {
+                0 => E::Fr::one(),
+                _ => E::Fr::from(rng.next_u64() as u128 | ((rng.next_u64() as u128) << 64)),
+            };
+            combined_check.accumulate(&pairing_check, randomizer);
+        }
+        end_timer!(combine_time);
+        Self::check_pairing(vk, combined_check)
     }
 }
 
@@ -579,9 +649,7 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
 impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
     #[allow(clippy::too_many_arguments)]
     fn accumulate_elems<'a>(
-        combined_comms: &mut BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: &mut E::G1Projective,
-        combined_adjusted_witness: &mut E::G1Projective,
+        pairing_check: &mut PairingCheck<E>,
         vk: &UniversalVerifier<E>,
         commitments: impl ExactSizeIterator<Item = &'a LabeledCommitment<Commitment<E>>>,
         point: E::Fr,
@@ -610,7 +678,8 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             let comm_with_challenge: E::G1Projective = comm.0.mul(coeff);
 
             // Accumulate values in the BTreeMap
-            *combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) += &comm_with_challenge;
+            *pairing_check.combined_comms.entry(degree_bound).or_insert_with(E::G1Projective::zero) +=
+                &comm_with_challenge;
             end_timer!(acc_timer);
         }
 
@@ -621,24 +690,21 @@ impl<E: PairingEngine, S: AlgebraicSponge<E::Fq, 2>> SonicKZG10<E, S> {
             bases.push(vk.vk.gamma_g);
             coeffs.push(random_v);
         }
-        *combined_witness += if let Some(randomizer) = randomizer {
+        pairing_check.combined_witness += if let Some(randomizer) = randomizer {
             coeffs.iter_mut().for_each(|c| *c *= randomizer);
             proof.w.mul(randomizer)
         } else {
             proof.w.to_projective()
         };
         let coeffs = coeffs.into_iter().map(|c| c.into()).collect::<Vec<_>>();
-        *combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
+        pairing_check.combined_adjusted_witness += VariableBase::msm(&bases, &coeffs);
         end_timer!(acc_time);
         Ok(())
     }
 
-    fn check_elems(
-        vk: &UniversalVerifier<E>,
-        combined_comms: BTreeMap<Option<usize>, E::G1Projective>,
-        combined_witness: E::G1Projective,
-        combined_adjusted_witness: E::G1Projective,
-    ) -> Result<bool> {
+    /// Returns `true` if the given pairing check is satisfied.
+    pub fn check_pairing(vk: &UniversalVerifier<E>, pairing_check: PairingCheck<E>) -> Result<bool> {
+        let PairingCheck { combined_comms, combined_witness, combined_adjusted_witness } = pairing_check;
         let check_time = start_timer!(|| "Checking elems");
         let mut g1_projective_elems = Vec::with_capacity(combined_comms.len() + 2);
         let mut g2_prepared_elems = Vec::with_capacity(combined_comms.len() + 2);
@@ -0,0 +1,172 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use anyhow::{ensure, Result};
+use core::cell::Cell;
+
+thread_local! {
+    /// The prover configuration in effect on the current thread.
+    static CURRENT: Cell<ProverConfig> = Cell::new(ProverConfig::new());
+}
+
+/// The configuration for the parallelism of the prover's MSMs and FFTs, and for its memory retention.
+///
+/// By default, the prover uses all available threads, and selects the MSM window size from the number of scalars.
+/// Services running many concurrent provings may bound the number of threads of each proving,
+/// and tune the MSM window and chunk sizes, to avoid oversubscribing the CPU.
+/// They may also bound the memory that each thread's prover workspace retains across proofs.
+/// Note: The configuration is scoped to the operation passed to `install`, and does not affect other provings.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProverConfig {
+    /// The number of threads of the thread pool, or `None` for all available threads.
+    num_threads: Option<usize>,
+    /// The Pippenger window size for variable-base MSMs, or `None` to select it from the number of scalars.
+    msm_window_size: Option<usize>,
+    /// The number of bases per parallel chunk for variable-base MSMs, or `None` to process the MSM as a whole.
+    msm_chunk_size: Option<usize>,
+    /// The maximum number of bytes retained by the prover workspace of each thread, or `None` for the default.
+    max_workspace_bytes: Option<usize>,
+}
+
+impl ProverConfig {
+    /// The maximum Pippenger window size for variable-base MSMs.
+    pub const MAX_MSM_WINDOW_SIZE: usize = 24;
+
+    /// Initializes a new prover configuration with the default settings.
+    pub const fn new() -> Self {
+        Self { num_threads: None, msm_window_size: None, msm_chunk_size: None, max_workspace_bytes: None }
+    }
+
+    /// Returns the prover configuration that is in effect on the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.get())
+    }
+
+    /// Sets the number of threads of the thread pool.
+    pub const fn with_num_threads(mut self, num_threads: usize) -> Self {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Sets the Pippenger window size for variable-base MSMs.
+    pub const fn with_msm_window_size(mut self, window_size: usize) -> Self {
+        self.msm_window_size = Some(window_size);
+        self
+    }
+
+    /// Sets the number of bases per parallel chunk for variable-base MSMs.
+    pub const fn with_msm_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.msm_chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Sets the maximum number of bytes retained by the prover workspace of each thread, where `0` disables retention.
+    pub const fn with_max_workspace_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_workspace_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Returns the number of threads of the thread pool, if one is set.
+    pub const fn num_threads(&self) -> Option<usize> {
+        self.num_threads
+    }
+
+    /// Returns the Pippenger window size for variable-base MSMs, if one is set.
+    pub const fn msm_window_size(&self) -> Option<usize> {
+        self.msm_window_size
+    }
+
+    /// Returns the number of bases per parallel chunk for variable-base MSMs, if one is set.
+    pub const fn msm_chunk_size(&self) -> Option<usize> {
+        self.msm_chunk_size
+    }
+
+    /// Returns the maximum number of bytes retained by the prover workspace of each thread, if one is set.
+    pub const fn max_workspace_bytes(&self) -> Option<usize> {
+        self.max_workspace_bytes
+    }
+
+    /// Runs the given operation with this prover configuration in effect, and returns its output.
+    ///
+    /// The operation runs in a dedicated thread pool of `num_threads` threads, bounded by the available threads,
+    /// and every worker of the pool uses this configuration. The thread pools that the prover spawns from within
+    /// the operation reuse this pool, so the configuration applies to all of its MSMs and FFTs.
+    pub fn install<R: Send>(&self, operation: impl FnOnce() -> R + Send) -> Result<R> {
+        // Ensure the settings are non-zero.
+        ensure!(self.num_threads != Some(0), "The number of prover threads must be non-zero");
+        ensure!(self.msm_chunk_size != Some(0), "The MSM chunk size must be non-zero");
+        // Ensure the window size is within range.
+        if let Some(window_size) = self.msm_window_size {
+            ensure!((1..=Self::MAX_MSM_WINDOW_SIZE).contains(&window_size), "Invalid MSM window size");
+        }
+
+        #[cfg(not(any(feature = "serial", feature = "wasm")))]
+        {
+            // Bound the pool by the available threads, so that nested thread pools reuse it.
+            let max_threads = snarkvm_utilities::parallel::max_available_threads();
+            let num_threads = self.num_threads.map_or(max_threads, |num_threads| num_threads.min(max_threads));
+
+            let config = *self;
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .start_handler(move |_| CURRENT.with(|current| current.set(config)))
+                .build()?;
+            Ok(pool.install(operation))
+        }
+        #[cfg(any(feature = "serial", feature = "wasm"))]
+        {
+            let previous = CURRENT.with(|current| current.replace(*self));
+            let output = operation();
+            CURRENT.with(|current| current.set(previous));
+            Ok(output)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prover_config() {
+        // Ensure invalid configurations are rejected.
+        assert!(ProverConfig::new().with_num_threads(0).install(|| ()).is_err());
+        assert!(ProverConfig::new().with_msm_chunk_size(0).install(|| ()).is_err());
+        assert!(ProverConfig::new().with_msm_window_size(0).install(|| ()).is_err());
+        assert!(ProverConfig::new()
+            .with_msm_window_size(ProverConfig::MAX_MSM_WINDOW_SIZE + 1)
+            .install(|| ())
+            .is_err());
+
+        // Ensure a valid configuration is in effect within the operation, including on the workers of nested pools.
+        let config = ProverConfig::new()
+            .with_num_threads(2)
+            .with_msm_window_size(8)
+            .with_msm_chunk_size(1 << 10)
+            .with_max_workspace_bytes(1 << 20);
+        let (current, nested) = config
+            .install(|| {
+                let mut nested = snarkvm_utilities::ExecutionPool::<ProverConfig>::new();
+                nested.add_job(ProverConfig::current);
+                nested.add_job(ProverConfig::current);
+                (ProverConfig::current(), nested.execute_all())
+            })
+            .unwrap();
+        assert_eq!(current, config);
+        assert!(nested.iter().all(|nested| *nested == config));
+
+        // Ensure the configuration is not in effect outside of the operation.
+        assert_eq!(ProverConfig::current(), ProverConfig::default());
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::prover_config::ProverConfig;
+
+use core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+};
+use std::collections::HashMap;
+
+/// The default maximum number of bytes retained by the workspace of each thread.
+pub const DEFAULT_MAX_RETAINED_BYTES: usize = 1 << 26;
+
+thread_local! {
+    /// The prover workspace of the current thread.
+    static WORKSPACE: RefCell<Workspace> = RefCell::new(Workspace::default());
+}
+
+/// Returns an empty buffer with at least the given capacity, reusing a retained buffer if one is available.
+///
+/// The prover workspace retains large temporary buffers across proofs, to avoid reallocating them for every proof.
+/// Each thread has its own workspace, so buffers taken in a parallel closure are retained by the worker thread.
+pub fn take<T: 'static>(capacity: usize) -> Vec<T> {
+    WORKSPACE.with(|workspace| workspace.borrow_mut().take(capacity))
+}
+
+/// Returns the given buffer to the workspace of the current thread, for reuse by later proofs.
+/// If retaining the buffer would exceed the maximum number of retained bytes, the buffer is freed.
+pub fn recycle<T: 'static>(buffer: Vec<T>) {
+    WORKSPACE.with(|workspace| workspace.borrow_mut().recycle(buffer, max_retained_bytes()))
+}
+
+/// Returns the number of bytes retained by the workspace of the current thread.
+pub fn retained_bytes() -> usize {
+    WORKSPACE.with(|workspace| workspace.borrow().retained_bytes)
+}
+
+/// Frees the buffers retained by the workspace of the current thread.
+pub fn clear() {
+    WORKSPACE.with(|workspace| workspace.borrow_mut().trim(0))
+}
+
+/// Returns the maximum number of bytes retained by the workspace of the current thread,
+/// as set in the prover configuration in effect on the thread, or else the default.
+/// Note: Each workspace frees its excess buffers the next time a buffer is returned to it.
+pub fn max_retained_bytes() -> usize {
+    ProverConfig::current().max_workspace_bytes().unwrap_or(DEFAULT_MAX_RETAINED_BYTES)
+}
+
+/// The retained buffers of a single thread.
+#[derive(Default)]
+struct Workspace {
+    /// The map of element types to retained buffers, where each buffer is a `Vec` of the element type.
+    buffers: HashMap<TypeId, Vec<(usize, Box<dyn Any>)>>,
+    /// The number of bytes retained by the buffers.
+    retained_bytes: usize,
+}
+
+impl Workspace {
+    /// Returns an empty buffer with at least the given capacity, reusing a retained buffer if one is available.
+    fn take<T: 'static>(&mut self, capacity: usize) -> Vec<T> {
+        let buffers = match self.buffers.get_mut(&TypeId::of::<T>()) {
+            Some(buffers) if !buffers.is_empty() => buffers,
+            _ => return Vec::with_capacity(capacity),
+        };
+        // Select the smallest buffer that fits the capacity, or else the largest buffer.
+        let index = buffers
+            .iter()
+            .enumerate()
+            .filter(|(_, (num_bytes, _))| *num_bytes >= capacity.saturating_mul(core::mem::size_of::<T>()))
+            .min_by_key(|(_, (num_bytes, _))| *num_bytes)
+            .or_else(|| buffers.iter().enumerate().max_by_key(|(_, (num_bytes, _))| *num_bytes))
+            .map(|(index, _)| index)
+            .unwrap_or_default();
+        let (num_bytes, buffer) = buffers.swap_remove(index);
+        self.retained_bytes -= num_bytes;
+
+        // Downcast the buffer, which always succeeds as buffers are keyed by their element type.
+        let mut buffer = match buffer.downcast::<Vec<T>>() {
+            Ok(buffer) => *buffer,
+            Err(_) => return Vec::with_capacity(capacity),
+        };
+        buffer.reserve(capacity);
+        buffer
+    }
+
+    /// Retains the given buffer, unless it would exceed the given maximum number of retained bytes.
+    fn recycle<T: 'static>(&mut self, mut buffer: Vec<T>, max_bytes: usize) {
+        // Free any excess buffers, in case the maximum was lowered.
+        self.trim(max_bytes);
+
+        let num_bytes = buffer.capacity().saturating_mul(core::mem::size_of::<T>());
+        // If the buffer is empty, or would exceed the maximum, free it.
+        if num_bytes == 0 || self.retained_bytes.saturating_add(num_bytes) > max_bytes {
+            return;
+        }
+        buffer.clear();
+        self.buffers.entry(TypeId::of::<T>()).or_default().push((num_bytes, Box::new(buffer)));
+        self.retained_bytes += num_bytes;
+    }
+
+    /// Frees buffers until at most the given maximum number of bytes is retained.
+    fn trim(&mut self, max_bytes: usize) {
+        if self.retained_bytes <= max_bytes {
+            return;
+        }
+        if max_bytes == 0 {
+            self.buffers.clear();
+            self.retained_bytes = 0;
+            return;
+        }
+        for buffers in self.buffers.values_mut() {
+            while self.retained_bytes > max_bytes {
+                match buffers.pop() {
+                    Some((num_bytes, _)) => self.retained_bytes -= num_bytes,
+                    None => break,
+                }
+            }
+        }
+        self.buffers.retain(|_, buffers| !buffers.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_and_recycle() {
+        clear();
+        assert_eq!(retained_bytes(), 0);
+
+        // Ensure a recycled buffer is reused.
+        let mut buffer = take::<u64>(1024);
+        assert!(buffer.capacity() >= 1024);
+        buffer.extend(0..1024);
+        let pointer = buffer.as_ptr();
+        let capacity = buffer.capacity();
+        recycle(buffer);
+        assert_eq!(retained_bytes(), capacity * 8);
+
+        let buffer = take::<u64>(512);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.as_ptr(), pointer);
+        assert_eq!(retained_bytes(), 0);
+
+        // Ensure buffers of other element types are not reused.
+        recycle(buffer);
+        let other = take::<u32>(16);
+        assert_ne!(other.as_ptr() as usize, pointer as usize);
+        assert_eq!(retained_bytes(), capacity * 8);
+
+        // Ensure the retained buffers are freed.
+        clear();
+        assert_eq!(retained_bytes(), 0);
+    }
+
+    #[test]
+    fn test_max_retained_bytes() {
+        let mut workspace = Workspace::default();
+
+        // Ensure buffers are retained up to the maximum.
+        workspace.recycle(Vec::<u8>::with_capacity(64), 100);
+        assert_eq!(workspace.retained_bytes, 64);
+        workspace.recycle(Vec::<u8>::with_capacity(64), 100);
+        assert_eq!(workspace.retained_bytes, 64);
+
+        // Ensure the smallest buffer that fits is reused.
+        workspace.recycle(Vec::<u8>::with_capacity(32), 100);
+        assert_eq!(workspace.retained_bytes, 96);
+        assert_eq!(workspace.take::<u8>(16).capacity(), 32);
+        assert_eq!(workspace.retained_bytes, 64);
+
+        // Ensure the largest buffer is grown if none fits.
+        assert!(workspace.take::<u8>(128).capacity() >= 128);
+        assert_eq!(workspace.retained_bytes, 0);
+
+        // Ensure lowering the maximum frees the excess buffers.
+        workspace.recycle(Vec::<u8>::with_capacity(64), 100);
+        workspace.recycle(Vec::<u16>::with_capacity(16), 100);
+        assert_eq!(workspace.retained_bytes, 96);
+        workspace.recycle(Vec::<u8>::new(), 50);
+        assert!(workspace.retained_bytes <= 50);
+
+        // Ensure a maximum of zero disables retention.
+        workspace.recycle(Vec::<u8>::with_capacity(8), 0);
+        assert_eq!(workspace.retained_bytes, 0);
+        assert!(workspace.buffers.is_empty());
+    }
+}
@@ -28,7 +28,7 @@ use rand::Rng;
 use rand_core::CryptoRng;
 use std::collections::BTreeMap;
 
-use snarkvm_utilities::cfg_iter;
+use snarkvm_utilities::{cfg_into_iter, cfg_iter};
 #[cfg(not(feature = "std"))]
 use snarkvm_utilities::println;
 
@@ -65,9 +65,12 @@ impl<F: PrimeField, SM: SNARKMode> AHPForR1CS<F, SM> {
             randomizing_assignments.push(circuit_assignments);
         }
 
-        let indices_and_assignments = circuits_to_constraints
-            .iter()
-            .zip_eq(randomizing_assignments.into_iter())
+        // Load the assignments of each circuit (e.g. the transitions of an execution) into the prover
+        // in parallel, as the circuits are independent of each other.
+        // Note: The assignments themselves are synthesized beforehand, by `Process::execute`, on a single thread.
+        let circuits_and_rand_assignments =
+            circuits_to_constraints.iter().zip_eq(randomizing_assignments).collect::<Vec<_>>();
+        let indices_and_assignments = cfg_into_iter!(circuits_and_rand_assignments)
             .map(|((circuit, constraints), circuit_rand_assignments)| {
                 let num_non_zero_a = circuit.index_info.num_non_zero_a;
                 let num_non_zero_b = circuit.index_info.num_non_zero_b;
@@ -315,7 +315,7 @@ mod varuna_hiding {
         ToBytes,
     };
 
-    use std::str::FromStr;
+    use std::{collections::BTreeMap, str::FromStr};
 
     type VarunaInst = VarunaSNARK<Bls12_377, FS, VarunaHidingMode>;
     type FS = PoseidonSponge<Fq, 2, 1>;
@@ -492,6 +492,42 @@ mod varuna_hiding {
         assert!(VarunaInst::verify(universal_verifier, &fs_parameters, &new_vk, public_inputs, &proof).unwrap());
     }
 
+    #[test]
+    fn test_verify_batch_proofs() {
+        let rng = &mut TestRng::default();
+
+        let max_degree = AHPForR1CS::<Fr, VarunaHidingMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = VarunaInst::universal_setup(max_degree).unwrap();
+        let universal_prover = &universal_srs.to_universal_prover().unwrap();
+        let universal_verifier = &universal_srs.to_universal_verifier().unwrap();
+        let fs_parameters = FS::sample_parameters();
+
+        // Prove a few independent circuits.
+        let mut keys_inputs_and_proofs = Vec::new();
+        for i in 0..3 {
+            let (circuit, public_inputs) = TestCircuit::gen_rand(2 + i, 25 + i, 25, rng);
+            let (index_pk, index_vk) = VarunaInst::circuit_setup(&universal_srs, &circuit).unwrap();
+            let proof = VarunaInst::prove(universal_prover, &fs_parameters, &index_pk, &circuit, rng).unwrap();
+            keys_inputs_and_proofs.push((index_vk, vec![public_inputs], proof));
+        }
+
+        // Ensure the proofs verify together.
+        let instances = keys_inputs_and_proofs
+            .iter()
+            .map(|(vk, inputs, proof)| (BTreeMap::from([(vk, inputs.as_slice())]), proof))
+            .collect::<Vec<_>>();
+        assert!(VarunaInst::verify_batch_proofs(universal_verifier, &fs_parameters, &instances, rng).unwrap());
+
+        // Ensure the proofs do not verify together if a single proof has invalid inputs.
+        let fake_inputs = vec![vec![Fr::rand(rng), Fr::rand(rng)]];
+        let mut instances = instances;
+        instances[1].0 = BTreeMap::from([(&keys_inputs_and_proofs[1].0, fake_inputs.as_slice())]);
+        assert!(!VarunaInst::verify_batch_proofs(universal_verifier, &fs_parameters, &instances, rng).unwrap());
+
+        // Ensure an empty batch is rejected.
+        assert!(VarunaInst::verify_batch_proofs::<Vec<Fr>, _>(universal_verifier, &fs_parameters, &[], rng).is_err());
+    }
+
     #[test]
     fn test_srs_downloads() {
         let rng = &mut TestRng::default();
@@ -20,6 +20,7 @@ use crate::{
         CommitterUnionKey,
         Evaluations,
         LabeledCommitment,
+        PairingCheck,
         QuerySet,
         Randomness,
         SonicKZG10,
@@ -628,6 +629,72 @@ where
         keys_to_inputs: &BTreeMap<&Self::VerifyingKey, &[B]>,
         proof: &Self::Proof,
     ) -> Result<bool> {
+        let verifier_time = start_timer!(|| "Varuna::Verify");
+        let Some(pairing_check) =
+            Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)?
+        else {
+            return Ok(false);
+        };
+        let pc_time = start_timer!(|| "Checking linear combinations with PC");
+        let evaluations_are_correct = SonicKZG10::<E, FS>::check_pairing(universal_verifier, pairing_check)?;
+        end_timer!(pc_time);
+
+        if !evaluations_are_correct {
+            #[cfg(debug_assertions)]
+            eprintln!("SonicKZG10::Check failed");
+        }
+
+        end_timer!(verifier_time, || format!(
+            " SonicKZG10::Check for AHP Verifier linear equations: {evaluations_are_correct}"
+        ));
+        Ok(evaluations_are_correct)
+    }
+}
+
+impl<E: PairingEngine, FS, SM> VarunaSNARK<E, FS, SM>
+where
+    E::Fr: PrimeField,
+    E::Fq: PrimeField,
+    FS: AlgebraicSponge<E::Fq, 2>,
+    SM: SNARKMode,
+{
+    /// Verifies many batch proofs together.
+    ///
+    /// Each proof is checked up to its final pairing check, and the pairing checks of all proofs
+    /// are then combined with random coefficients into a single product of pairings.
+    /// Returns `true` if and only if every proof is valid for its verifying keys and public inputs.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch_proofs<B: Borrow<[E::Fr]>, R: Rng + CryptoRng>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        instances: &[(BTreeMap<&CircuitVerifyingKey<E>, &[B]>, &Proof<E>)],
+        rng: &mut R,
+    ) -> Result<bool> {
+        if instances.is_empty() {
+            bail!(SNARKError::EmptyBatch);
+        }
+
+        let verifier_time = start_timer!(|| format!("Varuna::VerifyBatchProofs with {} proofs", instances.len()));
+        let mut pairing_checks = Vec::with_capacity(instances.len());
+        for (keys_to_inputs, proof) in instances {
+            match Self::verify_batch_deferred(universal_verifier, fs_parameters, keys_to_inputs, proof)? {
+                Some(pairing_check) => pairing_checks.push(pairing_check),
+                None => return Ok(false),
+            }
+        }
+        let is_valid = SonicKZG10::<E, FS>::check_pairings(universal_verifier, pairing_checks, rng)?;
+        end_timer!(verifier_time);
+        Ok(is_valid)
+    }
+
+    /// Verifies the given batch proof up to (but excluding) the final pairing check,
+    /// and returns the pairing check. Returns `None` if the proof is rejected before the pairing check.
+    fn verify_batch_deferred<B: Borrow<[E::Fr]>>(
+        universal_verifier: &UniversalVerifier<E>,
+        fs_parameters: &FS::Parameters,
+        keys_to_inputs: &BTreeMap<&CircuitVerifyingKey<E>, &[B]>,
+        proof: &Proof<E>,
+    ) -> Result<Option<PairingCheck<E>>> {
         if keys_to_inputs.is_empty() {
             bail!(SNARKError::EmptyBatch);
         }
@@ -715,7 +782,7 @@ where
                 "Found `mask_poly` in the first round when not expected, or proof has incorrect hiding mode ({})",
                 proof.pc_proof.is_hiding()
             );
-            return Ok(false);
+            return Ok(None);
         }
 
         let verifier_time = start_timer!(|| format!("Varuna::Verify with batch sizes: {:?}", batch_sizes));
@@ -888,8 +955,8 @@ where
         )?;
         end_timer!(lc_time);
 
-        let pc_time = start_timer!(|| "Checking linear combinations with PC");
-        let evaluations_are_correct = SonicKZG10::<E, FS>::check_combinations(
+        let pc_time = start_timer!(|| "Accumulating linear combinations with PC");
+        let pairing_check = SonicKZG10::<E, FS>::check_combinations_deferred(
             universal_verifier,
             lc_s.values(),
             &commitments,
@@ -900,15 +967,7 @@ where
         )?;
         end_timer!(pc_time);
 
-        if !evaluations_are_correct {
-            #[cfg(debug_assertions)]
-            eprintln!("SonicKZG10::Check failed using final challenge: {:?}", verifier_state.gamma);
-        }
-
-        end_timer!(verifier_time, || format!(
-            " SonicKZG10::Check for AHP Verifier linear equations: {}",
-            evaluations_are_correct & proof_has_correct_zk_mode
-        ));
-        Ok(evaluations_are_correct & proof_has_correct_zk_mode)
+        end_timer!(verifier_time);
+        Ok(Some(pairing_check))
     }
 }
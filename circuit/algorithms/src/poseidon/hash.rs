@@ -114,4 +114,38 @@ mod tests {
         check_hash(Mode::Private, 9, 1, 0, 1060, 1060, &mut rng)?;
         check_hash(Mode::Private, 10, 1, 0, 1060, 1060, &mut rng)
     }
+
+    #[test]
+    fn test_hash_with_parameters() -> Result<()> {
+        use console::Hash as H;
+        use snarkvm_fields::{PoseidonDefaultField, PoseidonDefaultParametersEntry};
+
+        const WIDE_RATE: usize = 16;
+
+        let mut rng = TestRng::default();
+
+        // Initialize Poseidon with a rate that has no default parameters.
+        let entry = PoseidonDefaultParametersEntry::new(WIDE_RATE, 17, 8, 31, 0);
+        let parameters = <Circuit as Environment>::BaseField::poseidon_parameters::<WIDE_RATE>(&entry)?;
+        let native = console::Poseidon::<<Circuit as Environment>::Network, WIDE_RATE>::setup_with_parameters(
+            DOMAIN, parameters,
+        )?;
+        let poseidon = Poseidon::<Circuit, WIDE_RATE>::constant(native.clone());
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            // Prepare the preimage.
+            let native_input = (0..WIDE_RATE)
+                .map(|_| console::Field::<<Circuit as Environment>::Network>::rand(&mut rng))
+                .collect::<Vec<_>>();
+            let input = native_input.iter().map(|v| Field::<Circuit>::new(mode, *v)).collect::<Vec<_>>();
+
+            // Ensure the circuit hash matches the native hash.
+            let expected = native.hash(&native_input)?;
+            let candidate = poseidon.hash(&input);
+            assert_eq!(expected, candidate.eject_value());
+            assert!(Circuit::is_satisfied());
+            Circuit::reset();
+        }
+        Ok(())
+    }
 }
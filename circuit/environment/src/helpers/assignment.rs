@@ -13,10 +13,17 @@
 // limitations under the License.
 
 use crate::Index;
+use console::prelude::{bail, ensure, Result};
 use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{
+    error,
+    io::{Read, Result as IoResult, Write},
+    FromBytes,
+    ToBytes,
+};
 
 use indexmap::IndexMap;
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum AssignmentVariable<F: PrimeField> {
@@ -148,6 +155,239 @@ impl<F: PrimeField> Assignment<F> {
     }
 }
 
+impl<F: PrimeField> Assignment<F> {
+    /// Returns the constraints of the assignment, encoded in the `.r1cs` (version 1) binary format of circom.
+    /// Wire 0 is the constant one, followed by the public variables, and then the private variables.
+    pub fn to_r1cs_bytes(&self) -> Result<Vec<u8>> {
+        // Prepare the field header.
+        let modulus = F::modulus().to_bytes_le()?;
+        let num_wires = self.num_wires()?;
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        header.extend_from_slice(&u32::try_from(modulus.len())?.to_le_bytes());
+        header.extend_from_slice(&modulus);
+        header.extend_from_slice(&num_wires.to_le_bytes());
+        // The number of public outputs.
+        header.extend_from_slice(&0u32.to_le_bytes());
+        // The number of public inputs.
+        header.extend_from_slice(&u32::try_from(self.public.len())?.to_le_bytes());
+        // The number of private inputs.
+        header.extend_from_slice(&u32::try_from(self.private.len())?.to_le_bytes());
+        // The number of labels.
+        header.extend_from_slice(&u64::from(num_wires).to_le_bytes());
+        header.extend_from_slice(&u32::try_from(self.constraints.len())?.to_le_bytes());
+
+        // Construct the constraints section.
+        let mut constraints = Vec::new();
+        for (a, b, c) in self.constraints.iter() {
+            for lc in [a, b, c] {
+                let terms = self.to_wire_terms(lc)?;
+                constraints.extend_from_slice(&u32::try_from(terms.len())?.to_le_bytes());
+                for (wire, coefficient) in terms {
+                    constraints.extend_from_slice(&wire.to_le_bytes());
+                    constraints.extend_from_slice(&coefficient.to_bigint().to_bytes_le()?);
+                }
+            }
+        }
+
+        // Construct the wire-to-label section, where each wire is its own label.
+        let mut labels = Vec::with_capacity(num_wires as usize * 8);
+        for wire in 0..u64::from(num_wires) {
+            labels.extend_from_slice(&wire.to_le_bytes());
+        }
+
+        // Construct the file.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"r1cs");
+        bytes.extend_from_slice(&1u32.to_le_bytes());
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        write_section(&mut bytes, 1, &header);
+        write_section(&mut bytes, 2, &constraints);
+        write_section(&mut bytes, 3, &labels);
+        Ok(bytes)
+    }
+
+    /// Returns the witness of the assignment, encoded in the `.wtns` (version 2) binary format of circom.
+    /// The wires are ordered as in `Self::to_r1cs_bytes`.
+    pub fn to_wtns_bytes(&self) -> Result<Vec<u8>> {
+        // Prepare the field header.
+        let modulus = F::modulus().to_bytes_le()?;
+
+        // Construct the header section.
+        let mut header = Vec::new();
+        header.extend_from_slice(&u32::try_from(modulus.len())?.to_le_bytes());
+        header.extend_from_slice(&modulus);
+        header.extend_from_slice(&self.num_wires()?.to_le_bytes());
+
+        // Construct the witness section.
+        let mut witness = Vec::with_capacity((self.public.len() + self.private.len() + 1) * modulus.len());
+        witness.extend_from_slice(&F::one().to_bigint().to_bytes_le()?);
+        for (_, value) in self.public.iter().chain(self.private.iter()) {
+            witness.extend_from_slice(&value.to_bigint().to_bytes_le()?);
+        }
+
+        // Construct the file.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"wtns");
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        write_section(&mut bytes, 1, &header);
+        write_section(&mut bytes, 2, &witness);
+        Ok(bytes)
+    }
+
+    /// Returns the number of wires in the exported circuit, including the constant one.
+    fn num_wires(&self) -> Result<u32> {
+        Ok(u32::try_from(self.public.len() + self.private.len() + 1)?)
+    }
+
+    /// Returns the nonzero `(wire, coefficient)` terms of the given linear combination, ordered by wire.
+    fn to_wire_terms(&self, lc: &AssignmentLC<F>) -> Result<BTreeMap<u32, F>> {
+        let mut terms = BTreeMap::new();
+        // The constant term is assigned to the constant one wire.
+        if !lc.constant.is_zero() {
+            terms.insert(0u32, lc.constant);
+        }
+        for (variable, coefficient) in lc.terms.iter() {
+            let wire = match variable {
+                AssignmentVariable::Constant(_) => bail!("A linear combination cannot contain constant variables"),
+                AssignmentVariable::Public(index) => {
+                    ensure!(*index < self.num_public(), "Public variable {index} is out of bounds");
+                    u32::try_from(index + 1)?
+                }
+                AssignmentVariable::Private(index) => {
+                    ensure!(*index < self.num_private(), "Private variable {index} is out of bounds");
+                    u32::try_from(self.num_public() + index + 1)?
+                }
+            };
+            *terms.entry(wire).or_insert_with(F::zero) += coefficient;
+        }
+        // Remove any terms that cancelled out.
+        terms.retain(|_, coefficient| !coefficient.is_zero());
+        Ok(terms)
+    }
+}
+
+/// Appends a section, with the given type and contents, to the given bytes.
+fn write_section(bytes: &mut Vec<u8>, section_type: u32, section: &[u8]) {
+    bytes.extend_from_slice(&section_type.to_le_bytes());
+    bytes.extend_from_slice(&(section.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(section);
+}
+
+impl<F: PrimeField> ToBytes for AssignmentVariable<F> {
+    /// Writes the assignment variable to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        match self {
+            Self::Constant(value) => {
+                0u8.write_le(&mut writer)?;
+                value.write_le(&mut writer)
+            }
+            Self::Public(index) => {
+                1u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+            Self::Private(index) => {
+                2u8.write_le(&mut writer)?;
+                index.write_le(&mut writer)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentVariable<F> {
+    /// Reads the assignment variable from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        match u8::read_le(&mut reader)? {
+            0 => Ok(Self::Constant(F::read_le(&mut reader)?)),
+            1 => Ok(Self::Public(Index::read_le(&mut reader)?)),
+            2 => Ok(Self::Private(Index::read_le(&mut reader)?)),
+            _ => Err(error("Invalid assignment variable")),
+        }
+    }
+}
+
+impl<F: PrimeField> ToBytes for AssignmentLC<F> {
+    /// Writes the assignment linear combination to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.constant.write_le(&mut writer)?;
+        (self.terms.len() as u64).write_le(&mut writer)?;
+        for (variable, coefficient) in &self.terms {
+            variable.write_le(&mut writer)?;
+            coefficient.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for AssignmentLC<F> {
+    /// Reads the assignment linear combination from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let constant = F::read_le(&mut reader)?;
+        let num_terms = u64::read_le(&mut reader)?;
+        let terms = (0..num_terms)
+            .map(|_| Ok((AssignmentVariable::read_le(&mut reader)?, F::read_le(&mut reader)?)))
+            .collect::<IoResult<_>>()?;
+        Ok(Self { constant, terms })
+    }
+}
+
+impl<F: PrimeField> ToBytes for Assignment<F> {
+    /// Writes the assignment to the writer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the public and private variables.
+        for variables in [&self.public, &self.private] {
+            (variables.len() as u64).write_le(&mut writer)?;
+            for (index, value) in variables.iter() {
+                index.write_le(&mut writer)?;
+                value.write_le(&mut writer)?;
+            }
+        }
+        // Write the constraints.
+        (self.constraints.len() as u64).write_le(&mut writer)?;
+        for (a, b, c) in self.constraints.iter() {
+            a.write_le(&mut writer)?;
+            b.write_le(&mut writer)?;
+            c.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> FromBytes for Assignment<F> {
+    /// Reads the assignment from the reader.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid assignment version"));
+        }
+        // Read the public and private variables.
+        let mut read_variables = || -> IoResult<Arc<[(Index, F)]>> {
+            let num_variables = u64::read_le(&mut reader)?;
+            (0..num_variables).map(|_| Ok((Index::read_le(&mut reader)?, F::read_le(&mut reader)?))).collect()
+        };
+        let public = read_variables()?;
+        let private = read_variables()?;
+        // Read the constraints.
+        let num_constraints = u64::read_le(&mut reader)?;
+        let constraints = (0..num_constraints)
+            .map(|_| {
+                Ok((
+                    AssignmentLC::read_le(&mut reader)?,
+                    AssignmentLC::read_le(&mut reader)?,
+                    AssignmentLC::read_le(&mut reader)?,
+                ))
+            })
+            .collect::<IoResult<_>>()?;
+        Ok(Self { public, private, constraints })
+    }
+}
+
 impl<F: PrimeField> snarkvm_algorithms::r1cs::ConstraintSynthesizer<F> for Assignment<F> {
     /// Synthesizes the constraints from the environment into a `snarkvm_algorithms::r1cs`-compliant constraint system.
     fn generate_constraints<CS: snarkvm_algorithms::r1cs::ConstraintSystem<F>>(
@@ -316,6 +556,70 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_r1cs_export() {
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let assignment = Circuit::eject_assignment_and_reset();
+
+        let read_u32 = |bytes: &[u8], offset: usize| u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap());
+        let read_u64 = |bytes: &[u8], offset: usize| u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+
+        // The example circuit has the constant one wire, 2 public variables, and 129 private variables.
+        let num_wires = 1 + 2 + 129;
+
+        // Check the `.r1cs` file header.
+        let r1cs = assignment.to_r1cs_bytes().unwrap();
+        assert_eq!(b"r1cs", &r1cs[0..4]);
+        assert_eq!(1, read_u32(&r1cs, 4));
+        assert_eq!(3, read_u32(&r1cs, 8));
+        // Check the header section.
+        assert_eq!(1, read_u32(&r1cs, 12));
+        assert_eq!(32, read_u32(&r1cs, 24));
+        assert_eq!(num_wires, read_u32(&r1cs, 60));
+        assert_eq!(0, read_u32(&r1cs, 64));
+        assert_eq!(2, read_u32(&r1cs, 68));
+        assert_eq!(129, read_u32(&r1cs, 72));
+        assert_eq!(num_wires as u64, read_u64(&r1cs, 76));
+        assert_eq!(64, read_u32(&r1cs, 84));
+        // Check the constraints section follows the header section.
+        assert_eq!(2, read_u32(&r1cs, 88));
+
+        // Check the `.wtns` file.
+        let wtns = assignment.to_wtns_bytes().unwrap();
+        assert_eq!(b"wtns", &wtns[0..4]);
+        assert_eq!(2, read_u32(&wtns, 4));
+        assert_eq!(2, read_u32(&wtns, 8));
+        assert_eq!(32, read_u32(&wtns, 24));
+        assert_eq!(num_wires, read_u32(&wtns, 60));
+        assert_eq!(2, read_u32(&wtns, 64));
+        assert_eq!(num_wires as u64 * 32, read_u64(&wtns, 68));
+        assert_eq!(76 + num_wires as usize * 32, wtns.len());
+    }
+
+    #[test]
+    fn test_assignment_bytes() {
+        use snarkvm_circuit::environment::Assignment;
+        use snarkvm_utilities::{FromBytes, ToBytes};
+
+        let _candidate_output = create_example_circuit::<Circuit>();
+        let expected = Circuit::eject_assignment_and_reset();
+
+        // Ensure the assignment round trips.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        let candidate = Assignment::<Fr>::read_le(&expected_bytes[..]).unwrap();
+        assert_eq!(expected_bytes, candidate.to_bytes_le().unwrap());
+        assert_eq!(expected.to_r1cs_bytes().unwrap(), candidate.to_r1cs_bytes().unwrap());
+        assert_eq!(expected.to_wtns_bytes().unwrap(), candidate.to_wtns_bytes().unwrap());
+
+        // Ensure the deserialized assignment is satisfied.
+        let mut cs = snarkvm_algorithms::r1cs::TestConstraintSystem::new();
+        candidate.generate_constraints(&mut cs).unwrap();
+        assert!(cs.is_satisfied());
+
+        // Ensure a truncated assignment is rejected.
+        assert!(Assignment::<Fr>::read_le(&expected_bytes[..expected_bytes.len() - 1]).is_err());
+    }
+
     #[test]
     fn test_varuna() {
         let _candidate_output = create_example_circuit::<Circuit>();
@@ -117,3 +117,15 @@ impl<A: Aleo> ToBits for Plaintext<A> {
         }
     }
 }
+
+impl<A: Aleo> Plaintext<A> {
+    /// Returns the raw **little-endian** bits of this plaintext, without any variant or size metadata.
+    /// Literals are written as their own bits, while structs and arrays are written as the concatenation of their members.
+    pub fn to_bits_raw_le(&self) -> Vec<Boolean<A>> {
+        match self {
+            Self::Literal(literal, _) => literal.to_bits_le(),
+            Self::Struct(members, _) => members.values().flat_map(|member| member.to_bits_raw_le()).collect(),
+            Self::Array(elements, _) => elements.iter().flat_map(|element| element.to_bits_raw_le()).collect(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Self {
+        // Ensure the concatenation is within the allowed capacity.
+        let num_bytes = self.bytes.len().saturating_add(other.bytes.len());
+        if num_bytes > E::MAX_STRING_BYTES as usize {
+            E::halt(format!("Attempted to concatenate strings into {num_bytes} bytes"))
+        }
+        Self {
+            mode: Mode::combine(self.mode, [other.mode]),
+            bytes: self.bytes.iter().chain(&other.bytes).cloned().collect(),
+            size_in_bytes: &self.size_in_bytes + &other.size_in_bytes,
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_concat(mode_a: Mode, mode_b: Mode) {
+        let a = StringType::<Circuit>::new(mode_a, console::StringType::new("hello, "));
+        let b = StringType::<Circuit>::new(mode_b, console::StringType::new("world"));
+
+        Circuit::scope(format!("{mode_a} {mode_b}"), || {
+            let candidate = a.concat(&b);
+            assert_eq!(*candidate.eject_value(), *"hello, world");
+            assert_eq!(candidate.eject_mode(), Mode::combine(mode_a, [mode_b]));
+            assert_eq!(candidate.size_in_bytes.eject_value(), console::Field::from_u32(12));
+            assert_scope!(0, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_concat() {
+        check_concat(Mode::Constant, Mode::Constant);
+        check_concat(Mode::Constant, Mode::Public);
+        check_concat(Mode::Public, Mode::Private);
+        check_concat(Mode::Private, Mode::Private);
+    }
+}
@@ -15,8 +15,10 @@
 #![forbid(unsafe_code)]
 #![cfg_attr(test, allow(clippy::assertions_on_result_states))]
 
+mod concat;
 mod equal;
 mod helpers;
+mod slice;
 
 #[cfg(test)]
 use console::TestRng;
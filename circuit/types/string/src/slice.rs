@@ -0,0 +1,58 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring of `self` from the byte index `start` (inclusive) to `end` (exclusive).
+    pub fn slice(&self, start: usize, end: usize) -> Self {
+        match self.bytes.get(start..end) {
+            Some(bytes) => Self {
+                mode: self.mode,
+                bytes: bytes.to_vec(),
+                size_in_bytes: Field::constant(console::Field::from_u32(
+                    u32::try_from(bytes.len()).unwrap_or_else(|error| E::halt(error.to_string())),
+                )),
+            },
+            None => {
+                E::halt(format!("Attempted to slice bytes {start} to {end} of a string of {} bytes", self.bytes.len()))
+            }
+        }
+    }
+}
+
+#[cfg(all(test, console))]
+mod tests {
+    use super::*;
+    use snarkvm_circuit_environment::Circuit;
+
+    fn check_slice(mode: Mode) {
+        let string = StringType::<Circuit>::new(mode, console::StringType::new("hello, world"));
+
+        Circuit::scope(format!("{mode}"), || {
+            let candidate = string.slice(7, 12);
+            assert_eq!(*candidate.eject_value(), *"world");
+            assert_eq!(candidate.eject_mode(), mode);
+            assert_scope!(1, 0, 0, 0);
+        });
+        Circuit::reset();
+    }
+
+    #[test]
+    fn test_slice() {
+        check_slice(Mode::Constant);
+        check_slice(Mode::Public);
+        check_slice(Mode::Private);
+    }
+}
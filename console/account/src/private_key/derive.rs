@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+static ACCOUNT_CHILD_SEED_DOMAIN: &str = "AleoAccountChildSeed0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the child private key at the given index, using hardened derivation.
+    /// The child seed is a hash of the parent seed and the index, so a child private key
+    /// cannot be used to recover its parent private key, nor any of its siblings.
+    #[inline]
+    pub fn derive_child(&self, index: u32) -> Result<Self> {
+        // Construct the child seed domain separator.
+        let domain = Field::<N>::new_domain_separator(ACCOUNT_CHILD_SEED_DOMAIN);
+        // Compute the child seed.
+        let child_seed = N::hash_psd2(&[domain, self.seed, Field::from_u32(index)])?;
+        // Return the child private key.
+        Self::try_from(child_seed)
+    }
+
+    /// Returns the descendant private key at the given path of indices, using hardened derivation at each level.
+    /// An empty path returns the private key itself.
+    #[inline]
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self> {
+        path.iter().try_fold(*self, |private_key, index| private_key.derive_child(*index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_derive_child() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new master private key.
+            let master = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the derivation is deterministic.
+            let index = rng.gen();
+            let child = master.derive_child(index)?;
+            assert_eq!(child, master.derive_child(index)?);
+            assert_eq!(child, PrivateKey::try_from(child.seed())?);
+
+            // Ensure distinct indices and parents derive distinct children.
+            assert_ne!(child, master);
+            assert_ne!(child, master.derive_child(index.wrapping_add(1))?);
+            assert_ne!(child, PrivateKey::<CurrentNetwork>::new(&mut rng)?.derive_child(index)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_derive_path() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new master private key.
+            let master = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the path derivation matches the chained child derivation.
+            let (first, second) = (rng.gen(), rng.gen());
+            let expected = master.derive_child(first)?.derive_child(second)?;
+            assert_eq!(expected, master.derive_path(&[first, second])?);
+
+            // Ensure the path is ordered.
+            if first != second {
+                assert_ne!(expected, master.derive_path(&[second, first])?);
+            }
+
+            // Ensure an empty path returns the master private key.
+            assert_eq!(master, master.derive_path(&[])?);
+        }
+        Ok(())
+    }
+}
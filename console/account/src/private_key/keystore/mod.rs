@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use openssl::{
+    pkcs5::scrypt,
+    symm::{decrypt_aead, encrypt_aead, Cipher},
+};
+use zeroize::Zeroizing;
+
+/// The version of the keystore format.
+const KEYSTORE_VERSION: u64 = 2;
+/// The default scrypt cost parameter, as a power of two.
+const DEFAULT_SCRYPT_LOG_N: u8 = 15;
+/// The default scrypt block size.
+const DEFAULT_SCRYPT_R: u32 = 8;
+/// The default scrypt parallelization parameter.
+const DEFAULT_SCRYPT_P: u32 = 1;
+/// The maximum scrypt cost parameter accepted when decrypting, as a power of two.
+const MAX_SCRYPT_LOG_N: u8 = 20;
+/// The maximum scrypt block size or parallelization parameter accepted when decrypting.
+const MAX_SCRYPT_R_OR_P: u32 = 8;
+/// The size of the ChaCha20-Poly1305 nonce, in bytes.
+const NONCE_SIZE: usize = 12;
+/// The size of the ChaCha20-Poly1305 authentication tag, in bytes.
+const TAG_SIZE: usize = 16;
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key encrypted under the given password, as a JSON keystore.
+    /// The encryption key is derived with scrypt, and the private key is encrypted with ChaCha20-Poly1305.
+    pub fn to_ciphertext<R: Rng + CryptoRng>(&self, password: &str, rng: &mut R) -> Result<String> {
+        self.encrypt_with_parameters(password, DEFAULT_SCRYPT_LOG_N, DEFAULT_SCRYPT_R, DEFAULT_SCRYPT_P, rng)
+    }
+
+    /// Returns the private key decrypted from the given JSON keystore, using the given password.
+    pub fn from_ciphertext(ciphertext: &str, password: &str) -> Result<Self> {
+        let keystore: serde_json::Value = serde_json::from_str(ciphertext)?;
+
+        // Ensure the version and algorithms are supported.
+        ensure!(keystore["version"].as_u64() == Some(KEYSTORE_VERSION), "Unsupported keystore version");
+        ensure!(keystore["kdf"].as_str() == Some("scrypt"), "Unsupported keystore key derivation function");
+        ensure!(keystore["cipher"].as_str() == Some("chacha20-poly1305"), "Unsupported keystore cipher");
+
+        // Retrieve the key derivation parameters, and ensure they are within bounds.
+        let parameter = |name: &str| {
+            keystore["kdfparams"][name].as_u64().ok_or_else(|| anyhow!("Missing keystore parameter '{name}'"))
+        };
+        let (log_n, r, p) =
+            (u8::try_from(parameter("log_n")?)?, u32::try_from(parameter("r")?)?, u32::try_from(parameter("p")?)?);
+        ensure!((1..=MAX_SCRYPT_LOG_N).contains(&log_n), "Invalid scrypt cost parameter");
+        ensure!((1..=MAX_SCRYPT_R_OR_P).contains(&r), "Invalid scrypt block size");
+        ensure!((1..=MAX_SCRYPT_R_OR_P).contains(&p), "Invalid scrypt parallelization parameter");
+
+        // Retrieve the salt, nonce, ciphertext, and tag.
+        let bytes =
+            |name: &str| from_hex(keystore[name].as_str().ok_or_else(|| anyhow!("Missing keystore field '{name}'"))?);
+        let (salt, nonce, encrypted, tag) = (bytes("salt")?, bytes("nonce")?, bytes("ciphertext")?, bytes("tag")?);
+        ensure!(nonce.len() == NONCE_SIZE, "Invalid keystore nonce");
+        ensure!(tag.len() == TAG_SIZE, "Invalid keystore tag");
+
+        // Derive the encryption key.
+        let key = derive_key(password, &salt, log_n, r, p)?;
+        // Decrypt the private key, authenticating the keystore parameters and the ciphertext.
+        let aad = associated_data((log_n, r, p), &salt);
+        let plaintext = decrypt_aead(Cipher::chacha20_poly1305(), &*key, Some(&nonce), &aad, &encrypted, &tag)
+            .map(Zeroizing::new)
+            .map_err(|_| anyhow!("Incorrect password, or the keystore is corrupted"))?;
+        Self::from_bytes_le(&plaintext)
+    }
+
+    /// Returns the private key encrypted under the given password, using the given scrypt parameters.
+    fn encrypt_with_parameters<R: Rng + CryptoRng>(
+        &self,
+        password: &str,
+        log_n: u8,
+        r: u32,
+        p: u32,
+        rng: &mut R,
+    ) -> Result<String> {
+        // Sample the salt and nonce.
+        let salt: [u8; 32] = rng.gen();
+        let nonce: [u8; NONCE_SIZE] = rng.gen();
+
+        // Derive the encryption key.
+        let key = derive_key(password, &salt, log_n, r, p)?;
+        // Encrypt the private key, authenticating the keystore parameters.
+        let plaintext = Zeroizing::new(self.to_bytes_le()?);
+        let aad = associated_data((log_n, r, p), &salt);
+        let mut tag = [0u8; TAG_SIZE];
+        let encrypted = encrypt_aead(Cipher::chacha20_poly1305(), &*key, Some(&nonce), &aad, &plaintext, &mut tag)?;
+
+        // Construct the keystore.
+        let keystore = serde_json::json!({
+            "version": KEYSTORE_VERSION,
+            "kdf": "scrypt",
+            "kdfparams": { "log_n": log_n, "r": r, "p": p },
+            "salt": to_hex(&salt),
+            "cipher": "chacha20-poly1305",
+            "nonce": to_hex(&nonce),
+            "ciphertext": to_hex(&encrypted),
+            "tag": to_hex(&tag),
+        });
+        Ok(keystore.to_string())
+    }
+}
+
+/// Returns the encryption key derived from the given password and salt, with scrypt.
+fn derive_key(password: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<Zeroizing<[u8; 32]>> {
+    let (n, r, p) = (1u64 << log_n, u64::from(r), u64::from(p));
+    // Allow exactly the memory needed by scrypt, which is `128 * r * (n + p + 2)` bytes.
+    let max_memory = 128 * r * (n + p + 2);
+    let mut key = Zeroizing::new([0u8; 32]);
+    scrypt(password.as_bytes(), salt, n, r, p, max_memory, &mut *key)?;
+    Ok(key)
+}
+
+/// Returns the associated data that binds the ciphertext to the keystore parameters.
+fn associated_data((log_n, r, p): (u8, u32, u32), salt: &[u8]) -> Vec<u8> {
+    [&KEYSTORE_VERSION.to_le_bytes()[..], &[log_n], &r.to_le_bytes(), &p.to_le_bytes(), salt].concat()
+}
+
+/// Returns the lowercase hex encoding of the given bytes.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Returns the bytes of the given hex string.
+fn from_hex(hex: &str) -> Result<Vec<u8>> {
+    ensure!(hex.len() % 2 == 0 && hex.is_ascii(), "Invalid hex string");
+    (0..hex.len()).step_by(2).map(|i| Ok(u8::from_str_radix(&hex[i..i + 2], 16)?)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 10;
+
+    #[test]
+    fn test_ciphertext() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the private key round trips, with low scrypt parameters for speed.
+            let ciphertext = expected.encrypt_with_parameters("password", 4, 1, 1, &mut rng)?;
+            assert_eq!(expected, PrivateKey::from_ciphertext(&ciphertext, "password")?);
+
+            // Ensure an incorrect password is rejected.
+            assert!(PrivateKey::<CurrentNetwork>::from_ciphertext(&ciphertext, "passw0rd").is_err());
+
+            // Ensure a tampered ciphertext is rejected.
+            let mut keystore: serde_json::Value = serde_json::from_str(&ciphertext)?;
+            let mut encrypted = from_hex(keystore["ciphertext"].as_str().unwrap())?;
+            encrypted[0] ^= 1;
+            keystore["ciphertext"] = serde_json::Value::from(to_hex(&encrypted));
+            assert!(PrivateKey::<CurrentNetwork>::from_ciphertext(&keystore.to_string(), "password").is_err());
+
+            // Ensure tampered parameters are rejected.
+            let mut keystore: serde_json::Value = serde_json::from_str(&ciphertext)?;
+            keystore["kdfparams"]["r"] = serde_json::Value::from(2);
+            assert!(PrivateKey::<CurrentNetwork>::from_ciphertext(&keystore.to_string(), "password").is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ciphertext_with_default_parameters() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new private key.
+        let expected = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let ciphertext = expected.to_ciphertext("password", &mut rng)?;
+
+        // Ensure the keystore does not contain the private key.
+        assert!(!ciphertext.contains(&to_hex(&expected.to_bytes_le()?)));
+        assert!(!ciphertext.contains(&expected.to_string()));
+        // Ensure the private key round trips.
+        assert_eq!(expected, PrivateKey::from_ciphertext(&ciphertext, "password")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ciphertext_rejects_excessive_parameters() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample a new private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+        let ciphertext = private_key.encrypt_with_parameters("password", 4, 1, 1, &mut rng)?;
+
+        // Ensure a keystore demanding excessive memory is rejected before any key derivation.
+        let mut keystore: serde_json::Value = serde_json::from_str(&ciphertext)?;
+        keystore["kdfparams"]["log_n"] = serde_json::Value::from(MAX_SCRYPT_LOG_N + 1);
+        assert!(PrivateKey::<CurrentNetwork>::from_ciphertext(&keystore.to_string(), "password").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+/// The BIP-39 English wordlist.
+static WORDLIST: &str = include_str!("english.txt");
+
+/// The number of bits encoded by each word.
+const BITS_PER_WORD: usize = 11;
+
+static ACCOUNT_MNEMONIC_PASSPHRASE_DOMAIN: &str = "AleoAccountMnemonicPassphrase0";
+
+impl<N: Network> PrivateKey<N> {
+    /// Returns the private key from the given mnemonic phrase and (optionally empty) passphrase.
+    /// The entropy of the phrase is the little-endian account seed, reduced modulo the field order.
+    /// A non-empty passphrase derives a distinct account from the same phrase.
+    ///
+    /// Note: The phrase uses the BIP-39 wordlist and checksum, but not the BIP-39 seed derivation,
+    /// so that every private key has a phrase. A phrase does not derive the same keys as in BIP-39 wallets.
+    pub fn from_mnemonic(phrase: &str, passphrase: &str) -> Result<Self> {
+        // Decode the entropy from the phrase.
+        let entropy = mnemonic_to_entropy(phrase)?;
+        // Compute the account seed.
+        let seed = Field::<N>::new(N::Field::from_bytes_le_mod_order(&entropy));
+        match passphrase.is_empty() {
+            true => Self::try_from(seed),
+            false => {
+                // Construct the passphrase domain separator.
+                let domain = Field::<N>::new_domain_separator(ACCOUNT_MNEMONIC_PASSPHRASE_DOMAIN);
+                // Encode the passphrase as field elements, in chunks that fit in the field.
+                let mut input = vec![domain, seed, Field::from_u64(passphrase.len() as u64)];
+                input.extend(
+                    passphrase.as_bytes().chunks(31).map(|chunk| Field::new(N::Field::from_bytes_le_mod_order(chunk))),
+                );
+                // Compute the account seed for the passphrase.
+                Self::try_from(N::hash_psd2(&input)?)
+            }
+        }
+    }
+
+    /// Returns the 24-word mnemonic phrase that encodes the account seed.
+    /// The private key is recovered with `PrivateKey::from_mnemonic`, using an empty passphrase.
+    pub fn to_mnemonic(&self) -> Result<String> {
+        entropy_to_mnemonic(&self.seed.to_bytes_le()?)
+    }
+}
+
+/// Returns the word at the given index in the wordlist.
+fn word(index: usize) -> Result<&'static str> {
+    WORDLIST.lines().nth(index).ok_or_else(|| anyhow!("Invalid mnemonic word index {index}"))
+}
+
+/// Returns the mnemonic phrase for the given entropy, which must be 16, 20, 24, 28, or 32 bytes.
+fn entropy_to_mnemonic(entropy: &[u8]) -> Result<String> {
+    // Ensure the entropy is of a valid length.
+    ensure!(matches!(entropy.len(), 16 | 20 | 24 | 28 | 32), "Invalid mnemonic entropy length ({})", entropy.len());
+
+    // Append the checksum, which is the first `entropy.len() / 4` bits of the SHA-256 hash of the entropy.
+    let checksum = Sha256::digest(entropy);
+    let num_checksum_bits = entropy.len() / 4;
+    let bits = entropy
+        .iter()
+        .flat_map(|byte| (0..8).rev().map(move |i| (byte >> i) & 1 == 1))
+        .chain((0..num_checksum_bits).map(|i| (checksum[i / 8] >> (7 - i % 8)) & 1 == 1))
+        .collect::<Vec<_>>();
+
+    // Encode each group of bits as a word, in big-endian order.
+    bits.chunks(BITS_PER_WORD)
+        .map(|chunk| word(chunk.iter().fold(0usize, |index, bit| (index << 1) | *bit as usize)))
+        .collect::<Result<Vec<_>>>()
+        .map(|words| words.join(" "))
+}
+
+/// Returns the entropy of the given mnemonic phrase, after checking its checksum.
+fn mnemonic_to_entropy(phrase: &str) -> Result<Vec<u8>> {
+    let words = phrase.split_whitespace().collect::<Vec<_>>();
+    // Ensure the phrase has a valid number of words.
+    ensure!(matches!(words.len(), 12 | 15 | 18 | 21 | 24), "Invalid mnemonic length ({} words)", words.len());
+
+    // Decode each word into its bits, in big-endian order.
+    let mut bits = Vec::with_capacity(words.len() * BITS_PER_WORD);
+    for word in words {
+        let Some(index) = WORDLIST.lines().position(|candidate| candidate == word) else {
+            bail!("Invalid mnemonic word '{word}'")
+        };
+        bits.extend((0..BITS_PER_WORD).rev().map(|i| (index >> i) & 1 == 1));
+    }
+
+    // Split the bits into the entropy and the checksum.
+    let num_checksum_bits = bits.len() / 33;
+    let (entropy_bits, checksum_bits) = bits.split_at(bits.len() - num_checksum_bits);
+    let entropy = entropy_bits
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |byte, bit| (byte << 1) | *bit as u8))
+        .collect::<Vec<_>>();
+
+    // Ensure the checksum is valid.
+    let checksum = Sha256::digest(&entropy);
+    let is_valid = checksum_bits.iter().enumerate().all(|(i, bit)| ((checksum[i / 8] >> (7 - i % 8)) & 1 == 1) == *bit);
+    ensure!(is_valid, "Invalid mnemonic checksum");
+    Ok(entropy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    /// The `(entropy, mnemonic)` test vectors from the BIP-39 reference implementation.
+    const TEST_VECTORS: &[(&str, &str)] = &[
+        (
+            "00000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+        ),
+        ("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f", "legal winner thank year wave sausage worth useful legal winner thank yellow"),
+        (
+            "80808080808080808080808080808080",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+        ),
+        ("ffffffffffffffffffffffffffffffff", "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"),
+        ("9e885d952ad362caeb4efe34a8e91bd2", "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic"),
+        ("c0ba5a8e914111210f2bd131f3d5e08d", "scheme spot photo card baby mountain device kick cradle pact join borrow"),
+        ("f30f8c1da665478f49b001d94c5fc452", "vessel ladder alter error federal sibling chat ability sun glass valve picture"),
+        ("0460ef47585604c5660618db2e6a7e7f", "afford alter spike radar gate glance object seek swamp infant panel yellow"),
+        ("eaebabb2383351fd31d703840b32e9e2", "turtle front uncle idea crush write shrug there lottery flower risk shell"),
+        ("18ab19a9f54a9274f03e5209a2ac8a91", "board flee heavy tunnel powder denial science ski answer betray cargo cat"),
+        (
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art",
+        ),
+        (
+            "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+            "legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth useful legal winner thank year wave sausage worth title",
+        ),
+        (
+            "8080808080808080808080808080808080808080808080808080808080808080",
+            "letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic avoid letter advice cage absurd amount doctor acoustic bless",
+        ),
+        (
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+        ),
+        (
+            "68a79eaca2324873eacc50cb9c6eca8cc68ea5d936f98787c60c7ebc74e6ce7c",
+            "hamster diagram private dutch cause delay private meat slide toddler razor book happy fancy gospel tennis maple dilemma loan word shrug inflict delay length",
+        ),
+        (
+            "9f6a2878b2520799a44ef18bc7df394e7061a224d2c33cd015b157d746869863",
+            "panda eyebrow bullet gorilla call smoke muffin taste mesh discover soft ostrich alcohol speed nation flash devote level hobby quick inner drive ghost inside",
+        ),
+    ];
+
+    /// Returns the bytes of the given hex string.
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len()).step_by(2).map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_wordlist() {
+        let words = WORDLIST.lines().collect::<Vec<_>>();
+        assert_eq!(1 << BITS_PER_WORD, words.len());
+        // Ensure the words are sorted and unique.
+        assert!(words.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_mnemonic_test_vectors() -> Result<()> {
+        for (entropy, mnemonic) in TEST_VECTORS {
+            let entropy = from_hex(entropy);
+            assert_eq!(*mnemonic, entropy_to_mnemonic(&entropy)?);
+            assert_eq!(entropy, mnemonic_to_entropy(mnemonic)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_mnemonic() {
+        let prefix = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+
+        // Ensure an invalid checksum is rejected.
+        assert!(mnemonic_to_entropy(&format!("{prefix} abandon abandon")).is_err());
+        // Ensure an unknown word is rejected.
+        assert!(mnemonic_to_entropy(&format!("{prefix} abandon aleo")).is_err());
+        // Ensure an invalid number of words is rejected.
+        assert!(mnemonic_to_entropy(&format!("{prefix} about")).is_err());
+        // Ensure an invalid entropy length is rejected.
+        assert!(entropy_to_mnemonic(&[0u8; 15]).is_err());
+    }
+
+    #[test]
+    fn test_private_key_mnemonic() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for _ in 0..ITERATIONS {
+            // Sample a new private key.
+            let expected = PrivateKey::<CurrentNetwork>::new(&mut rng)?;
+
+            // Ensure the private key round trips through its mnemonic.
+            let mnemonic = expected.to_mnemonic()?;
+            assert_eq!(24, mnemonic.split_whitespace().count());
+            assert_eq!(expected, PrivateKey::from_mnemonic(&mnemonic, "")?);
+
+            // Ensure a passphrase derives a distinct, deterministic private key.
+            let candidate = PrivateKey::<CurrentNetwork>::from_mnemonic(&mnemonic, "passphrase")?;
+            assert_ne!(expected, candidate);
+            assert_eq!(candidate, PrivateKey::from_mnemonic(&mnemonic, "passphrase")?);
+            assert_ne!(candidate, PrivateKey::from_mnemonic(&mnemonic, "passphrase ")?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_private_key_from_12_word_mnemonic() -> Result<()> {
+        // Ensure a 12-word mnemonic derives a private key.
+        let (_, mnemonic) = TEST_VECTORS[4];
+        let private_key = PrivateKey::<CurrentNetwork>::from_mnemonic(mnemonic, "")?;
+        assert_eq!(private_key, PrivateKey::from_mnemonic(&format!("  {mnemonic} "), "")?);
+        assert_ne!(private_key, PrivateKey::from_mnemonic(TEST_VECTORS[5].1, "")?);
+        Ok(())
+    }
+}
@@ -13,10 +13,17 @@
 // limitations under the License.
 
 mod bytes;
+mod derive;
 mod serialize;
 mod string;
 mod try_from;
 
+#[cfg(feature = "keystore")]
+mod keystore;
+
+#[cfg(feature = "mnemonic")]
+mod mnemonic;
+
 #[cfg(feature = "signature")]
 mod sign;
 
@@ -14,6 +14,9 @@
 
 use super::*;
 
+#[cfg(not(feature = "serial"))]
+use rayon::prelude::*;
+
 impl<N: Network> Signature<N> {
     /// Verifies (challenge == challenge') && (address == address') where:
     ///     challenge' := HashToScalar(G^response pk_sig^challenge, pk_sig, pr_sig, address, message)
@@ -57,6 +60,17 @@ impl<N: Network> Signature<N> {
         self.challenge == candidate_challenge && *address == candidate_address
     }
 
+    /// Verifies the given batch of (address, message, signature) triples,
+    /// and returns `true` if every signature is valid for its address and message.
+    ///
+    /// The signatures are verified in parallel. Note that each signature commits to `G^r` through
+    /// its challenge hash, so `G^r` must be recomputed for each signature, and the batch cannot be
+    /// folded into a single multi-scalar multiplication.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch(batch: &[(Address<N>, &[Field<N>], Self)]) -> bool {
+        cfg_iter!(batch).all(|(address, message, signature)| signature.verify(address, message))
+    }
+
     /// Verifies a signature for the given address and message (as bytes).
     pub fn verify_bytes(&self, address: &Address<N>, message: &[u8]) -> bool {
         // Convert the message into bits, and verify the signature.
@@ -109,6 +123,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_verify_batch() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Sample a batch of signatures.
+        let mut messages = Vec::new();
+        let mut signers = Vec::new();
+        for i in 0..ITERATIONS {
+            let private_key = PrivateKey::<CurrentNetwork>::new(rng)?;
+            let message: Vec<Field<CurrentNetwork>> = (0..i % 8).map(|_| Uniform::rand(rng)).collect();
+            let signature = Signature::sign(&private_key, &message, rng)?;
+            signers.push((Address::try_from(&private_key)?, signature));
+            messages.push(message);
+        }
+        let mut batch: Vec<_> = signers
+            .iter()
+            .zip(&messages)
+            .map(|((address, signature), message)| (*address, message.as_slice(), *signature))
+            .collect();
+
+        // Check that the batch is valid.
+        assert!(Signature::verify_batch(&batch));
+        assert!(Signature::<CurrentNetwork>::verify_batch(&[]));
+
+        // Check that the batch is invalid if a single signature is for another address.
+        let index = rng.gen_range(0..batch.len());
+        batch[index].0 = batch[(index + 1) % batch.len()].0;
+        assert!(!Signature::verify_batch(&batch));
+        Ok(())
+    }
+
     #[test]
     fn test_sign_and_verify_bytes() -> Result<()> {
         let rng = &mut TestRng::default();
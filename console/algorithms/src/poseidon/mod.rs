@@ -46,15 +46,36 @@ pub struct Poseidon<E: Environment, const RATE: usize> {
 impl<E: Environment, const RATE: usize> Poseidon<E, RATE> {
     /// Initializes a new instance of Poseidon.
     pub fn setup(domain: &str) -> Result<Self> {
+        Self::setup_with_parameters(domain, E::Field::default_poseidon_parameters::<RATE>()?)
+    }
+
+    /// Initializes a new instance of Poseidon with the given parameters.
+    /// This enables rates without default parameters, with parameters from `PoseidonDefaultField::poseidon_parameters`.
+    pub fn setup_with_parameters(
+        domain: &str,
+        parameters: PoseidonParameters<E::Field, RATE, CAPACITY>,
+    ) -> Result<Self> {
         // Ensure the given domain is within the allowed size in bits.
         let num_bits = domain.len().saturating_mul(8);
         let max_bits = Field::<E>::size_in_data_bits();
         ensure!(num_bits <= max_bits, "Domain cannot exceed {max_bits} bits, found {num_bits} bits");
 
-        Ok(Self {
-            domain: Field::<E>::new_domain_separator(domain),
-            parameters: Arc::new(E::Field::default_poseidon_parameters::<RATE>()?),
-        })
+        // Ensure the parameters are consistent with the rate.
+        ensure!(RATE > 0, "The Poseidon rate must be greater than zero");
+        let num_rounds = parameters.full_rounds + parameters.partial_rounds;
+        ensure!(parameters.ark.len() == num_rounds, "Expected {num_rounds} rounds of Poseidon round keys");
+        ensure!(
+            parameters.ark.iter().all(|keys| keys.len() == RATE + CAPACITY),
+            "Poseidon round keys must have {} elements per round",
+            RATE + CAPACITY
+        );
+        ensure!(
+            parameters.mds.len() == RATE + CAPACITY && parameters.mds.iter().all(|row| row.len() == RATE + CAPACITY),
+            "The Poseidon MDS matrix must be of size {0}x{0}",
+            RATE + CAPACITY
+        );
+
+        Ok(Self { domain: Field::<E>::new_domain_separator(domain), parameters: Arc::new(parameters) })
     }
 
     /// Returns the domain separator for the hash function.
@@ -73,7 +94,7 @@ mod tests {
     use super::*;
     use snarkvm_console_types::environment::Console;
     use snarkvm_curves::edwards_bls12::Fq;
-    use snarkvm_fields::{PoseidonDefaultField, PoseidonGrainLFSR};
+    use snarkvm_fields::{PoseidonDefaultField, PoseidonDefaultParametersEntry, PoseidonGrainLFSR};
 
     type CurrentEnvironment = Console;
 
@@ -170,4 +191,27 @@ mod tests {
         single_rate_test::<7>();
         single_rate_test::<8>();
     }
+
+    #[test]
+    fn test_setup_with_parameters() -> Result<()> {
+        const DOMAIN: &str = "PoseidonTest";
+
+        // Ensure the default parameters match the default setup.
+        let expected = Poseidon::<CurrentEnvironment, 8>::setup(DOMAIN)?;
+        let candidate =
+            Poseidon::<CurrentEnvironment, 8>::setup_with_parameters(DOMAIN, Fq::default_poseidon_parameters::<8>()?)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a rate without default parameters can be instantiated.
+        let entry = PoseidonDefaultParametersEntry::new(16, 17, 8, 31, 0);
+        let parameters = Fq::poseidon_parameters::<16>(&entry)?;
+        let poseidon = Poseidon::<CurrentEnvironment, 16>::setup_with_parameters(DOMAIN, parameters)?;
+        let input = (0..16).map(Field::<CurrentEnvironment>::from_u64).collect::<Vec<_>>();
+        assert_eq!(poseidon.hash(&input)?, poseidon.hash(&input)?);
+        assert_ne!(poseidon.hash(&input)?, poseidon.hash(&input[..15])?);
+
+        // Ensure an entry with a mismatched rate is rejected.
+        assert!(Fq::poseidon_parameters::<16>(&PoseidonDefaultParametersEntry::new(8, 17, 8, 31, 0)).is_err());
+        Ok(())
+    }
 }
@@ -28,7 +28,7 @@ pub mod prelude {
     pub use crate::{environment::*, helpers::*, traits::*};
 
     pub use snarkvm_curves::{AffineCurve, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters};
-    pub use snarkvm_fields::{Field as _, PrimeField as _, SquareRootField as _, Zero as _};
+    pub use snarkvm_fields::{batch_inversion, Field as _, PrimeField as _, SquareRootField as _, Zero as _};
     pub use snarkvm_utilities::{
         cfg_chunks,
         cfg_find,
@@ -41,6 +41,7 @@ pub mod prelude {
         error,
         has_duplicates,
         io::{Read, Result as IoResult, Write},
+        ByteCounter,
         DeserializeExt,
         FromBits as _,
         FromBytes,
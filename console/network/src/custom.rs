@@ -0,0 +1,243 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use core::marker::PhantomData;
+
+/// The configuration of a custom network, such as a private network operated by an enterprise.
+///
+/// A custom network shares the cryptographic parameters (and `credits.aleo` keys) of `Testnet3`,
+/// and overrides its chain identifier, genesis block, fee schedule, and block-time constants.
+///
+/// The cryptographic parameters are expensive to compute, and must be memoized by the implementor:
+/// ```ignore
+/// fn parameters() -> &'static NetworkParameters<CustomNetwork<Self>> {
+///     static INSTANCE: OnceCell<NetworkParameters<CustomNetwork<MyConfig>>> = OnceCell::new();
+///     INSTANCE.get_or_init(|| NetworkParameters::setup().expect("Failed to setup the network parameters"))
+/// }
+/// ```
+pub trait CustomNetworkConfig:
+    'static + Copy + Clone + Debug + Default + Eq + PartialEq + core::hash::Hash + Send + Sync
+{
+    /// The network ID, which must be distinct from the ID of any other network.
+    const ID: u16;
+    /// The network name.
+    const NAME: &'static str;
+    /// The network edition.
+    const EDITION: u16 = 0;
+
+    /// The fixed timestamp of the genesis block.
+    const GENESIS_TIMESTAMP: i64 = Testnet3::GENESIS_TIMESTAMP;
+    /// The genesis block coinbase target.
+    const GENESIS_COINBASE_TARGET: u64 = Testnet3::GENESIS_COINBASE_TARGET;
+    /// The genesis block proof target.
+    const GENESIS_PROOF_TARGET: u64 = Testnet3::GENESIS_PROOF_TARGET;
+
+    /// The starting supply of Aleo credits.
+    const STARTING_SUPPLY: u64 = Testnet3::STARTING_SUPPLY;
+    /// The cost in microcredits per byte for the deployment transaction.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = Testnet3::DEPLOYMENT_FEE_MULTIPLIER;
+    /// The maximum number of microcredits that can be spent as a fee.
+    const MAX_FEE: u64 = Testnet3::MAX_FEE;
+
+    /// The anchor time in seconds.
+    const ANCHOR_TIME: u16 = Testnet3::ANCHOR_TIME;
+    /// The expected time per block in seconds.
+    const BLOCK_TIME: u16 = Testnet3::BLOCK_TIME;
+
+    /// Returns the genesis block bytes.
+    fn genesis_bytes() -> &'static [u8];
+
+    /// Returns the (memoized) cryptographic parameters of the network.
+    fn parameters() -> &'static NetworkParameters<CustomNetwork<Self>>;
+}
+
+/// A custom network, parameterized by its configuration.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CustomNetwork<C: CustomNetworkConfig>(PhantomData<C>);
+
+impl<C: CustomNetworkConfig> CustomNetwork<C> {
+    /// Returns the cryptographic parameters of the network.
+    pub fn parameters() -> &'static NetworkParameters<Self> {
+        C::parameters()
+    }
+}
+
+impl<C: CustomNetworkConfig> Environment for CustomNetwork<C> {
+    type Affine = <Console as Environment>::Affine;
+    type BigInteger = <Console as Environment>::BigInteger;
+    type Field = <Console as Environment>::Field;
+    type PairingCurve = <Console as Environment>::PairingCurve;
+    type Projective = <Console as Environment>::Projective;
+    type Scalar = <Console as Environment>::Scalar;
+
+    /// The coefficient `A` of the twisted Edwards curve.
+    const EDWARDS_A: Self::Field = Console::EDWARDS_A;
+    /// The coefficient `D` of the twisted Edwards curve.
+    const EDWARDS_D: Self::Field = Console::EDWARDS_D;
+    /// The coefficient `A` of the Montgomery curve.
+    const MONTGOMERY_A: Self::Field = Console::MONTGOMERY_A;
+    /// The coefficient `B` of the Montgomery curve.
+    const MONTGOMERY_B: Self::Field = Console::MONTGOMERY_B;
+}
+
+impl<C: CustomNetworkConfig> Network for CustomNetwork<C> {
+    /// The block hash type.
+    type BlockHash = AleoID<Field<Self>, { hrp2!("ab") }>;
+    /// The ratification ID type.
+    type RatificationID = AleoID<Field<Self>, { hrp2!("ar") }>;
+    /// The state root type.
+    type StateRoot = AleoID<Field<Self>, { hrp2!("sr") }>;
+    /// The transaction ID type.
+    type TransactionID = AleoID<Field<Self>, { hrp2!(TRANSACTION_PREFIX) }>;
+    /// The transition ID type.
+    type TransitionID = AleoID<Field<Self>, { hrp2!("au") }>;
+
+    /// The anchor time in seconds.
+    const ANCHOR_TIME: u16 = C::ANCHOR_TIME;
+    /// The expected time per block in seconds.
+    const BLOCK_TIME: u16 = C::BLOCK_TIME;
+    /// The cost in microcredits per byte for the deployment transaction.
+    const DEPLOYMENT_FEE_MULTIPLIER: u64 = C::DEPLOYMENT_FEE_MULTIPLIER;
+    /// The network edition.
+    const EDITION: u16 = C::EDITION;
+    /// The genesis block coinbase target.
+    const GENESIS_COINBASE_TARGET: u64 = C::GENESIS_COINBASE_TARGET;
+    /// The genesis block proof target.
+    const GENESIS_PROOF_TARGET: u64 = C::GENESIS_PROOF_TARGET;
+    /// The fixed timestamp of the genesis block.
+    const GENESIS_TIMESTAMP: i64 = C::GENESIS_TIMESTAMP;
+    /// The network ID.
+    const ID: u16 = C::ID;
+    /// The function name for the inclusion circuit.
+    const INCLUSION_FUNCTION_NAME: &'static str = Testnet3::INCLUSION_FUNCTION_NAME;
+    /// The maximum number of microcredits that can be spent as a fee.
+    const MAX_FEE: u64 = C::MAX_FEE;
+    /// The network name.
+    const NAME: &'static str = C::NAME;
+    /// The starting supply of Aleo credits.
+    const STARTING_SUPPLY: u64 = C::STARTING_SUPPLY;
+
+    impl_network_parameters!();
+
+    /// Returns the genesis block bytes.
+    fn genesis_bytes() -> &'static [u8] {
+        C::genesis_bytes()
+    }
+
+    /// Returns the proving key for the given function name in `credits.aleo`.
+    fn get_credits_proving_key(function_name: String) -> Result<&'static Arc<VarunaProvingKey<Self>>> {
+        Testnet3::get_credits_proving_key(function_name)
+    }
+
+    /// Returns the verifying key for the given function name in `credits.aleo`.
+    fn get_credits_verifying_key(function_name: String) -> Result<&'static Arc<VarunaVerifyingKey<Self>>> {
+        Testnet3::get_credits_verifying_key(function_name)
+    }
+
+    /// Returns the `proving key` for the inclusion circuit.
+    fn inclusion_proving_key() -> &'static Arc<VarunaProvingKey<Self>> {
+        Testnet3::inclusion_proving_key()
+    }
+
+    /// Returns the `verifying key` for the inclusion circuit.
+    fn inclusion_verifying_key() -> &'static Arc<VarunaVerifyingKey<Self>> {
+        Testnet3::inclusion_verifying_key()
+    }
+
+    /// Returns the Varuna universal prover.
+    fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
+        Testnet3::varuna_universal_prover()
+    }
+
+    /// Returns the Varuna universal verifier.
+    fn varuna_universal_verifier() -> &'static UniversalVerifier<Self::PairingCurve> {
+        Testnet3::varuna_universal_verifier()
+    }
+
+    /// Returns the sponge parameters used for the sponge in the Varuna SNARK.
+    fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
+        Testnet3::varuna_fs_parameters()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    struct PrivateConfig;
+
+    impl CustomNetworkConfig for PrivateConfig {
+        const BLOCK_TIME: u16 = 5;
+        const DEPLOYMENT_FEE_MULTIPLIER: u64 = 10;
+        const ID: u16 = 1234;
+        const NAME: &'static str = "Private Network";
+
+        fn genesis_bytes() -> &'static [u8] {
+            &[]
+        }
+
+        fn parameters() -> &'static NetworkParameters<CustomNetwork<Self>> {
+            static INSTANCE: OnceCell<NetworkParameters<CustomNetwork<PrivateConfig>>> = OnceCell::new();
+            INSTANCE.get_or_init(|| NetworkParameters::setup().expect("Failed to setup the network parameters"))
+        }
+    }
+
+    type CurrentNetwork = CustomNetwork<PrivateConfig>;
+
+    #[test]
+    fn test_constants() {
+        // Ensure the configured constants are used.
+        assert_eq!(CurrentNetwork::ID, 1234);
+        assert_eq!(CurrentNetwork::NAME, "Private Network");
+        assert_eq!(CurrentNetwork::BLOCK_TIME, 5);
+        assert_eq!(CurrentNetwork::DEPLOYMENT_FEE_MULTIPLIER, 10);
+        // Ensure the derived constants use the configured constants.
+        assert_eq!(CurrentNetwork::ANCHOR_HEIGHT, (Testnet3::ANCHOR_TIME / 5) as u32);
+        assert_eq!(CurrentNetwork::NUM_BLOCKS_PER_EPOCH, 3600 / 5);
+        // Ensure the remaining constants default to those of `Testnet3`.
+        assert_eq!(CurrentNetwork::GENESIS_TIMESTAMP, Testnet3::GENESIS_TIMESTAMP);
+        assert_eq!(CurrentNetwork::STARTING_SUPPLY, Testnet3::STARTING_SUPPLY);
+        assert_eq!(CurrentNetwork::MAX_FEE, Testnet3::MAX_FEE);
+    }
+
+    #[test]
+    fn test_parameters_match_testnet3() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        // Ensure the generator matches.
+        let scalar = Scalar::<CurrentNetwork>::rand(rng);
+        let group = CurrentNetwork::g_scalar_multiply(&scalar);
+        assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
+        assert_eq!(
+            group.to_x_coordinate().to_string(),
+            Testnet3::g_scalar_multiply(&Scalar::new(*scalar)).to_x_coordinate().to_string()
+        );
+
+        // Ensure the hash functions match.
+        let input = (0..64).map(|_| bool::rand(rng)).collect::<Vec<_>>();
+        assert_eq!(*CurrentNetwork::hash_bhp256(&input)?, *Testnet3::hash_bhp256(&input)?);
+        assert_eq!(*CurrentNetwork::hash_ped64(&input)?, *Testnet3::hash_ped64(&input)?);
+        let input = [Field::<CurrentNetwork>::rand(rng)];
+        assert_eq!(*CurrentNetwork::hash_psd2(&input)?, *Testnet3::hash_psd2(&[Field::new(*input[0])])?);
+
+        // Ensure the domains match.
+        assert_eq!(*CurrentNetwork::encryption_domain(), *Testnet3::encryption_domain());
+        assert_eq!(*CurrentNetwork::serial_number_domain(), *Testnet3::serial_number_domain());
+        Ok(())
+    }
+}
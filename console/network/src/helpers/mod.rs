@@ -17,3 +17,6 @@ pub use id::*;
 
 mod object;
 pub use object::*;
+
+mod parameters;
+pub use parameters::*;
@@ -0,0 +1,418 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::Network;
+use snarkvm_console_algorithms::{
+    Blake2Xs,
+    Pedersen128,
+    Pedersen64,
+    Poseidon2,
+    Poseidon4,
+    Poseidon8,
+    BHP1024,
+    BHP256,
+    BHP512,
+    BHP768,
+};
+use snarkvm_console_network_environment::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+/// The cryptographic parameters of a network.
+pub struct NetworkParameters<N: Network> {
+    /// The group bases for the Aleo signature and encryption schemes.
+    pub(crate) generator_g: Vec<Group<N>>,
+    /// The encryption domain as a constant field element.
+    pub(crate) encryption_domain: Field<N>,
+    /// The graph key domain as a constant field element.
+    pub(crate) graph_key_domain: Field<N>,
+    /// The serial number domain as a constant field element.
+    pub(crate) serial_number_domain: Field<N>,
+    /// The BHP hash function, which can take an input of up to 256 bits.
+    pub(crate) bhp_256: BHP256<N>,
+    /// The BHP hash function, which can take an input of up to 512 bits.
+    pub(crate) bhp_512: BHP512<N>,
+    /// The BHP hash function, which can take an input of up to 768 bits.
+    pub(crate) bhp_768: BHP768<N>,
+    /// The BHP hash function, which can take an input of up to 1024 bits.
+    pub(crate) bhp_1024: BHP1024<N>,
+    /// The Pedersen hash function, which can take an input of up to 64 bits.
+    pub(crate) pedersen_64: Pedersen64<N>,
+    /// The Pedersen hash function, which can take an input of up to 128 bits.
+    pub(crate) pedersen_128: Pedersen128<N>,
+    /// The Poseidon hash function, using a rate of 2.
+    pub(crate) poseidon_2: Poseidon2<N>,
+    /// The Poseidon hash function, using a rate of 4.
+    pub(crate) poseidon_4: Poseidon4<N>,
+    /// The Poseidon hash function, using a rate of 8.
+    pub(crate) poseidon_8: Poseidon8<N>,
+}
+
+impl<N: Network> NetworkParameters<N> {
+    /// Initializes the cryptographic parameters.
+    pub fn setup() -> Result<Self> {
+        Ok(Self {
+            generator_g: Self::new_bases("AleoAccountEncryptionAndSignatureScheme0"),
+            encryption_domain: Field::new_domain_separator("AleoSymmetricEncryption0"),
+            graph_key_domain: Field::new_domain_separator("AleoGraphKey0"),
+            serial_number_domain: Field::new_domain_separator("AleoSerialNumber0"),
+            bhp_256: BHP256::setup("AleoBHP256")?,
+            bhp_512: BHP512::setup("AleoBHP512")?,
+            bhp_768: BHP768::setup("AleoBHP768")?,
+            bhp_1024: BHP1024::setup("AleoBHP1024")?,
+            pedersen_64: Pedersen64::setup("AleoPedersen64"),
+            pedersen_128: Pedersen128::setup("AleoPedersen128"),
+            poseidon_2: Poseidon2::setup("AleoPoseidon2")?,
+            poseidon_4: Poseidon4::setup("AleoPoseidon4")?,
+            poseidon_8: Poseidon8::setup("AleoPoseidon8")?,
+        })
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 256 bits.
+    pub const fn bhp_256(&self) -> &BHP256<N> {
+        &self.bhp_256
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 512 bits.
+    pub const fn bhp_512(&self) -> &BHP512<N> {
+        &self.bhp_512
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 768 bits.
+    pub const fn bhp_768(&self) -> &BHP768<N> {
+        &self.bhp_768
+    }
+
+    /// Returns the BHP hash function, which can take an input of up to 1024 bits.
+    pub const fn bhp_1024(&self) -> &BHP1024<N> {
+        &self.bhp_1024
+    }
+
+    /// Returns the Pedersen hash function, which can take an input of up to 64 bits.
+    pub const fn pedersen_64(&self) -> &Pedersen64<N> {
+        &self.pedersen_64
+    }
+
+    /// Returns the Pedersen hash function, which can take an input of up to 128 bits.
+    pub const fn pedersen_128(&self) -> &Pedersen128<N> {
+        &self.pedersen_128
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 2.
+    pub const fn poseidon_2(&self) -> &Poseidon2<N> {
+        &self.poseidon_2
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 4.
+    pub const fn poseidon_4(&self) -> &Poseidon4<N> {
+        &self.poseidon_4
+    }
+
+    /// Returns the Poseidon hash function, using a rate of 8.
+    pub const fn poseidon_8(&self) -> &Poseidon8<N> {
+        &self.poseidon_8
+    }
+
+    /// Initializes a new instance of group bases from a given input domain message.
+    fn new_bases(message: &str) -> Vec<Group<N>> {
+        // Hash the given message to a point on the curve, to initialize the starting base.
+        let (base, _, _) = Blake2Xs::hash_to_curve::<<N as Environment>::Affine>(message);
+
+        // Compute the bases up to the size of the scalar field (in bits).
+        let mut g = Group::<N>::new(base);
+        let mut g_bases = Vec::with_capacity(Scalar::<N>::size_in_bits());
+        for _ in 0..Scalar::<N>::size_in_bits() {
+            g_bases.push(g);
+            g = g.double();
+        }
+        g_bases
+    }
+}
+
+/// Implements the cryptographic functions of the `Network` trait, using the `NetworkParameters`
+/// returned by `Self::parameters()`.
+macro_rules! impl_network_parameters {
+    () => {
+        /// Returns the powers of `G`.
+        fn g_powers() -> &'static Vec<Group<Self>> {
+            &Self::parameters().generator_g
+        }
+
+        /// Returns the scalar multiplication on the generator `G`.
+        fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
+            Self::parameters()
+                .generator_g
+                .iter()
+                .zip_eq(&scalar.to_bits_le())
+                .filter_map(|(base, bit)| match bit {
+                    true => Some(base),
+                    false => None,
+                })
+                .sum()
+        }
+
+        /// Returns the encryption domain as a constant field element.
+        fn encryption_domain() -> Field<Self> {
+            Self::parameters().encryption_domain
+        }
+
+        /// Returns the graph key domain as a constant field element.
+        fn graph_key_domain() -> Field<Self> {
+            Self::parameters().graph_key_domain
+        }
+
+        /// Returns the serial number domain as a constant field element.
+        fn serial_number_domain() -> Field<Self> {
+            Self::parameters().serial_number_domain
+        }
+
+        /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+        fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Self::parameters().bhp_256.commit(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+        fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Self::parameters().bhp_512.commit(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+        fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Self::parameters().bhp_768.commit(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+        fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Self::parameters().bhp_1024.commit(input, randomizer)
+        }
+
+        /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+        fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Self::parameters().pedersen_64.commit(input, randomizer)
+        }
+
+        /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+        fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
+            Self::parameters().pedersen_128.commit(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
+        fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Self::parameters().bhp_256.commit_uncompressed(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
+        fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Self::parameters().bhp_512.commit_uncompressed(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
+        fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Self::parameters().bhp_768.commit_uncompressed(input, randomizer)
+        }
+
+        /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
+        fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Self::parameters().bhp_1024.commit_uncompressed(input, randomizer)
+        }
+
+        /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
+        fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Self::parameters().pedersen_64.commit_uncompressed(input, randomizer)
+        }
+
+        /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
+        fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
+            Self::parameters().pedersen_128.commit_uncompressed(input, randomizer)
+        }
+
+        /// Returns the BHP hash with an input hasher of 256-bits.
+        fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
+            Self::parameters().bhp_256.hash(input)
+        }
+
+        /// Returns the BHP hash with an input hasher of 512-bits.
+        fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
+            Self::parameters().bhp_512.hash(input)
+        }
+
+        /// Returns the BHP hash with an input hasher of 768-bits.
+        fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
+            Self::parameters().bhp_768.hash(input)
+        }
+
+        /// Returns the BHP hash with an input hasher of 1024-bits.
+        fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
+            Self::parameters().bhp_1024.hash(input)
+        }
+
+        /// Returns the Keccak hash with a 256-bit output.
+        fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
+            snarkvm_console_algorithms::Keccak256::default().hash(input)
+        }
+
+        /// Returns the Keccak hash with a 384-bit output.
+        fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
+            snarkvm_console_algorithms::Keccak384::default().hash(input)
+        }
+
+        /// Returns the Keccak hash with a 512-bit output.
+        fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
+            snarkvm_console_algorithms::Keccak512::default().hash(input)
+        }
+
+        /// Returns the Pedersen hash for a given (up to) 64-bit input.
+        fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
+            Self::parameters().pedersen_64.hash(input)
+        }
+
+        /// Returns the Pedersen hash for a given (up to) 128-bit input.
+        fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
+            Self::parameters().pedersen_128.hash(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 2.
+        fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
+            Self::parameters().poseidon_2.hash(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 4.
+        fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
+            Self::parameters().poseidon_4.hash(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 8.
+        fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
+            Self::parameters().poseidon_8.hash(input)
+        }
+
+        /// Returns the SHA-3 hash with a 256-bit output.
+        fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
+            snarkvm_console_algorithms::Sha3_256::default().hash(input)
+        }
+
+        /// Returns the SHA-3 hash with a 384-bit output.
+        fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
+            snarkvm_console_algorithms::Sha3_384::default().hash(input)
+        }
+
+        /// Returns the SHA-3 hash with a 512-bit output.
+        fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
+            snarkvm_console_algorithms::Sha3_512::default().hash(input)
+        }
+
+        /// Returns the extended Poseidon hash with an input rate of 2.
+        fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+            Self::parameters().poseidon_2.hash_many(input, num_outputs)
+        }
+
+        /// Returns the extended Poseidon hash with an input rate of 4.
+        fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+            Self::parameters().poseidon_4.hash_many(input, num_outputs)
+        }
+
+        /// Returns the extended Poseidon hash with an input rate of 8.
+        fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
+            Self::parameters().poseidon_8.hash_many(input, num_outputs)
+        }
+
+        /// Returns the BHP hash with an input hasher of 256-bits.
+        fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
+            Self::parameters().bhp_256.hash_uncompressed(input)
+        }
+
+        /// Returns the BHP hash with an input hasher of 512-bits.
+        fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
+            Self::parameters().bhp_512.hash_uncompressed(input)
+        }
+
+        /// Returns the BHP hash with an input hasher of 768-bits.
+        fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
+            Self::parameters().bhp_768.hash_uncompressed(input)
+        }
+
+        /// Returns the BHP hash with an input hasher of 1024-bits.
+        fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
+            Self::parameters().bhp_1024.hash_uncompressed(input)
+        }
+
+        /// Returns the Pedersen hash for a given (up to) 64-bit input.
+        fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
+            Self::parameters().pedersen_64.hash_uncompressed(input)
+        }
+
+        /// Returns the Pedersen hash for a given (up to) 128-bit input.
+        fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
+            Self::parameters().pedersen_128.hash_uncompressed(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
+        fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
+            Self::parameters().poseidon_2.hash_to_group(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
+        fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
+            Self::parameters().poseidon_4.hash_to_group(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
+        fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
+            Self::parameters().poseidon_8.hash_to_group(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
+        fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+            Self::parameters().poseidon_2.hash_to_scalar(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
+        fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+            Self::parameters().poseidon_4.hash_to_scalar(input)
+        }
+
+        /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
+        fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
+            Self::parameters().poseidon_8.hash_to_scalar(input)
+        }
+
+        /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
+        fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
+            MerkleTree::new(&Self::parameters().bhp_1024, &Self::parameters().bhp_512, leaves)
+        }
+
+        /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
+        fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
+            MerkleTree::new(&Self::parameters().poseidon_4, &Self::parameters().poseidon_2, leaves)
+        }
+
+        /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+        fn verify_merkle_path_bhp<const DEPTH: u8>(
+            path: &MerklePath<Self, DEPTH>,
+            root: &Field<Self>,
+            leaf: &Vec<bool>,
+        ) -> bool {
+            path.verify(&Self::parameters().bhp_1024, &Self::parameters().bhp_512, root, leaf)
+        }
+
+        /// Returns `true` if the given Merkle path is valid for the given root and leaf.
+        fn verify_merkle_path_psd<const DEPTH: u8>(
+            path: &MerklePath<Self, DEPTH>,
+            root: &Field<Self>,
+            leaf: &Vec<Field<Self>>,
+        ) -> bool {
+            path.verify(&Self::parameters().poseidon_4, &Self::parameters().poseidon_2, root, leaf)
+        }
+    };
+}
+pub(crate) use impl_network_parameters;
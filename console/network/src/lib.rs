@@ -22,6 +22,9 @@ extern crate lazy_static;
 pub use snarkvm_console_network_environment as environment;
 pub use snarkvm_console_network_environment::*;
 
+mod custom;
+pub use custom::*;
+
 mod helpers;
 pub use helpers::*;
 
@@ -53,6 +56,9 @@ pub type BHPMerkleTree<N, const DEPTH: u8> = MerkleTree<N, BHP1024<N>, BHP512<N>
 /// A helper type for the Poseidon Merkle tree.
 pub type PoseidonMerkleTree<N, const DEPTH: u8> = MerkleTree<N, Poseidon4<N>, Poseidon2<N>, DEPTH>;
 
+/// A helper type for the human-readable encoding of record commitments.
+pub type CommitmentID<N> = AleoID<Field<N>, { hrp2!("cm") }>;
+
 /// Helper types for the Varuna parameters.
 type Fq<N> = <<N as Environment>::PairingCurve as PairingEngine>::Fq;
 pub type FiatShamir<N> = PoseidonSponge<Fq<N>, 2, 1>;
@@ -13,71 +13,97 @@
 // limitations under the License.
 
 use super::*;
-use snarkvm_console_algorithms::{
-    Blake2Xs,
-    Keccak256,
-    Keccak384,
-    Keccak512,
-    Pedersen128,
-    Pedersen64,
-    Poseidon2,
-    Poseidon4,
-    Poseidon8,
-    Sha3_256,
-    Sha3_384,
-    Sha3_512,
-    BHP1024,
-    BHP256,
-    BHP512,
-    BHP768,
-};
+use snarkvm_console_algorithms::{Pedersen128, Pedersen64, Poseidon8, BHP256, BHP768};
 
 lazy_static! {
-    /// The group bases for the Aleo signature and encryption schemes.
-    pub static ref GENERATOR_G: Vec<Group<Testnet3>> = Testnet3::new_bases("AleoAccountEncryptionAndSignatureScheme0");
-
     /// The Varuna sponge parameters.
     pub static ref VARUNA_FS_PARAMETERS: FiatShamirParameters<Testnet3> = FiatShamir::<Testnet3>::sample_parameters();
 
+    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
+        let mut map = IndexMap::new();
+        snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
+        map
+    };
+    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
+        let mut map = IndexMap::new();
+        snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
+        map
+    };
+}
+
+/// Declares a deprecated static that dereferences to a field of `Testnet3::parameters()`.
+macro_rules! deprecated_parameter {
+    ($(#[$doc:meta])* $name:ident: $alias:ident => $ty:ty = $field:ident) => {
+        $(#[$doc])*
+        #[deprecated(note = "use the accessors on `Testnet3::parameters()` instead")]
+        pub static $name: $alias = $alias;
+
+        #[doc(hidden)]
+        #[derive(Copy, Clone, Debug)]
+        pub struct $alias;
+
+        impl core::ops::Deref for $alias {
+            type Target = $ty;
+
+            fn deref(&self) -> &Self::Target {
+                &Testnet3::parameters().$field
+            }
+        }
+    };
+}
+
+deprecated_parameter!(
+    /// The group bases for the Aleo signature and encryption schemes.
+    GENERATOR_G: GeneratorG => Vec<Group<Testnet3>> = generator_g
+);
+deprecated_parameter!(
     /// The encryption domain as a constant field element.
-    pub static ref ENCRYPTION_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSymmetricEncryption0");
+    ENCRYPTION_DOMAIN: EncryptionDomain => Field<Testnet3> = encryption_domain
+);
+deprecated_parameter!(
     /// The graph key domain as a constant field element.
-    pub static ref GRAPH_KEY_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoGraphKey0");
+    GRAPH_KEY_DOMAIN: GraphKeyDomain => Field<Testnet3> = graph_key_domain
+);
+deprecated_parameter!(
     /// The serial number domain as a constant field element.
-    pub static ref SERIAL_NUMBER_DOMAIN: Field<Testnet3> = Field::<Testnet3>::new_domain_separator("AleoSerialNumber0");
-
+    SERIAL_NUMBER_DOMAIN: SerialNumberDomain => Field<Testnet3> = serial_number_domain
+);
+deprecated_parameter!(
     /// The BHP hash function, which can take an input of up to 256 bits.
-    pub static ref BHP_256: BHP256<Testnet3> = BHP256::<Testnet3>::setup("AleoBHP256").expect("Failed to setup BHP256");
+    BHP_256: Bhp256 => BHP256<Testnet3> = bhp_256
+);
+deprecated_parameter!(
     /// The BHP hash function, which can take an input of up to 512 bits.
-    pub static ref BHP_512: BHP512<Testnet3> = BHP512::<Testnet3>::setup("AleoBHP512").expect("Failed to setup BHP512");
+    BHP_512: Bhp512 => BHP512<Testnet3> = bhp_512
+);
+deprecated_parameter!(
     /// The BHP hash function, which can take an input of up to 768 bits.
-    pub static ref BHP_768: BHP768<Testnet3> = BHP768::<Testnet3>::setup("AleoBHP768").expect("Failed to setup BHP768");
+    BHP_768: Bhp768 => BHP768<Testnet3> = bhp_768
+);
+deprecated_parameter!(
     /// The BHP hash function, which can take an input of up to 1024 bits.
-    pub static ref BHP_1024: BHP1024<Testnet3> = BHP1024::<Testnet3>::setup("AleoBHP1024").expect("Failed to setup BHP1024");
-
+    BHP_1024: Bhp1024 => BHP1024<Testnet3> = bhp_1024
+);
+deprecated_parameter!(
     /// The Pedersen hash function, which can take an input of up to 64 bits.
-    pub static ref PEDERSEN_64: Pedersen64<Testnet3> = Pedersen64::<Testnet3>::setup("AleoPedersen64");
+    PEDERSEN_64: Pedersen64Parameter => Pedersen64<Testnet3> = pedersen_64
+);
+deprecated_parameter!(
     /// The Pedersen hash function, which can take an input of up to 128 bits.
-    pub static ref PEDERSEN_128: Pedersen128<Testnet3> = Pedersen128::<Testnet3>::setup("AleoPedersen128");
-
+    PEDERSEN_128: Pedersen128Parameter => Pedersen128<Testnet3> = pedersen_128
+);
+deprecated_parameter!(
     /// The Poseidon hash function, using a rate of 2.
-    pub static ref POSEIDON_2: Poseidon2<Testnet3> = Poseidon2::<Testnet3>::setup("AleoPoseidon2").expect("Failed to setup Poseidon2");
+    POSEIDON_2: Poseidon2Parameter => Poseidon2<Testnet3> = poseidon_2
+);
+deprecated_parameter!(
     /// The Poseidon hash function, using a rate of 4.
-    pub static ref POSEIDON_4: Poseidon4<Testnet3> = Poseidon4::<Testnet3>::setup("AleoPoseidon4").expect("Failed to setup Poseidon4");
+    POSEIDON_4: Poseidon4Parameter => Poseidon4<Testnet3> = poseidon_4
+);
+deprecated_parameter!(
     /// The Poseidon hash function, using a rate of 8.
-    pub static ref POSEIDON_8: Poseidon8<Testnet3> = Poseidon8::<Testnet3>::setup("AleoPoseidon8").expect("Failed to setup Poseidon8");
-
-    pub static ref CREDITS_PROVING_KEYS: IndexMap<String, Arc<VarunaProvingKey<Console>>> = {
-        let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, VarunaProvingKey<Console>, Prover);
-        map
-    };
-    pub static ref CREDITS_VERIFYING_KEYS: IndexMap<String, Arc<VarunaVerifyingKey<Console>>> = {
-        let mut map = IndexMap::new();
-        snarkvm_parameters::insert_credit_keys!(map, VarunaVerifyingKey<Console>, Verifier);
-        map
-    };
-}
+    POSEIDON_8: Poseidon8Parameter => Poseidon8<Testnet3> = poseidon_8
+);
 
 pub const TRANSACTION_PREFIX: &str = "at";
 
@@ -85,19 +111,10 @@ pub const TRANSACTION_PREFIX: &str = "at";
 pub struct Testnet3;
 
 impl Testnet3 {
-    /// Initializes a new instance of group bases from a given input domain message.
-    fn new_bases(message: &str) -> Vec<Group<Self>> {
-        // Hash the given message to a point on the curve, to initialize the starting base.
-        let (base, _, _) = Blake2Xs::hash_to_curve::<<Self as Environment>::Affine>(message);
-
-        // Compute the bases up to the size of the scalar field (in bits).
-        let mut g = Group::<Self>::new(base);
-        let mut g_bases = Vec::with_capacity(Scalar::<Self>::size_in_bits());
-        for _ in 0..Scalar::<Self>::size_in_bits() {
-            g_bases.push(g);
-            g = g.double();
-        }
-        g_bases
+    /// Returns the cryptographic parameters of the network.
+    pub fn parameters() -> &'static NetworkParameters<Self> {
+        static INSTANCE: OnceCell<NetworkParameters<Testnet3>> = OnceCell::new();
+        INSTANCE.get_or_init(|| NetworkParameters::setup().expect("Failed to setup the network parameters"))
     }
 }
 
@@ -140,6 +157,8 @@ impl Network for Testnet3 {
     /// The network name.
     const NAME: &'static str = "Aleo Testnet 3";
 
+    impl_network_parameters!();
+
     /// Returns the genesis block bytes.
     fn genesis_bytes() -> &'static [u8] {
         snarkvm_parameters::testnet3::GenesisBytes::load_bytes()
@@ -183,23 +202,6 @@ impl Network for Testnet3 {
         })
     }
 
-    /// Returns the powers of `G`.
-    fn g_powers() -> &'static Vec<Group<Self>> {
-        &GENERATOR_G
-    }
-
-    /// Returns the scalar multiplication on the generator `G`.
-    fn g_scalar_multiply(scalar: &Scalar<Self>) -> Group<Self> {
-        GENERATOR_G
-            .iter()
-            .zip_eq(&scalar.to_bits_le())
-            .filter_map(|(base, bit)| match bit {
-                true => Some(base),
-                false => None,
-            })
-            .sum()
-    }
-
     /// Returns the Varuna universal prover.
     fn varuna_universal_prover() -> &'static UniversalProver<Self::PairingCurve> {
         static INSTANCE: OnceCell<UniversalProver<<Console as Environment>::PairingCurve>> = OnceCell::new();
@@ -226,259 +228,6 @@ impl Network for Testnet3 {
     fn varuna_fs_parameters() -> &'static FiatShamirParameters<Self> {
         &VARUNA_FS_PARAMETERS
     }
-
-    /// Returns the encryption domain as a constant field element.
-    fn encryption_domain() -> Field<Self> {
-        *ENCRYPTION_DOMAIN
-    }
-
-    /// Returns the graph key domain as a constant field element.
-    fn graph_key_domain() -> Field<Self> {
-        *GRAPH_KEY_DOMAIN
-    }
-
-    /// Returns the serial number domain as a constant field element.
-    fn serial_number_domain() -> Field<Self> {
-        *SERIAL_NUMBER_DOMAIN
-    }
-
-    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
-    fn commit_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_256.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
-    fn commit_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_512.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
-    fn commit_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_768.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
-    fn commit_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        BHP_1024.commit(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
-    fn commit_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        PEDERSEN_64.commit(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
-    fn commit_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Field<Self>> {
-        PEDERSEN_128.commit(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 256-bits and randomizer.
-    fn commit_to_group_bhp256(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_256.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 512-bits and randomizer.
-    fn commit_to_group_bhp512(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_512.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 768-bits and randomizer.
-    fn commit_to_group_bhp768(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_768.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a BHP commitment with an input hasher of 1024-bits and randomizer.
-    fn commit_to_group_bhp1024(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        BHP_1024.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 64-bit input and randomizer.
-    fn commit_to_group_ped64(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        PEDERSEN_64.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns a Pedersen commitment for the given (up to) 128-bit input and randomizer.
-    fn commit_to_group_ped128(input: &[bool], randomizer: &Scalar<Self>) -> Result<Group<Self>> {
-        PEDERSEN_128.commit_uncompressed(input, randomizer)
-    }
-
-    /// Returns the BHP hash with an input hasher of 256-bits.
-    fn hash_bhp256(input: &[bool]) -> Result<Field<Self>> {
-        BHP_256.hash(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 512-bits.
-    fn hash_bhp512(input: &[bool]) -> Result<Field<Self>> {
-        BHP_512.hash(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 768-bits.
-    fn hash_bhp768(input: &[bool]) -> Result<Field<Self>> {
-        BHP_768.hash(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 1024-bits.
-    fn hash_bhp1024(input: &[bool]) -> Result<Field<Self>> {
-        BHP_1024.hash(input)
-    }
-
-    /// Returns the Keccak hash with a 256-bit output.
-    fn hash_keccak256(input: &[bool]) -> Result<Vec<bool>> {
-        Keccak256::default().hash(input)
-    }
-
-    /// Returns the Keccak hash with a 384-bit output.
-    fn hash_keccak384(input: &[bool]) -> Result<Vec<bool>> {
-        Keccak384::default().hash(input)
-    }
-
-    /// Returns the Keccak hash with a 512-bit output.
-    fn hash_keccak512(input: &[bool]) -> Result<Vec<bool>> {
-        Keccak512::default().hash(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 64-bit input.
-    fn hash_ped64(input: &[bool]) -> Result<Field<Self>> {
-        PEDERSEN_64.hash(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 128-bit input.
-    fn hash_ped128(input: &[bool]) -> Result<Field<Self>> {
-        PEDERSEN_128.hash(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 2.
-    fn hash_psd2(input: &[Field<Self>]) -> Result<Field<Self>> {
-        POSEIDON_2.hash(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 4.
-    fn hash_psd4(input: &[Field<Self>]) -> Result<Field<Self>> {
-        POSEIDON_4.hash(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 8.
-    fn hash_psd8(input: &[Field<Self>]) -> Result<Field<Self>> {
-        POSEIDON_8.hash(input)
-    }
-
-    /// Returns the SHA-3 hash with a 256-bit output.
-    fn hash_sha3_256(input: &[bool]) -> Result<Vec<bool>> {
-        Sha3_256::default().hash(input)
-    }
-
-    /// Returns the SHA-3 hash with a 384-bit output.
-    fn hash_sha3_384(input: &[bool]) -> Result<Vec<bool>> {
-        Sha3_384::default().hash(input)
-    }
-
-    /// Returns the SHA-3 hash with a 512-bit output.
-    fn hash_sha3_512(input: &[bool]) -> Result<Vec<bool>> {
-        Sha3_512::default().hash(input)
-    }
-
-    /// Returns the extended Poseidon hash with an input rate of 2.
-    fn hash_many_psd2(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
-        POSEIDON_2.hash_many(input, num_outputs)
-    }
-
-    /// Returns the extended Poseidon hash with an input rate of 4.
-    fn hash_many_psd4(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
-        POSEIDON_4.hash_many(input, num_outputs)
-    }
-
-    /// Returns the extended Poseidon hash with an input rate of 8.
-    fn hash_many_psd8(input: &[Field<Self>], num_outputs: u16) -> Vec<Field<Self>> {
-        POSEIDON_8.hash_many(input, num_outputs)
-    }
-
-    /// Returns the BHP hash with an input hasher of 256-bits.
-    fn hash_to_group_bhp256(input: &[bool]) -> Result<Group<Self>> {
-        BHP_256.hash_uncompressed(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 512-bits.
-    fn hash_to_group_bhp512(input: &[bool]) -> Result<Group<Self>> {
-        BHP_512.hash_uncompressed(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 768-bits.
-    fn hash_to_group_bhp768(input: &[bool]) -> Result<Group<Self>> {
-        BHP_768.hash_uncompressed(input)
-    }
-
-    /// Returns the BHP hash with an input hasher of 1024-bits.
-    fn hash_to_group_bhp1024(input: &[bool]) -> Result<Group<Self>> {
-        BHP_1024.hash_uncompressed(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 64-bit input.
-    fn hash_to_group_ped64(input: &[bool]) -> Result<Group<Self>> {
-        PEDERSEN_64.hash_uncompressed(input)
-    }
-
-    /// Returns the Pedersen hash for a given (up to) 128-bit input.
-    fn hash_to_group_ped128(input: &[bool]) -> Result<Group<Self>> {
-        PEDERSEN_128.hash_uncompressed(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 2 on the affine curve.
-    fn hash_to_group_psd2(input: &[Field<Self>]) -> Result<Group<Self>> {
-        POSEIDON_2.hash_to_group(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 4 on the affine curve.
-    fn hash_to_group_psd4(input: &[Field<Self>]) -> Result<Group<Self>> {
-        POSEIDON_4.hash_to_group(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 8 on the affine curve.
-    fn hash_to_group_psd8(input: &[Field<Self>]) -> Result<Group<Self>> {
-        POSEIDON_8.hash_to_group(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 2 on the scalar field.
-    fn hash_to_scalar_psd2(input: &[Field<Self>]) -> Result<Scalar<Self>> {
-        POSEIDON_2.hash_to_scalar(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 4 on the scalar field.
-    fn hash_to_scalar_psd4(input: &[Field<Self>]) -> Result<Scalar<Self>> {
-        POSEIDON_4.hash_to_scalar(input)
-    }
-
-    /// Returns the Poseidon hash with an input rate of 8 on the scalar field.
-    fn hash_to_scalar_psd8(input: &[Field<Self>]) -> Result<Scalar<Self>> {
-        POSEIDON_8.hash_to_scalar(input)
-    }
-
-    /// Returns a Merkle tree with a BHP leaf hasher of 1024-bits and a BHP path hasher of 512-bits.
-    fn merkle_tree_bhp<const DEPTH: u8>(leaves: &[Vec<bool>]) -> Result<BHPMerkleTree<Self, DEPTH>> {
-        MerkleTree::new(&*BHP_1024, &*BHP_512, leaves)
-    }
-
-    /// Returns a Merkle tree with a Poseidon leaf hasher with input rate of 4 and a Poseidon path hasher with input rate of 2.
-    fn merkle_tree_psd<const DEPTH: u8>(leaves: &[Vec<Field<Self>>]) -> Result<PoseidonMerkleTree<Self, DEPTH>> {
-        MerkleTree::new(&*POSEIDON_4, &*POSEIDON_2, leaves)
-    }
-
-    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
-    fn verify_merkle_path_bhp<const DEPTH: u8>(
-        path: &MerklePath<Self, DEPTH>,
-        root: &Field<Self>,
-        leaf: &Vec<bool>,
-    ) -> bool {
-        path.verify(&*BHP_1024, &*BHP_512, root, leaf)
-    }
-
-    /// Returns `true` if the given Merkle path is valid for the given root and leaf.
-    fn verify_merkle_path_psd<const DEPTH: u8>(
-        path: &MerklePath<Self, DEPTH>,
-        root: &Field<Self>,
-        leaf: &Vec<Field<Self>>,
-    ) -> bool {
-        path.verify(&*POSEIDON_4, &*POSEIDON_2, root, leaf)
-    }
 }
 
 #[cfg(test)]
@@ -494,4 +243,47 @@ mod tests {
         let group = CurrentNetwork::g_scalar_multiply(&scalar);
         assert_eq!(group, CurrentNetwork::g_powers()[0] * scalar);
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_parameters() {
+        // Ensure the deprecated statics resolve to the network parameters.
+        assert_eq!(&*GENERATOR_G, CurrentNetwork::g_powers());
+        assert_eq!(*ENCRYPTION_DOMAIN, CurrentNetwork::encryption_domain());
+        assert_eq!(*SERIAL_NUMBER_DOMAIN, CurrentNetwork::serial_number_domain());
+        assert!(std::ptr::eq(&*BHP_256, CurrentNetwork::parameters().bhp_256()));
+        assert!(std::ptr::eq(&*POSEIDON_8, CurrentNetwork::parameters().poseidon_8()));
+    }
+
+    #[test]
+    fn test_bech32m_identifiers() -> Result<()> {
+        let rng = &mut TestRng::default();
+
+        fn check<ID: Bech32ID<Field<CurrentNetwork>>>(rng: &mut TestRng) -> Result<()> {
+            let id = ID::from(Field::rand(rng));
+            // Check the prefix and the round trip.
+            let string = id.to_string();
+            assert!(string.starts_with(&format!("{}1", ID::prefix())));
+            assert_eq!(id, ID::from_str(&string).map_err(|_| anyhow!("Failed to parse '{string}'"))?);
+
+            // Ensure a typo is detected by the checksum.
+            let mut typo = string.clone().into_bytes();
+            let index = typo.len() - 10;
+            typo[index] = if typo[index] == b'q' { b'p' } else { b'q' };
+            assert!(ID::from_str(std::str::from_utf8(&typo)?).is_err());
+            Ok(())
+        }
+
+        for _ in 0..10 {
+            check::<<CurrentNetwork as Network>::TransactionID>(rng)?;
+            check::<<CurrentNetwork as Network>::TransitionID>(rng)?;
+            check::<CommitmentID<CurrentNetwork>>(rng)?;
+        }
+
+        // Ensure the identifier types are distinguished by their prefix.
+        let id = <CurrentNetwork as Network>::TransactionID::from(Field::rand(rng)).to_string();
+        assert!(<CurrentNetwork as Network>::TransitionID::from_str(&id).is_err());
+        assert!(CommitmentID::<CurrentNetwork>::from_str(&id).is_err());
+        Ok(())
+    }
 }
@@ -31,7 +31,7 @@ mod plaintext;
 pub use plaintext::Plaintext;
 
 mod record;
-pub use record::{Entry, Owner, Record};
+pub use record::{Entry, Owner, Record, RecordHint, RecordHints};
 
 mod register;
 pub use register::Register;
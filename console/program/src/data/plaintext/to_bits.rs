@@ -162,3 +162,15 @@ impl<N: Network> ToBits for Plaintext<N> {
         }
     }
 }
+
+impl<N: Network> Plaintext<N> {
+    /// Returns the raw **little-endian** bits of this plaintext, without any variant or size metadata.
+    /// Literals are written as their own bits, while structs and arrays are written as the concatenation of their members.
+    pub fn to_bits_raw_le(&self) -> Vec<bool> {
+        match self {
+            Self::Literal(literal, _) => literal.to_bits_le(),
+            Self::Struct(struct_, _) => struct_.values().flat_map(|member| member.to_bits_raw_le()).collect(),
+            Self::Array(array, _) => array.iter().flat_map(|element| element.to_bits_raw_le()).collect(),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console_account::{Address, ViewKey};
+use snarkvm_console_network::prelude::*;
+use snarkvm_console_types::{Field, Group, Scalar};
+
+static RECORD_HINT_DOMAIN: &str = "AleoRecordHint0";
+static RECORD_HINT_TAG_DOMAIN: &str = "AleoRecordHintTag0";
+
+/// An encryption of a record view key to an additional recipient (e.g. an auditor),
+/// which allows the recipient to decrypt the record without the view key of the record owner.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordHint<N: Network> {
+    /// The nonce of the hint.
+    nonce: Group<N>,
+    /// The encrypted record view key.
+    ciphertext: Field<N>,
+    /// The tag of the record view key, used to recognize the recipient.
+    tag: Field<N>,
+}
+
+impl<N: Network> RecordHint<N> {
+    /// Returns a hint that encrypts the given record view key to the given recipient.
+    pub fn new<R: Rng + CryptoRng>(record_view_key: &Field<N>, recipient: &Address<N>, rng: &mut R) -> Result<Self> {
+        // Sample a randomizer, and compute the nonce.
+        let randomizer = Scalar::rand(rng);
+        let nonce = N::g_scalar_multiply(&randomizer);
+        // Compute the shared secret with the recipient.
+        let shared_secret = (**recipient * randomizer).to_x_coordinate();
+        // Encrypt the record view key.
+        let ciphertext = *record_view_key + Self::to_mask(&shared_secret)?;
+        // Compute the tag.
+        let tag = Self::to_tag(record_view_key)?;
+        Ok(Self { nonce, ciphertext, tag })
+    }
+
+    /// Returns the nonce of the hint.
+    pub const fn nonce(&self) -> &Group<N> {
+        &self.nonce
+    }
+
+    /// Returns `true` if the hint was encrypted to the given view key.
+    pub fn is_recipient(&self, view_key: &ViewKey<N>) -> bool {
+        self.decrypt(view_key).is_ok()
+    }
+
+    /// Returns the record view key, if the hint was encrypted to the given view key.
+    pub fn decrypt(&self, view_key: &ViewKey<N>) -> Result<Field<N>> {
+        // Compute the shared secret with the sender.
+        let shared_secret = (self.nonce * **view_key).to_x_coordinate();
+        // Decrypt the record view key.
+        let record_view_key = self.ciphertext - Self::to_mask(&shared_secret)?;
+        // Ensure the record view key matches the tag.
+        ensure!(Self::to_tag(&record_view_key)? == self.tag, "The record hint is not for the given view key");
+        Ok(record_view_key)
+    }
+
+    /// Returns the mask of the record view key, for the given shared secret.
+    fn to_mask(shared_secret: &Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::new_domain_separator(RECORD_HINT_DOMAIN), *shared_secret])
+    }
+
+    /// Returns the tag of the given record view key.
+    fn to_tag(record_view_key: &Field<N>) -> Result<Field<N>> {
+        N::hash_psd2(&[Field::new_domain_separator(RECORD_HINT_TAG_DOMAIN), *record_view_key])
+    }
+}
+
+impl<N: Network> FromBytes for RecordHint<N> {
+    /// Reads the record hint from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let nonce = Group::read_le(&mut reader)?;
+        let ciphertext = Field::read_le(&mut reader)?;
+        let tag = Field::read_le(&mut reader)?;
+        Ok(Self { nonce, ciphertext, tag })
+    }
+}
+
+impl<N: Network> ToBytes for RecordHint<N> {
+    /// Writes the record hint to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.nonce.write_le(&mut writer)?;
+        self.ciphertext.write_le(&mut writer)?;
+        self.tag.write_le(&mut writer)
+    }
+}
+
+/// The hints for a record, which encrypt its record view key to additional recipients (e.g. an auditor).
+///
+/// The hints are not part of the record or its transaction, and are shared alongside the transaction
+/// (e.g. by a wallet or over RPC). As a result, they do not affect the transaction ID, and adding, removing,
+/// or changing hints cannot alter a transaction or its validity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordHints<N: Network> {
+    /// The commitment of the record.
+    commitment: Field<N>,
+    /// The hints of the record.
+    hints: Vec<RecordHint<N>>,
+}
+
+impl<N: Network> RecordHints<N> {
+    /// The maximum number of hints for a record.
+    pub const MAX_HINTS: usize = 8;
+
+    /// Initializes the hints for the record with the given commitment.
+    pub fn new(commitment: Field<N>, hints: Vec<RecordHint<N>>) -> Result<Self> {
+        // Ensure the number of hints is within the maximum limit.
+        ensure!(
+            hints.len() <= Self::MAX_HINTS,
+            "Found a record that exceeds the maximum number of hints ({})",
+            hints.len()
+        );
+        Ok(Self { commitment, hints })
+    }
+
+    /// Returns the commitment of the record.
+    pub const fn commitment(&self) -> &Field<N> {
+        &self.commitment
+    }
+
+    /// Returns the hints of the record.
+    pub fn hints(&self) -> &[RecordHint<N>] {
+        &self.hints
+    }
+
+    /// Returns the hint that was encrypted to the given view key, if one exists.
+    pub fn find(&self, view_key: &ViewKey<N>) -> Option<&RecordHint<N>> {
+        self.hints.iter().find(|hint| hint.is_recipient(view_key))
+    }
+
+    /// Returns `true` if one of the hints was encrypted to the given view key.
+    pub fn is_recipient(&self, view_key: &ViewKey<N>) -> bool {
+        self.find(view_key).is_some()
+    }
+}
+
+impl<N: Network> FromBytes for RecordHints<N> {
+    /// Reads the record hints from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid record hints version"));
+        }
+        // Read the commitment.
+        let commitment = Field::read_le(&mut reader)?;
+        // Read the number of hints.
+        let num_hints = u8::read_le(&mut reader)?;
+        // Ensure the number of hints is within the maximum limit.
+        if num_hints as usize > Self::MAX_HINTS {
+            return Err(error(format!("Invalid number of record hints ({num_hints})")));
+        }
+        // Read the hints.
+        let hints = (0..num_hints).map(|_| RecordHint::read_le(&mut reader)).collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { commitment, hints })
+    }
+}
+
+impl<N: Network> ToBytes for RecordHints<N> {
+    /// Writes the record hints to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+        // Write the commitment.
+        self.commitment.write_le(&mut writer)?;
+        // Write the number of hints.
+        u8::try_from(self.hints.len()).map_err(|_| error("Too many record hints"))?.write_le(&mut writer)?;
+        // Write the hints.
+        self.hints.iter().try_for_each(|hint| hint.write_le(&mut writer))
+    }
+}
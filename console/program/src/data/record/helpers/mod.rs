@@ -12,5 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod hint;
+pub use hint::*;
+
 mod owner;
 pub use owner::*;
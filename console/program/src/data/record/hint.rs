@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network> Record<N, Plaintext<N>> {
+    /// Encrypts `self` for the record owner, and returns the record hints for each of the given recipients
+    /// (e.g. an auditor). The hints are created by the sender of the record, and do not require the involvement
+    /// of the record owner. They are not part of the record ciphertext, and must be shared alongside the transaction.
+    #[allow(clippy::type_complexity)]
+    pub fn encrypt_with_hints<R: Rng + CryptoRng>(
+        &self,
+        randomizer: Scalar<N>,
+        program_id: &ProgramID<N>,
+        record_name: &Identifier<N>,
+        recipients: &[Address<N>],
+        rng: &mut R,
+    ) -> Result<(Record<N, Ciphertext<N>>, RecordHints<N>)> {
+        // Encrypt the record, which ensures the randomizer corresponds to the record nonce.
+        let record = self.encrypt(randomizer)?;
+        // Compute the record commitment.
+        let commitment = self.to_commitment(program_id, record_name)?;
+        // Compute the record view key.
+        let record_view_key = (**self.owner * randomizer).to_x_coordinate();
+        // Create the hints.
+        let hints = record.to_hints(commitment, &record_view_key, recipients, rng)?;
+        Ok((record, hints))
+    }
+}
+
+impl<N: Network> Record<N, Ciphertext<N>> {
+    /// Returns the record hints for each of the given recipients (e.g. an auditor),
+    /// where the given commitment and record view key must be those of `self`.
+    pub fn to_hints<R: Rng + CryptoRng>(
+        &self,
+        commitment: Field<N>,
+        record_view_key: &Field<N>,
+        recipients: &[Address<N>],
+        rng: &mut R,
+    ) -> Result<RecordHints<N>> {
+        // Ensure the record view key decrypts the record.
+        self.decrypt_symmetric_unchecked(record_view_key)?;
+        // Encrypt the record view key to each recipient.
+        let hints = recipients
+            .iter()
+            .map(|recipient| RecordHint::new(record_view_key, recipient, rng))
+            .collect::<Result<Vec<_>>>()?;
+        RecordHints::new(commitment, hints)
+    }
+
+    /// Decrypts `self` into plaintext using the hint in the given record hints that was encrypted to the given view key.
+    pub fn decrypt_with_hints(&self, hints: &RecordHints<N>, view_key: &ViewKey<N>) -> Result<Record<N, Plaintext<N>>> {
+        // Retrieve the hint for the given view key.
+        let hint = match hints.find(view_key) {
+            Some(hint) => hint,
+            None => bail!("The record hints do not have a hint for the given view key"),
+        };
+        // Decrypt the record view key.
+        let record_view_key = hint.decrypt(view_key)?;
+        // Decrypt the record.
+        self.decrypt_symmetric_unchecked(&record_view_key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Literal;
+    use snarkvm_console_network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    const ITERATIONS: u64 = 100;
+
+    #[test]
+    fn test_decrypt_with_hints() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Prepare the program ID and record name.
+        let program_id = ProgramID::from_str("token.aleo")?;
+        let record_name = Identifier::from_str("token")?;
+
+        for _ in 0..ITERATIONS {
+            // Sample the owner and auditor accounts.
+            let owner_view_key = ViewKey::<CurrentNetwork>::try_from(PrivateKey::new(&mut rng)?)?;
+            let auditor_view_key = ViewKey::<CurrentNetwork>::try_from(PrivateKey::new(&mut rng)?)?;
+            let auditor_address = auditor_view_key.to_address();
+
+            // Prepare the record.
+            let randomizer = Scalar::rand(&mut rng);
+            let record = Record {
+                owner: Owner::Private(Plaintext::from(Literal::Address(owner_view_key.to_address()))),
+                data: IndexMap::from_iter([(
+                    Identifier::from_str("a")?,
+                    Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+                )]),
+                nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+            };
+            // Encrypt the record, with a hint for the auditor.
+            let (ciphertext, hints) =
+                record.encrypt_with_hints(randomizer, &program_id, &record_name, &[auditor_address], &mut rng)?;
+            assert_eq!(hints.hints().len(), 1);
+            assert_eq!(*hints.commitment(), record.to_commitment(&program_id, &record_name)?);
+
+            // Ensure the hints do not change the record ciphertext.
+            assert_eq!(ciphertext, record.encrypt(randomizer)?);
+            // Ensure the owner can still decrypt the record.
+            assert_eq!(record, ciphertext.decrypt(&owner_view_key)?);
+
+            // Ensure the auditor recognizes and decrypts the record, without the view key of the owner.
+            assert!(hints.is_recipient(&auditor_view_key));
+            assert_eq!(record, ciphertext.decrypt_with_hints(&hints, &auditor_view_key)?);
+
+            // Ensure the hint is not recognized by another view key.
+            assert!(!hints.is_recipient(&owner_view_key));
+            assert!(ciphertext.decrypt_with_hints(&hints, &owner_view_key).is_err());
+
+            // Ensure the hints round trip through their byte representation.
+            let hints_bytes = hints.to_bytes_le()?;
+            assert_eq!(hints, RecordHints::read_le(&hints_bytes[..])?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_bytes_reject_hints() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        // Sample the owner account.
+        let owner_view_key = ViewKey::<CurrentNetwork>::try_from(PrivateKey::new(&mut rng)?)?;
+
+        // Prepare the record ciphertext.
+        let randomizer = Scalar::rand(&mut rng);
+        let record = Record {
+            owner: Owner::Private(Plaintext::from(Literal::Address(owner_view_key.to_address()))),
+            data: IndexMap::from_iter([(
+                Identifier::from_str("a")?,
+                Entry::Private(Plaintext::from(Literal::Field(Field::rand(&mut rng)))),
+            )]),
+            nonce: CurrentNetwork::g_scalar_multiply(&randomizer),
+        };
+        let ciphertext = record.encrypt(randomizer)?;
+        let bytes = ciphertext.to_bytes_le()?;
+
+        // Ensure a record that flags embedded hints in its number of entries is rejected,
+        // so that hints cannot be added to the records of a transaction.
+        let mut flagged_bytes = bytes.clone();
+        flagged_bytes[ciphertext.owner().to_bytes_le()?.len()] |= 0x80;
+        assert!(Record::<CurrentNetwork, Ciphertext<CurrentNetwork>>::read_le(&flagged_bytes[..]).is_err());
+
+        // Ensure the record bytes round trip unchanged.
+        assert_eq!(ciphertext, Record::read_le(&bytes[..])?);
+        Ok(())
+    }
+}
@@ -16,13 +16,14 @@ mod entry;
 pub use entry::Entry;
 
 mod helpers;
-pub use helpers::Owner;
+pub use helpers::{Owner, RecordHint, RecordHints};
 
 mod bytes;
 mod decrypt;
 mod encrypt;
 mod equal;
 mod find;
+mod hint;
 mod is_owner;
 mod num_randomizers;
 mod parse_ciphertext;
@@ -257,4 +257,38 @@ mod tests {
             assert!(request.verify(&input_types));
         }
     }
+
+    #[test]
+    fn test_sign_is_deterministic() {
+        let rng = &mut TestRng::default();
+
+        // Sample a random private key.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+        // Construct a program ID, function name, and inputs.
+        let program_id = ProgramID::from_str("token.aleo").unwrap();
+        let function_name = Identifier::from_str("transfer").unwrap();
+        let inputs = [Value::from_str("{ token_amount: 9876543210u128 }").unwrap()];
+        let input_types = vec![ValueType::from_str("amount.private").unwrap()];
+
+        // Sign the same request twice, with identically-seeded RNGs.
+        let seed = rng.gen();
+        let sign = |seed| {
+            Request::sign(
+                &private_key,
+                program_id,
+                function_name,
+                inputs.clone().into_iter(),
+                &input_types,
+                &mut TestRng::from_seed(seed),
+            )
+            .unwrap()
+        };
+        let (first, second) = (sign(seed), sign(seed));
+        // Ensure the requests are identical, and valid.
+        assert_eq!(first, second);
+        assert!(first.verify(&input_types));
+        // Ensure a different seed produces a different request.
+        assert_ne!(first, sign(seed.wrapping_add(1)));
+    }
 }
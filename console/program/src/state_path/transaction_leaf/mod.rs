@@ -47,6 +47,11 @@ impl<N: Network> TransactionLeaf<N> {
         Self { variant: 1, index, id }
     }
 
+    /// Initializes a new instance of `TransactionLeaf`.
+    pub const fn new_expiration(index: u16, id: Field<N>) -> Self {
+        Self { variant: 2, index, id }
+    }
+
     /// Initializes a new instance of `TransactionLeaf`.
     pub const fn from(variant: u8, index: u16, id: Field<N>) -> Self {
         Self { variant, index, id }
@@ -223,6 +223,23 @@ impl<E: Environment> Inverse for Field<E> {
     }
 }
 
+impl<E: Environment> Field<E> {
+    /// Inverts each of the given field elements in place, using Montgomery's trick.
+    /// This requires a single field inversion for the entire batch.
+    pub fn batch_inverse(fields: &mut [Self]) -> Result<()> {
+        // Ensure none of the field elements are zero.
+        if let Some(index) = fields.iter().position(|field| field.is_zero()) {
+            bail!("Failed to batch invert field elements: the field element at index {index} is zero")
+        }
+        // Invert the field elements.
+        let mut inverses = fields.iter().map(|field| field.field).collect::<Vec<_>>();
+        batch_inversion(&mut inverses);
+        // Store the inverses.
+        fields.iter_mut().zip_eq(inverses).for_each(|(field, inverse)| *field = Field::new(inverse));
+        Ok(())
+    }
+}
+
 impl<E: Environment> Square for Field<E> {
     type Output = Field<E>;
 
@@ -323,4 +340,23 @@ mod tests {
         let result = std::panic::catch_unwind(|| one / zero);
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_batch_inverse() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_fields in 0..100 {
+            let fields = (0..num_fields).map(|_| Field::<CurrentEnvironment>::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = fields.iter().map(|field| field.inverse()).collect::<Result<Vec<_>>>()?;
+
+            let mut candidate = fields.clone();
+            Field::batch_inverse(&mut candidate)?;
+            assert_eq!(expected, candidate);
+        }
+
+        // Ensure a batch containing zero fails.
+        let mut fields = vec![Field::<CurrentEnvironment>::one(), Field::zero()];
+        assert!(Field::batch_inverse(&mut fields).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,44 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> Group<E> {
+    /// Returns the affine coordinates of each of the given group elements.
+    /// The group elements are normalized as a batch, which requires a single field inversion.
+    pub fn batch_to_affine(groups: &[Self]) -> Vec<E::Affine> {
+        E::Projective::batch_normalization_into_affine(groups.iter().map(|group| group.group).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    const ITERATIONS: usize = 100;
+
+    #[test]
+    fn test_batch_to_affine() {
+        let mut rng = TestRng::default();
+
+        for num_groups in 0..ITERATIONS {
+            let groups = (0..num_groups).map(|_| Group::<CurrentEnvironment>::rand(&mut rng)).collect::<Vec<_>>();
+            let expected = groups.iter().map(|group| group.to_affine()).collect::<Vec<_>>();
+            assert_eq!(expected, Group::batch_to_affine(&groups));
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the concatenation of `self` and `other`.
+    pub fn concat(&self, other: &Self) -> Result<Self> {
+        // Ensure the concatenation is within the allowed capacity.
+        let num_bytes = self.len().saturating_add(other.len());
+        ensure!(
+            num_bytes <= E::MAX_STRING_BYTES as usize,
+            "Cannot concatenate strings into {num_bytes} bytes (the maximum is {})",
+            E::MAX_STRING_BYTES
+        );
+        Ok(Self::new(&format!("{}{}", self.string, other.string)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_concat() -> Result<()> {
+        let a = StringType::<CurrentEnvironment>::new("hello, ");
+        let b = StringType::<CurrentEnvironment>::new("world");
+        assert_eq!(*a.concat(&b)?, *"hello, world");
+        assert_eq!(*a.concat(&StringType::new(""))?, *"hello, ");
+
+        // Ensure the concatenation cannot exceed the maximum size.
+        let long = StringType::<CurrentEnvironment>::new(&"a".repeat(CurrentEnvironment::MAX_STRING_BYTES as usize));
+        assert!(long.concat(&StringType::new("")).is_ok());
+        assert!(long.concat(&StringType::new("b")).is_err());
+        Ok(())
+    }
+}
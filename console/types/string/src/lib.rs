@@ -17,9 +17,11 @@
 
 mod bitwise;
 mod bytes;
+mod concat;
 mod parse;
 mod random;
 mod serialize;
+mod slice;
 
 pub use snarkvm_console_network_environment::prelude::*;
 pub use snarkvm_console_types_boolean::Boolean;
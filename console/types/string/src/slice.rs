@@ -0,0 +1,51 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<E: Environment> StringType<E> {
+    /// Returns the substring of `self` from the byte index `start` (inclusive) to `end` (exclusive).
+    pub fn slice(&self, start: usize, end: usize) -> Result<Self> {
+        match self.string.get(start..end) {
+            Some(substring) => Ok(Self::new(substring)),
+            None => bail!("Cannot slice bytes {start} to {end} of a string of {} bytes", self.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_console_network_environment::Console;
+
+    type CurrentEnvironment = Console;
+
+    #[test]
+    fn test_slice() -> Result<()> {
+        let string = StringType::<CurrentEnvironment>::new("hello, world");
+        assert_eq!(*string.slice(0, 5)?, *"hello");
+        assert_eq!(*string.slice(7, 12)?, *"world");
+        assert_eq!(*string.slice(3, 3)?, *"");
+
+        // Ensure the bounds are checked.
+        assert!(string.slice(5, 4).is_err());
+        assert!(string.slice(0, 13).is_err());
+
+        // Ensure the slice is on character boundaries.
+        let string = StringType::<CurrentEnvironment>::new("é");
+        assert!(string.slice(0, 1).is_err());
+        assert_eq!(*string.slice(0, 2)?, *"é");
+        Ok(())
+    }
+}
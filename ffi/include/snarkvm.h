@@ -0,0 +1,57 @@
+/*
+ * Copyright (C) 2019-2023 Aleo Systems Inc.
+ * This file is part of the snarkVM library.
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at:
+ * http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#ifndef SNARKVM_H
+#define SNARKVM_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Every function uses the Testnet3 network parameters.
+ * On failure, including an internal panic, a function returns NULL (or -1),
+ * and the error message is available via `snarkvm_last_error`.
+ */
+
+/* Memory management. */
+char *snarkvm_last_error(void);
+void snarkvm_string_free(char *string);
+void snarkvm_bytes_free(uint8_t *bytes, size_t length);
+
+/* Accounts. */
+char *snarkvm_private_key_new(void);
+char *snarkvm_private_key_to_view_key(const char *private_key);
+char *snarkvm_private_key_to_address(const char *private_key);
+char *snarkvm_view_key_to_address(const char *view_key);
+
+/* Records. */
+char *snarkvm_record_decrypt(const char *view_key, const char *record);
+int32_t snarkvm_record_is_owner(const char *view_key, const char *record);
+
+/* Transactions. */
+char *snarkvm_transaction_from_bytes(const uint8_t *bytes, size_t length);
+uint8_t *snarkvm_transaction_to_bytes(const char *transaction, size_t *length);
+char *snarkvm_transaction_id(const char *transaction);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SNARKVM_H */
@@ -0,0 +1,135 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{catch_panic, read_string, write_string, CurrentNetwork};
+use snarkvm_console::account::{Address, PrivateKey, ViewKey};
+
+use core::{ptr, str::FromStr};
+use std::os::raw::c_char;
+
+/// Returns a new private key, sampled from the operating system's randomness.
+#[no_mangle]
+pub extern "C" fn snarkvm_private_key_new() -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        write_string(
+            PrivateKey::<CurrentNetwork>::new(&mut rand::thread_rng())
+                .map(|private_key| private_key.to_string())
+                .map_err(|error| error.to_string()),
+        )
+    })
+}
+
+/// Returns the view key of the given private key.
+///
+/// # Safety
+/// The given pointer must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_view_key(private_key: *const c_char) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+        write_string(unsafe { parse_private_key(private_key) }.and_then(|private_key| {
+            ViewKey::try_from(&private_key).map(|view_key| view_key.to_string()).map_err(|error| error.to_string())
+        }))
+    })
+}
+
+/// Returns the address of the given private key.
+///
+/// # Safety
+/// The given pointer must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_private_key_to_address(private_key: *const c_char) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+        write_string(unsafe { parse_private_key(private_key) }.and_then(|private_key| {
+            Address::try_from(&private_key).map(|address| address.to_string()).map_err(|error| error.to_string())
+        }))
+    })
+}
+
+/// Returns the address of the given view key.
+///
+/// # Safety
+/// The given pointer must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_view_key_to_address(view_key: *const c_char) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+        write_string(unsafe { parse_view_key(view_key) }.and_then(|view_key| {
+            Address::try_from(&view_key).map(|address| address.to_string()).map_err(|error| error.to_string())
+        }))
+    })
+}
+
+/// Parses a private key from the given C string.
+///
+/// # Safety
+/// The given pointer must be null, or point to a valid NUL-terminated string.
+unsafe fn parse_private_key(private_key: *const c_char) -> Result<PrivateKey<CurrentNetwork>, String> {
+    // SAFETY: The caller guarantees the pointer is null or a valid NUL-terminated string.
+    let private_key = unsafe { read_string(private_key) }?;
+    PrivateKey::from_str(private_key).map_err(|error| format!("Invalid private key: {error}"))
+}
+
+/// Parses a view key from the given C string.
+///
+/// # Safety
+/// The given pointer must be null, or point to a valid NUL-terminated string.
+pub(crate) unsafe fn parse_view_key(view_key: *const c_char) -> Result<ViewKey<CurrentNetwork>, String> {
+    // SAFETY: The caller guarantees the pointer is null or a valid NUL-terminated string.
+    let view_key = unsafe { read_string(view_key) }?;
+    ViewKey::from_str(view_key).map_err(|error| format!("Invalid view key: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{snarkvm_last_error, test_helpers::take_string};
+
+    use std::ffi::CString;
+
+    #[test]
+    fn test_account() {
+        const ALEO_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
+        const ALEO_VIEW_KEY: &str = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+        const ALEO_ADDRESS: &str = "aleo1wvgwnqvy46qq0zemj0k6sfp3zv0mp77rw97khvwuhac05yuwscxqmfyhwf";
+
+        let private_key = CString::new(ALEO_PRIVATE_KEY).unwrap();
+        let view_key = take_string(unsafe { snarkvm_private_key_to_view_key(private_key.as_ptr()) });
+        assert_eq!(ALEO_VIEW_KEY, view_key);
+
+        let address = take_string(unsafe { snarkvm_private_key_to_address(private_key.as_ptr()) });
+        assert_eq!(ALEO_ADDRESS, address);
+
+        let view_key = CString::new(view_key).unwrap();
+        let address = take_string(unsafe { snarkvm_view_key_to_address(view_key.as_ptr()) });
+        assert_eq!(ALEO_ADDRESS, address);
+    }
+
+    #[test]
+    fn test_private_key_new() {
+        let private_key = take_string(snarkvm_private_key_new());
+        assert!(PrivateKey::<CurrentNetwork>::from_str(&private_key).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let invalid = CString::new("APrivateKey1invalid").unwrap();
+        assert!(unsafe { snarkvm_private_key_to_address(invalid.as_ptr()) }.is_null());
+        assert!(take_string(snarkvm_last_error()).starts_with("Invalid private key"));
+
+        assert!(unsafe { snarkvm_view_key_to_address(core::ptr::null()) }.is_null());
+        assert_eq!(take_string(snarkvm_last_error()), "Received a null string");
+    }
+}
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A stable C ABI for the account, record, and transaction primitives of snarkVM.
+//!
+//! Every function that returns a string returns a NUL-terminated, heap-allocated C string,
+//! which must be released with [`snarkvm_string_free`]. Every function that returns a byte
+//! buffer writes its length into the given out-parameter, and the buffer must be released
+//! with [`snarkvm_bytes_free`]. On failure, a null pointer (or `-1`) is returned, and the
+//! error message for the calling thread is available via [`snarkvm_last_error`].
+//! Panics are caught at the boundary and reported the same way, so they never unwind into C.
+//!
+//! Note: Every function uses the Testnet3 network parameters, so keys, addresses, records,
+//! and transactions from any other network are rejected or misinterpreted.
+
+#![forbid(unsafe_op_in_unsafe_fn)]
+#![warn(clippy::cast_possible_truncation)]
+
+mod account;
+pub use account::*;
+
+mod record;
+pub use record::*;
+
+mod transaction;
+pub use transaction::*;
+
+use snarkvm_console::network::Testnet3;
+
+use core::{cell::RefCell, ptr};
+use std::{
+    any::Any,
+    ffi::{CStr, CString},
+    os::raw::c_char,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+/// The network of every function in this library.
+type CurrentNetwork = Testnet3;
+
+thread_local! {
+    /// The last error message that occurred on the current thread.
+    static LAST_ERROR: RefCell<Option<CString>> = RefCell::new(None);
+}
+
+/// Returns the last error message that occurred on the calling thread, or null if there is none.
+/// The returned string must be released with `snarkvm_string_free`.
+#[no_mangle]
+pub extern "C" fn snarkvm_last_error() -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        LAST_ERROR.with(|error| match error.borrow().as_ref() {
+            Some(message) => message.clone().into_raw(),
+            None => ptr::null_mut(),
+        })
+    })
+}
+
+/// Releases a string that was returned by this library.
+///
+/// # Safety
+/// The given pointer must be null, or have been returned by this library and not yet released.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_string_free(string: *mut c_char) {
+    catch_panic((), || {
+        if !string.is_null() {
+            // SAFETY: The caller guarantees the string was allocated by `CString::into_raw`.
+            drop(unsafe { CString::from_raw(string) });
+        }
+    })
+}
+
+/// Releases a byte buffer that was returned by this library.
+///
+/// # Safety
+/// The given pointer must be null, or have been returned by this library with the given length,
+/// and not yet released.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_bytes_free(bytes: *mut u8, length: usize) {
+    catch_panic((), || {
+        if !bytes.is_null() {
+            // SAFETY: The caller guarantees the buffer was allocated by `Box<[u8]>::into_raw` with this length.
+            drop(unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(bytes, length)) });
+        }
+    })
+}
+
+/// Records the given error as the last error on the current thread.
+fn set_last_error(error: impl ToString) {
+    // Interior NUL bytes are stripped, as they cannot be represented in a C string.
+    let message = error.to_string().replace('\0', "");
+    LAST_ERROR.with(|last| *last.borrow_mut() = CString::new(message).ok());
+}
+
+/// Runs the given function, catching any panic so that it does not unwind across the C ABI.
+/// On a panic, the panic message is recorded as the last error, and `on_panic` is returned.
+fn catch_panic<T>(on_panic: T, function: impl FnOnce() -> T) -> T {
+    match catch_unwind(AssertUnwindSafe(function)) {
+        Ok(output) => output,
+        Err(payload) => {
+            set_last_error(format!("Panicked: {}", panic_message(payload.as_ref())));
+            on_panic
+        }
+    }
+}
+
+/// Returns the message of the given panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        (None, None) => "unknown panic",
+    }
+}
+
+/// Reads a UTF-8 string from the given C string.
+///
+/// # Safety
+/// The given pointer must be null, or point to a valid NUL-terminated string.
+unsafe fn read_string<'a>(string: *const c_char) -> Result<&'a str, String> {
+    if string.is_null() {
+        return Err("Received a null string".to_string());
+    }
+    // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+    unsafe { CStr::from_ptr(string) }.to_str().map_err(|error| format!("Received an invalid UTF-8 string: {error}"))
+}
+
+/// Returns the given result as a C string, or null if it is an error.
+fn write_string(result: Result<String, String>) -> *mut c_char {
+    match result.and_then(|string| CString::new(string).map_err(|error| error.to_string())) {
+        Ok(string) => string.into_raw(),
+        Err(error) => {
+            set_last_error(error);
+            ptr::null_mut()
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use super::*;
+
+    /// Takes ownership of the given C string, returning it as a Rust string.
+    pub(crate) fn take_string(string: *mut c_char) -> String {
+        assert!(!string.is_null());
+        let result = unsafe { CStr::from_ptr(string) }.to_str().unwrap().to_string();
+        unsafe { snarkvm_string_free(string) };
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::take_string;
+
+    #[test]
+    fn test_catch_panic() {
+        // Ensure a panic is caught, and reported as the last error.
+        assert_eq!(catch_panic(-1, || panic!("Failed to {}", "proceed")), -1);
+        assert_eq!(take_string(snarkvm_last_error()), "Panicked: Failed to proceed");
+        assert!(catch_panic(ptr::null_mut::<c_char>(), || panic!("Failed")).is_null());
+        assert_eq!(take_string(snarkvm_last_error()), "Panicked: Failed");
+
+        // Ensure the output is returned if there is no panic.
+        assert_eq!(catch_panic(-1, || 1), 1);
+    }
+}
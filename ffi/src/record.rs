@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{account::parse_view_key, catch_panic, read_string, set_last_error, write_string, CurrentNetwork};
+use snarkvm_console::program::{Ciphertext, Record};
+
+use core::{ptr, str::FromStr};
+use std::os::raw::c_char;
+
+/// Returns the plaintext of the given record ciphertext, if it is owned by the given view key.
+///
+/// # Safety
+/// The given pointers must be valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_decrypt(view_key: *const c_char, record: *const c_char) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        // SAFETY: The caller guarantees the pointers are valid NUL-terminated strings.
+        write_string(unsafe { parse_view_key(view_key) }.and_then(|view_key| {
+            unsafe { parse_record(record) }?
+                .decrypt(&view_key)
+                .map(|record| record.to_string())
+                .map_err(|error| error.to_string())
+        }))
+    })
+}
+
+/// Returns `1` if the given record ciphertext is owned by the given view key, `0` if it is not,
+/// and `-1` if the inputs are invalid.
+///
+/// # Safety
+/// The given pointers must be valid NUL-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_record_is_owner(view_key: *const c_char, record: *const c_char) -> i32 {
+    catch_panic(-1, || {
+        // SAFETY: The caller guarantees the pointers are valid NUL-terminated strings.
+        let result = unsafe { parse_view_key(view_key) }
+            .and_then(|view_key| Ok(unsafe { parse_record(record) }?.is_owner(&view_key)));
+        match result {
+            Ok(is_owner) => i32::from(is_owner),
+            Err(error) => {
+                set_last_error(error);
+                -1
+            }
+        }
+    })
+}
+
+/// Parses a record ciphertext from the given C string.
+///
+/// # Safety
+/// The given pointer must be null, or point to a valid NUL-terminated string.
+unsafe fn parse_record(record: *const c_char) -> Result<Record<CurrentNetwork, Ciphertext<CurrentNetwork>>, String> {
+    // SAFETY: The caller guarantees the pointer is null or a valid NUL-terminated string.
+    let record = unsafe { read_string(record) }?;
+    Record::from_str(record).map_err(|error| format!("Invalid record ciphertext: {error}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::take_string;
+    use snarkvm_console::{
+        account::{Address, PrivateKey, ViewKey},
+        network::Network,
+        prelude::{TestRng, Uniform},
+        program::Plaintext,
+        types::Scalar,
+    };
+
+    use std::ffi::CString;
+
+    #[test]
+    fn test_record_decrypt() {
+        let rng = &mut TestRng::default();
+
+        // Sample a new account.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let view_key = ViewKey::try_from(&private_key).unwrap();
+        let address = Address::try_from(&view_key).unwrap();
+
+        // Construct and encrypt a record owned by the account.
+        let randomizer = Scalar::rand(rng);
+        let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+        let expected = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_str(&format!(
+            "{{ owner: {address}.private, amount: 5u64.private, _nonce: {nonce}.public }}"
+        ))
+        .unwrap();
+        let record = CString::new(expected.encrypt(randomizer).unwrap().to_string()).unwrap();
+
+        // Ensure the owner can decrypt the record.
+        let view_key = CString::new(view_key.to_string()).unwrap();
+        assert_eq!(unsafe { snarkvm_record_is_owner(view_key.as_ptr(), record.as_ptr()) }, 1);
+        let candidate = take_string(unsafe { snarkvm_record_decrypt(view_key.as_ptr(), record.as_ptr()) });
+        assert_eq!(expected.to_string(), candidate);
+
+        // Ensure a different account cannot decrypt the record.
+        let other = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let other = CString::new(other.to_string()).unwrap();
+        assert_eq!(unsafe { snarkvm_record_is_owner(other.as_ptr(), record.as_ptr()) }, 0);
+        assert!(unsafe { snarkvm_record_decrypt(other.as_ptr(), record.as_ptr()) }.is_null());
+
+        // Ensure malformed inputs are rejected.
+        let invalid = CString::new("record1invalid").unwrap();
+        assert_eq!(unsafe { snarkvm_record_is_owner(view_key.as_ptr(), invalid.as_ptr()) }, -1);
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{catch_panic, read_string, set_last_error, write_string, CurrentNetwork};
+use snarkvm_console::prelude::{FromBytes, ToBytes};
+use snarkvm_ledger_block::Transaction;
+
+use core::{ptr, str::FromStr};
+use std::os::raw::c_char;
+
+/// Returns the JSON representation of the given transaction bytes.
+///
+/// # Safety
+/// The given pointer must point to a buffer of at least `length` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_from_bytes(bytes: *const u8, length: usize) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        if bytes.is_null() {
+            return write_string(Err("Received a null buffer".to_string()));
+        }
+        // SAFETY: The caller guarantees the buffer holds at least `length` bytes.
+        let bytes = unsafe { std::slice::from_raw_parts(bytes, length) };
+        write_string(
+            Transaction::<CurrentNetwork>::from_bytes_le(bytes)
+                .map(|transaction| transaction.to_string())
+                .map_err(|error| format!("Invalid transaction bytes: {error}")),
+        )
+    })
+}
+
+/// Returns the bytes of the given transaction JSON, writing the number of bytes into `length`.
+/// The returned buffer must be released with `snarkvm_bytes_free`.
+///
+/// # Safety
+/// The given string must be a valid NUL-terminated string, and `length` must be a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_to_bytes(transaction: *const c_char, length: *mut usize) -> *mut u8 {
+    catch_panic(ptr::null_mut(), || {
+        // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+        let result = unsafe { read_string(transaction) }.and_then(|transaction| {
+            Transaction::<CurrentNetwork>::from_str(transaction)
+                .map_err(|error| format!("Invalid transaction: {error}"))?
+                .to_bytes_le()
+                .map_err(|error| error.to_string())
+        });
+        match (result, length.is_null()) {
+            (Ok(bytes), false) => {
+                // SAFETY: The caller guarantees `length` is a valid pointer.
+                unsafe { *length = bytes.len() };
+                Box::into_raw(bytes.into_boxed_slice()).cast()
+            }
+            (Ok(_), true) => {
+                set_last_error("Received a null length pointer");
+                ptr::null_mut()
+            }
+            (Err(error), _) => {
+                set_last_error(error);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Returns the ID of the given transaction JSON.
+///
+/// # Safety
+/// The given pointer must be a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn snarkvm_transaction_id(transaction: *const c_char) -> *mut c_char {
+    catch_panic(ptr::null_mut(), || {
+        // SAFETY: The caller guarantees the pointer is a valid NUL-terminated string.
+        write_string(unsafe { read_string(transaction) }.and_then(|transaction| {
+            Transaction::<CurrentNetwork>::from_str(transaction)
+                .map(|transaction| transaction.id().to_string())
+                .map_err(|error| format!("Invalid transaction: {error}"))
+        }))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{snarkvm_bytes_free, test_helpers::take_string};
+    use snarkvm_console::prelude::TestRng;
+
+    use std::ffi::CString;
+
+    #[test]
+    fn test_transaction_bytes() {
+        let rng = &mut TestRng::default();
+
+        for expected in [
+            ledger_test_helpers::sample_deployment_transaction(true, rng),
+            ledger_test_helpers::sample_execution_transaction_with_fee(false, rng),
+        ] {
+            let expected_bytes = expected.to_bytes_le().unwrap();
+
+            // Ensure the bytes convert to the transaction JSON.
+            let json =
+                take_string(unsafe { snarkvm_transaction_from_bytes(expected_bytes.as_ptr(), expected_bytes.len()) });
+            assert_eq!(expected.to_string(), json);
+
+            // Ensure the transaction JSON converts back to the same bytes.
+            let json = CString::new(json).unwrap();
+            let mut length = 0;
+            let bytes = unsafe { snarkvm_transaction_to_bytes(json.as_ptr(), &mut length) };
+            assert!(!bytes.is_null());
+            assert_eq!(expected_bytes, unsafe { std::slice::from_raw_parts(bytes, length) });
+            unsafe { snarkvm_bytes_free(bytes, length) };
+
+            // Ensure the transaction ID matches.
+            assert_eq!(expected.id().to_string(), take_string(unsafe { snarkvm_transaction_id(json.as_ptr()) }));
+        }
+    }
+
+    #[test]
+    fn test_invalid_transaction() {
+        // Ensure malformed bytes are rejected.
+        assert!(unsafe { snarkvm_transaction_from_bytes([0u8; 4].as_ptr(), 4) }.is_null());
+
+        // Ensure malformed JSON is rejected.
+        let invalid = CString::new("{}").unwrap();
+        let mut length = 0;
+        assert!(unsafe { snarkvm_transaction_to_bytes(invalid.as_ptr(), &mut length) }.is_null());
+        assert_eq!(length, 0);
+    }
+}
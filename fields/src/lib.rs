@@ -63,19 +63,22 @@ impl_field_to_biginteger!(Fp384, BigInteger384, Fp384Parameters);
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
 
-// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
+/// Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}.
+/// Zero elements are left unchanged.
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
     batch_inversion_and_mul(v, &F::one());
 }
 
 #[cfg(feature = "serial")]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+/// Zero elements are left unchanged.
 pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     serial_batch_inversion_and_mul(v, coeff);
 }
 
 #[cfg(not(feature = "serial"))]
-// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}
+/// Given a vector of field elements {v_i}, compute the vector {coeff * v_i^(-1)}.
+/// Zero elements are left unchanged.
 pub fn batch_inversion_and_mul<F: Field>(v: &mut [F], coeff: &F) {
     use rayon::prelude::*;
     // Divide the vector v evenly between all available cores
@@ -39,6 +39,22 @@ pub trait PoseidonDefaultField {
     /// Obtain the default Poseidon parameters for this rate and for this prime field,
     /// with a specific optimization goal.
     fn default_poseidon_parameters<const RATE: usize>() -> Result<PoseidonParameters<Self, RATE, 1>>
+    where
+        Self: PrimeField,
+    {
+        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
+            Some(entry) => Self::poseidon_parameters::<RATE>(entry),
+            None => bail!("No Poseidon parameters were found for this rate"),
+        }
+    }
+
+    /// Generates the Poseidon parameters for the given entry, for this prime field.
+    /// This allows instantiating Poseidon with a rate that has no default parameters (e.g. a rate of 16).
+    ///
+    /// Note: The caller is responsible for choosing a number of rounds that is secure for the given rate and `alpha`.
+    fn poseidon_parameters<const RATE: usize>(
+        entry: &PoseidonDefaultParametersEntry,
+    ) -> Result<PoseidonParameters<Self, RATE, 1>>
     where
         Self: PrimeField,
     {
@@ -89,23 +105,29 @@ pub trait PoseidonDefaultField {
             Ok((ark, mds))
         }
 
-        match Self::Parameters::PARAMS_OPT_FOR_CONSTRAINTS.iter().find(|entry| entry.rate == RATE) {
-            Some(entry) => {
-                let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
-                    entry.full_rounds as u64,
-                    entry.partial_rounds as u64,
-                    entry.skip_matrices as u64,
-                )?;
-                Ok(PoseidonParameters {
-                    full_rounds: entry.full_rounds,
-                    partial_rounds: entry.partial_rounds,
-                    alpha: entry.alpha as u64,
-                    ark,
-                    mds,
-                })
-            }
-            None => bail!("No Poseidon parameters were found for this rate"),
+        // Ensure the entry is well-formed.
+        if RATE == 0 || entry.rate != RATE {
+            bail!("The Poseidon parameters entry has rate {}, but rate {RATE} was requested", entry.rate)
         }
+        if entry.full_rounds == 0 || entry.full_rounds % 2 != 0 {
+            bail!("The number of full rounds must be a positive even number, found {}", entry.full_rounds)
+        }
+        if entry.alpha < 3 || entry.alpha % 2 == 0 {
+            bail!("The S-box exponent must be an odd number greater than 1, found {}", entry.alpha)
+        }
+
+        let (ark, mds) = find_poseidon_ark_and_mds::<Self, RATE>(
+            entry.full_rounds as u64,
+            entry.partial_rounds as u64,
+            entry.skip_matrices as u64,
+        )?;
+        Ok(PoseidonParameters {
+            full_rounds: entry.full_rounds,
+            partial_rounds: entry.partial_rounds,
+            alpha: entry.alpha as u64,
+            ark,
+            mds,
+        })
     }
 }
 
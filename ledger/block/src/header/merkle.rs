@@ -26,6 +26,50 @@ impl<N: Network> Header<N> {
         self.to_tree()?.prove(leaf.index() as usize, &leaf.to_bits_le())
     }
 
+    /// Returns the Merkle path proving that the given leaf belongs to the block header.
+    /// This allows a client to verify a single header field against the header root, without the block.
+    pub fn prove_inclusion(&self, leaf: &HeaderLeaf<N>) -> Result<HeaderPath<N>> {
+        // Ensure the leaf matches the corresponding leaf in the header.
+        ensure!(
+            &self.to_leaf_at(leaf.index())? == leaf,
+            "Block header leaf {} does not match the header",
+            leaf.index()
+        );
+        // Compute the Merkle path.
+        self.to_path(leaf)
+    }
+
+    /// Returns `true` if the given Merkle path proves that the leaf belongs to a block header with the given root.
+    pub fn verify_inclusion(root: &Field<N>, path: &HeaderPath<N>, leaf: &HeaderLeaf<N>) -> bool {
+        // Ensure the path is for the leaf index.
+        if *path.leaf_index() != leaf.index() as u64 {
+            return false;
+        }
+        N::verify_merkle_path_bhp(path, root, &leaf.to_bits_le())
+    }
+
+    /// Returns the Merkle leaf at the given index in the header.
+    pub fn to_leaf_at(&self, index: u8) -> Result<HeaderLeaf<N>> {
+        match self.to_leaves()?.into_iter().nth(index as usize) {
+            Some(leaf) => Ok(leaf),
+            None => bail!("Non-existent block header leaf index: {index}"),
+        }
+    }
+
+    /// Returns the Merkle leaves of the block header, in order.
+    pub fn to_leaves(&self) -> Result<Vec<HeaderLeaf<N>>> {
+        Ok(vec![
+            HeaderLeaf::<N>::new(0, *self.previous_state_root),
+            HeaderLeaf::<N>::new(1, self.transactions_root),
+            HeaderLeaf::<N>::new(2, self.finalize_root),
+            HeaderLeaf::<N>::new(3, self.ratifications_root),
+            HeaderLeaf::<N>::new(4, self.solutions_root),
+            HeaderLeaf::<N>::new(5, self.subdag_root),
+            HeaderLeaf::<N>::new(6, Field::zero()),
+            HeaderLeaf::<N>::new(7, self.metadata.to_hash()?),
+        ])
+    }
+
     /// Returns the Merkle leaf for the given ID in the header.
     pub fn to_leaf(&self, id: &Field<N>) -> Result<HeaderLeaf<N>> {
         // If the ID is the previous state root, return the 0th leaf.
@@ -68,15 +112,7 @@ impl<N: Network> Header<N> {
         let num_leaves = usize::pow(2, HEADER_DEPTH as u32);
 
         // Construct the Merkle leaves.
-        let mut leaves: Vec<Vec<bool>> = Vec::with_capacity(num_leaves);
-        leaves.push(HeaderLeaf::<N>::new(0, *self.previous_state_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(1, self.transactions_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(2, self.finalize_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(3, self.ratifications_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(4, self.solutions_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(5, self.subdag_root).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(6, Field::zero()).to_bits_le());
-        leaves.push(HeaderLeaf::<N>::new(7, self.metadata.to_hash()?).to_bits_le());
+        let leaves: Vec<Vec<bool>> = self.to_leaves()?.iter().map(|leaf| leaf.to_bits_le()).collect();
 
         // Ensure the correct number of leaves are allocated.
         ensure!(num_leaves == leaves.len(), "Incorrect number of leaves in the Merkle tree for the block header");
@@ -172,6 +208,19 @@ mod tests {
             let leaf = header.to_leaf(&CurrentNetwork::hash_bhp1024(&header.metadata().to_bits_le())?)?;
             assert_eq!(leaf.index(), 7);
             check_path(header.to_path(&leaf)?, root, &leaf)?;
+
+            // Check the inclusion proofs for every leaf.
+            for (index, leaf) in header.to_leaves()?.iter().enumerate() {
+                assert_eq!(leaf.index() as usize, index);
+                assert_eq!(&header.to_leaf_at(leaf.index())?, leaf);
+                let path = header.prove_inclusion(leaf)?;
+                assert!(Header::verify_inclusion(&root, &path, leaf));
+                // Ensure a different leaf does not verify against the same path.
+                let other = HeaderLeaf::new(leaf.index(), leaf.id() + Field::one());
+                assert!(header.prove_inclusion(&other).is_err());
+                assert!(!Header::verify_inclusion(&root, &path, &other));
+            }
+            assert!(header.to_leaf_at(8).is_err());
         }
 
         Ok(())
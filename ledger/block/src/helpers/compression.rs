@@ -0,0 +1,104 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Block, Transactions};
+use console::network::prelude::*;
+
+use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+
+/// The version of the compressed encoding.
+const COMPRESSED_VERSION: u8 = 1;
+
+/// An opt-in compressed encoding, for sending large objects over the wire.
+///
+/// The encoding is a version byte, followed by a zlib stream of the little-endian byte encoding.
+/// The zlib stream is checksummed, so corrupted data is rejected when it is read.
+pub trait CompressedBytes: FromBytes + ToBytes + Sized {
+    /// Writes the object to the given writer, in the compressed encoding.
+    fn write_le_compressed<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        COMPRESSED_VERSION.write_le(&mut writer)?;
+        // Write the compressed object.
+        let mut encoder = ZlibEncoder::new(writer, Compression::default());
+        self.write_le(&mut encoder)?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    /// Reads the object from the given reader, in the compressed encoding.
+    fn read_le_compressed<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != COMPRESSED_VERSION {
+            return Err(error("Invalid compressed encoding version"));
+        }
+        // Read the compressed object.
+        let mut decoder = ZlibDecoder::new(reader);
+        let object = Self::read_le(&mut decoder)?;
+        // Ensure the stream is fully consumed, so that the checksum is verified.
+        if decoder.read(&mut [0u8])? != 0 {
+            return Err(error("Trailing bytes in the compressed encoding"));
+        }
+        Ok(object)
+    }
+
+    /// Returns the object as bytes, in the compressed encoding.
+    fn to_bytes_le_compressed(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.write_le_compressed(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the object from bytes, in the compressed encoding.
+    fn from_bytes_le_compressed(bytes: &[u8]) -> Result<Self> {
+        Ok(Self::read_le_compressed(bytes)?)
+    }
+}
+
+impl<N: Network> CompressedBytes for Block<N> {}
+
+impl<N: Network> CompressedBytes for Transactions<N> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_compressed_bytes() -> Result<()> {
+        // Load the genesis block.
+        let expected = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+
+        // Check the compressed encoding of the block.
+        let compressed = expected.to_bytes_le_compressed()?;
+        assert!(compressed.len() < expected.to_bytes_le()?.len());
+        assert_eq!(expected, Block::from_bytes_le_compressed(&compressed)?);
+
+        // Check the compressed encoding of the transactions.
+        let transactions = expected.transactions();
+        let compressed = transactions.to_bytes_le_compressed()?;
+        assert_eq!(*transactions, Transactions::from_bytes_le_compressed(&compressed)?);
+
+        // Ensure corrupted and truncated encodings are rejected.
+        let mut corrupted = compressed.clone();
+        let index = corrupted.len() / 2;
+        corrupted[index] ^= 1;
+        assert!(Transactions::<CurrentNetwork>::from_bytes_le_compressed(&corrupted).is_err());
+        assert!(Transactions::<CurrentNetwork>::from_bytes_le_compressed(&compressed[..index]).is_err());
+        Ok(())
+    }
+}
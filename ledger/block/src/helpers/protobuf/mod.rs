@@ -0,0 +1,635 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    Block,
+    ConfirmedTransaction,
+    Deployment,
+    Execution,
+    Fee,
+    Header,
+    Input,
+    Metadata,
+    Output,
+    Ratify,
+    Rejected,
+    Transaction,
+    Transactions,
+    Transition,
+};
+use console::{network::prelude::*, program::ProgramOwner};
+use ledger_authority::Authority;
+use ledger_coinbase::ProverSolution;
+use ledger_committee::Committee;
+use ledger_narwhal_batch_certificate::BatchCertificate;
+use ledger_narwhal_subdag::Subdag;
+use ledger_narwhal_transmission_id::TransmissionID;
+use synthesizer_program::FinalizeOperation;
+
+/// The protobuf schema for the core chain types.
+pub const PROTOBUF_SCHEMA: &str = include_str!("./block.proto");
+
+/// The protobuf wire type for varints.
+const WIRE_TYPE_VARINT: u64 = 0;
+/// The protobuf wire type for length-delimited fields.
+const WIRE_TYPE_LEN: u64 = 2;
+
+/// An encoder for the protobuf wire format.
+#[derive(Default)]
+pub struct ProtobufWriter {
+    /// The encoded bytes.
+    buffer: Vec<u8>,
+}
+
+impl ProtobufWriter {
+    /// Initializes a new, empty writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the encoded bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    /// Writes a varint field, omitting it if it is zero.
+    pub fn write_uint(&mut self, field: u64, value: u64) {
+        if value != 0 {
+            self.write_key(field, WIRE_TYPE_VARINT);
+            self.write_varint(value);
+        }
+    }
+
+    /// Writes a varint field with explicit presence (in a `oneof`, or `optional`), even if it is zero.
+    pub fn write_explicit_uint(&mut self, field: u64, value: u64) {
+        self.write_key(field, WIRE_TYPE_VARINT);
+        self.write_varint(value);
+    }
+
+    /// Writes a boolean field, omitting it if it is `false`.
+    pub fn write_bool(&mut self, field: u64, value: bool) {
+        self.write_uint(field, u64::from(value));
+    }
+
+    /// Writes a signed varint field (`int64`), omitting it if it is zero.
+    pub fn write_int(&mut self, field: u64, value: i64) {
+        // Note: Protobuf encodes `int64` as the two's complement of the value.
+        self.write_uint(field, value as u64);
+    }
+
+    /// Writes a string field, omitting it if it is empty.
+    pub fn write_string(&mut self, field: u64, value: &str) {
+        self.write_bytes(field, value.as_bytes());
+    }
+
+    /// Writes a bytes field, omitting it if it is empty.
+    pub fn write_bytes(&mut self, field: u64, value: &[u8]) {
+        if !value.is_empty() {
+            self.write_len(field, value);
+        }
+    }
+
+    /// Writes a message field, with its contents encoded by the given closure.
+    pub fn write_message(&mut self, field: u64, write: impl FnOnce(&mut Self) -> Result<()>) -> Result<()> {
+        let mut writer = Self::new();
+        write(&mut writer)?;
+        self.write_len(field, &writer.buffer);
+        Ok(())
+    }
+
+    /// Writes a length-delimited field.
+    fn write_len(&mut self, field: u64, value: &[u8]) {
+        self.write_key(field, WIRE_TYPE_LEN);
+        self.write_varint(value.len() as u64);
+        self.buffer.extend_from_slice(value);
+    }
+
+    /// Writes the key of a field.
+    fn write_key(&mut self, field: u64, wire_type: u64) {
+        self.write_varint((field << 3) | wire_type);
+    }
+
+    /// Writes a varint.
+    fn write_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.push(value as u8);
+    }
+}
+
+/// A type that can be encoded as a protobuf message, as described by [`PROTOBUF_SCHEMA`].
+pub trait ToProtobuf {
+    /// Writes the fields of the message.
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()>;
+
+    /// Returns the protobuf encoding of the message.
+    fn to_protobuf(&self) -> Result<Vec<u8>> {
+        let mut writer = ProtobufWriter::new();
+        self.write_protobuf(&mut writer)?;
+        Ok(writer.into_bytes())
+    }
+}
+
+impl<N: Network> ToProtobuf for Block<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.hash().to_string());
+        writer.write_string(2, &self.previous_hash().to_string());
+        writer.write_message(3, |writer| self.header().write_protobuf(writer))?;
+        writer.write_message(4, |writer| self.authority().write_protobuf(writer))?;
+        for ratify in self.ratifications().iter() {
+            writer.write_message(5, |writer| ratify.write_protobuf(writer))?;
+        }
+        for solution in self.solutions().into_iter().flat_map(|solutions| solutions.values()) {
+            writer.write_message(6, |writer| solution.write_protobuf(writer))?;
+        }
+        for transaction in self.transactions().iter() {
+            writer.write_message(7, |writer| transaction.write_protobuf(writer))?;
+        }
+        for transaction_id in self.aborted_transaction_ids() {
+            writer.write_string(8, &transaction_id.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Header<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.previous_state_root().to_string());
+        writer.write_string(2, &self.transactions_root().to_string());
+        writer.write_string(3, &self.finalize_root().to_string());
+        writer.write_string(4, &self.ratifications_root().to_string());
+        writer.write_string(5, &self.solutions_root().to_string());
+        writer.write_string(6, &self.subdag_root().to_string());
+        writer.write_message(7, |writer| self.metadata().write_protobuf(writer))
+    }
+}
+
+impl<N: Network> ToProtobuf for Metadata<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_uint(1, u64::from(self.network()));
+        writer.write_uint(2, self.round());
+        writer.write_uint(3, u64::from(self.height()));
+        writer.write_string(4, &self.cumulative_weight().to_string());
+        writer.write_string(5, &self.cumulative_proof_target().to_string());
+        writer.write_uint(6, self.coinbase_target());
+        writer.write_uint(7, self.proof_target());
+        writer.write_uint(8, self.last_coinbase_target());
+        writer.write_int(9, self.last_coinbase_timestamp());
+        writer.write_int(10, self.timestamp());
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Authority<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        match self {
+            Self::Beacon(signature) => {
+                writer.write_string(1, &signature.to_string());
+                Ok(())
+            }
+            Self::Quorum(subdag) => writer.write_message(2, |writer| subdag.write_protobuf(writer)),
+        }
+    }
+}
+
+impl<N: Network> ToProtobuf for Subdag<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        for (round, certificates) in self.iter() {
+            writer.write_message(1, |writer| {
+                writer.write_uint(1, *round);
+                for certificate in certificates {
+                    writer.write_message(2, |writer| certificate.write_protobuf(writer))?;
+                }
+                Ok(())
+            })?;
+        }
+        for certificate_id in self.election_certificate_ids() {
+            writer.write_string(2, &certificate_id.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for BatchCertificate<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.id().to_string());
+        // Write the batch header.
+        let header = self.batch_header();
+        writer.write_message(2, |writer| {
+            writer.write_string(1, &header.batch_id().to_string());
+            writer.write_string(2, &header.author().to_string());
+            writer.write_uint(3, header.round());
+            writer.write_int(4, header.timestamp());
+            for transmission_id in header.transmission_ids() {
+                writer.write_message(5, |writer| transmission_id.write_protobuf(writer))?;
+            }
+            for certificate_id in header.previous_certificate_ids() {
+                writer.write_string(6, &certificate_id.to_string());
+            }
+            for certificate_id in header.last_election_certificate_ids() {
+                writer.write_string(7, &certificate_id.to_string());
+            }
+            writer.write_string(8, &header.signature().to_string());
+            Ok(())
+        })?;
+        // Write the signatures, with their timestamps for a version 1 certificate.
+        match self {
+            Self::V1 { signatures, .. } => {
+                for (signature, timestamp) in signatures {
+                    writer.write_message(3, |writer| {
+                        writer.write_string(1, &signature.to_string());
+                        writer.write_int(2, *timestamp);
+                        Ok(())
+                    })?;
+                }
+            }
+            Self::V2 { signatures, .. } => {
+                for signature in signatures {
+                    writer.write_message(3, |writer| {
+                        writer.write_string(1, &signature.to_string());
+                        Ok(())
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for TransmissionID<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        match self {
+            Self::Ratification => writer.write_bool(1, true),
+            Self::Solution(commitment) => writer.write_string(2, &commitment.to_string()),
+            Self::Transaction(transaction_id) => writer.write_string(3, &transaction_id.to_string()),
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Ratify<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        match self {
+            Self::Genesis(committee, public_balances) => writer.write_message(1, |writer| {
+                writer.write_message(1, |writer| committee.write_protobuf(writer))?;
+                for (address, balance) in public_balances {
+                    // Note: Each entry of a map is encoded as a message with the key and value as fields 1 and 2.
+                    writer.write_message(2, |writer| {
+                        writer.write_string(1, &address.to_string());
+                        writer.write_uint(2, *balance);
+                        Ok(())
+                    })?;
+                }
+                Ok(())
+            }),
+            Self::BlockReward(reward) => {
+                writer.write_explicit_uint(2, *reward);
+                Ok(())
+            }
+            Self::PuzzleReward(reward) => {
+                writer.write_explicit_uint(3, *reward);
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<N: Network> ToProtobuf for Committee<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_uint(1, self.starting_round());
+        for (address, (stake, is_open)) in self.members() {
+            writer.write_message(2, |writer| {
+                writer.write_string(1, &address.to_string());
+                writer.write_uint(2, *stake);
+                writer.write_bool(3, *is_open);
+                Ok(())
+            })?;
+        }
+        writer.write_uint(3, self.total_stake());
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for ProverSolution<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.address().to_string());
+        writer.write_uint(2, self.nonce());
+        writer.write_string(3, &self.commitment().to_string());
+        writer.write_bytes(4, &self.proof().to_bytes_le()?);
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Transactions<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        for transaction in self.iter() {
+            writer.write_message(1, |writer| transaction.write_protobuf(writer))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for ConfirmedTransaction<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_bool(1, self.is_rejected());
+        let is_execute = matches!(self, Self::AcceptedExecute(..) | Self::RejectedExecute(..));
+        writer.write_bool(2, is_execute);
+        writer.write_uint(3, u64::from(self.index()));
+        writer.write_message(4, |writer| self.transaction().write_protobuf(writer))?;
+        if let Some(rejected) = self.to_rejected() {
+            writer.write_message(5, |writer| rejected.write_protobuf(writer))?;
+        }
+        for operation in self.finalize_operations() {
+            writer.write_message(6, |writer| operation.write_protobuf(writer))?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Rejected<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        match self {
+            Self::Deployment(owner, deployment) => writer.write_message(1, |writer| {
+                writer.write_message(1, |writer| owner.write_protobuf(writer))?;
+                writer.write_message(2, |writer| deployment.write_protobuf(writer))
+            }),
+            Self::Execution(execution) => writer.write_message(2, |writer| execution.write_protobuf(writer)),
+        }
+    }
+}
+
+impl<N: Network> ToProtobuf for FinalizeOperation<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        // Retrieve the type, mapping ID, index, key ID, and value ID of the operation.
+        let (variant, mapping_id, index, key_id, value_id) = match self {
+            Self::InitializeMapping(mapping_id) => (0, mapping_id, None, None, None),
+            Self::InsertKeyValue(mapping_id, key_id, value_id) => (1, mapping_id, None, Some(key_id), Some(value_id)),
+            Self::UpdateKeyValue(mapping_id, index, key_id, value_id) => {
+                (2, mapping_id, Some(index), Some(key_id), Some(value_id))
+            }
+            Self::RemoveKeyValue(mapping_id, index) => (3, mapping_id, Some(index), None, None),
+            Self::ReplaceMapping(mapping_id) => (4, mapping_id, None, None, None),
+            Self::RemoveMapping(mapping_id) => (5, mapping_id, None, None, None),
+        };
+        writer.write_uint(1, variant);
+        writer.write_string(2, &mapping_id.to_string());
+        if let Some(index) = index {
+            writer.write_uint(3, *index);
+        }
+        if let Some(key_id) = key_id {
+            writer.write_string(4, &key_id.to_string());
+        }
+        if let Some(value_id) = value_id {
+            writer.write_string(5, &value_id.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Transaction<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.id().to_string());
+        match self {
+            Self::Deploy(_, owner, deployment, fee) => writer.write_message(2, |writer| {
+                writer.write_message(1, |writer| owner.write_protobuf(writer))?;
+                writer.write_message(2, |writer| deployment.write_protobuf(writer))?;
+                writer.write_message(3, |writer| fee.write_protobuf(writer))
+            }),
+            Self::Execute(_, execution, fee) => writer.write_message(3, |writer| {
+                execution.write_protobuf(writer)?;
+                match fee {
+                    Some(fee) => writer.write_message(4, |writer| fee.write_protobuf(writer)),
+                    None => Ok(()),
+                }
+            }),
+            Self::Fee(_, fee) => writer.write_message(4, |writer| fee.write_protobuf(writer)),
+        }
+    }
+}
+
+impl<N: Network> ToProtobuf for ProgramOwner<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.address().to_string());
+        writer.write_string(2, &self.signature().to_string());
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Deployment<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_uint(1, u64::from(self.edition()));
+        writer.write_string(2, &self.program().to_string());
+        for (function_name, (verifying_key, certificate)) in self.verifying_keys() {
+            writer.write_message(3, |writer| {
+                writer.write_string(1, &function_name.to_string());
+                writer.write_string(2, &verifying_key.to_string());
+                writer.write_string(3, &certificate.to_string());
+                Ok(())
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Execution<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        for transition in self.transitions() {
+            writer.write_message(1, |writer| transition.write_protobuf(writer))?;
+        }
+        writer.write_string(2, &self.global_state_root().to_string());
+        if let Some(proof) = self.proof() {
+            writer.write_string(3, &proof.to_string());
+        }
+        if let Some(expiration) = self.expiration() {
+            writer.write_explicit_uint(5, u64::from(expiration));
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Fee<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_message(1, |writer| self.transition().write_protobuf(writer))?;
+        writer.write_string(2, &self.global_state_root().to_string());
+        if let Some(proof) = self.proof() {
+            writer.write_string(3, &proof.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Transition<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        writer.write_string(1, &self.id().to_string());
+        writer.write_string(2, &self.program_id().to_string());
+        writer.write_string(3, &self.function_name().to_string());
+        for input in self.inputs() {
+            writer.write_message(4, |writer| input.write_protobuf(writer))?;
+        }
+        for output in self.outputs() {
+            writer.write_message(5, |writer| output.write_protobuf(writer))?;
+        }
+        writer.write_string(6, &self.tpk().to_string());
+        writer.write_string(7, &self.tcm().to_string());
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Input<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        // Retrieve the type, ID, value, and tag of the input.
+        let (variant, id, value, tag) = match self {
+            Self::Constant(id, value) => (0, id, value.as_ref().map(ToString::to_string), None),
+            Self::Public(id, value) => (1, id, value.as_ref().map(ToString::to_string), None),
+            Self::Private(id, value) => (2, id, value.as_ref().map(ToString::to_string), None),
+            Self::Record(serial_number, tag) => (3, serial_number, None, Some(tag)),
+            Self::ExternalRecord(id) => (4, id, None, None),
+        };
+        writer.write_uint(1, variant);
+        writer.write_string(2, &id.to_string());
+        if let Some(value) = value {
+            writer.write_string(3, &value);
+        }
+        if let Some(tag) = tag {
+            writer.write_string(4, &tag.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> ToProtobuf for Output<N> {
+    fn write_protobuf(&self, writer: &mut ProtobufWriter) -> Result<()> {
+        // Retrieve the type, ID, value, and checksum of the output.
+        let (variant, id, value, checksum) = match self {
+            Self::Constant(id, value) => (0, id, value.as_ref().map(ToString::to_string), None),
+            Self::Public(id, value) => (1, id, value.as_ref().map(ToString::to_string), None),
+            Self::Private(id, value) => (2, id, value.as_ref().map(ToString::to_string), None),
+            Self::Record(commitment, checksum, value) => {
+                (3, commitment, value.as_ref().map(ToString::to_string), Some(checksum))
+            }
+            Self::ExternalRecord(id) => (4, id, None, None),
+            Self::Future(id, value) => (5, id, value.as_ref().map(ToString::to_string), None),
+        };
+        writer.write_uint(1, variant);
+        writer.write_string(2, &id.to_string());
+        if let Some(value) = value {
+            writer.write_string(3, &value);
+        }
+        if let Some(checksum) = checksum {
+            writer.write_string(4, &checksum.to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Decodes the top-level fields of a protobuf message, as (field, value) pairs.
+    /// Varint values are returned as their little-endian bytes.
+    fn decode_fields(mut bytes: &[u8]) -> Vec<(u64, Vec<u8>)> {
+        fn read_varint(bytes: &mut &[u8]) -> u64 {
+            let mut value = 0;
+            for shift in (0..64).step_by(7) {
+                let byte = bytes[0];
+                *bytes = &bytes[1..];
+                value |= u64::from(byte & 0x7f) << shift;
+                if byte < 0x80 {
+                    break;
+                }
+            }
+            value
+        }
+
+        let mut fields = Vec::new();
+        while !bytes.is_empty() {
+            let key = read_varint(&mut bytes);
+            match key & 0x7 {
+                WIRE_TYPE_VARINT => fields.push((key >> 3, read_varint(&mut bytes).to_le_bytes().to_vec())),
+                WIRE_TYPE_LEN => {
+                    let length = read_varint(&mut bytes) as usize;
+                    fields.push((key >> 3, bytes[..length].to_vec()));
+                    bytes = &bytes[length..];
+                }
+                wire_type => panic!("Unexpected wire type {wire_type}"),
+            }
+        }
+        fields
+    }
+
+    #[test]
+    fn test_varint() {
+        for (value, expected) in [
+            (0, vec![0x00]),
+            (1, vec![0x01]),
+            (300, vec![0xac, 0x02]),
+            (u64::MAX, vec![0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]),
+        ] {
+            let mut writer = ProtobufWriter::new();
+            writer.write_varint(value);
+            assert_eq!(writer.into_bytes(), expected);
+        }
+    }
+
+    #[test]
+    fn test_block_to_protobuf() -> Result<()> {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+
+        // Decode the top-level fields of the block.
+        let fields = decode_fields(&block.to_protobuf()?);
+        let get = |field| fields.iter().filter(move |(f, _)| *f == field).map(|(_, value)| value.as_slice());
+
+        // Check the block hashes and the number of transactions.
+        assert_eq!(get(1).collect::<Vec<_>>(), [block.hash().to_string().as_bytes()]);
+        assert_eq!(get(2).collect::<Vec<_>>(), [block.previous_hash().to_string().as_bytes()]);
+        assert_eq!(get(7).count(), block.transactions().len());
+        assert_eq!(get(8).count(), 0);
+
+        // Check the authority and the ratifications.
+        let authority = decode_fields(get(4).next().unwrap());
+        match block.authority() {
+            Authority::Beacon(signature) => assert_eq!(authority, [(1, signature.to_string().into_bytes())]),
+            Authority::Quorum(..) => panic!("The genesis block must have a beacon authority"),
+        }
+        assert_eq!(get(5).count(), block.ratifications().len());
+        for (bytes, ratify) in get(5).zip_eq(block.ratifications().iter()) {
+            assert_eq!(ratify.to_protobuf()?, bytes);
+        }
+
+        // Check the metadata of the header.
+        let header = decode_fields(get(3).next().unwrap());
+        let metadata = decode_fields(&header.iter().find(|(f, _)| *f == 7).unwrap().1);
+        let height = metadata.iter().find(|(f, _)| *f == 3).map(|(_, value)| value.clone());
+        // Note: The genesis height is zero, so it is omitted.
+        assert_eq!(height, None);
+        let timestamp = metadata.iter().find(|(f, _)| *f == 10).unwrap();
+        assert_eq!(i64::from_le_bytes(timestamp.1.clone().try_into().unwrap()), block.timestamp());
+
+        // Check the transaction IDs.
+        for (bytes, transaction) in get(7).zip_eq(block.transactions().iter()) {
+            let confirmed = decode_fields(bytes);
+            let inner = decode_fields(&confirmed.iter().find(|(f, _)| *f == 4).unwrap().1);
+            assert_eq!(inner[0], (1, transaction.id().to_string().into_bytes()));
+            assert_eq!(transaction.to_protobuf()?, bytes);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Block, Header, Ratifications, Transactions};
+use console::network::prelude::*;
+use ledger_authority::Authority;
+use ledger_coinbase::CoinbaseSolution;
+
+use std::cell::OnceCell;
+
+/// The sections of a block that follow the header, in the order they are serialized.
+const AUTHORITY: usize = 0;
+const RATIFICATIONS: usize = 1;
+const SOLUTIONS: usize = 2;
+const TRANSACTIONS: usize = 3;
+const ABORTED_TRANSACTION_IDS: usize = 4;
+const NUM_SECTIONS: usize = 5;
+
+/// A borrowed view over a serialized block.
+///
+/// The block hash, previous block hash, and header are decoded when the view is created.
+/// The remaining sections are only decoded when they are accessed, directly from the input buffer.
+/// As the sections are not length-prefixed, accessing a section requires decoding the sections before it
+/// once, after which its position in the buffer is cached.
+///
+/// Note: The view does not check that the block is well-formed. Use `BlockView::to_block` to fully
+/// decode and check the block.
+pub struct BlockView<'a, N: Network> {
+    /// The serialized block.
+    bytes: &'a [u8],
+    /// The hash of the block.
+    block_hash: N::BlockHash,
+    /// The hash of the previous block.
+    previous_hash: N::BlockHash,
+    /// The header of the block.
+    header: Header<N>,
+    /// The byte offset at which the header ends.
+    header_end: usize,
+    /// The byte offsets at which each section ends, once known.
+    section_ends: [OnceCell<usize>; NUM_SECTIONS],
+}
+
+impl<'a, N: Network> BlockView<'a, N> {
+    /// Initializes a new view over the given serialized block.
+    pub fn new(bytes: &'a [u8]) -> Result<Self> {
+        let mut reader = bytes;
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        ensure!(version == 1, "Invalid block version");
+
+        // Read the block hash.
+        let block_hash = FromBytes::read_le(&mut reader)?;
+        // Read the previous block hash.
+        let previous_hash = FromBytes::read_le(&mut reader)?;
+        // Read the header.
+        let header = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self {
+            bytes,
+            block_hash,
+            previous_hash,
+            header,
+            header_end: bytes.len() - reader.len(),
+            section_ends: Default::default(),
+        })
+    }
+
+    /// Returns the serialized block.
+    pub const fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the block hash, as stated in the serialized block.
+    pub const fn hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the block header.
+    pub const fn header(&self) -> &Header<N> {
+        &self.header
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.header.height()
+    }
+
+    /// Returns `true` if the stated block hash matches the previous block hash and header.
+    pub fn is_hash_valid(&self) -> Result<bool> {
+        let block_hash = N::hash_bhp1024(&to_bits_le![self.previous_hash, self.header.to_root()?])?;
+        Ok(self.block_hash == block_hash.into())
+    }
+
+    /// Decodes and returns the authority.
+    pub fn authority(&self) -> Result<Authority<N>> {
+        self.decode(AUTHORITY, |reader| FromBytes::read_le(reader))
+    }
+
+    /// Decodes and returns the ratifications.
+    pub fn ratifications(&self) -> Result<Ratifications<N>> {
+        self.decode(RATIFICATIONS, |reader| FromBytes::read_le(reader))
+    }
+
+    /// Decodes and returns the solutions, if any.
+    pub fn solutions(&self) -> Result<Option<CoinbaseSolution<N>>> {
+        self.decode(SOLUTIONS, read_solutions)
+    }
+
+    /// Decodes and returns the transactions.
+    pub fn transactions(&self) -> Result<Transactions<N>> {
+        self.decode(TRANSACTIONS, |reader| FromBytes::read_le(reader))
+    }
+
+    /// Returns the serialized transactions, without decoding them.
+    pub fn transactions_bytes(&self) -> Result<&'a [u8]> {
+        self.section(TRANSACTIONS)
+    }
+
+    /// Returns the number of transactions, only decoding the sections before the transactions.
+    pub fn num_transactions(&self) -> Result<usize> {
+        // Skip the version of the transactions, and read the number of transactions.
+        let mut reader = self.transactions_bytes()?.get(1..).unwrap_or_default();
+        Ok(u32::read_le(&mut reader)? as usize)
+    }
+
+    /// Decodes and returns the aborted transaction IDs.
+    pub fn aborted_transaction_ids(&self) -> Result<Vec<N::TransactionID>> {
+        self.decode(ABORTED_TRANSACTION_IDS, read_aborted_transaction_ids::<N>)
+    }
+
+    /// Fully decodes and checks the block.
+    pub fn to_block(&self) -> Result<Block<N>> {
+        Ok(Block::read_le(self.bytes)?)
+    }
+
+    /// Returns the serialized bytes of the given section.
+    fn section(&self, index: usize) -> Result<&'a [u8]> {
+        let start = self.section_start(index)?;
+        let end = self.section_end(index)?;
+        Ok(&self.bytes[start..end])
+    }
+
+    /// Returns the byte offset at which the given section starts.
+    fn section_start(&self, index: usize) -> Result<usize> {
+        match index {
+            AUTHORITY => Ok(self.header_end),
+            _ => self.section_end(index - 1),
+        }
+    }
+
+    /// Returns the byte offset at which the given section ends, decoding the section if it is not yet known.
+    fn section_end(&self, index: usize) -> Result<usize> {
+        if let Some(end) = self.section_ends[index].get() {
+            return Ok(*end);
+        }
+        match index {
+            AUTHORITY => self.decode(index, |reader| Authority::<N>::read_le(reader).map(|_| ()))?,
+            RATIFICATIONS => self.decode(index, |reader| Ratifications::<N>::read_le(reader).map(|_| ()))?,
+            SOLUTIONS => self.decode(index, |reader| read_solutions::<N>(reader).map(|_| ()))?,
+            TRANSACTIONS => self.decode(index, |reader| Transactions::<N>::read_le(reader).map(|_| ()))?,
+            ABORTED_TRANSACTION_IDS => {
+                self.decode(index, |reader| read_aborted_transaction_ids::<N>(reader).map(|_| ()))?
+            }
+            _ => bail!("Invalid block section '{index}'"),
+        }
+        self.section_ends[index].get().copied().ok_or_else(|| anyhow!("Failed to locate block section '{index}'"))
+    }
+
+    /// Decodes the given section with the given reader, and caches the offset at which the section ends.
+    fn decode<T>(&self, index: usize, read: impl FnOnce(&mut &'a [u8]) -> IoResult<T>) -> Result<T> {
+        let mut reader = &self.bytes[self.section_start(index)?..];
+        let value = read(&mut reader)?;
+        // Cache the offset at which the section ends.
+        let _ = self.section_ends[index].set(self.bytes.len() - reader.len());
+        Ok(value)
+    }
+}
+
+/// Reads the optional solutions of a block.
+fn read_solutions<N: Network>(reader: &mut &[u8]) -> IoResult<Option<CoinbaseSolution<N>>> {
+    match u8::read_le(&mut *reader)? {
+        0 => Ok(None),
+        1 => Ok(Some(FromBytes::read_le(reader)?)),
+        _ => Err(error("Invalid solutions variant in the block")),
+    }
+}
+
+/// Reads the aborted transaction IDs of a block.
+fn read_aborted_transaction_ids<N: Network>(reader: &mut &[u8]) -> IoResult<Vec<N::TransactionID>> {
+    // Read the number of aborted transaction IDs.
+    let num_aborted = u32::read_le(&mut *reader)?;
+    // Ensure the number of aborted transaction IDs is within bounds (this is an early safety check).
+    if num_aborted as usize > Transactions::<N>::MAX_TRANSACTIONS {
+        return Err(error("Invalid number of aborted transaction IDs in the block"));
+    }
+    (0..num_aborted).map(|_| FromBytes::read_le(&mut *reader)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_genesis_view() -> Result<()> {
+        // Load the genesis block.
+        let bytes = CurrentNetwork::genesis_bytes();
+        let expected = Block::<CurrentNetwork>::read_le(bytes)?;
+
+        // Check the eagerly-decoded fields.
+        let view = BlockView::<CurrentNetwork>::new(bytes)?;
+        assert_eq!(view.hash(), expected.hash());
+        assert_eq!(view.previous_hash(), expected.previous_hash());
+        assert_eq!(view.header(), expected.header());
+        assert_eq!(view.height(), 0);
+        assert!(view.is_hash_valid()?);
+
+        // Check the lazily-decoded fields, starting from the last section.
+        assert_eq!(view.aborted_transaction_ids()?, expected.aborted_transaction_ids().clone());
+        assert_eq!(view.num_transactions()?, expected.transactions().len());
+        assert_eq!(&view.transactions()?, expected.transactions());
+        assert_eq!(view.solutions()?.as_ref(), expected.solutions());
+        assert_eq!(&view.ratifications()?, expected.ratifications());
+        assert_eq!(&view.authority()?, expected.authority());
+        assert_eq!(view.to_block()?, expected);
+
+        // Ensure the view covers the entire buffer.
+        assert_eq!(view.section_end(ABORTED_TRANSACTION_IDS)?, bytes.len());
+        // Ensure a truncated buffer is rejected.
+        assert!(BlockView::<CurrentNetwork>::new(&bytes[1..]).is_err());
+        assert!(BlockView::<CurrentNetwork>::new(&bytes[..bytes.len() - 1])?.aborted_transaction_ids().is_err());
+        Ok(())
+    }
+}
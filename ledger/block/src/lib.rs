@@ -38,6 +38,9 @@ pub use transactions::*;
 pub mod transition;
 pub use transition::*;
 
+#[cfg(any(test, feature = "prop-tests"))]
+pub mod prop_tests;
+
 mod bytes;
 mod genesis;
 mod serialize;
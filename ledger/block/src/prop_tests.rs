@@ -0,0 +1,311 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use console::{
+    program::{Entry, Identifier, Literal, Owner, Plaintext, ProgramID},
+    types::{Address, Scalar, U16},
+};
+
+use indexmap::IndexMap;
+use proptest::{
+    collection::vec,
+    prelude::{any, Arbitrary, BoxedStrategy, Strategy},
+};
+use test_strategy::proptest;
+
+type CurrentNetwork = console::network::Testnet3;
+
+/// The name of the program used by the sampled transitions.
+const PROGRAM_ID: &str = "proptest.aleo";
+/// The name of the function used by the sampled transitions.
+const FUNCTION_NAME: &str = "transfer";
+/// The name of the record used by the sampled transitions.
+const RECORD_NAME: &str = "token";
+
+/// A record with an arbitrary owner, amount, and nonce, along with the randomizer of its nonce.
+#[derive(Debug, Clone)]
+pub struct RecordContext(pub Record<CurrentNetwork, Plaintext<CurrentNetwork>>, pub Scalar<CurrentNetwork>);
+
+impl Arbitrary for RecordContext {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<u64>()
+            .prop_map(|seed| {
+                let (record, randomizer) = sample_record(&mut TestRng::fixed(seed));
+                RecordContext(record, randomizer)
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Transition<CurrentNetwork> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u64>(), vec(any::<u64>(), 1..=3), 0..=2usize)
+            .prop_map(|(seed, amounts, num_records)| {
+                sample_transition(&amounts, num_records, &mut TestRng::fixed(seed)).unwrap()
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Transaction<CurrentNetwork> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (vec(any::<Transition<CurrentNetwork>>(), 1..=2), any::<u64>())
+            .prop_map(|(transitions, seed)| {
+                let global_state_root = Field::<CurrentNetwork>::rand(&mut TestRng::fixed(seed)).into();
+                let execution = Execution::from(transitions.into_iter(), global_state_root, None).unwrap();
+                Transaction::from_execution(execution, None).unwrap()
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Transactions<CurrentNetwork> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(any::<Transaction<CurrentNetwork>>(), 1..=3)
+            .prop_map(|transactions| {
+                transactions
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, transaction)| {
+                        ConfirmedTransaction::accepted_execute(u32::try_from(index).unwrap(), transaction, vec![])
+                            .unwrap()
+                    })
+                    .collect()
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Header<CurrentNetwork> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any_metadata(), any::<u64>())
+            .prop_map(|(metadata, seed)| {
+                let rng = &mut TestRng::fixed(seed);
+                Header::from(
+                    Field::<CurrentNetwork>::rand(rng).into(),
+                    Field::rand(rng),
+                    Field::rand(rng),
+                    Field::rand(rng),
+                    Field::zero(),
+                    Field::zero(),
+                    metadata,
+                )
+                .unwrap()
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Block<CurrentNetwork> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any_metadata(), any::<Transactions<CurrentNetwork>>(), any::<u64>())
+            .prop_map(|(metadata, transactions, seed)| {
+                let rng = &mut TestRng::fixed(seed);
+                // Sample the block producer.
+                let private_key = PrivateKey::new(rng).unwrap();
+                // Construct a header that commits to the transactions and ratifications.
+                let ratifications = Ratifications::try_from(vec![]).unwrap();
+                let header = Header::from(
+                    Field::<CurrentNetwork>::rand(rng).into(),
+                    transactions.to_transactions_root().unwrap(),
+                    transactions.to_finalize_root(vec![]).unwrap(),
+                    ratifications.to_ratifications_root().unwrap(),
+                    Field::zero(),
+                    Field::zero(),
+                    metadata,
+                )
+                .unwrap();
+                // Construct the beacon block.
+                let previous_hash = Field::<CurrentNetwork>::rand(rng).into();
+                Block::new_beacon(&private_key, previous_hash, header, ratifications, None, transactions, vec![], rng)
+                    .unwrap()
+            })
+            .boxed()
+    }
+}
+
+/// Returns a strategy for well-formed, non-genesis block metadata.
+///
+/// Note: The cumulative weight and proof target are sampled within `u64`, as the JSON encoding
+/// of the metadata does not preserve `u128` values beyond this range.
+pub fn any_metadata() -> BoxedStrategy<Metadata<CurrentNetwork>> {
+    (1..u32::MAX / 2, 0..1_000_000u64, 0..1u64 << 32, 1..1u64 << 32, 1..1i64 << 32, any::<u64>(), any::<u64>())
+        .prop_map(
+            |(
+                height,
+                round_offset,
+                proof_offset,
+                coinbase_offset,
+                time_offset,
+                cumulative_weight,
+                cumulative_target,
+            )| {
+                let proof_target = CurrentNetwork::GENESIS_PROOF_TARGET + proof_offset;
+                let coinbase_target = CurrentNetwork::GENESIS_COINBASE_TARGET.max(proof_target) + coinbase_offset;
+                Metadata::new(
+                    CurrentNetwork::ID,
+                    u64::from(height) + round_offset,
+                    height,
+                    u128::from(cumulative_weight),
+                    u128::from(cumulative_target),
+                    coinbase_target,
+                    proof_target,
+                    coinbase_target,
+                    CurrentNetwork::GENESIS_TIMESTAMP,
+                    CurrentNetwork::GENESIS_TIMESTAMP + time_offset,
+                )
+                .unwrap()
+            },
+        )
+        .boxed()
+}
+
+/// Samples a record with a random owner, amount, and nonce, returning the record and its randomizer.
+fn sample_record(rng: &mut TestRng) -> (Record<CurrentNetwork, Plaintext<CurrentNetwork>>, Scalar<CurrentNetwork>) {
+    // Sample the owner.
+    let owner = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    // Sample the nonce.
+    let randomizer = Scalar::rand(rng);
+    let nonce = CurrentNetwork::g_scalar_multiply(&randomizer);
+    // Construct the record.
+    let data = IndexMap::from([(
+        Identifier::from_str("amount").unwrap(),
+        Entry::Private(Plaintext::from(Literal::U64(U64::new(rng.gen())))),
+    )]);
+    let record = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::from_plaintext(
+        Owner::Private(Plaintext::from(Literal::Address(owner))),
+        data,
+        nonce,
+    )
+    .unwrap();
+    (record, randomizer)
+}
+
+/// Samples a transition with the given public inputs and number of record outputs.
+fn sample_transition(amounts: &[u64], num_records: usize, rng: &mut TestRng) -> Result<Transition<CurrentNetwork>> {
+    let program_id = ProgramID::from_str(PROGRAM_ID)?;
+    let function_name = Identifier::from_str(FUNCTION_NAME)?;
+    let record_name = Identifier::from_str(RECORD_NAME)?;
+
+    // Compute the function ID as `Hash(network_id, program_id, function_name)`.
+    let network_id = U16::<CurrentNetwork>::new(CurrentNetwork::ID);
+    let function_id = CurrentNetwork::hash_bhp1024(
+        &(network_id, program_id.name(), program_id.network(), function_name).to_bits_le(),
+    )?;
+    // Sample the transition public key and commitment.
+    let tpk = Group::rand(rng);
+    let tcm = Field::rand(rng);
+
+    // Construct the public inputs, hashed as `(function ID || input || tcm || index)`.
+    let inputs = amounts
+        .iter()
+        .enumerate()
+        .map(|(index, amount)| {
+            let plaintext = Plaintext::from(Literal::U64(U64::new(*amount)));
+            let mut preimage = vec![function_id];
+            preimage.extend(plaintext.to_fields()?);
+            preimage.push(tcm);
+            preimage.push(Field::from_u16(u16::try_from(index)?));
+            Ok(Input::Public(CurrentNetwork::hash_psd8(&preimage)?, Some(plaintext)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Construct the record outputs.
+    let outputs = (0..num_records)
+        .map(|_| {
+            let (record, randomizer) = sample_record(rng);
+            let commitment = record.to_commitment(&program_id, &record_name)?;
+            let ciphertext = record.encrypt(randomizer)?;
+            let checksum = CurrentNetwork::hash_bhp1024(&ciphertext.to_bits_le())?;
+            Ok(Output::Record(commitment, checksum, Some(ciphertext)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Transition::new(program_id, function_name, inputs, outputs, tpk, tcm)
+}
+
+#[proptest(cases = 8)]
+fn record_serialization(input: RecordContext) {
+    let RecordContext(record, randomizer) = input;
+    // Ensure the record round-trips through bytes and strings.
+    assert_eq!(record, Record::read_le(&record.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(record, Record::from_str(&record.to_string()).unwrap());
+    // Ensure the encrypted record round-trips through bytes and strings.
+    let ciphertext = record.encrypt(randomizer).unwrap();
+    assert_eq!(record.nonce(), ciphertext.nonce());
+    assert_eq!(ciphertext, Record::read_le(&ciphertext.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(ciphertext, Record::from_str(&ciphertext.to_string()).unwrap());
+}
+
+#[proptest(cases = 8)]
+fn transition_serialization(transition: Transition<CurrentNetwork>) {
+    // Ensure the inputs and outputs are well-formed.
+    let function_id = CurrentNetwork::hash_bhp1024(
+        &(
+            U16::<CurrentNetwork>::new(CurrentNetwork::ID),
+            transition.program_id().name(),
+            transition.program_id().network(),
+            transition.function_name(),
+        )
+            .to_bits_le(),
+    )
+    .unwrap();
+    for (index, input) in transition.inputs().iter().enumerate() {
+        assert!(input.verify(function_id, transition.tcm(), index));
+    }
+    for (index, output) in transition.outputs().iter().enumerate() {
+        assert!(output.verify(function_id, transition.tcm(), transition.inputs().len() + index));
+    }
+    // Ensure the transition round-trips through bytes and strings.
+    assert_eq!(transition, Transition::read_le(&transition.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(transition, Transition::from_str(&transition.to_string()).unwrap());
+}
+
+#[proptest(cases = 8)]
+fn header_serialization(header: Header<CurrentNetwork>) {
+    assert!(header.is_valid());
+    assert_eq!(header, Header::read_le(&header.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(header, Header::from_str(&header.to_string()).unwrap());
+}
+
+#[proptest(cases = 4)]
+fn transactions_serialization(transactions: Transactions<CurrentNetwork>) {
+    assert_eq!(transactions, Transactions::read_le(&transactions.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(transactions, Transactions::from_str(&transactions.to_string()).unwrap());
+}
+
+#[proptest(cases = 4)]
+fn block_serialization(block: Block<CurrentNetwork>) {
+    assert_eq!(block, Block::read_le(&block.to_bytes_le().unwrap()[..]).unwrap());
+    assert_eq!(block, Block::from_str(&block.to_string()).unwrap());
+}
@@ -145,4 +145,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_genesis_serde_json_test_vector() -> Result<()> {
+        // Load the genesis block.
+        let genesis_block = Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Load the test vector.
+        let expected_string = include_str!("./resources/genesis.json").trim_end();
+
+        // Ensure the JSON encoding matches the test vector.
+        assert_eq!(serde_json::to_string_pretty(&genesis_block)?, expected_string);
+        assert_eq!(genesis_block, serde_json::from_str(expected_string)?);
+
+        // Ensure the header, transactions, and transitions match the test vector.
+        let expected: serde_json::Value = serde_json::from_str(expected_string)?;
+        assert_eq!(serde_json::to_value(genesis_block.header())?, expected["header"]);
+        assert_eq!(*genesis_block.header(), serde_json::from_value(expected["header"].clone())?);
+        assert_eq!(serde_json::to_value(genesis_block.transactions())?, expected["transactions"]);
+        for (confirmed, expected) in
+            genesis_block.transactions().iter().zip_eq(expected["transactions"].as_array().unwrap())
+        {
+            let transaction = confirmed.transaction();
+            assert_eq!(serde_json::to_value(transaction)?, expected["transaction"]);
+            assert_eq!(*transaction, serde_json::from_value(expected["transaction"].clone())?);
+
+            if let Transaction::Execute(_, execution, _) = transaction {
+                let transitions = expected["transaction"]["execution"]["transitions"].as_array().unwrap();
+                for (transition, expected) in execution.transitions().zip_eq(transitions) {
+                    assert_eq!(serde_json::to_value(transition)?, *expected);
+                    assert_eq!(*transition, serde_json::from_value(expected.clone())?);
+                }
+            }
+        }
+        Ok(())
+    }
 }
@@ -29,13 +29,17 @@ pub struct Execution<N: Network> {
     /// The global state root.
     global_state_root: N::StateRoot,
     /// The proof.
+    /// Note: This is a single batch proof for all of the transitions (and their inclusion) in the execution.
+    /// Recursive aggregation into a succinct wrapper proof is not supported, as there is no in-circuit Varuna verifier.
     proof: Option<Proof<N>>,
+    /// The (optional) expiration height, which is the last block height the execution may be included in.
+    expiration: Option<u32>,
 }
 
 impl<N: Network> Execution<N> {
     /// Initialize a new `Execution` instance.
     pub fn new() -> Self {
-        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None }
+        Self { transitions: Default::default(), global_state_root: Default::default(), proof: None, expiration: None }
     }
 
     /// Initializes a new `Execution` instance with the given transitions.
@@ -45,7 +49,12 @@ impl<N: Network> Execution<N> {
         proof: Option<Proof<N>>,
     ) -> Result<Self> {
         // Construct the execution.
-        let execution = Self { transitions: transitions.map(|t| (*t.id(), t)).collect(), global_state_root, proof };
+        let execution = Self {
+            transitions: transitions.map(|t| (*t.id(), t)).collect(),
+            global_state_root,
+            proof,
+            expiration: None,
+        };
         // Ensure the transitions are not empty.
         ensure!(!execution.transitions.is_empty(), "Execution cannot initialize from empty list of transitions");
         // Return the new `Execution` instance.
@@ -62,11 +71,27 @@ impl<N: Network> Execution<N> {
         self.global_state_root
     }
 
-    /// Returns the proof.
+    /// Returns the batch proof for all of the transitions in the execution.
     pub const fn proof(&self) -> Option<&Proof<N>> {
         self.proof.as_ref()
     }
 
+    /// Returns the expiration height, if one is set.
+    pub const fn expiration(&self) -> Option<u32> {
+        self.expiration
+    }
+
+    /// Sets the expiration height, which is the last block height the execution may be included in.
+    /// Note: The expiration height is bound to the execution ID, so it must be set before the fee is authorized.
+    pub fn set_expiration(&mut self, expiration: Option<u32>) {
+        self.expiration = expiration;
+    }
+
+    /// Returns `true` if the execution has expired for a block at the given height.
+    pub fn is_expired(&self, height: u32) -> bool {
+        self.expiration.map_or(false, |expiration| height > expiration)
+    }
+
     /// Returns the execution ID.
     pub fn to_execution_id(&self) -> Result<Field<N>> {
         Ok(*Transaction::execution_tree(self, &None)?.root())
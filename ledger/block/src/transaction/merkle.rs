@@ -136,25 +136,35 @@ impl<N: Network> Transaction<N> {
 
     /// Returns the Merkle tree for the given execution.
     pub fn execution_tree(execution: &Execution<N>, fee: &Option<Fee<N>>) -> Result<TransactionTree<N>> {
-        Self::transitions_tree(execution.transitions(), fee)
+        Self::transitions_tree(execution.transitions(), fee, execution.expiration())
     }
 
     /// Returns the Merkle tree for the given transitions.
+    /// Note: If an expiration height is given, it is committed to in the last leaf of the tree.
     pub fn transitions_tree<'a>(
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         fee: &Option<Fee<N>>,
+        expiration: Option<u32>,
     ) -> Result<TransactionTree<N>> {
         // Retrieve the number of transitions.
         let num_transitions = transitions.len();
         // Ensure the number of leaves is within the Merkle tree size.
         Self::check_execution_size(num_transitions)?;
+        // Ensure there is room for the expiration leaf.
+        if expiration.is_some() {
+            ensure!(
+                num_transitions + 1 < Self::MAX_TRANSITIONS,
+                "Execution with an expiration must contain less than {} transitions, found {num_transitions}",
+                Self::MAX_TRANSITIONS - 1
+            );
+        }
         // Prepare the leaves.
         let leaves = transitions.enumerate().map(|(index, transition)| {
             // Construct the transaction leaf.
             Ok::<_, Error>(TransactionLeaf::new_execution(u16::try_from(index)?, **transition.id()).to_bits_le())
         });
         // If the fee is present, add it to the leaves.
-        let leaves = match fee {
+        let mut leaves = match fee {
             Some(fee) => {
                 // Construct the transaction leaf.
                 let leaf = TransactionLeaf::new_fee(
@@ -167,6 +177,16 @@ impl<N: Network> Transaction<N> {
             }
             None => leaves.collect::<Result<Vec<_>, _>>()?,
         };
+        // If the expiration height is present, add it to the leaves.
+        if let Some(expiration) = expiration {
+            // Construct the transaction leaf.
+            let leaf = TransactionLeaf::new_expiration(
+                u16::try_from(leaves.len())?, // The last index.
+                Field::<N>::from_u32(expiration),
+            );
+            // Add the leaf to the leaves.
+            leaves.push(leaf.to_bits_le());
+        }
 
         // Compute the execution tree.
         N::merkle_tree_bhp::<TRANSACTION_DEPTH>(&leaves)
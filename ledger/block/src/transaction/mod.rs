@@ -79,6 +79,15 @@ impl<N: Network> Transaction<N> {
     }
 }
 
+impl<N: Network> Transaction<N> {
+    /// Returns the size in bytes, without allocating the bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        let mut counter = ByteCounter::default();
+        self.write_le(&mut counter)?;
+        Ok(counter.num_bytes())
+    }
+}
+
 impl<N: Network> Transaction<N> {
     /// Returns `true` if the transaction is a deploy transaction.
     #[inline]
@@ -139,6 +148,16 @@ impl<N: Network> Transaction<N> {
             _ => None,
         }
     }
+
+    /// Returns the expiration height, if the transaction is an execution with an expiration height.
+    pub fn expiration(&self) -> Option<u32> {
+        self.execution().and_then(Execution::expiration)
+    }
+
+    /// Returns `true` if the transaction has expired for a block at the given height.
+    pub fn is_expired(&self, height: u32) -> bool {
+        self.execution().map_or(false, |execution| execution.is_expired(height))
+    }
 }
 
 /// A helper enum for iterators and consuming iterators over a transaction.
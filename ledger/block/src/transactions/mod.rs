@@ -168,6 +168,18 @@ impl<N: Network> Transactions<N> {
 impl<N: Network> Transactions<N> {
     /// The maximum number of transactions allowed in a block.
     pub const MAX_TRANSACTIONS: usize = usize::pow(2, TRANSACTIONS_DEPTH as u32);
+    /// The maximum weight of the transactions in a block, in bytes.
+    pub const MAX_WEIGHT: u64 = 32 * 1024 * 1024; // 32 MiB
+
+    /// Returns the weight of the transactions, as the sum of the sizes in bytes of the (unconfirmed) transactions.
+    /// Note: A rejected transaction is weighed by its fee transaction.
+    pub fn weight(&self) -> Result<u64> {
+        self.iter().try_fold(0u64, |weight, transaction| {
+            weight
+                .checked_add(transaction.transaction().size_in_bytes()?)
+                .ok_or_else(|| anyhow!("Overflow in the weight of the transactions"))
+        })
+    }
 
     /// Returns an iterator over all transactions, for all transactions in `self`.
     pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = &ConfirmedTransaction<N>> {
@@ -353,4 +365,28 @@ mod tests {
             ledger_narwhal_batch_header::BatchHeader::<CurrentNetwork>::MAX_TRANSACTIONS
         );
     }
+
+    #[test]
+    fn test_weight() -> Result<()> {
+        // Load the transactions of the genesis block.
+        let block = crate::Block::<CurrentNetwork>::read_le(CurrentNetwork::genesis_bytes())?;
+        let transactions = block.transactions();
+
+        // Ensure the weight is the sum of the transaction sizes.
+        let mut expected = 0;
+        for confirmed in transactions.iter() {
+            let transaction = confirmed.transaction();
+            let size = transaction.size_in_bytes()?;
+            assert_eq!(size, transaction.to_bytes_le()?.len() as u64);
+            // Ensure the transitions are no larger than the transaction.
+            for transition in transaction.transitions() {
+                assert_eq!(transition.size_in_bytes()?, transition.to_bytes_le()?.len() as u64);
+                assert!(transition.size_in_bytes()? < size);
+            }
+            expected += size;
+        }
+        assert_eq!(transactions.weight()?, expected);
+        assert!(transactions.weight()? <= Transactions::<CurrentNetwork>::MAX_WEIGHT);
+        Ok(())
+    }
 }
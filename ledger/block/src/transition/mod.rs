@@ -295,6 +295,13 @@ impl<N: Network> Transition<N> {
     pub const fn tcm(&self) -> &Field<N> {
         &self.tcm
     }
+
+    /// Returns the size in bytes, without allocating the bytes.
+    pub fn size_in_bytes(&self) -> Result<u64> {
+        let mut counter = ByteCounter::default();
+        self.write_le(&mut counter)?;
+        Ok(counter.num_bytes())
+    }
 }
 
 impl<N: Network> Transition<N> {
@@ -90,11 +90,17 @@ impl<N: Network> BatchCertificate<N> {
             bail!("Invalid batch certificate ID")
         }
         // Verify the signatures are valid.
-        for (signature, timestamp) in &signatures {
-            let preimage = [batch_header.batch_id(), Field::from_u64(*timestamp as u64)];
-            if !signature.verify(&signature.to_address(), &preimage) {
-                bail!("Invalid batch certificate signature")
-            }
+        let preimages = signatures
+            .iter()
+            .map(|(_, timestamp)| [batch_header.batch_id(), Field::from_u64(*timestamp as u64)])
+            .collect::<Vec<_>>();
+        let batch = signatures
+            .iter()
+            .zip_eq(&preimages)
+            .map(|((signature, _), preimage)| (signature.to_address(), preimage.as_slice(), *signature))
+            .collect::<Vec<_>>();
+        if !Signature::verify_batch(&batch) {
+            bail!("Invalid batch certificate signature")
         }
         // Return the V1 batch certificate.
         Ok(Self::V1 { certificate_id, batch_header, signatures })
@@ -0,0 +1,162 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use rand::{rngs::StdRng, SeedableRng};
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks and adds the given consecutive blocks to the ledger, in order, for initial sync.
+    ///
+    /// The hash linkage and uniqueness of the batch are checked upfront, and the transactions of all blocks
+    /// are verified in parallel against the current ledger state, before any block is added. Transactions that
+    /// depend on an earlier block in the batch (e.g. a program deployed or a state root introduced by it), and
+    /// deployments, are instead verified when their block is checked. Each block is then checked and added
+    /// as in `check_next_block` and `advance_to_next_block`, which stages the storage writes of the block and its
+    /// finalize operations in a single atomic batch. A failure leaves the ledger at the last valid block of the batch.
+    ///
+    /// Note: The blocks cannot share an atomic batch, as checking and finalizing a block reads the state
+    /// committed by the previous block.
+    pub fn add_blocks(&self, blocks: Vec<Block<N>>) -> Result<()> {
+        // Ensure the batch is not empty.
+        ensure!(!blocks.is_empty(), "Cannot add an empty batch of blocks");
+
+        // Ensure the blocks extend the latest block, in order.
+        let mut previous_height = self.latest_height();
+        let mut previous_hash = self.latest_hash();
+        for block in &blocks {
+            let height = block.height();
+            let expected_height = previous_height.saturating_add(1);
+            ensure!(height == expected_height, "Expected block {expected_height}, found block {height}");
+            ensure!(block.previous_hash() == previous_hash, "Block {height} does not extend block {previous_height}");
+            previous_height = height;
+            previous_hash = block.hash();
+        }
+
+        // Ensure there are no duplicates across the batch.
+        Self::check_batch_is_unique(&blocks)?;
+
+        // Verify the transactions in parallel.
+        let verified_transaction_ids = self.verify_batch_transactions(&blocks, &mut OsRng)?;
+
+        // Check and add each block.
+        for block in &blocks {
+            self.check_next_block_with_verified(block, &verified_transaction_ids, &mut OsRng)?;
+            self.advance_to_next_block(block)?;
+        }
+        Ok(())
+    }
+
+    /// Ensures the given blocks do not contain duplicate transactions, transitions, inputs, or outputs.
+    fn check_batch_is_unique(blocks: &[Block<N>]) -> Result<()> {
+        // Ensure there are no duplicate transaction IDs.
+        if has_duplicates(
+            blocks.iter().flat_map(|block| block.transaction_ids().chain(block.aborted_transaction_ids().iter())),
+        ) {
+            bail!("Found a duplicate transaction in the batch of blocks");
+        }
+        // Ensure there are no duplicate transition IDs.
+        if has_duplicates(blocks.iter().flat_map(|block| block.transition_ids())) {
+            bail!("Found a duplicate transition in the batch of blocks");
+        }
+
+        /* Input */
+
+        // Ensure there are no duplicate input IDs.
+        if has_duplicates(blocks.iter().flat_map(|block| block.input_ids())) {
+            bail!("Found a duplicate input ID in the batch of blocks");
+        }
+        // Ensure there are no duplicate serial numbers.
+        if has_duplicates(blocks.iter().flat_map(|block| block.serial_numbers())) {
+            bail!("Found a duplicate serial number in the batch of blocks");
+        }
+        // Ensure there are no duplicate tags.
+        if has_duplicates(blocks.iter().flat_map(|block| block.tags())) {
+            bail!("Found a duplicate tag in the batch of blocks");
+        }
+
+        /* Output */
+
+        // Ensure there are no duplicate output IDs.
+        if has_duplicates(blocks.iter().flat_map(|block| block.output_ids())) {
+            bail!("Found a duplicate output ID in the batch of blocks");
+        }
+        // Ensure there are no duplicate commitments.
+        if has_duplicates(blocks.iter().flat_map(|block| block.commitments())) {
+            bail!("Found a duplicate commitment in the batch of blocks");
+        }
+        // Ensure there are no duplicate nonces.
+        if has_duplicates(blocks.iter().flat_map(|block| block.nonces())) {
+            bail!("Found a duplicate nonce in the batch of blocks");
+        }
+
+        /* Metadata */
+
+        // Ensure there are no duplicate transition public keys.
+        if has_duplicates(blocks.iter().flat_map(|block| block.transition_public_keys())) {
+            bail!("Found a duplicate transition public key in the batch of blocks");
+        }
+        // Ensure there are no duplicate transition commitments.
+        if has_duplicates(blocks.iter().flat_map(|block| block.transition_commitments())) {
+            bail!("Found a duplicate transition commitment in the batch of blocks");
+        }
+        Ok(())
+    }
+
+    /// Verifies the transactions of the given blocks in parallel, against the current ledger state,
+    /// and returns the IDs of the transactions that were verified.
+    ///
+    /// Deployments, and executions that were rejected earlier in the batch, are skipped,
+    /// as their validity depends on the earlier blocks in the batch.
+    fn verify_batch_transactions<R: CryptoRng + Rng>(
+        &self,
+        blocks: &[Block<N>],
+        rng: &mut R,
+    ) -> Result<IndexSet<N::TransactionID>> {
+        // Collect the rejected deployment and execution IDs in the batch.
+        let rejected_ids = blocks
+            .iter()
+            .flat_map(|block| block.transactions().iter())
+            .filter_map(|transaction| transaction.to_rejected_id().transpose())
+            .collect::<Result<IndexSet<_>>>()?;
+
+        // Collect the transactions to verify, along with their rejected IDs.
+        let mut transactions = Vec::new();
+        for transaction in blocks.iter().flat_map(|block| block.transactions().iter()) {
+            // Skip deployments.
+            if transaction.transaction().is_deploy() {
+                continue;
+            }
+            // Skip executions that were rejected in the batch.
+            if let Some(execution) = transaction.transaction().execution() {
+                if rejected_ids.contains(&execution.to_execution_id()?) {
+                    continue;
+                }
+            }
+            transactions.push((transaction.transaction(), transaction.to_rejected_id()?));
+        }
+
+        // Prepare an RNG for each transaction.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        // Verify the transactions, and retain the IDs of the valid transactions.
+        // Note: Transactions that fail here are verified again when their block is checked.
+        let verified_transaction_ids = cfg_into_iter!(transactions)
+            .zip(rngs)
+            .filter_map(|((transaction, rejected_id), mut rng)| {
+                self.vm.check_transaction(transaction, rejected_id, &mut rng).ok().map(|_| transaction.id())
+            })
+            .collect::<Vec<_>>();
+        Ok(verified_transaction_ids.into_iter().collect())
+    }
+}
@@ -82,15 +82,33 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     }
 
     /// Adds the given block as the next block in the ledger.
+    ///
+    /// Readers are not blocked while the block is written to storage; they observe the previous
+    /// block as the latest block until the new block has been fully added.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            name = "Ledger::advance_to_next_block",
+            skip_all,
+            fields(height = block.height(), transactions = block.transactions().len())
+        )
+    )]
     pub fn advance_to_next_block(&self, block: &Block<N>) -> Result<()> {
-        // Acquire the write lock on the current block.
-        let mut current_block = self.current_block.write();
+        // Acquire the advance lock, which is needed to ensure this function is not called concurrently.
+        // Note: This lock must be held for the entire scope of this function.
+        let _advance_lock = self.advance_lock.lock();
+
+        #[cfg(feature = "metrics")]
+        let timer = std::time::Instant::now();
+
         // Update the VM.
-        self.vm.add_next_block(block)?;
+        if let Err(error) = self.vm.add_next_block(block) {
+            // Invalidate any cached entries for the rolled back block.
+            self.cache.lock().invalidate_from(block.height());
+            return Err(error);
+        }
         // Update the current block.
-        *current_block = block.clone();
-        // Drop the write lock on the current block.
-        drop(current_block);
+        *self.current_block.write() = block.clone();
 
         // Update the cached committee from storage.
         if let Ok(current_committee) = self.vm.finalize_store().committee_store().current_committee() {
@@ -103,6 +121,19 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(block.height()).ok());
         }
 
+        // Update the owner index.
+        self.update_owner_index(block);
+        // Notify the subscribers.
+        self.notify_block_added(block);
+
+        #[cfg(feature = "metrics")]
+        {
+            metrics::increment_counter(metrics::ledger::BLOCKS_ADDED);
+            metrics::counter(metrics::ledger::TRANSACTIONS_ADDED, block.transactions().len() as u64);
+            metrics::gauge(metrics::ledger::HEIGHT, block.height());
+            metrics::histogram(metrics::ledger::BLOCK_ADD_TIME, timer.elapsed().as_secs_f64());
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,72 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the spendable balance in microcredits of the given view key, as of the block at the given height.
+    ///
+    /// The balance is the sum of the `credits.aleo` records owned by the view key that were created
+    /// at or before the given height, and that were not spent at or before the given height.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "Ledger::get_balance", skip_all, fields(height = height))
+    )]
+    pub fn get_balance(&self, view_key: &ViewKey<N>, height: u32) -> Result<u64> {
+        // Ensure the height exists.
+        ensure!(height <= self.latest_height(), "Block {height} does not exist in the ledger");
+
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+        // Prepare the `credits.aleo` program ID and the `microcredits` identifier.
+        let credits = ProgramID::from_str("credits.aleo")?;
+        let microcredits = Identifier::from_str("microcredits")?;
+
+        let mut balance = 0u64;
+        for (commitment, record) in self.find_records(view_key, RecordsFilter::All)? {
+            // Retrieve the amount of microcredits in the record.
+            let amount = match record.data().get(&microcredits) {
+                Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => **amount,
+                _ => continue,
+            };
+            // Skip records created after the given height.
+            let location = match self.locate_commitment(&commitment)? {
+                Some(location) if location.height() <= height => location,
+                _ => continue,
+            };
+            // Skip records that are not from `credits.aleo`.
+            if self.vm.transition_store().get_program_id(&location.transition_id())? != Some(credits) {
+                continue;
+            }
+            // Skip records spent at or before the given height.
+            let tag = Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?;
+            if let Some(location) = self.locate_tag(&tag)? {
+                if location.height() <= height {
+                    continue;
+                }
+            }
+            // Add the amount to the balance.
+            balance = balance.checked_add(amount).ok_or_else(|| anyhow!("The balance overflows"))?;
+        }
+        Ok(balance)
+    }
+
+    /// Returns the spendable balance in microcredits of the given view key, as of the latest block.
+    pub fn latest_balance(&self, view_key: &ViewKey<N>) -> Result<u64> {
+        self.get_balance(view_key, self.latest_height())
+    }
+}
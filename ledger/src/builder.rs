@@ -0,0 +1,209 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// An input to a transaction builder.
+#[derive(Clone)]
+enum BuilderInput<N: Network> {
+    /// The given value.
+    Value(Value<N>),
+    /// An unspent `credits.aleo` record with at least the given amount of microcredits, selected from the ledger.
+    CreditsRecord(u64),
+}
+
+/// The call of a transaction builder.
+#[derive(Clone)]
+enum BuilderCall<N: Network> {
+    /// Execute the given program function.
+    Execute(ProgramID<N>, Identifier<N>),
+    /// Deploy the given program.
+    Deploy(Program<N>),
+}
+
+/// The source of the fee for a transaction builder.
+#[derive(Clone)]
+enum FeeSource<N: Network> {
+    /// Pay the fee with the largest remaining unspent `credits.aleo` record, or publicly if there is none.
+    Auto,
+    /// Pay the fee with the given record.
+    Private(Record<N, Plaintext<N>>),
+    /// Pay the fee from the public balance.
+    Public,
+}
+
+/// A builder for execute and deploy transactions.
+///
+/// The builder collects the call, its inputs, and the fee parameters, and then selects any missing records
+/// from the unspent `credits.aleo` records of the caller, before executing and proving the transaction.
+#[derive(Clone)]
+pub struct TransactionBuilder<N: Network> {
+    /// The private key of the caller.
+    private_key: PrivateKey<N>,
+    /// The program function to execute, or the program to deploy.
+    call: BuilderCall<N>,
+    /// The inputs to the function.
+    inputs: Vec<BuilderInput<N>>,
+    /// The source of the fee.
+    fee: FeeSource<N>,
+    /// The priority fee in microcredits.
+    priority_fee_in_microcredits: u64,
+}
+
+impl<N: Network> TransactionBuilder<N> {
+    /// Initializes a new builder for a transaction that executes the given program function.
+    pub fn execute(
+        private_key: PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+    ) -> Result<Self> {
+        let program_id = program_id.try_into().map_err(|_| anyhow!("Invalid program ID"))?;
+        let function_name = function_name.try_into().map_err(|_| anyhow!("Invalid function name"))?;
+        Ok(Self::new(private_key, BuilderCall::Execute(program_id, function_name)))
+    }
+
+    /// Initializes a new builder for a transaction that deploys the given program.
+    pub fn deploy(private_key: PrivateKey<N>, program: Program<N>) -> Self {
+        Self::new(private_key, BuilderCall::Deploy(program))
+    }
+
+    /// Initializes a new builder with the default fee parameters.
+    fn new(private_key: PrivateKey<N>, call: BuilderCall<N>) -> Self {
+        Self { private_key, call, inputs: Vec::new(), fee: FeeSource::Auto, priority_fee_in_microcredits: 0 }
+    }
+
+    /// Appends the given input to the function inputs.
+    pub fn input(mut self, input: impl TryInto<Value<N>>) -> Result<Self> {
+        let input = input.try_into().map_err(|_| anyhow!("Invalid input"))?;
+        self.inputs.push(BuilderInput::Value(input));
+        Ok(self)
+    }
+
+    /// Appends an unspent `credits.aleo` record with at least the given amount of microcredits to the function inputs.
+    ///
+    /// The smallest sufficient record of the caller is selected from the ledger when the transaction is built.
+    pub fn credits_record(mut self, min_microcredits: u64) -> Self {
+        self.inputs.push(BuilderInput::CreditsRecord(min_microcredits));
+        self
+    }
+
+    /// Pays the fee with the given record.
+    pub fn fee_record(mut self, record: Record<N, Plaintext<N>>) -> Self {
+        self.fee = FeeSource::Private(record);
+        self
+    }
+
+    /// Pays the fee from the public balance of the caller.
+    pub fn public_fee(mut self) -> Self {
+        self.fee = FeeSource::Public;
+        self
+    }
+
+    /// Sets the priority fee in microcredits, which is paid **on top** of the base fee.
+    pub fn priority_fee(mut self, priority_fee_in_microcredits: u64) -> Self {
+        self.priority_fee_in_microcredits = priority_fee_in_microcredits;
+        self
+    }
+
+    /// Builds the transaction, selecting any missing records from the given ledger.
+    pub fn build<C: ConsensusStorage<N>, R: Rng + CryptoRng>(
+        self,
+        ledger: &Ledger<N, C>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        // Retrieve the unspent records of the caller, excluding the records that are given as inputs or as the fee.
+        let mut records = match self.requires_records() {
+            true => ledger.find_unspent_credits_records(&ViewKey::try_from(&self.private_key)?)?,
+            false => Default::default(),
+        };
+        records.retain(|_, record| {
+            let is_input =
+                self.inputs.iter().any(|input| matches!(input, BuilderInput::Value(Value::Record(r)) if r == record));
+            let is_fee = matches!(&self.fee, FeeSource::Private(r) if r == record);
+            !is_input && !is_fee
+        });
+
+        // Prepare the inputs, selecting the smallest sufficient record for each requested record.
+        let mut inputs = Vec::with_capacity(self.inputs.len());
+        for input in self.inputs {
+            match input {
+                BuilderInput::Value(value) => inputs.push(value),
+                BuilderInput::CreditsRecord(min_microcredits) => {
+                    let commitment = records
+                        .iter()
+                        .filter_map(|(commitment, record)| Some((*commitment, microcredits(record)?)))
+                        .filter(|(_, amount)| *amount >= min_microcredits)
+                        .min_by_key(|(_, amount)| *amount)
+                        .map(|(commitment, _)| commitment);
+                    match commitment.and_then(|commitment| records.shift_remove(&commitment)) {
+                        Some(record) => inputs.push(Value::Record(record)),
+                        None => {
+                            bail!("The account has no unspent record with at least {min_microcredits} microcredits")
+                        }
+                    }
+                }
+            }
+        }
+
+        // Prepare the fee record, selecting the largest remaining record if none was given.
+        let fee_record = match self.fee {
+            FeeSource::Auto => records
+                .values()
+                .filter_map(|record| Some((record, microcredits(record)?)))
+                .max_by_key(|(_, amount)| *amount)
+                .map(|(record, _)| record.clone()),
+            FeeSource::Private(record) => Some(record),
+            FeeSource::Public => None,
+        };
+
+        // Execute and prove the transaction.
+        match self.call {
+            BuilderCall::Execute(program_id, function_name) => ledger.vm().execute(
+                &self.private_key,
+                (program_id, function_name),
+                inputs.into_iter(),
+                fee_record,
+                self.priority_fee_in_microcredits,
+                query,
+                rng,
+            ),
+            BuilderCall::Deploy(program) => {
+                ensure!(inputs.is_empty(), "A deployment does not take inputs");
+                ledger.vm().deploy(
+                    &self.private_key,
+                    &program,
+                    fee_record,
+                    self.priority_fee_in_microcredits,
+                    query,
+                    rng,
+                )
+            }
+        }
+    }
+
+    /// Returns `true` if the builder needs to select records from the ledger.
+    fn requires_records(&self) -> bool {
+        matches!(self.fee, FeeSource::Auto)
+            || self.inputs.iter().any(|input| matches!(input, BuilderInput::CreditsRecord(..)))
+    }
+}
+
+/// Returns the amount of microcredits in the given `credits.aleo` record.
+fn microcredits<N: Network>(record: &Record<N, Plaintext<N>>) -> Option<u64> {
+    match record.data().get(&Identifier::from_str("microcredits").ok()?) {
+        Some(Entry::Private(Plaintext::Literal(Literal::U64(amount), _))) => Some(**amount),
+        _ => None,
+    }
+}
@@ -14,11 +14,28 @@
 
 use super::*;
 
-use rand::{rngs::StdRng, SeedableRng};
-
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Checks the given block is valid next block.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            name = "Ledger::check_next_block",
+            skip_all,
+            fields(height = block.height(), transactions = block.transactions().len())
+        )
+    )]
     pub fn check_next_block<R: CryptoRng + Rng>(&self, block: &Block<N>, rng: &mut R) -> Result<()> {
+        self.check_next_block_with_verified(block, &IndexSet::new(), rng)
+    }
+
+    /// Checks the given block is valid next block, skipping the verification of the given transactions.
+    /// Note: The given transactions must have already been verified with `VM::check_transaction`.
+    pub(crate) fn check_next_block_with_verified<R: CryptoRng + Rng>(
+        &self,
+        block: &Block<N>,
+        verified_transaction_ids: &IndexSet<N::TransactionID>,
+        rng: &mut R,
+    ) -> Result<()> {
         let height = block.height();
 
         // Ensure the block hash does not already exist.
@@ -40,14 +57,37 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             }
         }
 
-        // Ensure each transaction is well-formed and unique.
-        // TODO: this intermediate allocation shouldn't be necessary; this is most likely https://github.com/rust-lang/rust/issues/89418.
-        let transactions = block.transactions().iter().collect::<Vec<_>>();
-        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
-        cfg_iter!(transactions).zip(rngs).try_for_each(|(transaction, mut rng)| {
-            self.check_transaction_basic(*transaction, transaction.to_rejected_id()?, &mut rng)
-                .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))
-        })?;
+        // Ensure the block does not deploy the same program more than once.
+        // Note: The deployments themselves (program, verifying keys, and certificates) are verified below.
+        let mut program_ids = IndexSet::new();
+        for transaction in block.transactions().iter().filter(|transaction| transaction.is_accepted()) {
+            if let Some(deployment) = transaction.transaction().deployment() {
+                if !program_ids.insert(deployment.program_id()) {
+                    bail!("Program '{}' is deployed more than once in block {height}", deployment.program_id())
+                }
+            }
+        }
+
+        // Ensure the block does not contain expired transactions.
+        for transaction in block.transactions().iter() {
+            let is_expired = match transaction.to_rejected() {
+                Some(rejected) => rejected.execution().map_or(false, |execution| execution.is_expired(height)),
+                None => transaction.transaction().is_expired(height),
+            };
+            if is_expired {
+                bail!("Transaction '{}' has expired in block {height}", transaction.id())
+            }
+        }
+
+        // Ensure each transaction that has not already been verified is well-formed and unique.
+        let transactions = block
+            .transactions()
+            .iter()
+            .filter(|transaction| !verified_transaction_ids.contains(&transaction.transaction().id()))
+            .map(|transaction| Ok((transaction.transaction(), transaction.to_rejected_id()?)))
+            .collect::<Result<Vec<_>>>()?;
+        self.check_transactions_basic(&transactions, rng)
+            .map_err(|e| anyhow!("Invalid transaction found in the transactions list: {e}"))?;
 
         // TODO (howardwu): Remove this after moving the total supply into credits.aleo.
         {
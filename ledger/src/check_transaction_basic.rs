@@ -15,13 +15,28 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
-    /// Checks the given transaction is well-formed and unique.
+    /// Checks the given transaction is well-formed, unique, and has not expired for the next block.
     pub fn check_transaction_basic<R: CryptoRng + Rng>(
         &self,
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
     ) -> Result<()> {
+        // Ensure the transaction can still be included in the next block.
+        if transaction.is_expired(self.latest_height().saturating_add(1)) {
+            bail!("Transaction '{}' has expired", transaction.id())
+        }
         self.vm().check_transaction(transaction, rejected_id, rng)
     }
+
+    /// Checks the given transactions are well-formed and unique, in parallel.
+    ///
+    /// Each transaction is paired with its optional rejected ID.
+    pub fn check_transactions_basic<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<()> {
+        self.vm().check_transactions(transactions, rng)
+    }
 }
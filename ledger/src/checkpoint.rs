@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Loads the ledger from storage, trusting the chain up to (and including) the given checkpoint.
+    ///
+    /// This is intended for nodes that bootstrap from a storage snapshot instead of syncing from genesis.
+    /// The history up to the checkpoint is not re-verified; instead, the stored checkpoint block is required
+    /// to match the trusted checkpoint, and the hash linkage of all stored blocks above it is checked.
+    /// Blocks added after loading are validated as usual via `check_next_block`.
+    ///
+    /// Note: The storage snapshot must contain the blocks and the finalize state up to the checkpoint, as a full
+    /// ledger cannot validate new blocks from the checkpoint data alone. To follow the chain from a checkpoint
+    /// without any stored history, use `LightLedger::from_checkpoint`.
+    pub fn load_with_checkpoint(genesis_block: Block<N>, checkpoint: &Checkpoint<N>, dev: Option<u16>) -> Result<Self> {
+        let timer = timer!("Ledger::load_with_checkpoint");
+
+        info!("Loading the ledger from storage...");
+        // Initialize the consensus store.
+        let store = match ConsensusStore::<N, C>::open(dev) {
+            Ok(store) => store,
+            Err(e) => bail!("Failed to load ledger (run 'snarkos clean' and try again)\n\n{e}\n"),
+        };
+        lap!(timer, "Load consensus store");
+
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store_with_checkpoint(genesis_block, checkpoint, store)?;
+
+        finish!(timer);
+        Ok(ledger)
+    }
+
+    /// Loads the ledger from the given consensus store, trusting the chain up to (and including) the given checkpoint.
+    pub(crate) fn from_store_with_checkpoint(
+        genesis_block: Block<N>,
+        checkpoint: &Checkpoint<N>,
+        store: ConsensusStore<N, C>,
+    ) -> Result<Self> {
+        let timer = timer!("Ledger::from_store_with_checkpoint");
+
+        // Initialize the ledger.
+        let ledger = Self::from_store_unchecked(genesis_block, store)?;
+        lap!(timer, "Load the ledger");
+
+        // Retrieve the checkpoint height.
+        let height = checkpoint.height();
+        // Retrieve the latest height.
+        let latest_height = ledger.latest_height();
+        // Ensure the ledger has reached the checkpoint.
+        if latest_height < height {
+            bail!("The ledger (at height {latest_height}) has not reached the checkpoint (at height {height})")
+        }
+
+        // Ensure the stored checkpoint block matches the trusted checkpoint.
+        if ledger.get_hash(height)? != checkpoint.block_hash() {
+            bail!("Mismatching block hash for checkpoint {height} (run 'snarkos clean' and try again)")
+        }
+        if ledger.get_previous_hash(height)? != checkpoint.previous_hash() {
+            bail!("Mismatching previous block hash for checkpoint {height}")
+        }
+        if &ledger.get_header(height)? != checkpoint.header() {
+            bail!("Mismatching block header for checkpoint {height}")
+        }
+        if ledger.get_state_root(height)? != Some(checkpoint.state_root()) {
+            bail!("Mismatching state root for checkpoint {height}")
+        }
+        lap!(timer, "Check the checkpoint block");
+
+        // Ensure the blocks above the checkpoint are linked to the checkpoint.
+        let mut previous_hash = checkpoint.block_hash();
+        for next_height in height.saturating_add(1)..=latest_height {
+            if ledger.get_previous_hash(next_height)? != previous_hash {
+                bail!("Block {next_height} is not linked to the checkpoint {height}")
+            }
+            previous_hash = ledger.get_hash(next_height)?;
+        }
+        lap!(timer, "Check the blocks above the checkpoint");
+
+        finish!(timer);
+        Ok(ledger)
+    }
+
+    /// Returns a checkpoint for the latest block in the ledger.
+    pub fn export_checkpoint(&self) -> Result<Checkpoint<N>> {
+        // Retrieve the latest block.
+        let block = self.latest_block();
+        // Retrieve the state root after the latest block.
+        let state_root = match self.get_state_root(block.height())? {
+            Some(state_root) => state_root,
+            None => bail!("Missing state root for block {}", block.height()),
+        };
+        // Construct the checkpoint.
+        let checkpoint = Checkpoint::new(block.previous_hash(), *block.header(), state_root)?;
+        // Ensure the checkpoint corresponds to the latest block.
+        ensure!(checkpoint.block_hash() == block.hash(), "Mismatching block hash for checkpoint {}", block.height());
+        Ok(checkpoint)
+    }
+}
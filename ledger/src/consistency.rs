@@ -0,0 +1,167 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Checks the consistency of the last `depth` blocks in storage (or the whole chain, if `depth` is `None`).
+    ///
+    /// This re-verifies the previous block hash linkage, the block hashes, the header roots
+    /// against the stored block contents, the block authorities, and the completeness of the
+    /// transaction, transition, serial number, and commitment indexes.
+    pub fn check_consistency(&self, depth: Option<u32>) -> Result<ConsistencyReport<N>> {
+        let timer = timer!("Ledger::check_consistency");
+
+        // Determine the range of block heights to check.
+        let end_height = self.latest_height();
+        let start_height = match depth {
+            Some(depth) => end_height.saturating_sub(depth.saturating_sub(1)),
+            None => 0,
+        };
+
+        let mut issues = Vec::new();
+        for height in start_height..=end_height {
+            self.check_block_consistency(height, &mut issues);
+        }
+        lap!(timer, "Check blocks {start_height} to {end_height}");
+
+        finish!(timer);
+        Ok(ConsistencyReport::new(start_height, end_height, issues))
+    }
+
+    /// Checks the consistency of the block at the given height, and records any issues found.
+    fn check_block_consistency(&self, height: u32, issues: &mut Vec<ConsistencyIssue<N>>) {
+        // Retrieve the block from storage.
+        let block = match self.vm.block_store().get_block_hash(height) {
+            Ok(Some(block_hash)) => match self.vm.block_store().get_block(&block_hash) {
+                Ok(Some(block)) => block,
+                Ok(None) => {
+                    issues.push(ConsistencyIssue::MissingBlock {
+                        height,
+                        error: format!("Missing block '{block_hash}'"),
+                    });
+                    return;
+                }
+                Err(error) => {
+                    issues.push(ConsistencyIssue::MissingBlock { height, error: error.to_string() });
+                    return;
+                }
+            },
+            Ok(None) => {
+                issues.push(ConsistencyIssue::MissingBlock { height, error: "Missing block hash".to_string() });
+                return;
+            }
+            Err(error) => {
+                issues.push(ConsistencyIssue::MissingBlock { height, error: error.to_string() });
+                return;
+            }
+        };
+
+        // Ensure the block is indexed at the correct height.
+        if block.height() != height {
+            issues.push(ConsistencyIssue::InvalidHeight { height, found: block.height() });
+        }
+
+        // Ensure the genesis block matches.
+        if height == 0 && block != self.genesis_block {
+            issues.push(ConsistencyIssue::InvalidBlockHash { height });
+        }
+
+        // Ensure the block hash is correct.
+        let is_valid_hash = block
+            .header()
+            .to_root()
+            .and_then(|header_root| N::hash_bhp1024(&to_bits_le![block.previous_hash(), header_root]))
+            .map(|candidate_hash| candidate_hash == *block.hash())
+            .unwrap_or(false);
+        if !is_valid_hash {
+            issues.push(ConsistencyIssue::InvalidBlockHash { height });
+        }
+
+        // Ensure the previous block hash links to the preceding block.
+        if height > 0 {
+            match self.vm.block_store().get_block_hash(height - 1) {
+                Ok(Some(expected)) if expected == block.previous_hash() => (),
+                Ok(Some(expected)) => {
+                    issues.push(ConsistencyIssue::BrokenLinkage { height, expected, found: block.previous_hash() })
+                }
+                Ok(None) | Err(_) => issues.push(ConsistencyIssue::MissingBlock {
+                    height: height - 1,
+                    error: "Missing block hash".to_string(),
+                }),
+            }
+        }
+
+        // Ensure the header roots match the block contents.
+        if block.transactions().to_transactions_root().ok() != Some(block.header().transactions_root()) {
+            issues.push(ConsistencyIssue::InvalidHeaderRoot { height, root: "transactions" });
+        }
+        if block.ratifications().to_ratifications_root().ok() != Some(block.header().ratifications_root()) {
+            issues.push(ConsistencyIssue::InvalidHeaderRoot { height, root: "ratifications" });
+        }
+        let solutions_root = match block.solutions() {
+            Some(solutions) => solutions.to_accumulator_point().ok(),
+            None => Some(Field::zero()),
+        };
+        if solutions_root != Some(block.header().solutions_root()) {
+            issues.push(ConsistencyIssue::InvalidHeaderRoot { height, root: "solutions" });
+        }
+
+        // Ensure the block authority is valid.
+        let is_valid_authority = match block.authority() {
+            Authority::Beacon(signature) => signature.verify(&signature.to_address(), &[*block.hash()]),
+            Authority::Quorum(subdag) => subdag.to_subdag_root().ok() == Some(block.header().subdag_root()),
+        };
+        if !is_valid_authority {
+            issues.push(ConsistencyIssue::InvalidAuthority { height });
+        }
+
+        // Ensure the transactions are indexed.
+        for transaction_id in block.transactions().transaction_ids() {
+            if !matches!(self.find_block_hash(transaction_id), Ok(Some(block_hash)) if block_hash == block.hash()) {
+                issues.push(ConsistencyIssue::MissingIndex {
+                    height,
+                    index: "transaction",
+                    id: transaction_id.to_string(),
+                });
+            }
+        }
+        // Ensure the transitions are indexed.
+        for transition_id in block.transactions().transition_ids() {
+            if !matches!(self.contains_transition_id(transition_id), Ok(true)) {
+                issues.push(ConsistencyIssue::MissingIndex {
+                    height,
+                    index: "transition",
+                    id: transition_id.to_string(),
+                });
+            }
+        }
+        // Ensure the serial numbers are indexed.
+        for serial_number in block.transactions().serial_numbers() {
+            if !matches!(self.contains_serial_number(serial_number), Ok(true)) {
+                issues.push(ConsistencyIssue::MissingIndex {
+                    height,
+                    index: "serial number",
+                    id: serial_number.to_string(),
+                });
+            }
+        }
+        // Ensure the commitments are indexed.
+        for commitment in block.transactions().commitments() {
+            if !matches!(self.contains_commitment(commitment), Ok(true)) {
+                issues.push(ConsistencyIssue::MissingIndex { height, index: "commitment", id: commitment.to_string() });
+            }
+        }
+    }
+}
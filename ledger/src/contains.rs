@@ -40,6 +40,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         self.vm.transaction_store().contains_program_id(program_id)
     }
 
+    /// Returns `true` if the given program can be retrieved with `get_program`.
+    /// Unlike `contains_program_id`, this includes `credits.aleo`, which is built into the VM.
+    pub fn contains_program(&self, program_id: &ProgramID<N>) -> Result<bool> {
+        Ok(*program_id == ProgramID::from_str("credits.aleo")? || self.contains_program_id(program_id)?)
+    }
+
     /// Returns `true` if the transmission exists in the ledger.
     pub fn contains_transmission(&self, transmission_id: &TransmissionID<N>) -> Result<bool> {
         match transmission_id {
@@ -77,12 +83,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
-        self.vm.transition_store().contains_serial_number(serial_number)
+        self.vm.block_store().contains_serial_number(serial_number)
     }
 
     /// Returns `true` if the given tag exists.
     pub fn contains_tag(&self, tag: &Field<N>) -> Result<bool> {
-        self.vm.transition_store().contains_tag(tag)
+        self.vm.block_store().contains_tag(tag)
     }
 
     /* Output */
@@ -94,7 +100,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
     /// Returns `true` if the given commitment exists.
     pub fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
-        self.vm.transition_store().contains_commitment(commitment)
+        self.vm.block_store().contains_commitment(commitment)
     }
 
     /// Returns `true` if the given checksum exists.
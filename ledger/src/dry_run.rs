@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Speculatively executes the given transaction on top of the latest block,
+    /// and returns whether it would be accepted, rejected, or aborted.
+    /// Note: The transaction is assumed to be well-formed; see `check_transaction_basic`.
+    pub fn dry_run(&self, transaction: &Transaction<N>) -> Result<DryRunOutcome> {
+        match self.dry_run_transactions(core::slice::from_ref(transaction))?.pop() {
+            Some((_, outcome)) => Ok(outcome),
+            None => bail!("Missing the dry-run outcome for transaction '{}'", transaction.id()),
+        }
+    }
+
+    /// Speculatively executes the given transactions, in order, on top of the latest block,
+    /// and returns whether each transaction would be accepted, rejected, or aborted.
+    /// This does not modify the ledger.
+    pub fn dry_run_transactions(
+        &self,
+        transactions: &[Transaction<N>],
+    ) -> Result<Vec<(N::TransactionID, DryRunOutcome)>> {
+        // Retrieve the latest block.
+        let latest_block = self.latest_block();
+        // Construct the finalize state of the next block.
+        let state = FinalizeGlobalState::new::<N>(
+            latest_block.round().saturating_add(1),
+            latest_block.height().saturating_add(1),
+            latest_block.cumulative_weight(),
+            latest_block.cumulative_proof_target(),
+            latest_block.hash(),
+        )?;
+
+        // Speculate over the transactions, without ratifications or solutions.
+        let (_, confirmed, aborted_ids, _) = self.vm.speculate(state, None, vec![], None, transactions.iter())?;
+
+        // Determine the outcome of each transaction.
+        let mut outcomes = IndexMap::with_capacity(transactions.len());
+        for transaction in confirmed.iter() {
+            let outcome = match transaction.is_accepted() {
+                true => DryRunOutcome::Accepted,
+                false => DryRunOutcome::Rejected,
+            };
+            outcomes.insert(transaction.to_unconfirmed_transaction_id()?, outcome);
+        }
+        for transaction_id in aborted_ids {
+            outcomes.insert(transaction_id, DryRunOutcome::Aborted);
+        }
+
+        // Return the outcomes, in the order of the given transactions.
+        transactions
+            .iter()
+            .map(|transaction| match outcomes.get(&transaction.id()) {
+                Some(outcome) => Ok((transaction.id(), outcome.clone())),
+                None => bail!("Missing the dry-run outcome for transaction '{}'", transaction.id()),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+
+/// The magic bytes at the start of a chain dump.
+const DUMP_MAGIC: [u8; 4] = *b"ALEO";
+/// The version of the chain dump format.
+const DUMP_VERSION: u8 = 1;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Writes the blocks in the given block range to the writer, and returns the number of blocks written.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// The dump consists of a preamble (magic, version, network ID), followed by one frame per block,
+    /// where each frame is `(height, length, block bytes, SHA-256 checksum of the block bytes)`.
+    pub fn export<W: Write>(&self, mut writer: W, heights: Range<u32>) -> Result<u32> {
+        // Ensure the range is within the ledger.
+        ensure!(
+            heights.end <= self.latest_height().saturating_add(1),
+            "Cannot export blocks beyond the latest height {}",
+            self.latest_height()
+        );
+
+        // Write the preamble.
+        writer.write_all(&DUMP_MAGIC)?;
+        DUMP_VERSION.write_le(&mut writer)?;
+        N::ID.write_le(&mut writer)?;
+
+        let mut num_blocks = 0u32;
+        for height in heights {
+            // Serialize the block.
+            let bytes = self.get_block(height)?.to_bytes_le()?;
+            // Write the frame.
+            height.write_le(&mut writer)?;
+            u32::try_from(bytes.len())?.write_le(&mut writer)?;
+            writer.write_all(&bytes)?;
+            writer.write_all(&Sha256::digest(&bytes))?;
+            num_blocks += 1;
+        }
+        writer.flush()?;
+        Ok(num_blocks)
+    }
+
+    /// Reads blocks from the given chain dump and adds them to the ledger, and returns the number of blocks added.
+    ///
+    /// Blocks that already exist in the ledger are checked to match, and skipped.
+    /// All other blocks are checked with `check_next_block` before being added.
+    pub fn import<R: Read>(&self, mut reader: R) -> Result<u32> {
+        // Read the preamble.
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == DUMP_MAGIC, "Invalid chain dump magic");
+        let version = u8::read_le(&mut reader)?;
+        ensure!(version == DUMP_VERSION, "Invalid chain dump version {version}");
+        let network = u16::read_le(&mut reader)?;
+        ensure!(network == N::ID, "Invalid chain dump network ID (found '{network}', expected '{}')", N::ID);
+
+        let mut num_blocks = 0u32;
+        loop {
+            // Read the height, or stop at the end of the dump.
+            let mut height_bytes = [0u8; 4];
+            let mut num_read = 0;
+            while num_read < height_bytes.len() {
+                match reader.read(&mut height_bytes[num_read..]) {
+                    Ok(0) => break,
+                    Ok(n) => num_read += n,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => return Err(e.into()),
+                }
+            }
+            // Ensure the dump ends on a frame boundary.
+            match num_read {
+                0 => break,
+                4 => (),
+                _ => bail!("Truncated frame in the chain dump"),
+            }
+            let height = u32::from_le_bytes(height_bytes);
+
+            // Read the block bytes.
+            let length = u32::read_le(&mut reader)?;
+            ensure!(length as usize <= MAX_BLOCK_SIZE, "Block {height} in the chain dump exceeds the maximum size");
+            let mut bytes = vec![0u8; length as usize];
+            reader.read_exact(&mut bytes)?;
+            // Read the checksum.
+            let mut checksum = [0u8; 32];
+            reader.read_exact(&mut checksum)?;
+            // Ensure the checksum matches.
+            ensure!(
+                Sha256::digest(&bytes)[..] == checksum[..],
+                "Invalid checksum for block {height} in the chain dump"
+            );
+
+            // Deserialize the block.
+            let block = Block::<N>::from_bytes_le(&bytes)?;
+            ensure!(block.height() == height, "Mismatching height for block {height} in the chain dump");
+
+            // If the block already exists, ensure it matches.
+            if height <= self.latest_height() {
+                ensure!(self.get_hash(height)? == block.hash(), "Block {height} does not match the ledger");
+                continue;
+            }
+            // Ensure the block is valid.
+            self.check_next_block(&block, &mut OsRng)?;
+            // Add the block to the ledger.
+            self.advance_to_next_block(&block)?;
+            num_blocks += 1;
+        }
+        Ok(num_blocks)
+    }
+}
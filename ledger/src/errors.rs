@@ -0,0 +1,76 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use thiserror::Error;
+
+/// The errors that may be returned when reading from the ledger.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum LedgerError {
+    #[error("Block {height} does not exist in storage")]
+    MissingBlock { height: u32 },
+
+    #[error("Block '{block_hash}' does not exist in storage")]
+    MissingBlockByHash { block_hash: String },
+
+    #[error("Block {given} exceeds the latest block height {tip}")]
+    HeightExceedsTip { given: u32, tip: u32 },
+
+    #[error("Missing block height for block '{block_hash}'")]
+    MissingHeight { block_hash: String },
+
+    #[error("Missing block hash for block {height}")]
+    MissingHash { height: u32 },
+
+    #[error("Missing previous block hash for block {height}")]
+    MissingPreviousHash { height: u32 },
+
+    #[error("Missing block header for block {height}")]
+    MissingHeader { height: u32 },
+
+    #[error("Missing block transactions for block {height}")]
+    MissingTransactions { height: u32 },
+
+    #[error("Missing aborted transaction IDs for block {height}")]
+    MissingAbortedTransactionIDs { height: u32 },
+
+    #[error("Missing authority for block {height}")]
+    MissingAuthority { height: u32 },
+
+    #[error("Missing transaction for ID {transaction_id}")]
+    MissingTransaction { transaction_id: String },
+
+    #[error("Missing transition for ID {transition_id}")]
+    MissingTransition { transition_id: String },
+
+    #[error("Missing confirmed transaction for ID {transaction_id}")]
+    MissingConfirmedTransaction { transaction_id: String },
+
+    #[error("Missing unconfirmed transaction for ID {transaction_id}")]
+    MissingUnconfirmedTransaction { transaction_id: String },
+
+    #[error("Missing program for ID {program_id}")]
+    MissingProgram { program_id: String },
+
+    #[error("Missing mappings for program ID {program_id}")]
+    MissingMappingNames { program_id: String },
+
+    /// An error from the underlying storage, or from decoding the stored data.
+    #[error(transparent)]
+    StorageError(anyhow::Error),
+
+    /// An error that did not originate from the underlying storage (e.g. an invalid request).
+    #[error(transparent)]
+    Other(anyhow::Error),
+}
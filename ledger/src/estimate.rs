@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use indexmap::IndexSet;
+use ledger_block::Execution;
+use synthesizer::vm::{deployment_cost, execution_cost};
+
+/// The estimated size in bytes of an execution proof, excluding its circuits and instances.
+const PROOF_BASE_SIZE_IN_BYTES: u64 = 469;
+/// The estimated size in bytes that each circuit adds to an execution proof.
+const PROOF_CIRCUIT_SIZE_IN_BYTES: u64 = 344;
+/// The estimated size in bytes that each circuit instance adds to an execution proof.
+const PROOF_INSTANCE_SIZE_IN_BYTES: u64 = 144;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the minimum fee in microcredits for the given transaction, excluding the priority fee.
+    pub fn estimate_fee(&self, transaction: &Transaction<N>) -> Result<u64> {
+        match transaction {
+            Transaction::Deploy(_, _, deployment, _) => Ok(deployment_cost(deployment)?.0),
+            // If the execution is a split, no fee is required.
+            Transaction::Execute(_, execution, _) => {
+                match execution.transitions().all(|transition| transition.is_split()) {
+                    true => Ok(0),
+                    false => Ok(execution_cost(&self.vm, execution)?.0),
+                }
+            }
+            Transaction::Fee(..) => bail!("A fee transaction does not pay for itself"),
+        }
+    }
+
+    /// Returns an estimate of the minimum fee in microcredits for executing the given program function,
+    /// excluding the priority fee.
+    ///
+    /// The function is authorized, but not proven, so the size of the execution proof is estimated.
+    pub fn estimate_fee_for_execution<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        program_id: impl TryInto<ProgramID<N>>,
+        function_name: impl TryInto<Identifier<N>>,
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        rng: &mut R,
+    ) -> Result<u64> {
+        // Authorize the function, to compute its transitions.
+        let authorization = self.vm.authorize(private_key, program_id, function_name, inputs, rng)?;
+        // If the execution is a split, no fee is required.
+        if authorization.is_split() {
+            return Ok(0);
+        }
+        // Construct the execution, without a proof.
+        let execution = Execution::from(authorization.transitions().into_values(), Default::default(), None)?;
+        // Compute the cost of the execution, without a proof.
+        let (cost, _) = execution_cost(&self.vm, &execution)?;
+        // Add the estimated size of the proof.
+        cost.checked_add(estimate_proof_size(&execution))
+            .ok_or_else(|| anyhow!("The fee estimate overflowed for an execution"))
+    }
+}
+
+/// Returns the estimated size in bytes of the proof for the given execution.
+///
+/// The proof batches one circuit per distinct function, with one instance per transition,
+/// and the inclusion circuit, with one instance per record input.
+fn estimate_proof_size<N: Network>(execution: &Execution<N>) -> u64 {
+    // Count the distinct functions, and the record inputs.
+    let num_functions = execution
+        .transitions()
+        .map(|transition| (transition.program_id(), transition.function_name()))
+        .collect::<IndexSet<_>>()
+        .len() as u64;
+    let num_records =
+        execution.transitions().map(|transition| transition.serial_numbers().count()).sum::<usize>() as u64;
+
+    // Determine the number of circuits and instances.
+    let num_circuits = num_functions.saturating_add(u64::from(num_records > 0));
+    let num_instances = (execution.len() as u64).saturating_add(num_records);
+
+    PROOF_BASE_SIZE_IN_BYTES
+        .saturating_add(num_circuits.saturating_mul(PROOF_CIRCUIT_SIZE_IN_BYTES))
+        .saturating_add(num_instances.saturating_mul(PROOF_INSTANCE_SIZE_IN_BYTES))
+}
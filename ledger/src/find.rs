@@ -65,7 +65,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
         };
 
+        // Initialize the span for the record scan.
+        #[cfg(feature = "instrument")]
+        let span = tracing::info_span!("Ledger::find_record_ciphertexts", ?filter);
+
         Ok(self.records().flat_map(move |cow| {
+            #[cfg(feature = "instrument")]
+            let _guard = span.enter();
+            #[cfg(feature = "metrics")]
+            metrics::increment_counter(metrics::ledger::RECORDS_SCANNED);
+
             // Retrieve the commitment and record.
             let (commitment, record) = match cow {
                 (Cow::Borrowed(commitment), record) => (*commitment, record),
@@ -73,41 +82,10 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             };
 
             // Determine whether to decrypt this record (or not), based on the filter.
-            let commitment = match filter {
-                RecordsFilter::All => Ok(Some(commitment)),
-                RecordsFilter::Spent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
-                        true => Some(commitment),
-                        false => None,
-                    })
-                }),
-                RecordsFilter::Unspent => Record::<N, Plaintext<N>>::tag(sk_tag, commitment).and_then(|tag| {
-                    // Determine if the record is spent.
-                    self.contains_tag(&tag).map(|is_spent| match is_spent {
-                        true => None,
-                        false => Some(commitment),
-                    })
-                }),
-                RecordsFilter::SlowSpent(private_key) => {
-                    Record::<N, Plaintext<N>>::serial_number(private_key, commitment).and_then(|serial_number| {
-                        // Determine if the record is spent.
-                        self.contains_serial_number(&serial_number).map(|is_spent| match is_spent {
-                            true => Some(commitment),
-                            false => None,
-                        })
-                    })
-                }
-                RecordsFilter::SlowUnspent(private_key) => {
-                    Record::<N, Plaintext<N>>::serial_number(private_key, commitment).and_then(|serial_number| {
-                        // Determine if the record is spent.
-                        self.contains_serial_number(&serial_number).map(|is_spent| match is_spent {
-                            true => None,
-                            false => Some(commitment),
-                        })
-                    })
-                }
-            };
+            let commitment = self.matches_records_filter(filter, sk_tag, commitment).map(|is_match| match is_match {
+                true => Some(commitment),
+                false => None,
+            });
 
             match commitment {
                 Ok(Some(commitment)) => {
@@ -141,4 +119,102 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             })
         })
     }
+
+    /// Returns the records in the ledger with a hint for the given view key (e.g. of an auditor),
+    /// which need not own the records. The record hints are shared alongside the transactions.
+    pub fn find_hinted_records<'a>(
+        &'a self,
+        view_key: &'a ViewKey<N>,
+        hints: &'a [RecordHints<N>],
+    ) -> impl '_ + Iterator<Item = (Field<N>, Record<N, Plaintext<N>>)> {
+        hints.iter().flat_map(move |hints| {
+            // Skip the record if its hints do not have a hint for the given view key.
+            if !hints.is_recipient(view_key) {
+                return None;
+            }
+            // Retrieve the record from the ledger.
+            let record = match self.vm.transition_store().get_record(hints.commitment()) {
+                Ok(Some(record)) => record,
+                Ok(None) => return None,
+                Err(e) => {
+                    warn!("Failed to retrieve the hinted record: {e}");
+                    return None;
+                }
+            };
+            match record.decrypt_with_hints(hints, view_key) {
+                Ok(record) => Some((*hints.commitment(), record)),
+                Err(e) => {
+                    warn!("Failed to decrypt the hinted record: {e}");
+                    None
+                }
+            }
+        })
+    }
+
+    /// Returns a bounded page of the records that belong to the given view key, from the given block range.
+    ///
+    /// At most `limit` records are returned. If the block range was not fully scanned,
+    /// the page includes the block range to pass in to retrieve the next page.
+    pub fn get_output_records_paged(
+        &self,
+        view_key: &ViewKey<N>,
+        filter: RecordsFilter<N>,
+        page: BlockRange,
+        limit: usize,
+    ) -> Result<RecordsPage<N>> {
+        // Ensure the limit is nonzero.
+        ensure!(limit > 0, "The page limit must be nonzero");
+        // Ensure the range is within the ledger.
+        ensure!(
+            page.end() <= self.latest_height().saturating_add(1),
+            "Cannot scan blocks beyond the latest height {}",
+            self.latest_height()
+        );
+
+        // Derive the x-coordinate of the address corresponding to the given view key.
+        let address_x_coordinate = view_key.to_address().to_x_coordinate();
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+
+        let mut records = Vec::new();
+        let mut offset = page.offset() as usize;
+        for height in page.start()..page.end() {
+            let block = self.get_block(height)?;
+            for (index, (commitment, record)) in block.records().enumerate().skip(offset) {
+                // If the page is full, return it, resuming from this record.
+                if records.len() == limit {
+                    let next = BlockRange::with_offset(height, page.end(), u32::try_from(index)?)?;
+                    return Ok(RecordsPage::new(records, Some(next)));
+                }
+                // Skip records that do not belong to the view key, or that do not match the filter.
+                if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate)
+                    || !self.matches_records_filter(filter, sk_tag, *commitment)?
+                {
+                    continue;
+                }
+                records.push((*commitment, record.decrypt(view_key)?));
+            }
+            offset = 0;
+        }
+        Ok(RecordsPage::new(records, None))
+    }
+
+    /// Returns `true` if the record with the given commitment matches the given filter.
+    fn matches_records_filter(&self, filter: RecordsFilter<N>, sk_tag: Field<N>, commitment: Field<N>) -> Result<bool> {
+        match filter {
+            RecordsFilter::All => Ok(true),
+            // Determine if the record is spent.
+            RecordsFilter::Spent => self.contains_tag(&Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?),
+            RecordsFilter::Unspent => Ok(!self.contains_tag(&Record::<N, Plaintext<N>>::tag(sk_tag, commitment)?)?),
+            RecordsFilter::SlowSpent(private_key) => {
+                self.contains_serial_number(&Record::<N, Plaintext<N>>::serial_number(private_key, commitment)?)
+            }
+            RecordsFilter::SlowUnspent(private_key) => {
+                Ok(!self.contains_serial_number(&Record::<N, Plaintext<N>>::serial_number(private_key, commitment)?)?)
+            }
+        }
+    }
 }
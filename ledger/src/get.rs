@@ -16,27 +16,27 @@ use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     /// Returns the committee for the given `block height`.
-    pub fn get_committee(&self, block_height: u32) -> Result<Option<Committee<N>>> {
-        self.vm.finalize_store().committee_store().get_committee(block_height)
+    pub fn get_committee(&self, block_height: u32) -> Result<Option<Committee<N>>, LedgerError> {
+        self.vm.finalize_store().committee_store().get_committee(block_height).map_err(LedgerError::StorageError)
     }
 
     /// Returns the committee for the given `round`.
-    pub fn get_committee_for_round(&self, round: u64) -> Result<Option<Committee<N>>> {
-        self.vm.finalize_store().committee_store().get_committee_for_round(round)
+    pub fn get_committee_for_round(&self, round: u64) -> Result<Option<Committee<N>>, LedgerError> {
+        self.vm.finalize_store().committee_store().get_committee_for_round(round).map_err(LedgerError::StorageError)
     }
 
     /// Returns the state root that contains the given `block height`.
-    pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>> {
-        self.vm.block_store().get_state_root(block_height)
+    pub fn get_state_root(&self, block_height: u32) -> Result<Option<N::StateRoot>, LedgerError> {
+        self.vm.block_store().get_state_root(block_height).map_err(LedgerError::StorageError)
     }
 
     /// Returns a state path for the given commitment.
-    pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>> {
-        self.vm.block_store().get_state_path_for_commitment(commitment)
+    pub fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>, LedgerError> {
+        self.vm.block_store().get_state_path_for_commitment(commitment).map_err(LedgerError::StorageError)
     }
 
     /// Returns the epoch challenge for the given block height.
-    pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>> {
+    pub fn get_epoch_challenge(&self, block_height: u32) -> Result<EpochChallenge<N>, LedgerError> {
         // Compute the epoch number from the current block height.
         let epoch_number = block_height / N::NUM_BLOCKS_PER_EPOCH;
         // Compute the epoch starting height (a multiple of `NUM_BLOCKS_PER_EPOCH`).
@@ -44,209 +44,361 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         // Retrieve the epoch block hash, defined as the 'previous block hash' from the epoch starting height.
         let epoch_block_hash = self.get_previous_hash(epoch_starting_height)?;
         // Construct the epoch challenge.
-        EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE)
+        EpochChallenge::new(epoch_number, epoch_block_hash, N::COINBASE_PUZZLE_DEGREE).map_err(LedgerError::Other)
     }
 
     /// Returns the block for the given block height.
-    pub fn get_block(&self, height: u32) -> Result<Block<N>> {
+    pub fn get_block(&self, height: u32) -> Result<Block<N>, LedgerError> {
         // If the height is 0, return the genesis block.
         if height == 0 {
             return Ok(self.genesis_block.clone());
         }
         // Retrieve the block hash.
-        let block_hash = match self.vm.block_store().get_block_hash(height)? {
-            Some(block_hash) => block_hash,
-            None => bail!("Block {height} does not exist in storage"),
-        };
+        let block_hash = self.get_block_hash_for_height(height)?;
         // Retrieve the block.
-        match self.vm.block_store().get_block(&block_hash)? {
+        match self.vm.block_store().get_block(&block_hash).map_err(LedgerError::StorageError)? {
             Some(block) => Ok(block),
-            None => bail!("Block {height} ('{block_hash}') does not exist in storage"),
+            None => Err(LedgerError::MissingBlock { height }),
         }
     }
 
     /// Returns the blocks in the given block range.
     /// The range is inclusive of the start and exclusive of the end.
-    pub fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>> {
+    pub fn get_blocks(&self, heights: Range<u32>) -> Result<Vec<Block<N>>, LedgerError> {
         cfg_into_iter!(heights).map(|height| self.get_block(height)).collect()
     }
 
+    /// Returns the serialized block for the given block height.
+    pub fn get_block_bytes(&self, height: u32) -> Result<Arc<Vec<u8>>, LedgerError> {
+        // Check the cache.
+        if let Some(block_bytes) = self.cache.lock().get_block_bytes(height) {
+            return Ok(block_bytes);
+        }
+        // Serialize the block.
+        let block_bytes = Arc::new(self.get_block(height)?.to_bytes_le().map_err(LedgerError::Other)?);
+        // Cache the serialized block.
+        self.cache.lock().insert_block_bytes(height, block_bytes.clone());
+        Ok(block_bytes)
+    }
+
+    /// Returns up to `MAX_BLOCK_CHUNKS` chunks of consecutive serialized blocks, starting at the given height.
+    /// Each chunk holds as many blocks as fit in `max_bytes` (at most `MAX_BLOCK_CHUNK_SIZE`),
+    /// or a single block if it exceeds this size alone.
+    /// To continue syncing, request the chunks starting at the end height of the last chunk.
+    pub fn get_block_chunks(&self, start: u32, max_bytes: usize) -> Result<Vec<BlockChunk>, LedgerError> {
+        // Ensure the start height is within the ledger.
+        let latest_height = self.latest_height();
+        if start > latest_height {
+            return Err(LedgerError::HeightExceedsTip { given: start, tip: latest_height });
+        }
+        // Clamp the requested chunk size.
+        let max_bytes = max_bytes.min(MAX_BLOCK_CHUNK_SIZE);
+
+        let mut chunks = Vec::new();
+        let mut chunk_start = start;
+        let mut chunk_blocks: Vec<Arc<Vec<u8>>> = Vec::new();
+        let mut chunk_size = 0usize;
+        for height in start..=latest_height {
+            // Retrieve the serialized block.
+            let block_bytes = self.get_block_bytes(height)?;
+            // Determine the size of the block frame.
+            let frame_size = block_bytes.len().saturating_add(4);
+            // If the block does not fit in the current chunk, close the current chunk.
+            if !chunk_blocks.is_empty() && chunk_size.saturating_add(frame_size) > max_bytes {
+                chunks.push(
+                    BlockChunk::new(chunk_start, chunk_blocks.iter().map(|bytes| bytes.as_slice()))
+                        .map_err(LedgerError::Other)?,
+                );
+                if chunks.len() == MAX_BLOCK_CHUNKS {
+                    return Ok(chunks);
+                }
+                chunk_start = height;
+                chunk_blocks.clear();
+                chunk_size = 0;
+            }
+            chunk_blocks.push(block_bytes);
+            chunk_size = chunk_size.saturating_add(frame_size);
+        }
+        // Close the last chunk.
+        chunks.push(
+            BlockChunk::new(chunk_start, chunk_blocks.iter().map(|bytes| bytes.as_slice()))
+                .map_err(LedgerError::Other)?,
+        );
+        Ok(chunks)
+    }
+
     /// Returns the block for the given block hash.
-    pub fn get_block_by_hash(&self, block_hash: &N::BlockHash) -> Result<Block<N>> {
+    pub fn get_block_by_hash(&self, block_hash: &N::BlockHash) -> Result<Block<N>, LedgerError> {
         // Retrieve the block.
-        match self.vm.block_store().get_block(block_hash)? {
+        match self.vm.block_store().get_block(block_hash).map_err(LedgerError::StorageError)? {
             Some(block) => Ok(block),
-            None => bail!("Block '{block_hash}' does not exist in storage"),
+            None => Err(LedgerError::MissingBlockByHash { block_hash: block_hash.to_string() }),
         }
     }
 
     /// Returns the block height for the given block hash.
-    pub fn get_height(&self, block_hash: &N::BlockHash) -> Result<u32> {
-        match self.vm.block_store().get_block_height(block_hash)? {
+    pub fn get_height(&self, block_hash: &N::BlockHash) -> Result<u32, LedgerError> {
+        match self.vm.block_store().get_block_height(block_hash).map_err(LedgerError::StorageError)? {
             Some(height) => Ok(height),
-            None => bail!("Missing block height for block '{block_hash}'"),
+            None => Err(LedgerError::MissingHeight { block_hash: block_hash.to_string() }),
         }
     }
 
     /// Returns the block hash for the given block height.
-    pub fn get_hash(&self, height: u32) -> Result<N::BlockHash> {
+    pub fn get_hash(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
         // If the height is 0, return the genesis block hash.
         if height == 0 {
             return Ok(self.genesis_block.hash());
         }
-        match self.vm.block_store().get_block_hash(height)? {
+        match self.vm.block_store().get_block_hash(height).map_err(LedgerError::StorageError)? {
             Some(block_hash) => Ok(block_hash),
-            None => bail!("Missing block hash for block {height}"),
+            None => Err(LedgerError::MissingHash { height }),
         }
     }
 
     /// Returns the previous block hash for the given block height.
-    pub fn get_previous_hash(&self, height: u32) -> Result<N::BlockHash> {
+    pub fn get_previous_hash(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
         // If the height is 0, return the default block hash.
         if height == 0 {
             return Ok(N::BlockHash::default());
         }
-        match self.vm.block_store().get_previous_block_hash(height)? {
-            Some(previous_hash) => Ok(previous_hash),
-            None => bail!("Missing previous block hash for block {height}"),
+        // Check the cache for the previous block hash.
+        if let Some(previous_hash) = self.cache.lock().get_previous_hash(height) {
+            return Ok(previous_hash);
+        }
+        match self.vm.block_store().get_previous_block_hash(height).map_err(LedgerError::StorageError)? {
+            Some(previous_hash) => {
+                // Cache the previous block hash, if the block is in the ledger.
+                if height <= self.latest_height() {
+                    self.cache.lock().insert_previous_hash(height, previous_hash);
+                }
+                Ok(previous_hash)
+            }
+            None => Err(LedgerError::MissingPreviousHash { height }),
         }
     }
 
     /// Returns the block header for the given block height.
-    pub fn get_header(&self, height: u32) -> Result<Header<N>> {
+    pub fn get_header(&self, height: u32) -> Result<Header<N>, LedgerError> {
         // If the height is 0, return the genesis block header.
         if height == 0 {
             return Ok(*self.genesis_block.header());
         }
+        // Check the cache for the block header.
+        if let Some(header) = self.cache.lock().get_header(height) {
+            return Ok(header);
+        }
         // Retrieve the block hash.
-        let block_hash = match self.vm.block_store().get_block_hash(height)? {
-            Some(block_hash) => block_hash,
-            None => bail!("Block {height} does not exist in storage"),
-        };
+        let block_hash = self.get_block_hash_for_height(height)?;
         // Retrieve the block header.
-        match self.vm.block_store().get_block_header(&block_hash)? {
-            Some(header) => Ok(header),
-            None => bail!("Missing block header for block {height}"),
+        match self.vm.block_store().get_block_header(&block_hash).map_err(LedgerError::StorageError)? {
+            Some(header) => {
+                // Cache the block header, if the block is in the ledger.
+                if height <= self.latest_height() {
+                    self.cache.lock().insert_header(height, header);
+                }
+                Ok(header)
+            }
+            None => Err(LedgerError::MissingHeader { height }),
         }
     }
 
     /// Returns the block transactions for the given block height.
-    pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>> {
+    pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>, LedgerError> {
         // If the height is 0, return the genesis block transactions.
         if height == 0 {
             return Ok(self.genesis_block.transactions().clone());
         }
+        // Check the cache for the block transactions.
+        if let Some(transactions) = self.cache.lock().get_transactions(height) {
+            return Ok(transactions);
+        }
         // Retrieve the block hash.
-        let Some(block_hash) = self.vm.block_store().get_block_hash(height)? else {
-            bail!("Block {height} does not exist in storage");
-        };
+        let block_hash = self.get_block_hash_for_height(height)?;
         // Retrieve the block transaction.
-        match self.vm.block_store().get_block_transactions(&block_hash)? {
-            Some(transactions) => Ok(transactions),
-            None => bail!("Missing block transactions for block {height}"),
+        match self.vm.block_store().get_block_transactions(&block_hash).map_err(LedgerError::StorageError)? {
+            Some(transactions) => {
+                // Cache the block transactions, if the block is in the ledger.
+                if height <= self.latest_height() {
+                    self.cache.lock().insert_transactions(height, transactions.clone());
+                }
+                Ok(transactions)
+            }
+            None => Err(LedgerError::MissingTransactions { height }),
         }
     }
 
     /// Returns the aborted transaction IDs for the given block height.
-    pub fn get_aborted_transaction_ids(&self, height: u32) -> Result<Vec<N::TransactionID>> {
+    pub fn get_aborted_transaction_ids(&self, height: u32) -> Result<Vec<N::TransactionID>, LedgerError> {
         // If the height is 0, return the genesis block aborted transaction IDs.
         if height == 0 {
             return Ok(self.genesis_block.aborted_transaction_ids().clone());
         }
         // Retrieve the block hash.
-        let Some(block_hash) = self.vm.block_store().get_block_hash(height)? else {
-            bail!("Block {height} does not exist in storage");
-        };
+        let block_hash = self.get_block_hash_for_height(height)?;
         // Retrieve the aborted transaction IDs.
-        match self.vm.block_store().get_block_aborted_transaction_ids(&block_hash)? {
+        match self.vm.block_store().get_block_aborted_transaction_ids(&block_hash).map_err(LedgerError::StorageError)? {
             Some(aborted_transaction_ids) => Ok(aborted_transaction_ids),
-            None => bail!("Missing aborted transaction IDs for block {height}"),
+            None => Err(LedgerError::MissingAbortedTransactionIDs { height }),
         }
     }
 
     /// Returns the transaction for the given transaction ID.
-    pub fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>> {
+    pub fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>, LedgerError> {
         // Retrieve the transaction.
-        match self.vm.block_store().get_transaction(&transaction_id)? {
+        match self.vm.block_store().get_transaction(&transaction_id).map_err(LedgerError::StorageError)? {
             Some(transaction) => Ok(transaction),
-            None => bail!("Missing transaction for ID {transaction_id}"),
+            None => Err(LedgerError::MissingTransaction { transaction_id: transaction_id.to_string() }),
+        }
+    }
+
+    /// Returns the transition for the given transition ID.
+    pub fn get_transition(&self, transition_id: N::TransitionID) -> Result<Transition<N>, LedgerError> {
+        // Retrieve the transition.
+        match self.vm.transition_store().get_transition(&transition_id).map_err(LedgerError::StorageError)? {
+            Some(transition) => Ok(transition),
+            None => Err(LedgerError::MissingTransition { transition_id: transition_id.to_string() }),
         }
     }
 
     /// Returns the confirmed transaction for the given transaction ID.
-    pub fn get_confirmed_transaction(&self, transaction_id: N::TransactionID) -> Result<ConfirmedTransaction<N>> {
+    pub fn get_confirmed_transaction(
+        &self,
+        transaction_id: N::TransactionID,
+    ) -> Result<ConfirmedTransaction<N>, LedgerError> {
         // Retrieve the confirmed transaction.
-        match self.vm.block_store().get_confirmed_transaction(&transaction_id)? {
+        match self.vm.block_store().get_confirmed_transaction(&transaction_id).map_err(LedgerError::StorageError)? {
             Some(confirmed_transaction) => Ok(confirmed_transaction),
-            None => bail!("Missing confirmed transaction for ID {transaction_id}"),
+            None => Err(LedgerError::MissingConfirmedTransaction { transaction_id: transaction_id.to_string() }),
         }
     }
 
     /// Returns the unconfirmed transaction for the given `transaction ID`.
-    pub fn get_unconfirmed_transaction(&self, transaction_id: &N::TransactionID) -> Result<Transaction<N>> {
+    pub fn get_unconfirmed_transaction(
+        &self,
+        transaction_id: &N::TransactionID,
+    ) -> Result<Transaction<N>, LedgerError> {
         // Retrieve the unconfirmed transaction.
-        match self.vm.block_store().get_unconfirmed_transaction(transaction_id)? {
+        match self.vm.block_store().get_unconfirmed_transaction(transaction_id).map_err(LedgerError::StorageError)? {
             Some(unconfirmed_transaction) => Ok(unconfirmed_transaction),
-            None => bail!("Missing unconfirmed transaction for ID {transaction_id}"),
+            None => Err(LedgerError::MissingUnconfirmedTransaction { transaction_id: transaction_id.to_string() }),
         }
     }
 
     /// Returns the program for the given program ID.
-    pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>> {
-        match self.vm.block_store().get_program(&program_id)? {
+    /// Note: `credits.aleo` is built into the VM, and is returned without being deployed.
+    pub fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>, LedgerError> {
+        match self.vm.block_store().get_program(&program_id).map_err(LedgerError::StorageError)? {
             Some(program) => Ok(program),
-            None => bail!("Missing program for ID {program_id}"),
+            None if program_id == ProgramID::from_str("credits.aleo").map_err(LedgerError::Other)? => {
+                Program::credits().map_err(LedgerError::Other)
+            }
+            None => Err(LedgerError::MissingProgram { program_id: program_id.to_string() }),
+        }
+    }
+
+    /// Returns the mapping names for the given program ID.
+    pub fn get_mapping_names(&self, program_id: &ProgramID<N>) -> Result<IndexSet<Identifier<N>>, LedgerError> {
+        match self.vm.finalize_store().get_mapping_names_confirmed(program_id).map_err(LedgerError::StorageError)? {
+            Some(mapping_names) => Ok(mapping_names),
+            None => Err(LedgerError::MissingMappingNames { program_id: program_id.to_string() }),
         }
     }
 
+    /// Returns the entries of the given mapping, as of the latest block.
+    #[allow(clippy::type_complexity)]
+    pub fn get_mapping(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+    ) -> Result<Vec<(Plaintext<N>, Value<N>)>, LedgerError> {
+        self.vm.finalize_store().get_mapping_confirmed(program_id, mapping_name).map_err(LedgerError::StorageError)
+    }
+
+    /// Returns the value for the given key in the given mapping, as of the latest block.
+    pub fn get_mapping_value(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+    ) -> Result<Option<Value<N>>, LedgerError> {
+        self.vm.finalize_store().get_value_confirmed(program_id, mapping_name, key).map_err(LedgerError::StorageError)
+    }
+
+    /// Returns the value for the given key in the given mapping, as of the block at the given height.
+    pub fn get_mapping_value_at(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>, LedgerError> {
+        // Ensure the height exists.
+        let tip = self.latest_height();
+        if height > tip {
+            return Err(LedgerError::HeightExceedsTip { given: height, tip });
+        }
+        self.vm
+            .finalize_store()
+            .get_value_at_height_confirmed(program_id, mapping_name, key, height)
+            .map_err(LedgerError::StorageError)
+    }
+
     /// Returns the block solutions for the given block height.
-    pub fn get_solutions(&self, height: u32) -> Result<Option<CoinbaseSolution<N>>> {
+    pub fn get_solutions(&self, height: u32) -> Result<Option<CoinbaseSolution<N>>, LedgerError> {
         // If the height is 0, return the genesis block solutions.
         if height == 0 {
             return Ok(self.genesis_block.solutions().cloned());
         }
         // Retrieve the block hash.
-        let block_hash = match self.vm.block_store().get_block_hash(height)? {
-            Some(block_hash) => block_hash,
-            None => bail!("Block {height} does not exist in storage"),
-        };
+        let block_hash = self.get_block_hash_for_height(height)?;
         // Retrieve the block solutions.
-        self.vm.block_store().get_block_solutions(&block_hash)
+        self.vm.block_store().get_block_solutions(&block_hash).map_err(LedgerError::StorageError)
     }
 
     /// Returns the solution for the given solution ID.
-    pub fn get_solution(&self, solution_id: &PuzzleCommitment<N>) -> Result<ProverSolution<N>> {
-        self.vm.block_store().get_solution(solution_id)
+    pub fn get_solution(&self, solution_id: &PuzzleCommitment<N>) -> Result<ProverSolution<N>, LedgerError> {
+        self.vm.block_store().get_solution(solution_id).map_err(LedgerError::StorageError)
     }
 
     /// Returns the block authority for the given block height.
-    pub fn get_authority(&self, height: u32) -> Result<Authority<N>> {
+    pub fn get_authority(&self, height: u32) -> Result<Authority<N>, LedgerError> {
         // If the height is 0, return the genesis block authority.
         if height == 0 {
             return Ok(self.genesis_block.authority().clone());
         }
         // Retrieve the block hash.
-        let block_hash = match self.vm.block_store().get_block_hash(height)? {
-            Some(block_hash) => block_hash,
-            None => bail!("Block {height} does not exist in storage"),
-        };
+        let block_hash = self.get_block_hash_for_height(height)?;
         // Retrieve the block authority.
-        match self.vm.block_store().get_block_authority(&block_hash)? {
+        match self.vm.block_store().get_block_authority(&block_hash).map_err(LedgerError::StorageError)? {
             Some(authority) => Ok(authority),
-            None => bail!("Missing authority for block {height}"),
+            None => Err(LedgerError::MissingAuthority { height }),
         }
     }
 
     /// Returns the batch certificate for the given `certificate ID`.
-    pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>> {
-        self.vm.block_store().get_batch_certificate(certificate_id)
+    pub fn get_batch_certificate(&self, certificate_id: &Field<N>) -> Result<Option<BatchCertificate<N>>, LedgerError> {
+        self.vm.block_store().get_batch_certificate(certificate_id).map_err(LedgerError::StorageError)
+    }
+
+    /// Returns the block hash for the given block height, if the block is in the ledger.
+    fn get_block_hash_for_height(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
+        // Ensure the block height does not exceed the latest block height.
+        let tip = self.latest_height();
+        if height > tip {
+            return Err(LedgerError::HeightExceedsTip { given: height, tip });
+        }
+        match self.vm.block_store().get_block_hash(height).map_err(LedgerError::StorageError)? {
+            Some(block_hash) => Ok(block_hash),
+            None => Err(LedgerError::MissingBlock { height }),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_helpers::CurrentLedger;
+    use crate::test_helpers::{add_transfer_block, sample_ledger_and_store, CurrentLedger};
     use console::network::Testnet3;
 
     type CurrentNetwork = Testnet3;
@@ -263,4 +415,51 @@ mod tests {
         // Ensure the genesis block matches.
         assert_eq!(genesis, candidate);
     }
+
+    #[test]
+    fn test_get_errors() {
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Initialize a new ledger.
+        let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+        // Ensure a block beyond the tip is reported as such.
+        assert!(matches!(ledger.get_block(1), Err(LedgerError::HeightExceedsTip { given: 1, tip: 0 })));
+        assert!(matches!(ledger.get_header(5), Err(LedgerError::HeightExceedsTip { given: 5, tip: 0 })));
+        // Ensure a missing transaction is reported as such.
+        assert!(matches!(ledger.get_transaction(Default::default()), Err(LedgerError::MissingTransaction { .. })));
+        // Ensure a missing transition is reported as such.
+        assert!(matches!(ledger.get_transition(Default::default()), Err(LedgerError::MissingTransition { .. })));
+        // Ensure a missing program is reported as such.
+        let program_id = ProgramID::from_str("missing.aleo").unwrap();
+        match ledger.get_program(program_id) {
+            Err(LedgerError::MissingProgram { program_id }) => assert_eq!(program_id, "missing.aleo"),
+            _ => panic!("Expected a missing program error"),
+        }
+    }
+
+    #[test]
+    fn test_get_block_after_reload() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a new ledger, with its store.
+        let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+        let (ledger, store) = sample_ledger_and_store(private_key, rng);
+        let genesis = ledger.get_block(0).unwrap();
+
+        // Advance the ledger by one block.
+        let block = add_transfer_block(&ledger, &private_key, rng);
+
+        // Drop the ledger, and reload it from the same store.
+        drop(ledger);
+        let ledger = CurrentLedger::from_store_unchecked(genesis.clone(), store).unwrap();
+
+        // Ensure the blocks are retrieved.
+        assert_eq!(ledger.latest_height(), 1);
+        assert_eq!(ledger.latest_block(), block);
+        assert_eq!(ledger.get_block(0).unwrap(), genesis);
+        assert_eq!(ledger.get_block(1).unwrap(), block);
+        assert!(matches!(ledger.get_block(2), Err(LedgerError::HeightExceedsTip { given: 2, tip: 1 })));
+    }
 }
@@ -0,0 +1,83 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use tokio::task;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the block for the given block height, without blocking the async executor.
+    pub async fn get_block_async(&self, height: u32) -> Result<Block<N>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_block(height)).await
+    }
+
+    /// Returns the blocks in the given block range, without blocking the async executor.
+    /// The range is inclusive of the start and exclusive of the end.
+    pub async fn get_blocks_async(&self, heights: Range<u32>) -> Result<Vec<Block<N>>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_blocks(heights)).await
+    }
+
+    /// Returns the block hash for the given block height, without blocking the async executor.
+    pub async fn get_hash_async(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_hash(height)).await
+    }
+
+    /// Returns the block header for the given block height, without blocking the async executor.
+    pub async fn get_header_async(&self, height: u32) -> Result<Header<N>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_header(height)).await
+    }
+
+    /// Returns the block transactions for the given block height, without blocking the async executor.
+    pub async fn get_transactions_async(&self, height: u32) -> Result<Transactions<N>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_transactions(height)).await
+    }
+
+    /// Returns the transaction for the given transaction ID, without blocking the async executor.
+    pub async fn get_transaction_async(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_transaction(transaction_id)).await
+    }
+
+    /// Returns the program for the given program ID, without blocking the async executor.
+    pub async fn get_program_async(&self, program_id: ProgramID<N>) -> Result<Program<N>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_program(program_id)).await
+    }
+
+    /// Returns the value for the given key in the given mapping, as of the latest block,
+    /// without blocking the async executor.
+    pub async fn get_mapping_value_async(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+    ) -> Result<Option<Value<N>>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_mapping_value(program_id, mapping_name, &key)).await
+    }
+
+    /// Returns the state path for the given commitment, without blocking the async executor.
+    pub async fn get_state_path_for_commitment_async(&self, commitment: Field<N>) -> Result<StatePath<N>, LedgerError> {
+        self.spawn_blocking(move |ledger| ledger.get_state_path_for_commitment(&commitment)).await
+    }
+
+    /// Runs the given read on the blocking thread pool of the current Tokio runtime.
+    async fn spawn_blocking<T: 'static + Send>(
+        &self,
+        read: impl 'static + Send + FnOnce(Self) -> Result<T, LedgerError>,
+    ) -> Result<T, LedgerError> {
+        let ledger = self.clone();
+        match task::spawn_blocking(move || read(ledger)).await {
+            Ok(result) => result,
+            Err(e) => Err(LedgerError::Other(anyhow!("Failed to run a ledger read: {e}"))),
+        }
+    }
+}
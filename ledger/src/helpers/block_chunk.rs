@@ -0,0 +1,210 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::Block;
+
+use sha2::{Digest, Sha256};
+
+/// The maximum number of chunks returned for a single block chunk request.
+pub const MAX_BLOCK_CHUNKS: usize = 16;
+/// The maximum size of a block chunk, in bytes, which bounds the size requested by a peer.
+/// Note: A chunk holding a single block may exceed this size, up to `MAX_BLOCK_SIZE`.
+pub const MAX_BLOCK_CHUNK_SIZE: usize = 16 * 1024 * 1024; // 16 MiB
+/// The maximum size of a serialized block, in bytes.
+/// Note: This exceeds `Transactions::MAX_WEIGHT`, to leave room for the header, ratifications, and solutions.
+pub const MAX_BLOCK_SIZE: usize = 64 * 1024 * 1024; // 64 MiB
+
+/// A chunk of consecutive, serialized blocks, for serving sync requests.
+///
+/// The chunk bytes consist of one frame per block, where each frame is `(length, block bytes)`,
+/// and the checksum is the SHA-256 digest of the chunk bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockChunk {
+    /// The height of the first block in the chunk.
+    start_height: u32,
+    /// The number of blocks in the chunk.
+    num_blocks: u32,
+    /// The serialized blocks.
+    bytes: Vec<u8>,
+    /// The SHA-256 checksum of the serialized blocks.
+    checksum: [u8; 32],
+}
+
+impl BlockChunk {
+    /// Initializes a new block chunk from the given serialized blocks, starting at the given height.
+    pub fn new<'a>(start_height: u32, blocks: impl IntoIterator<Item = &'a [u8]>) -> Result<Self> {
+        // Construct the chunk bytes.
+        let mut bytes = Vec::new();
+        let mut num_blocks = 0u32;
+        for block in blocks {
+            u32::try_from(block.len())?.write_le(&mut bytes)?;
+            bytes.extend_from_slice(block);
+            num_blocks = num_blocks.checked_add(1).ok_or_else(|| anyhow!("Too many blocks in the block chunk"))?;
+        }
+        // Ensure the chunk is not empty.
+        ensure!(num_blocks > 0, "A block chunk must contain at least one block");
+        // Ensure the heights do not overflow.
+        ensure!(start_height.checked_add(num_blocks).is_some(), "Block chunk heights exceed the maximum height");
+
+        // Compute the checksum.
+        let checksum = Sha256::digest(&bytes).into();
+        Ok(Self { start_height, num_blocks, bytes, checksum })
+    }
+
+    /// Returns the height of the first block in the chunk.
+    pub const fn start_height(&self) -> u32 {
+        self.start_height
+    }
+
+    /// Returns the height after the last block in the chunk.
+    pub const fn end_height(&self) -> u32 {
+        self.start_height + self.num_blocks
+    }
+
+    /// Returns the number of blocks in the chunk.
+    pub const fn num_blocks(&self) -> u32 {
+        self.num_blocks
+    }
+
+    /// Returns the serialized blocks.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the SHA-256 checksum of the serialized blocks.
+    pub const fn checksum(&self) -> &[u8; 32] {
+        &self.checksum
+    }
+
+    /// Returns `true` if the checksum matches the serialized blocks.
+    pub fn is_valid(&self) -> bool {
+        Sha256::digest(&self.bytes)[..] == self.checksum[..]
+    }
+
+    /// Returns the blocks in the chunk, ensuring the checksum and block heights are valid.
+    pub fn to_blocks<N: Network>(&self) -> Result<Vec<Block<N>>> {
+        // Ensure the checksum is valid.
+        ensure!(self.is_valid(), "Invalid checksum for the block chunk at height {}", self.start_height);
+
+        let mut reader = &self.bytes[..];
+        let mut blocks = Vec::with_capacity(self.num_blocks as usize);
+        for height in self.start_height..self.end_height() {
+            // Read the block bytes.
+            let length = u32::read_le(&mut reader)? as usize;
+            ensure!(length <= reader.len(), "Truncated block {height} in the block chunk");
+            let (block_bytes, remaining) = reader.split_at(length);
+            reader = remaining;
+            // Deserialize the block.
+            let block = Block::<N>::from_bytes_le(block_bytes)?;
+            ensure!(block.height() == height, "Mismatching height for block {height} in the block chunk");
+            blocks.push(block);
+        }
+        // Ensure there are no trailing bytes.
+        ensure!(reader.is_empty(), "Found trailing bytes in the block chunk");
+        Ok(blocks)
+    }
+}
+
+impl FromBytes for BlockChunk {
+    /// Reads the block chunk from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid block chunk version"));
+        }
+
+        // Read the heights.
+        let start_height = u32::read_le(&mut reader)?;
+        let num_blocks = u32::read_le(&mut reader)?;
+        if num_blocks == 0 || start_height.checked_add(num_blocks).is_none() {
+            return Err(error("Invalid block chunk heights"));
+        }
+        // Read the serialized blocks.
+        let num_bytes = u32::read_le(&mut reader)?;
+        if num_bytes as usize > MAX_BLOCK_CHUNK_SIZE.max(MAX_BLOCK_SIZE + 4) {
+            return Err(error("Block chunk exceeds the maximum size"));
+        }
+        let mut bytes = vec![0u8; num_bytes as usize];
+        reader.read_exact(&mut bytes)?;
+        // Read the checksum.
+        let checksum = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self { start_height, num_blocks, bytes, checksum })
+    }
+}
+
+impl ToBytes for BlockChunk {
+    /// Writes the block chunk to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the heights.
+        self.start_height.write_le(&mut writer)?;
+        self.num_blocks.write_le(&mut writer)?;
+        // Write the serialized blocks.
+        u32::try_from(self.bytes.len()).map_err(error)?.write_le(&mut writer)?;
+        writer.write_all(&self.bytes)?;
+        // Write the checksum.
+        self.checksum.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_block_chunk() {
+        // Load the genesis block.
+        let genesis_bytes = CurrentNetwork::genesis_bytes();
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(genesis_bytes).unwrap();
+
+        // Ensure an empty chunk is rejected.
+        assert!(BlockChunk::new(0, []).is_err());
+
+        let chunk = BlockChunk::new(0, [genesis_bytes]).unwrap();
+        assert_eq!(chunk.start_height(), 0);
+        assert_eq!(chunk.end_height(), 1);
+        assert_eq!(chunk.num_blocks(), 1);
+        assert_eq!(chunk.bytes().len(), 4 + genesis_bytes.len());
+        assert!(chunk.is_valid());
+        assert_eq!(chunk.to_blocks::<CurrentNetwork>().unwrap(), vec![genesis.clone()]);
+
+        // Check serialization.
+        let bytes = chunk.to_bytes_le().unwrap();
+        assert_eq!(chunk, BlockChunk::read_le(&bytes[..]).unwrap());
+        assert!(BlockChunk::read_le(&bytes[1..]).is_err());
+
+        // Ensure an oversized chunk is rejected before it is read.
+        let mut bytes = bytes;
+        bytes[9..13].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(BlockChunk::read_le(&bytes[..]).is_err());
+
+        // Ensure a chunk at the wrong height is rejected.
+        let chunk = BlockChunk::new(1, [genesis_bytes]).unwrap();
+        assert!(chunk.to_blocks::<CurrentNetwork>().is_err());
+
+        // Ensure a corrupted chunk is rejected.
+        let mut chunk = BlockChunk::new(0, [genesis_bytes]).unwrap();
+        chunk.bytes[8] ^= 1;
+        assert!(!chunk.is_valid());
+        assert!(chunk.to_blocks::<CurrentNetwork>().is_err());
+    }
+}
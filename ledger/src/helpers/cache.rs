@@ -0,0 +1,333 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{Header, Transactions};
+
+use indexmap::IndexMap;
+use std::sync::Arc;
+
+/// The default number of blocks retained in the ledger cache.
+pub const DEFAULT_CACHE_CAPACITY: usize = 256;
+/// The maximum total size of the serialized blocks retained in the ledger cache, in bytes.
+pub const MAX_CACHED_BLOCK_BYTES: usize = 128 * 1024 * 1024; // 128 MiB
+
+/// A least-recently-used cache with a fixed capacity, and an optional bound on the total weight of its values.
+#[derive(Clone, Debug)]
+pub struct LruCache<K: Copy + Eq + core::hash::Hash, V: Clone> {
+    /// The maximum number of entries in the cache.
+    capacity: usize,
+    /// The maximum total weight of the values in the cache.
+    max_weight: usize,
+    /// The total weight of the values in the cache.
+    weight: usize,
+    /// The function computing the weight of a value.
+    weigh: fn(&V) -> usize,
+    /// The entries of the cache, ordered from least to most recently used.
+    entries: IndexMap<K, V>,
+}
+
+impl<K: Copy + Eq + core::hash::Hash, V: Clone> LruCache<K, V> {
+    /// Initializes a new cache with the given capacity.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_max_weight(capacity, usize::MAX, |_| 0)
+    }
+
+    /// Initializes a new cache with the given capacity, which retains values up to the given total weight.
+    pub fn with_max_weight(capacity: usize, max_weight: usize, weigh: fn(&V) -> usize) -> Self {
+        Self { capacity, max_weight, weight: 0, weigh, entries: IndexMap::with_capacity(capacity) }
+    }
+
+    /// Returns the value for the given key, and marks it as most recently used.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.entries.shift_remove(key)?;
+        self.entries.insert(*key, value.clone());
+        Some(value)
+    }
+
+    /// Inserts the given key-value pair, evicting the least recently used entries if the cache is full.
+    pub fn insert(&mut self, key: K, value: V) {
+        // Remove the previous value for the key, if any.
+        if let Some(previous) = self.entries.shift_remove(&key) {
+            self.weight -= (self.weigh)(&previous);
+        }
+        // If the cache is disabled, or the value exceeds the maximum weight alone, return early.
+        let weight = (self.weigh)(&value);
+        if self.capacity == 0 || weight > self.max_weight {
+            return;
+        }
+        while self.entries.len() >= self.capacity || self.weight + weight > self.max_weight {
+            self.evict_least_recently_used();
+        }
+        self.weight += weight;
+        self.entries.insert(key, value);
+    }
+
+    /// Removes the entries whose keys do not satisfy the given predicate.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K) -> bool) {
+        let weigh = self.weigh;
+        let mut removed = 0;
+        self.entries.retain(|key, value| match keep(key) {
+            true => true,
+            false => {
+                removed += weigh(value);
+                false
+            }
+        });
+        self.weight -= removed;
+    }
+
+    /// Sets the capacity of the cache, evicting the least recently used entries as needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.evict_least_recently_used();
+        }
+    }
+
+    /// Returns the total weight of the values in the cache.
+    pub const fn weight(&self) -> usize {
+        self.weight
+    }
+
+    /// Removes the least recently used entry.
+    fn evict_least_recently_used(&mut self) {
+        if let Some((_, value)) = self.entries.shift_remove_index(0) {
+            self.weight -= (self.weigh)(&value);
+        }
+    }
+
+    /// Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// The hit and miss counters of the ledger cache.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CacheStatistics {
+    /// The number of lookups served from the cache.
+    pub hits: u64,
+    /// The number of lookups that were not served from the cache.
+    pub misses: u64,
+}
+
+/// A cache of the headers, previous block hashes, transactions, and serialized bytes of recent blocks.
+/// The serialized blocks are also bounded by `MAX_CACHED_BLOCK_BYTES` in total.
+#[derive(Clone, Debug)]
+pub struct BlockCache<N: Network> {
+    /// The map of block heights to block headers.
+    headers: LruCache<u32, Header<N>>,
+    /// The map of block heights to previous block hashes.
+    previous_hashes: LruCache<u32, N::BlockHash>,
+    /// The map of block heights to block transactions.
+    transactions: LruCache<u32, Transactions<N>>,
+    /// The map of block heights to serialized blocks.
+    block_bytes: LruCache<u32, Arc<Vec<u8>>>,
+    /// The hit and miss counters.
+    statistics: CacheStatistics,
+}
+
+impl<N: Network> Default for BlockCache<N> {
+    /// Initializes a new cache with the default capacity.
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+impl<N: Network> BlockCache<N> {
+    /// Initializes a new cache with the given capacity, in blocks.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            headers: LruCache::new(capacity),
+            previous_hashes: LruCache::new(capacity),
+            transactions: LruCache::new(capacity),
+            block_bytes: LruCache::with_max_weight(capacity, MAX_CACHED_BLOCK_BYTES, |bytes| bytes.len()),
+            statistics: Default::default(),
+        }
+    }
+
+    /// Returns the cached block header for the given block height.
+    pub fn get_header(&mut self, height: u32) -> Option<Header<N>> {
+        let header = self.headers.get(&height);
+        self.record(header.is_some());
+        header
+    }
+
+    /// Returns the cached previous block hash for the given block height.
+    pub fn get_previous_hash(&mut self, height: u32) -> Option<N::BlockHash> {
+        let previous_hash = self.previous_hashes.get(&height);
+        self.record(previous_hash.is_some());
+        previous_hash
+    }
+
+    /// Returns the cached block transactions for the given block height.
+    pub fn get_transactions(&mut self, height: u32) -> Option<Transactions<N>> {
+        let transactions = self.transactions.get(&height);
+        self.record(transactions.is_some());
+        transactions
+    }
+
+    /// Returns the cached serialized block for the given block height.
+    pub fn get_block_bytes(&mut self, height: u32) -> Option<Arc<Vec<u8>>> {
+        let block_bytes = self.block_bytes.get(&height);
+        self.record(block_bytes.is_some());
+        block_bytes
+    }
+
+    /// Caches the block header for the given block height.
+    pub fn insert_header(&mut self, height: u32, header: Header<N>) {
+        self.headers.insert(height, header);
+    }
+
+    /// Caches the previous block hash for the given block height.
+    pub fn insert_previous_hash(&mut self, height: u32, previous_hash: N::BlockHash) {
+        self.previous_hashes.insert(height, previous_hash);
+    }
+
+    /// Caches the block transactions for the given block height.
+    pub fn insert_transactions(&mut self, height: u32, transactions: Transactions<N>) {
+        self.transactions.insert(height, transactions);
+    }
+
+    /// Caches the serialized block for the given block height.
+    pub fn insert_block_bytes(&mut self, height: u32, block_bytes: Arc<Vec<u8>>) {
+        self.block_bytes.insert(height, block_bytes);
+    }
+
+    /// Removes the cached entries at or above the given block height.
+    pub fn invalidate_from(&mut self, height: u32) {
+        self.headers.retain(|candidate| *candidate < height);
+        self.previous_hashes.retain(|candidate| *candidate < height);
+        self.transactions.retain(|candidate| *candidate < height);
+        self.block_bytes.retain(|candidate| *candidate < height);
+    }
+
+    /// Sets the capacity of the cache, in blocks.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.headers.set_capacity(capacity);
+        self.previous_hashes.set_capacity(capacity);
+        self.transactions.set_capacity(capacity);
+        self.block_bytes.set_capacity(capacity);
+    }
+
+    /// Returns the hit and miss counters of the cache.
+    pub const fn statistics(&self) -> CacheStatistics {
+        self.statistics
+    }
+
+    /// Records a cache hit or miss.
+    fn record(&mut self, is_hit: bool) {
+        #[cfg(feature = "metrics")]
+        match is_hit {
+            true => metrics::increment_counter(metrics::ledger::CACHE_HITS),
+            false => metrics::increment_counter(metrics::ledger::CACHE_MISSES),
+        }
+        match is_hit {
+            true => self.statistics.hits = self.statistics.hits.saturating_add(1),
+            false => self.statistics.misses = self.statistics.misses.saturating_add(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ledger_block::Block;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_lru_cache() {
+        let mut cache = LruCache::new(2);
+        cache.insert(1u32, 'a');
+        cache.insert(2u32, 'b');
+        // Mark the first entry as most recently used.
+        assert_eq!(cache.get(&1), Some('a'));
+        // Ensure the least recently used entry is evicted.
+        cache.insert(3u32, 'c');
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.get(&1), Some('a'));
+        assert_eq!(cache.get(&3), Some('c'));
+
+        // Ensure the capacity can be reduced.
+        cache.set_capacity(1);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&3), Some('c'));
+
+        // Ensure a cache with zero capacity stores nothing.
+        cache.set_capacity(0);
+        cache.insert(4u32, 'd');
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_lru_cache_max_weight() {
+        let mut cache = LruCache::with_max_weight(8, 10, |value: &Vec<u8>| value.len());
+        cache.insert(1u32, vec![0u8; 4]);
+        cache.insert(2u32, vec![0u8; 4]);
+        assert_eq!(cache.weight(), 8);
+        // Ensure the least recently used entries are evicted to stay within the maximum weight.
+        cache.insert(3u32, vec![0u8; 4]);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.weight(), 8);
+        assert_eq!(cache.get(&1), None);
+        // Ensure replacing a value updates the weight.
+        cache.insert(3u32, vec![0u8; 6]);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.weight(), 10);
+        // Ensure a value exceeding the maximum weight is not cached.
+        cache.insert(4u32, vec![0u8; 11]);
+        assert_eq!(cache.get(&4), None);
+        assert_eq!(cache.weight(), 10);
+        // Ensure removed entries release their weight.
+        cache.retain(|key| *key != 3);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.weight(), 4);
+    }
+
+    #[test]
+    fn test_block_cache() {
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        let mut cache = BlockCache::<CurrentNetwork>::new(4);
+        assert_eq!(cache.get_header(1), None);
+        cache.insert_header(1, *genesis.header());
+        cache.insert_header(2, *genesis.header());
+        cache.insert_previous_hash(2, genesis.previous_hash());
+        cache.insert_transactions(2, genesis.transactions().clone());
+        cache.insert_block_bytes(2, Arc::new(CurrentNetwork::genesis_bytes().to_vec()));
+        assert_eq!(cache.get_header(1), Some(*genesis.header()));
+        assert_eq!(cache.get_previous_hash(2), Some(genesis.previous_hash()));
+        assert_eq!(cache.get_transactions(2).as_ref(), Some(genesis.transactions()));
+        assert_eq!(cache.get_block_bytes(2).as_deref().map(|bytes| &bytes[..]), Some(CurrentNetwork::genesis_bytes()));
+        assert_eq!(cache.statistics(), CacheStatistics { hits: 4, misses: 1 });
+
+        // Ensure the entries from the invalidated height are removed.
+        cache.invalidate_from(2);
+        assert_eq!(cache.get_header(1), Some(*genesis.header()));
+        assert_eq!(cache.get_header(2), None);
+        assert_eq!(cache.get_previous_hash(2), None);
+        assert_eq!(cache.get_transactions(2), None);
+        assert_eq!(cache.get_block_bytes(2), None);
+        assert_eq!(cache.statistics(), CacheStatistics { hits: 5, misses: 5 });
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::Header;
+
+/// A checkpoint is a trusted snapshot of the chain tip at a given height.
+///
+/// Nodes that bootstrap from a checkpoint trust the history up to (and including) the checkpoint
+/// block, and only validate the blocks that are added on top of it.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Checkpoint<N: Network> {
+    /// The hash of the checkpoint block.
+    block_hash: N::BlockHash,
+    /// The hash of the block preceding the checkpoint block.
+    previous_hash: N::BlockHash,
+    /// The header of the checkpoint block.
+    header: Header<N>,
+    /// The state root of the ledger, after the checkpoint block was added.
+    state_root: N::StateRoot,
+}
+
+impl<N: Network> Checkpoint<N> {
+    /// Initializes a new checkpoint from the given previous block hash, block header, and state root.
+    pub fn new(previous_hash: N::BlockHash, header: Header<N>, state_root: N::StateRoot) -> Result<Self> {
+        // Ensure the state root is nonzero.
+        ensure!(*state_root != Field::zero(), "The checkpoint state root must be nonzero");
+        // Compute the block hash.
+        let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?;
+        // Return the checkpoint.
+        Ok(Self { block_hash: block_hash.into(), previous_hash, header, state_root })
+    }
+
+    /// Returns the block height of the checkpoint.
+    pub const fn height(&self) -> u32 {
+        self.header.height()
+    }
+
+    /// Returns the block hash of the checkpoint.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the previous block hash of the checkpoint.
+    pub const fn previous_hash(&self) -> N::BlockHash {
+        self.previous_hash
+    }
+
+    /// Returns the block header of the checkpoint.
+    pub const fn header(&self) -> &Header<N> {
+        &self.header
+    }
+
+    /// Returns the state root of the checkpoint.
+    pub const fn state_root(&self) -> N::StateRoot {
+        self.state_root
+    }
+}
+
+impl<N: Network> FromBytes for Checkpoint<N> {
+    /// Reads the checkpoint from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid checkpoint version"));
+        }
+
+        // Read the block hash.
+        let block_hash: N::BlockHash = FromBytes::read_le(&mut reader)?;
+        // Read the previous block hash.
+        let previous_hash = FromBytes::read_le(&mut reader)?;
+        // Read the header.
+        let header = FromBytes::read_le(&mut reader)?;
+        // Read the state root.
+        let state_root = FromBytes::read_le(&mut reader)?;
+
+        // Construct the checkpoint.
+        let checkpoint = Self::new(previous_hash, header, state_root).map_err(error)?;
+
+        // Ensure the block hash matches.
+        match block_hash == checkpoint.block_hash() {
+            true => Ok(checkpoint),
+            false => Err(error("Mismatching checkpoint block hash, possible data corruption")),
+        }
+    }
+}
+
+impl<N: Network> ToBytes for Checkpoint<N> {
+    /// Writes the checkpoint to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the block hash.
+        self.block_hash.write_le(&mut writer)?;
+        // Write the previous block hash.
+        self.previous_hash.write_le(&mut writer)?;
+        // Write the header.
+        self.header.write_le(&mut writer)?;
+        // Write the state root.
+        self.state_root.write_le(&mut writer)
+    }
+}
+
+impl<N: Network> Debug for Checkpoint<N> {
+    /// Prints the checkpoint as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Checkpoint {{ height: {}, block_hash: {} }}", self.height(), self.block_hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ledger_block::Block;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes() {
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        // Sample a state root.
+        let state_root = Field::<CurrentNetwork>::one().into();
+
+        // Construct the checkpoint.
+        let expected = Checkpoint::new(genesis.previous_hash(), *genesis.header(), state_root).unwrap();
+        assert_eq!(expected.block_hash(), genesis.hash());
+        assert_eq!(expected.height(), 0);
+
+        // Check the byte representation.
+        let expected_bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(expected, Checkpoint::read_le(&expected_bytes[..]).unwrap());
+        assert!(Checkpoint::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+    }
+}
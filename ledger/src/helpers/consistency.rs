@@ -0,0 +1,111 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// A consistency issue found while auditing the ledger.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ConsistencyIssue<N: Network> {
+    /// The block could not be loaded from storage.
+    MissingBlock { height: u32, error: String },
+    /// The previous block hash does not match the hash of the preceding block.
+    BrokenLinkage { height: u32, expected: N::BlockHash, found: N::BlockHash },
+    /// The stored block hash does not match the block.
+    InvalidBlockHash { height: u32 },
+    /// The block height is indexed under an incorrect height.
+    InvalidHeight { height: u32, found: u32 },
+    /// A Merkle root in the block header does not match the stored block contents.
+    InvalidHeaderRoot { height: u32, root: &'static str },
+    /// The block authority is invalid.
+    InvalidAuthority { height: u32 },
+    /// An entry of the block is missing from (or mismatched in) the storage indexes.
+    MissingIndex { height: u32, index: &'static str, id: String },
+}
+
+impl<N: Network> ConsistencyIssue<N> {
+    /// Returns the block height of the issue.
+    pub const fn height(&self) -> u32 {
+        match self {
+            Self::MissingBlock { height, .. }
+            | Self::BrokenLinkage { height, .. }
+            | Self::InvalidBlockHash { height }
+            | Self::InvalidHeight { height, .. }
+            | Self::InvalidHeaderRoot { height, .. }
+            | Self::InvalidAuthority { height }
+            | Self::MissingIndex { height, .. } => *height,
+        }
+    }
+}
+
+impl<N: Network> Display for ConsistencyIssue<N> {
+    /// Prints the consistency issue as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingBlock { height, error } => write!(f, "Block {height} could not be loaded - {error}"),
+            Self::BrokenLinkage { height, expected, found } => {
+                write!(f, "Block {height} has an incorrect previous hash (found '{found}', expected '{expected}')")
+            }
+            Self::InvalidBlockHash { height } => write!(f, "Block {height} has an incorrect block hash"),
+            Self::InvalidHeight { height, found } => write!(f, "Block {height} is indexed at height {found}"),
+            Self::InvalidHeaderRoot { height, root } => write!(f, "Block {height} has an incorrect {root} root"),
+            Self::InvalidAuthority { height } => write!(f, "Block {height} has an invalid authority"),
+            Self::MissingIndex { height, index, id } => {
+                write!(f, "Block {height} is missing '{id}' in the {index} index")
+            }
+        }
+    }
+}
+
+/// The result of a ledger consistency audit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConsistencyReport<N: Network> {
+    /// The first block height that was checked.
+    start_height: u32,
+    /// The last block height that was checked.
+    end_height: u32,
+    /// The issues that were found.
+    issues: Vec<ConsistencyIssue<N>>,
+}
+
+impl<N: Network> ConsistencyReport<N> {
+    /// Initializes a new consistency report.
+    pub fn new(start_height: u32, end_height: u32, issues: Vec<ConsistencyIssue<N>>) -> Self {
+        Self { start_height, end_height, issues }
+    }
+
+    /// Returns the first block height that was checked.
+    pub const fn start_height(&self) -> u32 {
+        self.start_height
+    }
+
+    /// Returns the last block height that was checked.
+    pub const fn end_height(&self) -> u32 {
+        self.end_height
+    }
+
+    /// Returns the number of blocks that were checked.
+    pub const fn num_blocks(&self) -> u32 {
+        self.end_height.saturating_sub(self.start_height).saturating_add(1)
+    }
+
+    /// Returns the issues that were found.
+    pub fn issues(&self) -> &[ConsistencyIssue<N>] {
+        &self.issues
+    }
+
+    /// Returns `true` if no issues were found.
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The outcome of speculatively executing a transaction on top of the latest block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DryRunOutcome {
+    /// The transaction would be accepted, and its finalize logic applied.
+    Accepted,
+    /// The transaction would be rejected, and only its fee would be charged.
+    Rejected,
+    /// The transaction would be aborted, and excluded from the block.
+    Aborted,
+}
+
+impl DryRunOutcome {
+    /// Returns `true` if the transaction would be included in a block, as accepted or rejected.
+    pub const fn is_included(&self) -> bool {
+        matches!(self, Self::Accepted | Self::Rejected)
+    }
+}
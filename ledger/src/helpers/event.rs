@@ -0,0 +1,42 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::Address,
+    network::prelude::*,
+    program::{Plaintext, Record},
+    types::Field,
+};
+
+/// An event emitted by the ledger to its subscribers.
+#[derive(Clone, Debug)]
+pub enum LedgerEvent<N: Network> {
+    /// A block was added to the ledger.
+    BlockAdded { height: u32, block_hash: N::BlockHash },
+    /// A block was removed from the ledger.
+    BlockRemoved { height: u32, block_hash: N::BlockHash },
+    /// A record owned by a registered view key was added to the ledger.
+    RecordAdded { height: u32, owner: Address<N>, commitment: Field<N>, record: Record<N, Plaintext<N>> },
+}
+
+impl<N: Network> LedgerEvent<N> {
+    /// Returns the block height of the event.
+    pub const fn height(&self) -> u32 {
+        match self {
+            Self::BlockAdded { height, .. } | Self::BlockRemoved { height, .. } | Self::RecordAdded { height, .. } => {
+                *height
+            }
+        }
+    }
+}
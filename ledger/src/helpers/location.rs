@@ -0,0 +1,60 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+
+/// The location of a transition in the ledger.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Location<N: Network> {
+    /// The block height.
+    height: u32,
+    /// The block hash.
+    block_hash: N::BlockHash,
+    /// The transaction ID.
+    transaction_id: N::TransactionID,
+    /// The transition ID.
+    transition_id: N::TransitionID,
+}
+
+impl<N: Network> Location<N> {
+    /// Initializes a new location.
+    pub const fn new(
+        height: u32,
+        block_hash: N::BlockHash,
+        transaction_id: N::TransactionID,
+        transition_id: N::TransitionID,
+    ) -> Self {
+        Self { height, block_hash, transaction_id, transition_id }
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the block hash.
+    pub const fn block_hash(&self) -> N::BlockHash {
+        self.block_hash
+    }
+
+    /// Returns the transaction ID.
+    pub const fn transaction_id(&self) -> N::TransactionID {
+        self.transaction_id
+    }
+
+    /// Returns the transition ID.
+    pub const fn transition_id(&self) -> N::TransitionID {
+        self.transition_id
+    }
+}
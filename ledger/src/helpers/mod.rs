@@ -15,5 +15,37 @@
 mod bft;
 pub use bft::*;
 
+mod block_chunk;
+pub use block_chunk::*;
+
+mod cache;
+pub use cache::*;
+
+mod checkpoint;
+pub use checkpoint::*;
+
+mod consistency;
+pub use consistency::*;
+
+mod dry_run;
+pub use dry_run::*;
+
+mod event;
+pub use event::*;
+
+mod location;
+pub use location::*;
+
+mod owner_index;
+pub use owner_index::*;
+
+mod paging;
+pub use paging::*;
+
+pub use ledger_store::helpers::LedgerStatistics;
+
 mod supply;
 pub use supply::*;
+
+mod sync_state;
+pub use sync_state::*;
@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{Address, ViewKey},
+    network::prelude::*,
+    program::{Ciphertext, Owner, Record},
+    types::Field,
+};
+
+use indexmap::{IndexMap, IndexSet};
+
+/// An index from record owners to the commitments of their records with a private owner.
+///
+/// Only records that belong to one of the given view keys are indexed.
+/// Records with a public owner are indexed by the block store.
+#[derive(Clone, Debug, Default)]
+pub struct OwnerIndex<N: Network> {
+    /// The mapping of `address` to `commitments`.
+    commitments: IndexMap<Address<N>, IndexSet<Field<N>>>,
+}
+
+impl<N: Network> OwnerIndex<N> {
+    /// Initializes a new, empty owner index.
+    pub fn new() -> Self {
+        Self { commitments: Default::default() }
+    }
+
+    /// Inserts the given record into the index, if its owner is private and is one of the given view keys.
+    /// Returns `true` if the record was indexed.
+    pub fn insert_record(
+        &mut self,
+        commitment: Field<N>,
+        record: &Record<N, Ciphertext<N>>,
+        view_keys: &[(ViewKey<N>, Address<N>)],
+    ) -> bool {
+        // Determine the owner of the record.
+        let owner = match record.owner() {
+            Owner::Public(..) => None,
+            Owner::Private(..) => view_keys
+                .iter()
+                .find(|(view_key, address)| {
+                    record.is_owner_with_address_x_coordinate(view_key, &address.to_x_coordinate())
+                })
+                .map(|(_, address)| *address),
+        };
+        // Insert the commitment for the owner.
+        match owner {
+            Some(owner) => self.commitments.entry(owner).or_default().insert(commitment),
+            None => false,
+        }
+    }
+
+    /// Removes the given commitments from the index.
+    pub fn remove_commitments(&mut self, commitments: &[Field<N>]) {
+        // If there are no commitments to remove, return early.
+        if commitments.is_empty() {
+            return;
+        }
+        let commitments: IndexSet<_> = commitments.iter().collect();
+        // Remove the commitments, and the addresses that no longer own any records.
+        self.commitments.retain(|_, owned| {
+            owned.retain(|commitment| !commitments.contains(commitment));
+            !owned.is_empty()
+        });
+    }
+
+    /// Returns the commitments of the records owned by the given address.
+    pub fn get_commitments(&self, address: &Address<N>) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.commitments.get(address).into_iter().flatten()
+    }
+
+    /// Returns the number of indexed addresses.
+    pub fn num_addresses(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Returns the number of indexed commitments.
+    pub fn num_commitments(&self) -> usize {
+        self.commitments.values().map(IndexSet::len).sum()
+    }
+}
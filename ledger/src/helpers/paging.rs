@@ -0,0 +1,122 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Plaintext, Record},
+    types::Field,
+};
+
+/// A range of blocks to scan, inclusive of the start height and exclusive of the end height.
+///
+/// The `offset` is the number of records to skip in the block at the start height,
+/// and is only nonzero for ranges returned as the continuation of a page.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockRange {
+    /// The starting block height.
+    start: u32,
+    /// The ending block height (exclusive).
+    end: u32,
+    /// The number of records to skip in the starting block.
+    offset: u32,
+}
+
+impl BlockRange {
+    /// Initializes a new block range, from the given start height (inclusive) to the given end height (exclusive).
+    pub fn new(start: u32, end: u32) -> Result<Self> {
+        Self::with_offset(start, end, 0)
+    }
+
+    /// Initializes a new block range that skips the first `offset` records in the starting block.
+    pub fn with_offset(start: u32, end: u32, offset: u32) -> Result<Self> {
+        // Ensure the range is well-formed.
+        ensure!(start <= end, "The block range start ({start}) must not exceed the end ({end})");
+        // Ensure an offset is only given for a nonempty range.
+        ensure!(offset == 0 || start < end, "The offset of an empty block range must be zero");
+        Ok(Self { start, end, offset })
+    }
+
+    /// Returns the starting block height.
+    pub const fn start(&self) -> u32 {
+        self.start
+    }
+
+    /// Returns the ending block height (exclusive).
+    pub const fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// Returns the number of records to skip in the starting block.
+    pub const fn offset(&self) -> u32 {
+        self.offset
+    }
+
+    /// Returns `true` if the range contains no blocks.
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+}
+
+/// A record in a page, along with its commitment.
+type PageRecord<N> = (Field<N>, Record<N, Plaintext<N>>);
+
+/// A bounded page of records, along with the range to scan for the next page, if any.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordsPage<N: Network> {
+    /// The records in this page.
+    records: Vec<PageRecord<N>>,
+    /// The continuation token, i.e. the block range of the next page.
+    next: Option<BlockRange>,
+}
+
+impl<N: Network> RecordsPage<N> {
+    /// Initializes a new page from the given records and continuation token.
+    pub const fn new(records: Vec<PageRecord<N>>, next: Option<BlockRange>) -> Self {
+        Self { records, next }
+    }
+
+    /// Returns the records in this page.
+    pub fn records(&self) -> &[PageRecord<N>] {
+        &self.records
+    }
+
+    /// Returns the block range of the next page, or `None` if the requested range has been fully scanned.
+    pub const fn next(&self) -> Option<BlockRange> {
+        self.next
+    }
+
+    /// Returns the records in this page, and the block range of the next page.
+    pub fn into_parts(self) -> (Vec<PageRecord<N>>, Option<BlockRange>) {
+        (self.records, self.next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_range() {
+        let range = BlockRange::new(3, 7).unwrap();
+        assert_eq!(range.start(), 3);
+        assert_eq!(range.end(), 7);
+        assert_eq!(range.offset(), 0);
+        assert!(!range.is_empty());
+
+        assert!(BlockRange::new(5, 5).unwrap().is_empty());
+        assert!(BlockRange::new(6, 5).is_err());
+        assert_eq!(BlockRange::with_offset(3, 7, 2).unwrap().offset(), 2);
+        assert!(BlockRange::with_offset(5, 5, 1).is_err());
+    }
+}
@@ -15,6 +15,8 @@
 use console::network::Network;
 use ledger_block::Transactions;
 
+pub use ledger_store::helpers::{burned_supply, SupplyChange};
+
 use anyhow::{anyhow, Result};
 
 /// Returns the next total supply in microcredits, given the starting total supply and newly-confirmed transactions.
@@ -30,26 +32,10 @@ pub fn update_total_supply<N: Network>(
     next_total_supply = next_total_supply.saturating_add(block_reward);
     // Add the puzzle reward to the total supply.
     next_total_supply = next_total_supply.saturating_add(puzzle_reward);
-
-    // Iterate through the transactions to calculate the next total supply of microcredits.
-    for confirmed in transactions.iter() {
-        // Subtract the fee from the total supply.
-        next_total_supply = next_total_supply
-            .checked_sub(*confirmed.fee_amount()?)
-            .ok_or_else(|| anyhow!("The proposed fee underflows the total supply of microcredits"))?;
-
-        // Iterate over the transitions in the transaction.
-        for transition in confirmed.transaction().transitions() {
-            // If the transition contains a split, subtract the amount from the total supply.
-            if transition.is_split() {
-                // TODO (howardwu): Add a test that calls `split`, checks the output records - input records == 10_000u64.
-                // Subtract the amount split from the total supply.
-                next_total_supply = next_total_supply
-                    .checked_sub(10_000u64)
-                    .ok_or_else(|| anyhow!("The proposed split underflows the total supply of microcredits"))?;
-            }
-        }
-    }
+    // Subtract the burned microcredits from the total supply.
+    next_total_supply = next_total_supply
+        .checked_sub(burned_supply(transactions)?)
+        .ok_or_else(|| anyhow!("The proposed fees and splits underflow the total supply of microcredits"))?;
     // Return the final total supply in microcredits.
     Ok(next_total_supply)
 }
@@ -0,0 +1,312 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    account::{Address, GraphKey, ViewKey},
+    network::prelude::*,
+    program::{Plaintext, Record},
+    types::Field,
+};
+use ledger_block::Block;
+
+use indexmap::IndexMap;
+
+/// The scanning progress of a wallet for a single view key.
+///
+/// The sync state records the last scanned block, the commitments of the records owned by the view key,
+/// and the subset of those records that have been spent. It is meant to be persisted by the wallet
+/// (see `ToBytes` and `FromBytes`), so that scanning resumes from the next block after a restart.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SyncState<N: Network> {
+    /// The address of the view key being scanned.
+    address: Address<N>,
+    /// The height and hash of the last scanned block, if any.
+    last_block: Option<(u32, N::BlockHash)>,
+    /// The mapping of `commitment` to `(tag, height)`, for every record owned by the view key.
+    commitments: IndexMap<Field<N>, (Field<N>, u32)>,
+    /// The mapping of `tag` to `commitment`, for every record owned by the view key.
+    tags: IndexMap<Field<N>, Field<N>>,
+    /// The mapping of `commitment` to the height at which the record was spent.
+    spent: IndexMap<Field<N>, u32>,
+}
+
+impl<N: Network> SyncState<N> {
+    /// Initializes a new sync state for the given address, starting from the genesis block.
+    pub fn new(address: Address<N>) -> Self {
+        Self {
+            address,
+            last_block: None,
+            commitments: Default::default(),
+            tags: Default::default(),
+            spent: Default::default(),
+        }
+    }
+
+    /// Returns the address of the view key being scanned.
+    pub const fn address(&self) -> Address<N> {
+        self.address
+    }
+
+    /// Returns the height of the last scanned block, if any.
+    pub fn last_height(&self) -> Option<u32> {
+        self.last_block.map(|(height, _)| height)
+    }
+
+    /// Returns the hash of the last scanned block, if any.
+    pub fn last_hash(&self) -> Option<N::BlockHash> {
+        self.last_block.map(|(_, hash)| hash)
+    }
+
+    /// Returns the height of the next block to scan.
+    pub fn next_height(&self) -> u32 {
+        self.last_height().map_or(0, |height| height.saturating_add(1))
+    }
+
+    /// Returns the commitments of all known records, along with the height at which they were created.
+    pub fn commitments(&self) -> impl '_ + Iterator<Item = (&Field<N>, u32)> {
+        self.commitments.iter().map(|(commitment, (_, height))| (commitment, *height))
+    }
+
+    /// Returns the commitments of the known records that have not been spent.
+    pub fn unspent(&self) -> impl '_ + Iterator<Item = &Field<N>> {
+        self.commitments.keys().filter(|commitment| !self.spent.contains_key(*commitment))
+    }
+
+    /// Returns the commitments of the known records that have been spent, along with the height at which they were spent.
+    pub fn spent(&self) -> impl '_ + Iterator<Item = (&Field<N>, u32)> {
+        self.spent.iter().map(|(commitment, height)| (commitment, *height))
+    }
+
+    /// Returns `true` if the given commitment belongs to a known record.
+    pub fn contains_commitment(&self, commitment: &Field<N>) -> bool {
+        self.commitments.contains_key(commitment)
+    }
+
+    /// Returns `true` if the given commitment belongs to a known record that has been spent.
+    pub fn is_spent(&self, commitment: &Field<N>) -> bool {
+        self.spent.contains_key(commitment)
+    }
+
+    /// Scans the given block for records owned by (and spent by) the given view key.
+    ///
+    /// The block must directly follow the last scanned block, otherwise an error is returned
+    /// and the sync state is left unchanged.
+    pub fn scan_block(&mut self, view_key: &ViewKey<N>, block: &Block<N>) -> Result<()> {
+        // Ensure the view key matches the sync state.
+        ensure!(view_key.to_address() == self.address, "The view key does not match the sync state");
+        // Ensure the block is the next block to scan.
+        ensure!(
+            block.height() == self.next_height(),
+            "Expected to scan block {}, found block {}",
+            self.next_height(),
+            block.height()
+        );
+        // Ensure the block extends the last scanned block.
+        if let Some(last_hash) = self.last_hash() {
+            ensure!(
+                block.previous_hash() == last_hash,
+                "Block {} does not extend the last scanned block",
+                block.height()
+            );
+        }
+
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = match GraphKey::try_from(view_key) {
+            Ok(graph_key) => graph_key.sk_tag(),
+            Err(e) => bail!("Failed to derive the graph key from the view key: {e}"),
+        };
+
+        // Collect the records owned by the view key. Outputs are processed before inputs,
+        // as a record may be created and spent in the same block.
+        let mut owned = Vec::new();
+        for (commitment, record) in block.records() {
+            if record.is_owner(view_key) {
+                owned.push((*commitment, Record::<N, Plaintext<N>>::tag(sk_tag, *commitment)?));
+            }
+        }
+
+        // Update the sync state.
+        for (commitment, tag) in owned {
+            self.commitments.insert(commitment, (tag, block.height()));
+            self.tags.insert(tag, commitment);
+        }
+        for tag in block.tags() {
+            if let Some(commitment) = self.tags.get(tag) {
+                self.spent.insert(*commitment, block.height());
+            }
+        }
+        self.last_block = Some((block.height(), block.hash()));
+        Ok(())
+    }
+
+    /// Reverts the sync state to the given block, discarding everything scanned after it.
+    ///
+    /// This is used to recover from a reorganization of the chain after the given block.
+    pub fn revert_to(&mut self, height: u32, block_hash: N::BlockHash) -> Result<()> {
+        // Ensure the given height has been scanned.
+        match self.last_height() {
+            Some(last_height) => ensure!(height <= last_height, "Block {height} has not been scanned yet"),
+            None => bail!("No blocks have been scanned yet"),
+        }
+        // Discard the records created after the given height.
+        self.commitments.retain(|_, (_, created)| *created <= height);
+        self.tags.retain(|_, commitment| self.commitments.contains_key(commitment));
+        // Discard the spends after the given height.
+        self.spent.retain(|commitment, spent| *spent <= height && self.commitments.contains_key(commitment));
+        self.last_block = Some((height, block_hash));
+        Ok(())
+    }
+}
+
+impl<N: Network> FromBytes for SyncState<N> {
+    /// Reads the sync state from the buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Read the version.
+        let version = u8::read_le(&mut reader)?;
+        // Ensure the version is valid.
+        if version != 1 {
+            return Err(error("Invalid sync state version"));
+        }
+
+        // Read the address.
+        let address = FromBytes::read_le(&mut reader)?;
+        // Read the last scanned block.
+        let last_block = match u8::read_le(&mut reader)? {
+            0 => None,
+            1 => Some((u32::read_le(&mut reader)?, FromBytes::read_le(&mut reader)?)),
+            variant => return Err(error(format!("Invalid last block variant '{variant}'"))),
+        };
+
+        // Read the known records.
+        let num_commitments = u32::read_le(&mut reader)?;
+        let mut commitments = IndexMap::new();
+        let mut tags = IndexMap::new();
+        for _ in 0..num_commitments {
+            let commitment = Field::read_le(&mut reader)?;
+            let tag = Field::read_le(&mut reader)?;
+            let height = u32::read_le(&mut reader)?;
+            commitments.insert(commitment, (tag, height));
+            tags.insert(tag, commitment);
+        }
+
+        // Read the spent records.
+        let num_spent = u32::read_le(&mut reader)?;
+        let mut spent = IndexMap::new();
+        for _ in 0..num_spent {
+            let commitment = Field::read_le(&mut reader)?;
+            let height = u32::read_le(&mut reader)?;
+            // Ensure the spent record is known.
+            if !commitments.contains_key(&commitment) {
+                return Err(error("Found a spent record that is not in the sync state"));
+            }
+            spent.insert(commitment, height);
+        }
+
+        Ok(Self { address, last_block, commitments, tags, spent })
+    }
+}
+
+impl<N: Network> ToBytes for SyncState<N> {
+    /// Writes the sync state to the buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Write the version.
+        1u8.write_le(&mut writer)?;
+
+        // Write the address.
+        self.address.write_le(&mut writer)?;
+        // Write the last scanned block.
+        match &self.last_block {
+            None => 0u8.write_le(&mut writer)?,
+            Some((height, hash)) => {
+                1u8.write_le(&mut writer)?;
+                height.write_le(&mut writer)?;
+                hash.write_le(&mut writer)?;
+            }
+        }
+
+        // Write the known records.
+        u32::try_from(self.commitments.len()).map_err(error)?.write_le(&mut writer)?;
+        for (commitment, (tag, height)) in &self.commitments {
+            commitment.write_le(&mut writer)?;
+            tag.write_le(&mut writer)?;
+            height.write_le(&mut writer)?;
+        }
+
+        // Write the spent records.
+        u32::try_from(self.spent.len()).map_err(error)?.write_le(&mut writer)?;
+        for (commitment, height) in &self.spent {
+            commitment.write_le(&mut writer)?;
+            height.write_le(&mut writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl<N: Network> Debug for SyncState<N> {
+    /// Prints the sync state as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "SyncState {{ address: {}, last_height: {:?}, records: {}, spent: {} }}",
+            self.address,
+            self.last_height(),
+            self.commitments.len(),
+            self.spent.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::account::PrivateKey;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_scan_and_bytes() {
+        let rng = &mut TestRng::default();
+
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+
+        // Sample a view key.
+        let view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+        // Initialize the sync state.
+        let mut state = SyncState::new(view_key.to_address());
+        assert_eq!(state.last_height(), None);
+        assert_eq!(state.next_height(), 0);
+
+        // Scan the genesis block.
+        state.scan_block(&view_key, &genesis).unwrap();
+        assert_eq!(state.last_height(), Some(0));
+        assert_eq!(state.last_hash(), Some(genesis.hash()));
+        assert_eq!(state.next_height(), 1);
+        assert_eq!(state.commitments().count(), 0);
+
+        // Ensure the same block can not be scanned twice.
+        assert!(state.scan_block(&view_key, &genesis).is_err());
+
+        // Ensure a different view key is rejected.
+        let other_view_key = ViewKey::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        let mut other_state = SyncState::new(view_key.to_address());
+        assert!(other_state.scan_block(&other_view_key, &genesis).is_err());
+
+        // Check the byte representation.
+        let expected_bytes = state.to_bytes_le().unwrap();
+        assert_eq!(state, SyncState::read_le(&expected_bytes[..]).unwrap());
+        assert!(SyncState::<CurrentNetwork>::read_le(&expected_bytes[1..]).is_err());
+    }
+}
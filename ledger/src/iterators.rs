@@ -15,6 +15,11 @@
 use super::*;
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns an iterator over the block heights, for all blocks in `self`.
+    pub fn heights(&self) -> impl '_ + Iterator<Item = Cow<'_, u32>> {
+        self.vm.block_store().heights()
+    }
+
     /// Returns an iterator over the state roots, for all blocks in `self`.
     pub fn state_roots(&self) -> impl '_ + Iterator<Item = Cow<'_, N::StateRoot>> {
         self.vm.block_store().state_roots()
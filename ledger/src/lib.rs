@@ -31,16 +31,50 @@ pub use crate::block::*;
 #[cfg(feature = "test-helpers")]
 pub use ledger_test_helpers;
 
+mod errors;
+pub use errors::*;
+
 mod helpers;
 pub use helpers::*;
 
+mod add_blocks;
 mod advance;
+mod balance;
 mod check_next_block;
 mod check_transaction_basic;
+mod checkpoint;
+mod consistency;
 mod contains;
+mod dry_run;
+mod dump;
+mod estimate;
 mod find;
 mod get;
+#[cfg(feature = "async")]
+mod get_async;
 mod iterators;
+mod locate;
+
+mod builder;
+pub use builder::*;
+
+mod light;
+pub use light::*;
+
+mod owners;
+
+mod read_view;
+pub use read_view::*;
+
+mod replay;
+mod rollback;
+mod statistics;
+mod subscribe;
+mod supply;
+mod sync;
+
+mod traits;
+pub use traits::*;
 
 #[cfg(test)]
 mod tests;
@@ -48,11 +82,11 @@ mod tests;
 use console::{
     account::{Address, GraphKey, PrivateKey, ViewKey},
     network::prelude::*,
-    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, StatePath, Value},
+    program::{Ciphertext, Entry, Identifier, Literal, Plaintext, ProgramID, Record, RecordHints, StatePath, Value},
     types::{Field, Group},
 };
 use ledger_authority::Authority;
-use ledger_block::{Block, ConfirmedTransaction, Header, Metadata, Ratify, Transaction, Transactions};
+use ledger_block::{Block, ConfirmedTransaction, Header, Metadata, Ratify, Transaction, Transactions, Transition};
 use ledger_coinbase::{CoinbasePuzzle, CoinbaseSolution, EpochChallenge, ProverSolution, PuzzleCommitment};
 use ledger_committee::Committee;
 use ledger_narwhal::{BatchCertificate, Subdag, Transmission, TransmissionID};
@@ -61,15 +95,22 @@ use ledger_store::{ConsensusStorage, ConsensusStore};
 use synthesizer::{
     program::{FinalizeGlobalState, Program},
     vm::VM,
+    Process,
 };
 
 use aleo_std::prelude::{finish, lap, timer};
 use anyhow::Result;
 use core::ops::Range;
-use indexmap::IndexMap;
-use parking_lot::RwLock;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::{Mutex, RwLock};
 use rand::{prelude::IteratorRandom, rngs::OsRng};
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc,
+    },
+};
 use time::OffsetDateTime;
 
 #[cfg(not(feature = "serial"))]
@@ -91,6 +132,11 @@ pub enum RecordsFilter<N: Network> {
     SlowUnspent(PrivateKey<N>),
 }
 
+/// The ledger.
+///
+/// The ledger is a cheaply-cloneable handle over shared state, and is safe to use from many threads at once.
+/// Any number of readers may call the `get_*`, `contains_*`, `find_*`, and iterator methods concurrently
+/// with a single writer advancing the ledger; concurrent calls to advance the ledger are serialized.
 #[derive(Clone)]
 pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     /// The VM state.
@@ -105,6 +151,16 @@ pub struct Ledger<N: Network, C: ConsensusStorage<N>> {
     current_committee: Arc<RwLock<Option<Committee<N>>>>,
     /// The current block.
     current_block: Arc<RwLock<Block<N>>>,
+    /// The lock for ensuring there is no concurrency when advancing the ledger.
+    advance_lock: Arc<Mutex<()>>,
+    /// The senders for the subscribers of the ledger events.
+    subscribers: Arc<Mutex<Vec<Sender<LedgerEvent<N>>>>>,
+    /// The view keys registered for record events.
+    view_keys: Arc<RwLock<Vec<ViewKey<N>>>>,
+    /// The owner index, if enabled.
+    owner_index: Arc<RwLock<Option<OwnerIndex<N>>>>,
+    /// The cache of recent block headers, previous block hashes, transactions, and serialized blocks.
+    cache: Arc<Mutex<BlockCache<N>>>,
 }
 
 impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
@@ -152,6 +208,17 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         };
         lap!(timer, "Load consensus store");
 
+        // Initialize the ledger from the consensus store.
+        let ledger = Self::from_store_unchecked(genesis_block, store)?;
+
+        finish!(timer, "Initialize ledger");
+        Ok(ledger)
+    }
+
+    /// Loads the ledger from the given consensus store, without performing integrity checks.
+    pub(crate) fn from_store_unchecked(genesis_block: Block<N>, store: ConsensusStore<N, C>) -> Result<Self> {
+        let timer = timer!("Ledger::from_store_unchecked");
+
         // Initialize a new VM.
         let vm = VM::from(store)?;
         lap!(timer, "Initialize a new VM");
@@ -167,6 +234,11 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             current_epoch_challenge: Default::default(),
             current_committee: Arc::new(RwLock::new(current_committee)),
             current_block: Arc::new(RwLock::new(genesis_block.clone())),
+            advance_lock: Default::default(),
+            subscribers: Default::default(),
+            view_keys: Default::default(),
+            owner_index: Default::default(),
+            cache: Default::default(),
         };
 
         // If the block store is empty, initialize the genesis block.
@@ -180,9 +252,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let latest_height =
             *ledger.vm.block_store().heights().max().ok_or_else(|| anyhow!("Failed to load blocks from the ledger"))?;
         // Fetch the latest block.
-        let block = ledger
-            .get_block(latest_height)
-            .map_err(|_| anyhow!("Failed to load block {latest_height} from the ledger"))?;
+        // Note: The block is fetched from the block store, as the current block is not set yet.
+        let block = match ledger.vm.block_store().get_block_hash(latest_height)? {
+            Some(block_hash) => ledger.vm.block_store().get_block(&block_hash)?,
+            None => None,
+        };
+        let block = block.ok_or_else(|| anyhow!("Failed to load block {latest_height} from the ledger"))?;
 
         // Set the current block.
         ledger.current_block = Arc::new(RwLock::new(block));
@@ -205,6 +280,16 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         &self.coinbase_puzzle
     }
 
+    /// Sets the capacity of the ledger cache, in blocks.
+    pub fn set_cache_capacity(&self, capacity: usize) {
+        self.cache.lock().set_capacity(capacity)
+    }
+
+    /// Returns the hit and miss counters of the ledger cache.
+    pub fn cache_statistics(&self) -> CacheStatistics {
+        self.cache.lock().statistics()
+    }
+
     /// Returns the latest committee.
     pub fn latest_committee(&self) -> Result<Committee<N>> {
         match self.current_committee.read().as_ref() {
@@ -227,7 +312,7 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
     pub fn latest_epoch_challenge(&self) -> Result<EpochChallenge<N>> {
         match self.current_epoch_challenge.read().as_ref() {
             Some(challenge) => Ok(challenge.clone()),
-            None => self.get_epoch_challenge(self.latest_height()),
+            None => Ok(self.get_epoch_challenge(self.latest_height())?),
         }
     }
 
@@ -318,6 +403,12 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
             .collect::<IndexMap<_, _>>())
     }
 
+    /// Adds the given program to the given process, along with any of its imports that are not yet in the process.
+    /// The missing imports are retrieved from the programs deployed to the ledger.
+    pub fn add_program_with_imports(&self, process: &mut Process<N>, program: &Program<N>) -> Result<()> {
+        process.add_program_with_imports(program, |program_id| Ok(self.get_program(*program_id)?))
+    }
+
     /// Creates a deploy transaction.
     ///
     /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
@@ -383,11 +474,13 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
 
 #[cfg(test)]
 pub(crate) mod test_helpers {
-    use crate::Ledger;
+    use crate::{Ledger, RecordsFilter};
     use console::{
         account::{Address, PrivateKey, ViewKey},
         network::Testnet3,
         prelude::*,
+        program::{Literal, Value},
+        types::U64,
     };
     use ledger_block::Block;
     use ledger_store::ConsensusStore;
@@ -427,6 +520,114 @@ pub(crate) mod test_helpers {
         TestEnv { ledger, private_key, view_key, address }
     }
 
+    /// The number of accounts sampled by `sample_multi_block_test_env`.
+    pub(crate) const NUM_TEST_ACCOUNTS: usize = 4;
+
+    #[allow(dead_code)]
+    pub(crate) struct MultiBlockTestEnv {
+        pub ledger: CurrentLedger,
+        pub private_key: PrivateKey<CurrentNetwork>,
+        pub accounts: Vec<PrivateKey<CurrentNetwork>>,
+    }
+
+    /// Samples a ledger with `num_blocks` blocks after genesis, from the given seed.
+    ///
+    /// The first block funds `NUM_TEST_ACCOUNTS` sampled accounts with public credits from the genesis account.
+    /// Every subsequent block contains a transfer from one account to the next, alternating between
+    /// `transfer_public_to_private`, which creates a record for the recipient, and `transfer_private`, which
+    /// spends a record of the sender. The accounts and transactions are determined by the seed;
+    /// the block timestamps are not.
+    pub(crate) fn sample_multi_block_test_env(seed: u64, num_blocks: u32) -> MultiBlockTestEnv {
+        let rng = &mut TestRng::fixed(seed);
+
+        // Initialize the test environment.
+        let TestEnv { ledger, private_key, .. } = sample_test_env(rng);
+        // Sample the accounts.
+        let accounts: Vec<_> = (0..NUM_TEST_ACCOUNTS).map(|_| PrivateKey::new(rng).unwrap()).collect();
+
+        for height in 1..=num_blocks {
+            let transactions = match height {
+                // Fund the accounts with public credits.
+                1 => accounts
+                    .iter()
+                    .map(|account| {
+                        let inputs = [
+                            Value::from(Literal::Address(Address::try_from(account).unwrap())),
+                            value_u64(10_000_000_000),
+                        ];
+                        ledger
+                            .vm
+                            .execute(
+                                &private_key,
+                                ("credits.aleo", "transfer_public"),
+                                inputs.into_iter(),
+                                None,
+                                0,
+                                None,
+                                rng,
+                            )
+                            .unwrap()
+                    })
+                    .collect(),
+                // Transfer from one account to the next.
+                _ => {
+                    let index = usize::try_from(height).unwrap() % NUM_TEST_ACCOUNTS;
+                    let sender = &accounts[index];
+                    let recipient = Address::try_from(&accounts[(index + 1) % NUM_TEST_ACCOUNTS]).unwrap();
+                    // Retrieve an unspent record of the sender, if one exists.
+                    let record = match height % 2 {
+                        0 => None,
+                        _ => ledger
+                            .find_records(&ViewKey::try_from(sender).unwrap(), RecordsFilter::SlowUnspent(*sender))
+                            .unwrap()
+                            .next()
+                            .map(|(_, record)| record),
+                    };
+                    let transaction = match record {
+                        Some(record) => {
+                            let inputs =
+                                [Value::Record(record), Value::from(Literal::Address(recipient)), value_u64(1_000_000)];
+                            ledger.vm.execute(
+                                sender,
+                                ("credits.aleo", "transfer_private"),
+                                inputs.into_iter(),
+                                None,
+                                0,
+                                None,
+                                rng,
+                            )
+                        }
+                        None => {
+                            let inputs = [Value::from(Literal::Address(recipient)), value_u64(2_000_000)];
+                            ledger.vm.execute(
+                                sender,
+                                ("credits.aleo", "transfer_public_to_private"),
+                                inputs.into_iter(),
+                                None,
+                                0,
+                                None,
+                                rng,
+                            )
+                        }
+                    };
+                    vec![transaction.unwrap()]
+                }
+            };
+            // Construct and add the next block.
+            let block =
+                ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], transactions, rng).unwrap();
+            ledger.check_next_block(&block, rng).unwrap();
+            ledger.advance_to_next_block(&block).unwrap();
+        }
+
+        MultiBlockTestEnv { ledger, private_key, accounts }
+    }
+
+    /// Returns the given amount as a `u64` value.
+    fn value_u64(amount: u64) -> Value<CurrentNetwork> {
+        Value::from(Literal::U64(U64::new(amount)))
+    }
+
     pub(crate) fn sample_genesis_block() -> Block<CurrentNetwork> {
         Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap()
     }
@@ -446,4 +647,40 @@ pub(crate) mod test_helpers {
         // Return the ledger.
         ledger
     }
+
+    /// Samples a ledger with a genesis block from the given private key, and returns it with its consensus store,
+    /// so that the ledger can be reloaded from the same storage.
+    pub(crate) fn sample_ledger_and_store(
+        private_key: PrivateKey<CurrentNetwork>,
+        rng: &mut (impl Rng + CryptoRng),
+    ) -> (CurrentLedger, CurrentConsensusStore) {
+        // Initialize the store.
+        let store = CurrentConsensusStore::open(None).unwrap();
+        // Create a genesis block.
+        let genesis = VM::from(store.clone()).unwrap().genesis_beacon(&private_key, rng).unwrap();
+        // Initialize the ledger with the genesis block.
+        let ledger = CurrentLedger::from_store_unchecked(genesis, store.clone()).unwrap();
+        // Return the ledger and the store.
+        (ledger, store)
+    }
+
+    /// Adds a block with a public transfer from the given private key to itself to the ledger, and returns the block.
+    pub(crate) fn add_transfer_block(
+        ledger: &CurrentLedger,
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut (impl Rng + CryptoRng),
+    ) -> Block<CurrentNetwork> {
+        // Create a public transfer.
+        let inputs = [Value::from(Literal::Address(Address::try_from(private_key).unwrap())), value_u64(1_000_000)];
+        let transaction = ledger
+            .vm
+            .execute(private_key, ("credits.aleo", "transfer_public"), inputs.into_iter(), None, 0, None, rng)
+            .unwrap();
+        // Construct and add the next block.
+        let block =
+            ledger.prepare_advance_to_next_beacon_block(private_key, vec![], vec![], vec![transaction], rng).unwrap();
+        ledger.check_next_block(&block, rng).unwrap();
+        ledger.advance_to_next_block(&block).unwrap();
+        block
+    }
 }
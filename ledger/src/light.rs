@@ -0,0 +1,353 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A headers-only ledger, for light clients.
+///
+/// The light ledger stores the block hashes, previous block hashes, headers, and authorities of the chain,
+/// and validates the hash linkage and block authorities as new headers are added. Transaction and record
+/// inclusion is verified against the stored headers using state paths that are supplied externally.
+#[derive(Clone)]
+pub struct LightLedger<N: Network> {
+    /// The map of block heights to block hashes.
+    hashes: IndexMap<u32, N::BlockHash>,
+    /// The map of block hashes to block heights.
+    heights: IndexMap<N::BlockHash, u32>,
+    /// The map of block heights to previous block hashes.
+    previous_hashes: IndexMap<u32, N::BlockHash>,
+    /// The map of block heights to block headers.
+    headers: IndexMap<u32, Header<N>>,
+    /// The map of block heights to block authorities.
+    authorities: IndexMap<u32, Authority<N>>,
+    /// The map of state roots to the block height at which they were produced.
+    state_roots: IndexMap<N::StateRoot, u32>,
+}
+
+impl<N: Network> LightLedger<N> {
+    /// Initializes a new light ledger from the given genesis block.
+    pub fn new(genesis_block: &Block<N>) -> Result<Self> {
+        // Ensure the genesis block is at height 0.
+        ensure!(genesis_block.height() == 0, "The genesis block must be at height 0");
+        // Ensure the genesis header is well-formed.
+        ensure!(genesis_block.header().is_genesis(), "Invalid genesis block header");
+        // Ensure the genesis block hash is correct.
+        let block_hash = Self::compute_block_hash(genesis_block.previous_hash(), genesis_block.header())?;
+        ensure!(block_hash == genesis_block.hash(), "Incorrect genesis block hash");
+
+        // Initialize the light ledger.
+        let mut ledger = Self::empty();
+        ledger.insert(
+            block_hash,
+            genesis_block.previous_hash(),
+            *genesis_block.header(),
+            genesis_block.authority().clone(),
+        );
+        Ok(ledger)
+    }
+
+    /// Initializes a new light ledger from the given trusted checkpoint and its block authority.
+    pub fn from_checkpoint(checkpoint: &Checkpoint<N>, authority: Authority<N>) -> Result<Self> {
+        // Initialize the light ledger.
+        let mut ledger = Self::empty();
+        ledger.insert(checkpoint.block_hash(), checkpoint.previous_hash(), *checkpoint.header(), authority);
+        // Store the state root of the checkpoint, which is not yet referenced by a header.
+        ledger.state_roots.insert(checkpoint.state_root(), checkpoint.height());
+        Ok(ledger)
+    }
+
+    /// Returns the latest block height.
+    pub fn latest_height(&self) -> u32 {
+        self.headers.last().map(|(height, _)| *height).unwrap_or_default()
+    }
+
+    /// Returns the latest block hash.
+    pub fn latest_hash(&self) -> N::BlockHash {
+        self.hashes.last().map(|(_, hash)| *hash).unwrap_or_default()
+    }
+
+    /// Returns the latest block header.
+    pub fn latest_header(&self) -> Result<Header<N>, LedgerError> {
+        self.get_header(self.latest_height())
+    }
+
+    /// Returns the block height for the given block hash.
+    pub fn get_height(&self, block_hash: &N::BlockHash) -> Result<u32, LedgerError> {
+        match self.heights.get(block_hash) {
+            Some(height) => Ok(*height),
+            None => Err(LedgerError::MissingHeight { block_hash: block_hash.to_string() }),
+        }
+    }
+
+    /// Returns the block hash for the given block height.
+    pub fn get_hash(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
+        match self.hashes.get(&height) {
+            Some(block_hash) => Ok(*block_hash),
+            None => Err(LedgerError::MissingHash { height }),
+        }
+    }
+
+    /// Returns the previous block hash for the given block height.
+    pub fn get_previous_hash(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
+        match self.previous_hashes.get(&height) {
+            Some(previous_hash) => Ok(*previous_hash),
+            None => Err(LedgerError::MissingPreviousHash { height }),
+        }
+    }
+
+    /// Returns the block header for the given block height.
+    pub fn get_header(&self, height: u32) -> Result<Header<N>, LedgerError> {
+        match self.headers.get(&height) {
+            Some(header) => Ok(*header),
+            None => Err(LedgerError::MissingHeader { height }),
+        }
+    }
+
+    /// Returns the block authority for the given block height.
+    pub fn get_authority(&self, height: u32) -> Result<Authority<N>, LedgerError> {
+        match self.authorities.get(&height) {
+            Some(authority) => Ok(authority.clone()),
+            None => Err(LedgerError::MissingAuthority { height }),
+        }
+    }
+
+    /// Returns `true` if the given block hash exists.
+    pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
+        self.heights.contains_key(block_hash)
+    }
+
+    /// Returns `true` if the given state root exists.
+    pub fn contains_state_root(&self, state_root: &N::StateRoot) -> bool {
+        self.state_roots.contains_key(state_root)
+    }
+}
+
+impl<N: Network> LightLedger<N> {
+    /// Checks the given header is a valid next header, and returns the corresponding block hash.
+    pub fn check_next_header(
+        &self,
+        previous_hash: N::BlockHash,
+        header: &Header<N>,
+        authority: &Authority<N>,
+        current_committee: &Committee<N>,
+    ) -> Result<N::BlockHash> {
+        // Retrieve the latest header.
+        let latest_header = self.latest_header()?;
+        // Determine the expected height.
+        let expected_height = latest_header.height().saturating_add(1);
+
+        // Ensure the header is well-formed.
+        ensure!(header.is_valid(), "Invalid block header for block {expected_height}");
+        // Ensure the height is correct.
+        ensure!(
+            header.height() == expected_height,
+            "Block has an incorrect height (found '{}', expected '{expected_height}')",
+            header.height()
+        );
+        // Ensure the previous block hash matches.
+        ensure!(
+            previous_hash == self.latest_hash(),
+            "Previous block hash is incorrect in block {expected_height} (found '{previous_hash}', expected '{}')",
+            self.latest_hash()
+        );
+        // Ensure the round advances.
+        ensure!(
+            header.round() > latest_header.round(),
+            "Block {expected_height} has an invalid round (found '{}', expected after '{}')",
+            header.round(),
+            latest_header.round()
+        );
+        // Ensure the block round is at least the starting round of the committee.
+        ensure!(
+            header.round() >= current_committee.starting_round(),
+            "Block {expected_height} has an invalid round (found '{}', expected at least '{}')",
+            header.round(),
+            current_committee.starting_round()
+        );
+        // Ensure the timestamp does not decrease.
+        ensure!(
+            header.timestamp() >= latest_header.timestamp(),
+            "Block {expected_height} has a timestamp before its previous block"
+        );
+
+        // Compute the block hash.
+        let block_hash = Self::compute_block_hash(previous_hash, header)?;
+
+        // Ensure the block authority is correct.
+        match authority {
+            Authority::Beacon(signature) => {
+                // Retrieve the signer.
+                let signer = signature.to_address();
+                // Ensure the block is signed by a committee member.
+                ensure!(
+                    current_committee.is_committee_member(signer),
+                    "Beacon block {expected_height} has a signer not in the committee (found '{signer}')",
+                );
+                // Ensure the signature is valid.
+                ensure!(signature.verify(&signer, &[*block_hash]), "Signature is invalid in block {expected_height}");
+            }
+            Authority::Quorum(subdag) => {
+                // Ensure the subdag anchor round matches the block round.
+                ensure!(
+                    subdag.anchor_round() == header.round(),
+                    "Quorum block {expected_height} has a mismatching subdag anchor round"
+                );
+                // Ensure the subdag root matches the block header.
+                ensure!(
+                    subdag.to_subdag_root()? == header.subdag_root(),
+                    "Quorum block {expected_height} has a mismatching subdag root"
+                );
+                // Compute the expected leader.
+                let expected_leader = current_committee.get_leader(header.round())?;
+                // Ensure the block is authored by the expected leader.
+                ensure!(
+                    subdag.leader_address() == expected_leader,
+                    "Quorum block {expected_height} is authored by an unexpected leader (found: {}, expected: {expected_leader})",
+                    subdag.leader_address()
+                );
+            }
+        }
+
+        Ok(block_hash)
+    }
+
+    /// Adds the given header as the next header in the light ledger.
+    pub fn add_next_header(
+        &mut self,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        authority: Authority<N>,
+        current_committee: &Committee<N>,
+    ) -> Result<()> {
+        // Ensure the header is valid.
+        let block_hash = self.check_next_header(previous_hash, &header, &authority, current_committee)?;
+        // Insert the header.
+        self.insert(block_hash, previous_hash, header, authority);
+        Ok(())
+    }
+
+    /// Adds the header of the given block as the next header in the light ledger.
+    pub fn add_next_block(&mut self, block: &Block<N>, current_committee: &Committee<N>) -> Result<()> {
+        // Add the block header.
+        self.add_next_header(block.previous_hash(), *block.header(), block.authority().clone(), current_committee)?;
+        // Ensure the block hash matches.
+        ensure!(self.latest_hash() == block.hash(), "Mismatching block hash for block {}", block.height());
+        Ok(())
+    }
+
+    /// Checks the given state path is valid, and belongs to a block in the light ledger.
+    pub fn verify_state_path(&self, state_path: &StatePath<N>) -> Result<()> {
+        // Ensure the state path is valid.
+        state_path.verify(true, Field::zero())?;
+
+        // Ensure the global state root is known.
+        ensure!(
+            self.contains_state_root(&state_path.global_state_root()),
+            "Unknown global state root '{}'",
+            state_path.global_state_root()
+        );
+        // Retrieve the block height.
+        let height = self.get_height(&state_path.block_hash())?;
+        // Ensure the previous block hash matches.
+        ensure!(
+            state_path.previous_block_hash() == self.get_previous_hash(height)?,
+            "Mismatching previous block hash in the state path for block {height}"
+        );
+        // Ensure the header root matches.
+        ensure!(
+            *state_path.header_root() == self.get_header(height)?.to_root()?,
+            "Mismatching header root in the state path for block {height}"
+        );
+        Ok(())
+    }
+}
+
+impl<N: Network> LightLedger<N> {
+    /// Initializes an empty light ledger.
+    fn empty() -> Self {
+        Self {
+            hashes: Default::default(),
+            heights: Default::default(),
+            previous_hashes: Default::default(),
+            headers: Default::default(),
+            authorities: Default::default(),
+            state_roots: Default::default(),
+        }
+    }
+
+    /// Computes the block hash for the given previous block hash and header.
+    fn compute_block_hash(previous_hash: N::BlockHash, header: &Header<N>) -> Result<N::BlockHash> {
+        Ok(N::hash_bhp1024(&to_bits_le![previous_hash, header.to_root()?])?.into())
+    }
+
+    /// Inserts the given header into the light ledger.
+    fn insert(
+        &mut self,
+        block_hash: N::BlockHash,
+        previous_hash: N::BlockHash,
+        header: Header<N>,
+        authority: Authority<N>,
+    ) {
+        let height = header.height();
+        // A header commits to the state root of its previous block.
+        if height > 0 {
+            self.state_roots.insert(header.previous_state_root(), height - 1);
+        }
+        self.hashes.insert(height, block_hash);
+        self.heights.insert(block_hash, height);
+        self.previous_hashes.insert(height, previous_hash);
+        self.headers.insert(height, header);
+        self.authorities.insert(height, authority);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::{sample_genesis_block, CurrentNetwork};
+
+    #[test]
+    fn test_new() {
+        // Load the genesis block.
+        let genesis = sample_genesis_block();
+
+        // Initialize the light ledger.
+        let ledger = LightLedger::<CurrentNetwork>::new(&genesis).unwrap();
+        assert_eq!(ledger.latest_height(), 0);
+        assert_eq!(ledger.latest_hash(), genesis.hash());
+        assert_eq!(ledger.latest_header().unwrap(), *genesis.header());
+        assert_eq!(ledger.get_height(&genesis.hash()).unwrap(), 0);
+        assert_eq!(ledger.get_previous_hash(0).unwrap(), genesis.previous_hash());
+        assert_eq!(&ledger.get_authority(0).unwrap(), genesis.authority());
+        assert!(ledger.contains_block_hash(&genesis.hash()));
+    }
+
+    #[test]
+    fn test_check_next_header() {
+        // Load the genesis block.
+        let genesis = sample_genesis_block();
+        // Retrieve the genesis committee.
+        let committee = match genesis.ratifications().iter().next() {
+            Some(Ratify::Genesis(committee, ..)) => committee.clone(),
+            _ => panic!("The genesis block must contain a genesis ratification"),
+        };
+
+        // Initialize the light ledger.
+        let ledger = LightLedger::<CurrentNetwork>::new(&genesis).unwrap();
+        // Ensure the genesis header is not accepted as the next header.
+        assert!(ledger
+            .check_next_header(genesis.previous_hash(), genesis.header(), genesis.authority(), &committee)
+            .is_err());
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the location of the transition that created the record with the given `commitment`,
+    /// or `None` if the commitment does not exist.
+    pub fn locate_commitment(&self, commitment: &Field<N>) -> Result<Option<Location<N>>> {
+        // Ensure the commitment exists, as the output IDs also include non-record outputs.
+        if !self.contains_commitment(commitment)? {
+            return Ok(None);
+        }
+        match self.vm.transition_store().find_transition_id_from_commitment(commitment)? {
+            Some(transition_id) => self.locate_transition(transition_id).map(Some),
+            None => bail!("Missing the transition ID for commitment '{commitment}'"),
+        }
+    }
+
+    /// Returns the location of the transition that spent the record with the given `serial number`,
+    /// or `None` if the serial number does not exist.
+    pub fn locate_serial_number(&self, serial_number: &Field<N>) -> Result<Option<Location<N>>> {
+        // Ensure the serial number exists, as the input IDs also include non-record inputs.
+        if !self.contains_serial_number(serial_number)? {
+            return Ok(None);
+        }
+        match self.vm.transition_store().find_transition_id_from_serial_number(serial_number)? {
+            Some(transition_id) => self.locate_transition(transition_id).map(Some),
+            None => bail!("Missing the transition ID for serial number '{serial_number}'"),
+        }
+    }
+
+    /// Returns the location of the transition that spent the record with the given `tag`,
+    /// or `None` if the tag does not exist.
+    pub fn locate_tag(&self, tag: &Field<N>) -> Result<Option<Location<N>>> {
+        match self.vm.transition_store().find_serial_number_from_tag(tag)? {
+            Some(serial_number) => self.locate_serial_number(&serial_number),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the location of the given `transition ID`.
+    fn locate_transition(&self, transition_id: N::TransitionID) -> Result<Location<N>> {
+        // Retrieve the transaction ID.
+        let transaction_id = match self.find_transaction_id_from_transition_id(&transition_id)? {
+            Some(transaction_id) => transaction_id,
+            None => bail!("Missing the transaction ID for transition '{transition_id}'"),
+        };
+        // Retrieve the block hash.
+        let block_hash = match self.find_block_hash(&transaction_id)? {
+            Some(block_hash) => block_hash,
+            None => bail!("Missing the block hash for transaction '{transaction_id}'"),
+        };
+        // Retrieve the block height.
+        let height = self.get_height(&block_hash)?;
+
+        Ok(Location::new(height, block_hash, transaction_id, transition_id))
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Enables the owner index for the records owned by the registered view keys.
+    ///
+    /// Records with a public owner are always indexed by the block store, in the same atomic batch as their block.
+    /// Records with a private owner can only be identified with the owner's view key, so they are indexed in memory,
+    /// and the records in storage are only scanned if a view key is registered. Once enabled, it is maintained incrementally.
+    pub fn enable_owner_index(&self) {
+        // Acquire the advance lock, to ensure no blocks are added while the index is built.
+        let _advance_lock = self.advance_lock.lock();
+        // Prepare the registered view keys.
+        let view_keys = self.registered_view_keys();
+        // Build the owner index from the records in storage.
+        let mut index = OwnerIndex::new();
+        if !view_keys.is_empty() {
+            for (commitment, record) in self.records() {
+                index.insert_record(*commitment, &record, &view_keys);
+            }
+        }
+        *self.owner_index.write() = Some(index);
+    }
+
+    /// Disables the owner index, and frees its memory.
+    pub fn disable_owner_index(&self) {
+        *self.owner_index.write() = None;
+    }
+
+    /// Returns `true` if the owner index is enabled.
+    pub fn is_owner_index_enabled(&self) -> bool {
+        self.owner_index.read().is_some()
+    }
+
+    /// Returns the commitments of the records owned by the given address.
+    ///
+    /// Records with a private owner are only included if the owner index is enabled,
+    /// and the owner's view key is registered.
+    pub fn get_commitments_for_address(&self, address: &Address<N>) -> Result<Vec<Field<N>>> {
+        // Retrieve the commitments of the records with a public owner.
+        let mut commitments = self.vm.block_store().get_commitments_for_address(address)?;
+        // Append the commitments of the indexed records with a private owner.
+        if let Some(index) = self.owner_index.read().as_ref() {
+            commitments.extend(index.get_commitments(address).copied());
+        }
+        Ok(commitments)
+    }
+
+    /// Inserts the records of the given block into the owner index, if it is enabled.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "Ledger::update_owner_index", skip_all, fields(height = block.height()))
+    )]
+    pub(crate) fn update_owner_index(&self, block: &Block<N>) {
+        if let Some(index) = self.owner_index.write().as_mut() {
+            let view_keys = self.registered_view_keys();
+            for (commitment, record) in block.records() {
+                index.insert_record(*commitment, record, &view_keys);
+            }
+        }
+    }
+
+    /// Removes the given record commitments from the owner index, if it is enabled.
+    pub(crate) fn prune_owner_index(&self, commitments: &[Field<N>]) {
+        if let Some(index) = self.owner_index.write().as_mut() {
+            index.remove_commitments(commitments);
+        }
+    }
+
+    /// Inserts the records in storage owned by the given view key into the owner index, if it is enabled.
+    #[cfg_attr(feature = "instrument", tracing::instrument(name = "Ledger::index_view_key", skip_all))]
+    pub(crate) fn index_view_key(&self, view_key: &ViewKey<N>) {
+        if let Some(index) = self.owner_index.write().as_mut() {
+            let view_keys = [(*view_key, view_key.to_address())];
+            for (commitment, record) in self.records() {
+                index.insert_record(*commitment, &record, &view_keys);
+            }
+        }
+    }
+
+    /// Returns the registered view keys, with their addresses.
+    fn registered_view_keys(&self) -> Vec<(ViewKey<N>, Address<N>)> {
+        self.view_keys.read().iter().map(|view_key| (*view_key, view_key.to_address())).collect()
+    }
+}
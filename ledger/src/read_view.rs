@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// A read-only view of the ledger, pinned at the latest block at the time it was created.
+///
+/// Blocks are immutable once they are added to the ledger, so reads and iterators over a view remain
+/// consistent with one another while the ledger continues to advance, and never observe blocks that
+/// were added after the view was created.
+#[derive(Clone)]
+pub struct LedgerReadView<N: Network, C: ConsensusStorage<N>> {
+    /// The ledger.
+    ledger: Ledger<N, C>,
+    /// The latest block height in the view.
+    height: u32,
+    /// The latest block hash in the view.
+    hash: N::BlockHash,
+}
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a read-only view of the ledger, pinned at the current latest block.
+    pub fn read_view(&self) -> LedgerReadView<N, C> {
+        // Note: The current block is only updated once the block has been fully added to storage,
+        // so every block up to and including it is readable.
+        let (height, hash) = {
+            let current_block = self.current_block.read();
+            (current_block.height(), current_block.hash())
+        };
+        LedgerReadView { ledger: self.clone(), height, hash }
+    }
+}
+
+impl<N: Network, C: ConsensusStorage<N>> LedgerReadView<N, C> {
+    /// Returns the latest block height in the view.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the latest block hash in the view.
+    pub const fn hash(&self) -> N::BlockHash {
+        self.hash
+    }
+
+    /// Returns the latest block in the view.
+    pub fn latest_block(&self) -> Result<Block<N>, LedgerError> {
+        self.ledger.get_block(self.height)
+    }
+
+    /// Returns the block for the given block height.
+    pub fn get_block(&self, height: u32) -> Result<Block<N>, LedgerError> {
+        self.ensure_height(height)?;
+        self.ledger.get_block(height)
+    }
+
+    /// Returns the block header for the given block height.
+    pub fn get_header(&self, height: u32) -> Result<Header<N>, LedgerError> {
+        self.ensure_height(height)?;
+        self.ledger.get_header(height)
+    }
+
+    /// Returns the block transactions for the given block height.
+    pub fn get_transactions(&self, height: u32) -> Result<Transactions<N>, LedgerError> {
+        self.ensure_height(height)?;
+        self.ledger.get_transactions(height)
+    }
+
+    /// Returns an iterator over the blocks in the view, in order of height.
+    pub fn blocks(&self) -> impl '_ + Iterator<Item = Result<Block<N>, LedgerError>> {
+        (0..=self.height).map(|height| self.ledger.get_block(height))
+    }
+
+    /// Returns an iterator over the confirmed transactions in the view, in order of height.
+    pub fn transactions(&self) -> impl '_ + Iterator<Item = Result<ConfirmedTransaction<N>, LedgerError>> {
+        self.flat_map_transactions(|transactions| transactions.into_iter().collect())
+    }
+
+    /// Returns an iterator over the transitions in the view, in order of height.
+    pub fn transitions(&self) -> impl '_ + Iterator<Item = Result<Transition<N>, LedgerError>> {
+        self.flat_map_transactions(|transactions| transactions.into_transitions().collect())
+    }
+
+    /// Returns an iterator over the `(commitment, record)` pairs in the view, in order of height.
+    pub fn records(&self) -> impl '_ + Iterator<Item = Result<(Field<N>, Record<N, Ciphertext<N>>), LedgerError>> {
+        self.flat_map_transactions(|transactions| {
+            transactions.records().map(|(commitment, record)| (*commitment, record.clone())).collect()
+        })
+    }
+
+    /// Ensures the given block height is in the view.
+    fn ensure_height(&self, height: u32) -> Result<(), LedgerError> {
+        match height <= self.height {
+            true => Ok(()),
+            false => Err(LedgerError::HeightExceedsTip { given: height, tip: self.height }),
+        }
+    }
+
+    /// Returns an iterator over the items that `f` produces from the transactions of each block in the view.
+    fn flat_map_transactions<'a, T: 'a>(
+        &'a self,
+        f: impl 'a + Fn(Transactions<N>) -> Vec<T>,
+    ) -> impl 'a + Iterator<Item = Result<T, LedgerError>> {
+        (0..=self.height).flat_map(move |height| match self.ledger.get_transactions(height) {
+            Ok(transactions) => f(transactions).into_iter().map(Ok).collect::<Vec<_>>(),
+            Err(error) => vec![Err(error)],
+        })
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Replays the blocks in the given block range, in order, and returns the number of blocks replayed.
+    /// The range is inclusive of the start and exclusive of the end.
+    ///
+    /// Each block is checked to extend the previous block, and to match its header,
+    /// and then the `observer` is invoked on each transition of the confirmed transactions, in order.
+    /// For a rejected transaction, the observer only sees the fee transition, as the rest had no effect.
+    /// If the observer returns an error, the replay stops and returns the error.
+    pub fn replay(
+        &self,
+        heights: Range<u32>,
+        mut observer: impl FnMut(&Block<N>, &ConfirmedTransaction<N>, &Transition<N>) -> Result<()>,
+    ) -> Result<u32> {
+        // Ensure the range is within the ledger.
+        ensure!(
+            heights.end <= self.latest_height().saturating_add(1),
+            "Cannot replay blocks beyond the latest height {}",
+            self.latest_height()
+        );
+
+        // Retrieve the hash of the block before the range.
+        let mut previous_hash = match heights.start {
+            0 => N::BlockHash::default(),
+            start => self.get_hash(start - 1)?,
+        };
+
+        let mut num_blocks = 0u32;
+        for height in heights {
+            // Retrieve the block.
+            let block = self.get_block(height)?;
+            // Ensure the block extends the previous block.
+            ensure!(block.height() == height, "Block {height} has an incorrect height {}", block.height());
+            ensure!(block.previous_hash() == previous_hash, "Block {height} does not extend block {}", height - 1);
+            // Ensure the block hash matches the header.
+            let block_hash = N::hash_bhp1024(&to_bits_le![previous_hash, block.header().to_root()?])?;
+            ensure!(block.hash() == block_hash.into(), "Block {height} has an incorrect block hash");
+            // Ensure the transactions and ratifications match the header.
+            ensure!(
+                block.transactions().to_transactions_root()? == block.header().transactions_root(),
+                "Block {height} has an incorrect transactions root"
+            );
+            ensure!(
+                block.ratifications().to_ratifications_root()? == block.header().ratifications_root(),
+                "Block {height} has an incorrect ratifications root"
+            );
+
+            // Invoke the observer on each transition.
+            for transaction in block.transactions().iter() {
+                for transition in transaction.transaction().transitions() {
+                    observer(&block, transaction, transition)?;
+                }
+            }
+
+            previous_hash = block.hash();
+            num_blocks += 1;
+        }
+        Ok(num_blocks)
+    }
+}
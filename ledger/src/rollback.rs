@@ -0,0 +1,75 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Removes the last `n` blocks from the ledger, and notifies the subscribers of each removed block.
+    ///
+    /// Note: This removes the blocks from the block store (see `BlockStore::remove_last_n`),
+    /// and does not revert the finalize state of the removed blocks. Blocks should be removed through
+    /// this method rather than through the block store directly, so that the ledger cache is invalidated.
+    pub fn remove_last_n(&self, n: u32) -> Result<()> {
+        // Acquire the advance lock, to ensure no blocks are added while the blocks are removed.
+        let _advance_lock = self.advance_lock.lock();
+
+        // Retrieve the latest height.
+        let latest_height = self.latest_height();
+        // Ensure the genesis block is not removed.
+        ensure!(n <= latest_height, "Cannot remove {n} blocks from the ledger at height {latest_height}");
+        // Determine the latest height after the removal.
+        let new_height = latest_height.saturating_sub(n);
+
+        // Retrieve the hashes of the blocks to remove, in descending order.
+        let removed_blocks = (new_height + 1..=latest_height)
+            .rev()
+            .map(|height| Ok((height, self.get_hash(height)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Retrieve the record commitments of the blocks to remove, if the owner index is enabled.
+        let mut removed_commitments = Vec::new();
+        if self.is_owner_index_enabled() {
+            for (height, block_hash) in &removed_blocks {
+                match self.vm.block_store().get_block_transactions(block_hash)? {
+                    Some(transactions) => removed_commitments.extend(transactions.commitments().copied()),
+                    None => bail!("Failed to remove block {height}: missing transactions"),
+                }
+            }
+        }
+
+        // Remove the blocks from storage.
+        self.vm.block_store().remove_last_n(n)?;
+        // Invalidate any cached entries for the removed blocks.
+        self.cache.lock().invalidate_from(new_height + 1);
+        // Remove the records of the removed blocks from the owner index.
+        self.prune_owner_index(&removed_commitments);
+
+        // Update the current block.
+        let block = match self.vm.block_store().get_block_hash(new_height)? {
+            Some(block_hash) => self.vm.block_store().get_block(&block_hash)?,
+            None => None,
+        };
+        *self.current_block.write() = block.ok_or_else(|| anyhow!("Failed to load block {new_height}"))?;
+        // Update the current epoch challenge.
+        self.current_epoch_challenge.write().clone_from(&self.get_epoch_challenge(new_height).ok());
+
+        // Notify the subscribers.
+        self.notify_blocks_removed(&removed_blocks);
+
+        #[cfg(feature = "metrics")]
+        metrics::gauge(metrics::ledger::HEIGHT, new_height);
+
+        Ok(())
+    }
+}
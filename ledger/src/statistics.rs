@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the statistics of the ledger.
+    ///
+    /// The statistics are stored with each block, in the same atomic batch.
+    pub fn statistics(&self) -> Result<LedgerStatistics> {
+        let latest_height = self.latest_height();
+        match self.vm.block_store().get_statistics(latest_height)? {
+            Some(statistics) => Ok(statistics),
+            None => bail!("Missing the ledger statistics for block {latest_height}"),
+        }
+    }
+}
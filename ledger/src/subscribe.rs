@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns a receiver of the events emitted by the ledger, from this point onwards.
+    ///
+    /// The ledger stops sending events to a subscriber once its receiver is dropped.
+    pub fn subscribe(&self) -> Receiver<LedgerEvent<N>> {
+        let (sender, receiver) = channel();
+        self.subscribers.lock().push(sender);
+        receiver
+    }
+
+    /// Registers the given view key, so that subscribers are notified of new records it owns,
+    /// and so that its records are included in the owner index.
+    pub fn register_view_key(&self, view_key: ViewKey<N>) {
+        let mut view_keys = self.view_keys.write();
+        if !view_keys.contains(&view_key) {
+            view_keys.push(view_key);
+            drop(view_keys);
+            // Index the existing records owned by the view key.
+            self.index_view_key(&view_key);
+        }
+    }
+
+    /// Unregisters the given view key.
+    pub fn unregister_view_key(&self, view_key: &ViewKey<N>) {
+        self.view_keys.write().retain(|candidate| candidate != view_key);
+    }
+
+    /// Notifies the subscribers that the given block was added to the ledger.
+    pub(crate) fn notify_block_added(&self, block: &Block<N>) {
+        let mut subscribers = self.subscribers.lock();
+        // If there are no subscribers, return early.
+        if subscribers.is_empty() {
+            return;
+        }
+
+        // Prepare the events.
+        let mut events = vec![LedgerEvent::BlockAdded { height: block.height(), block_hash: block.hash() }];
+        // Find the records in the block that are owned by the registered view keys.
+        for view_key in self.view_keys.read().iter() {
+            // Derive the address and its x-coordinate.
+            let owner = view_key.to_address();
+            let address_x_coordinate = owner.to_x_coordinate();
+            for (commitment, record) in block.records() {
+                // Skip records that are not owned by the view key.
+                if !record.is_owner_with_address_x_coordinate(view_key, &address_x_coordinate) {
+                    continue;
+                }
+                match record.decrypt(view_key) {
+                    Ok(record) => events.push(LedgerEvent::RecordAdded {
+                        height: block.height(),
+                        owner,
+                        commitment: *commitment,
+                        record,
+                    }),
+                    Err(e) => warn!("Failed to decrypt the record: {e}"),
+                }
+            }
+        }
+
+        // Send the events, and drop the subscribers that have disconnected.
+        subscribers.retain(|subscriber| events.iter().all(|event| subscriber.send(event.clone()).is_ok()));
+    }
+
+    /// Notifies the subscribers that the given blocks, as `(height, block hash)` pairs, were removed from the ledger.
+    pub(crate) fn notify_blocks_removed(&self, blocks: &[(u32, N::BlockHash)]) {
+        // Prepare the events.
+        let events: Vec<_> = blocks
+            .iter()
+            .map(|(height, block_hash)| LedgerEvent::BlockRemoved { height: *height, block_hash: *block_hash })
+            .collect();
+        // Send the events, and drop the subscribers that have disconnected.
+        self.subscribers.lock().retain(|subscriber| events.iter().all(|event| subscriber.send(event.clone()).is_ok()));
+    }
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the total supply in microcredits after the block at the given height.
+    pub fn total_supply_at(&self, height: u32) -> Result<u64> {
+        Ok(self.get_supply_change(height)?.total_supply())
+    }
+
+    /// Returns the total supply in microcredits after the latest block.
+    pub fn latest_total_supply(&self) -> Result<u64> {
+        self.total_supply_at(self.latest_height())
+    }
+
+    /// Returns the microcredits minted and burned in the block at the given height.
+    ///
+    /// The supply changes are stored with each block, in the same atomic batch.
+    pub fn get_supply_change(&self, height: u32) -> Result<SupplyChange> {
+        match self.vm.block_store().get_supply_change(height)? {
+            Some(change) => Ok(change),
+            None => bail!("Missing the supply change for block {height}"),
+        }
+    }
+}
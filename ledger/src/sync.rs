@@ -0,0 +1,38 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Scans the blocks after the last scanned block of the given sync state, up to the latest block,
+    /// and returns the number of blocks scanned.
+    ///
+    /// If the last scanned block is no longer in the ledger (i.e. the chain was reorganized),
+    /// an error is returned, and the sync state must be reverted with `SyncState::revert_to` first.
+    pub fn sync(&self, view_key: &ViewKey<N>, state: &mut SyncState<N>) -> Result<u32> {
+        // Ensure the last scanned block is in the ledger.
+        if let Some((height, hash)) = state.last_height().zip(state.last_hash()) {
+            ensure!(height <= self.latest_height(), "Block {height} of the sync state does not exist in the ledger");
+            ensure!(self.get_hash(height)? == hash, "Block {height} of the sync state does not match the ledger");
+        }
+
+        // Scan the remaining blocks, in order.
+        let start = state.next_height();
+        let end = self.latest_height().saturating_add(1);
+        for height in start..end {
+            state.scan_block(view_key, &self.get_block(height)?)?;
+        }
+        Ok(end.saturating_sub(start))
+    }
+}
@@ -14,12 +14,18 @@
 
 use crate::{
     test_helpers::{CurrentLedger, CurrentNetwork},
+    BlockRange,
+    Checkpoint,
+    DryRunOutcome,
+    LedgerEvent,
     RecordsFilter,
+    TransactionBuilder,
 };
 use console::{
-    account::{Address, PrivateKey},
+    account::{Address, PrivateKey, ViewKey},
     network::prelude::*,
     program::{Entry, Identifier, Literal, Plaintext, ProgramID, Value},
+    types::Field,
 };
 use ledger_block::{ConfirmedTransaction, Rejected, Transaction};
 use ledger_store::{helpers::memory::ConsensusMemory, ConsensusStore};
@@ -64,6 +70,711 @@ fn test_load_unchecked() {
     assert_eq!(ledger.latest_block(), genesis);
 }
 
+#[test]
+fn test_checkpoint() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger, and export a checkpoint.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    let checkpoint = ledger.export_checkpoint().unwrap();
+    assert_eq!(checkpoint.height(), genesis.height());
+    assert_eq!(checkpoint.block_hash(), genesis.hash());
+
+    // Initialize the ledger from the checkpoint.
+    let ledger = CurrentLedger::load_with_checkpoint(genesis.clone(), &checkpoint, None).unwrap();
+    assert_eq!(ledger.latest_hash(), genesis.hash());
+    assert_eq!(ledger.export_checkpoint().unwrap(), checkpoint);
+
+    // Ensure a mismatching checkpoint is rejected.
+    let state_root = Field::<CurrentNetwork>::one().into();
+    let checkpoint = Checkpoint::new(genesis.previous_hash(), *genesis.header(), state_root).unwrap();
+    assert!(CurrentLedger::load_with_checkpoint(genesis, &checkpoint, None).is_err());
+}
+
+#[test]
+fn test_checkpoint_above_genesis() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the ledger, with its store.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let (ledger, store) = crate::test_helpers::sample_ledger_and_store(private_key, rng);
+    let genesis = ledger.get_block(0).unwrap();
+
+    // Advance the ledger by one block, and export a checkpoint.
+    let block = crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    let checkpoint = ledger.export_checkpoint().unwrap();
+    assert_eq!(checkpoint.height(), 1);
+    assert_eq!(checkpoint.block_hash(), block.hash());
+
+    // Advance the ledger by another block, and drop it.
+    let next_block = crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    drop(ledger);
+
+    // Load the ledger from the store, with the checkpoint.
+    let ledger = CurrentLedger::from_store_with_checkpoint(genesis.clone(), &checkpoint, store.clone()).unwrap();
+    assert_eq!(ledger.latest_height(), 2);
+    assert_eq!(ledger.latest_block(), next_block);
+    assert_eq!(ledger.get_block(1).unwrap(), block);
+
+    // Ensure the ledger continues to validate and add blocks on top of the checkpoint.
+    crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    assert_eq!(ledger.latest_height(), 3);
+    drop(ledger);
+
+    // Ensure a checkpoint from a different chain at a stored height is rejected.
+    let (other_ledger, _) = crate::test_helpers::sample_ledger_and_store(private_key, rng);
+    crate::test_helpers::add_transfer_block(&other_ledger, &private_key, rng);
+    let other_checkpoint = other_ledger.export_checkpoint().unwrap();
+    assert_eq!(other_checkpoint.height(), 1);
+    assert!(CurrentLedger::from_store_with_checkpoint(genesis, &other_checkpoint, store).is_err());
+}
+
+#[test]
+fn test_check_consistency() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Check the consistency of the whole chain.
+    let report = ledger.check_consistency(None).unwrap();
+    assert!(report.is_consistent(), "{:?}", report.issues());
+    assert_eq!(report.start_height(), 0);
+    assert_eq!(report.end_height(), 0);
+    assert_eq!(report.num_blocks(), 1);
+
+    // Check the consistency of the latest block.
+    let report = ledger.check_consistency(Some(1)).unwrap();
+    assert!(report.is_consistent(), "{:?}", report.issues());
+    assert_eq!(report.num_blocks(), 1);
+}
+
+#[test]
+fn test_concurrent_readers() {
+    // Ensure the ledger can be shared across threads.
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<CurrentLedger>();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Query the ledger from multiple threads.
+    let handles = (0..4)
+        .map(|_| {
+            let ledger = ledger.clone();
+            let genesis = genesis.clone();
+            std::thread::spawn(move || {
+                for _ in 0..10 {
+                    assert_eq!(ledger.latest_hash(), genesis.hash());
+                    assert_eq!(ledger.get_header(0).unwrap(), *genesis.header());
+                    assert!(ledger.contains_block_hash(&genesis.hash()).unwrap());
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
+
+#[test]
+fn test_subscribe() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Subscribe to the ledger, and register a view key.
+    let receiver = ledger.subscribe();
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    ledger.register_view_key(ViewKey::try_from(&other_private_key).unwrap());
+
+    // Ensure advancing the ledger emits exactly one event for the block.
+    let block = crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    match receiver.try_recv().unwrap() {
+        LedgerEvent::BlockAdded { height, block_hash } => {
+            assert_eq!(height, block.height());
+            assert_eq!(block_hash, block.hash());
+        }
+        event => panic!("Unexpected event: {event:?}"),
+    }
+    // Ensure no records were found for the view key.
+    assert!(receiver.try_recv().is_err());
+
+    // Ensure a failed advance emits no events.
+    assert!(ledger.advance_to_next_block(&block).is_err());
+    assert!(receiver.try_recv().is_err());
+
+    // Ensure removing the block emits exactly one event for the block.
+    ledger.remove_last_n(1).unwrap();
+    match receiver.try_recv().unwrap() {
+        LedgerEvent::BlockRemoved { height, block_hash } => {
+            assert_eq!(height, block.height());
+            assert_eq!(block_hash, block.hash());
+        }
+        event => panic!("Unexpected event: {event:?}"),
+    }
+    assert!(receiver.try_recv().is_err());
+    // Ensure the genesis block cannot be removed.
+    assert!(ledger.remove_last_n(1).is_err());
+    assert!(receiver.try_recv().is_err());
+
+    // Ensure dropped subscribers are removed.
+    drop(receiver);
+    crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    assert!(ledger.subscribers.lock().is_empty());
+}
+
+#[test]
+fn test_cache_after_remove() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Advance the ledger by one block, and cache its header and transactions.
+    let block = crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    assert_eq!(ledger.get_header(1).unwrap(), *block.header());
+    assert_eq!(&ledger.get_transactions(1).unwrap(), block.transactions());
+    let statistics = ledger.cache_statistics();
+    // Ensure the entries are served from the cache.
+    assert_eq!(ledger.get_header(1).unwrap(), *block.header());
+    assert_eq!(ledger.cache_statistics().hits, statistics.hits + 1);
+
+    // Remove the block, and ensure it is no longer served.
+    ledger.remove_last_n(1).unwrap();
+    assert!(ledger.get_header(1).is_err());
+    assert!(ledger.get_transactions(1).is_err());
+
+    // Add a different block at the same height, and ensure its entries miss the cache.
+    let next_block = crate::test_helpers::add_transfer_block(&ledger, &private_key, rng);
+    assert_ne!(next_block.hash(), block.hash());
+    let statistics = ledger.cache_statistics();
+    assert_eq!(ledger.get_header(1).unwrap(), *next_block.header());
+    assert_eq!(&ledger.get_transactions(1).unwrap(), next_block.transactions());
+    assert_eq!(*ledger.get_block_bytes(1).unwrap(), next_block.to_bytes_le().unwrap());
+    assert_eq!(ledger.cache_statistics().hits, statistics.hits);
+    assert_eq!(ledger.cache_statistics().misses, statistics.misses + 3);
+}
+
+#[test]
+fn test_statistics() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Check the statistics.
+    let statistics = ledger.statistics().unwrap();
+    assert_eq!(statistics.latest_height(), 0);
+    assert_eq!(statistics.num_blocks(), 1);
+    assert_eq!(statistics.num_transactions(), genesis.transactions().len() as u64);
+    assert_eq!(statistics.num_transitions(), genesis.transitions().count() as u64);
+    assert_eq!(statistics.num_commitments(), genesis.commitments().count() as u64);
+    assert_eq!(statistics.num_serial_numbers(), genesis.serial_numbers().count() as u64);
+    assert_eq!(statistics.num_bytes(), genesis.to_bytes_le().unwrap().len() as u64);
+
+    // Ensure the statistics are stored with the block.
+    assert_eq!(ledger.vm.block_store().get_statistics(0).unwrap(), Some(statistics));
+    assert_eq!(ledger.vm.block_store().get_statistics(1).unwrap(), None);
+
+    // Ensure the statistics are built on those of the previous block.
+    let mut next_statistics = statistics;
+    next_statistics.add_block(&genesis).unwrap();
+    assert_eq!(next_statistics.num_blocks(), 2);
+    assert_eq!(next_statistics.num_transactions(), 2 * genesis.transactions().len() as u64);
+}
+
+#[test]
+fn test_total_supply() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Check the total supply.
+    assert_eq!(ledger.latest_total_supply().unwrap(), CurrentNetwork::STARTING_SUPPLY);
+    assert_eq!(ledger.total_supply_at(0).unwrap(), CurrentNetwork::STARTING_SUPPLY);
+    assert!(ledger.total_supply_at(1).is_err());
+
+    // Check the supply change of the genesis block.
+    let change = ledger.get_supply_change(0).unwrap();
+    assert_eq!(change.height(), 0);
+    assert_eq!(change.minted(), CurrentNetwork::STARTING_SUPPLY);
+    assert_eq!(change.burned(), 0);
+
+    // Ensure the supply change is stored with the block.
+    assert_eq!(ledger.vm.block_store().get_supply_change(0).unwrap(), Some(change));
+
+    // Ensure a block that does not follow the latest block is rejected.
+    assert!(change.next(&genesis).is_err());
+}
+
+#[test]
+fn test_owner_index() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Sample an account.
+    let recipient_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let view_key = ViewKey::try_from(&recipient_private_key).unwrap();
+    let address = Address::try_from(&recipient_private_key).unwrap();
+
+    // Ensure the owner index is disabled by default.
+    assert!(!ledger.is_owner_index_enabled());
+    assert!(ledger.get_commitments_for_address(&address).unwrap().is_empty());
+
+    // Enable the owner index, and register the view key.
+    ledger.enable_owner_index();
+    ledger.register_view_key(view_key);
+    assert!(ledger.is_owner_index_enabled());
+    assert!(ledger.get_commitments_for_address(&address).unwrap().is_empty());
+
+    // Add a block with a private record for the account.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1000000u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute(&private_key, ("credits.aleo", "transfer_public_to_private"), inputs.into_iter(), None, 0, None, rng)
+        .unwrap();
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the record is indexed for the account.
+    let expected: Vec<_> =
+        block.records().filter(|(_, record)| record.is_owner(&view_key)).map(|(commitment, _)| *commitment).collect();
+    assert_eq!(expected.len(), 1);
+    assert_eq!(ledger.get_commitments_for_address(&address).unwrap(), expected);
+
+    // Remove the block, and ensure the record is no longer indexed.
+    ledger.remove_last_n(1).unwrap();
+    assert!(ledger.get_commitments_for_address(&address).unwrap().is_empty());
+
+    // Disable the owner index.
+    ledger.disable_owner_index();
+    assert!(!ledger.is_owner_index_enabled());
+    assert!(ledger.get_commitments_for_address(&address).unwrap().is_empty());
+}
+
+#[test]
+fn test_locate() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Ensure the commitments in the genesis block are located.
+    for transaction in genesis.transactions().iter() {
+        for transition in transaction.transitions() {
+            for commitment in transition.commitments() {
+                let location = ledger.locate_commitment(commitment).unwrap().unwrap();
+                assert_eq!(location.height(), 0);
+                assert_eq!(location.block_hash(), genesis.hash());
+                assert_eq!(location.transaction_id(), transaction.id());
+                assert_eq!(location.transition_id(), *transition.id());
+            }
+        }
+    }
+
+    // Ensure a random commitment and serial number are not located.
+    assert!(ledger.locate_commitment(&Field::rand(rng)).unwrap().is_none());
+    assert!(ledger.locate_serial_number(&Field::rand(rng)).unwrap().is_none());
+}
+
+#[test]
+fn test_balance() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Sample a view key.
+    let view_key = ViewKey::try_from(&PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+
+    // Ensure an account without records has no balance.
+    assert_eq!(ledger.get_balance(&view_key, 0).unwrap(), 0);
+    assert_eq!(ledger.latest_balance(&view_key).unwrap(), 0);
+    // Ensure the balance cannot be computed for a height beyond the latest height.
+    assert!(ledger.get_balance(&view_key, 1).is_err());
+}
+
+#[test]
+fn test_get_output_records_paged() {
+    let rng = &mut TestRng::default();
+
+    // Sample the test environment.
+    let crate::test_helpers::TestEnv { ledger, view_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Retrieve all of the records of the genesis account.
+    let expected = ledger.find_records(&view_key, RecordsFilter::Unspent).unwrap().collect::<Vec<_>>();
+    assert!(expected.len() > 1);
+
+    // Retrieve the records one page at a time.
+    let mut records = Vec::new();
+    let mut page = Some(BlockRange::new(0, ledger.latest_height() + 1).unwrap());
+    while let Some(range) = page {
+        let (page_records, next) =
+            ledger.get_output_records_paged(&view_key, RecordsFilter::Unspent, range, 1).unwrap().into_parts();
+        assert!(page_records.len() <= 1);
+        records.extend(page_records);
+        page = next;
+    }
+    assert_eq!(records, expected);
+
+    // Ensure a single page returns every record when the limit allows it.
+    let range = BlockRange::new(0, ledger.latest_height() + 1).unwrap();
+    let page = ledger.get_output_records_paged(&view_key, RecordsFilter::Unspent, range, expected.len() + 1).unwrap();
+    assert_eq!(page.records(), &expected[..]);
+    assert!(page.next().is_none());
+
+    // Ensure a zero limit and a range beyond the latest height are rejected.
+    assert!(ledger.get_output_records_paged(&view_key, RecordsFilter::Unspent, range, 0).is_err());
+    let range = BlockRange::new(0, ledger.latest_height() + 2).unwrap();
+    assert!(ledger.get_output_records_paged(&view_key, RecordsFilter::Unspent, range, 1).is_err());
+}
+
+#[test]
+fn test_transaction_builder_selects_records() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Sample an account without records.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Ensure a requested record cannot be selected for an account without records.
+    let builder = TransactionBuilder::execute(private_key, "credits.aleo", "transfer_private")
+        .unwrap()
+        .credits_record(1)
+        .input(Value::from_str(&address.to_string()).unwrap())
+        .unwrap()
+        .input(Value::from_str("1u64").unwrap())
+        .unwrap();
+    let error = builder.build(&ledger, None, rng).unwrap_err();
+    assert!(error.to_string().contains("no unspent record"));
+
+    // Ensure a deployment does not take inputs.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program dummy_program.aleo;
+function foo:
+    input r0 as u8.private;
+    async foo r0 into r1;
+    output r1 as dummy_program.aleo/foo.future;
+finalize foo:
+    input r0 as u8.public;
+    add r0 r0 into r1;",
+    )
+    .unwrap();
+    let builder = TransactionBuilder::deploy(private_key, program).input(Value::from_str("1u8").unwrap()).unwrap();
+    let error = builder.build(&ledger, None, rng).unwrap_err();
+    assert!(error.to_string().contains("does not take inputs"));
+}
+
+#[test]
+fn test_estimate_fee() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Sample an account.
+    let private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let address = Address::try_from(&private_key).unwrap();
+
+    // Estimate the fee for an execution, before proving it.
+    let inputs = [Value::from_str(&address.to_string()).unwrap(), Value::from_str("1u64").unwrap()];
+    let estimate = ledger
+        .estimate_fee_for_execution(&private_key, "credits.aleo", "transfer_public_to_private", inputs.into_iter(), rng)
+        .unwrap();
+
+    // Ensure the estimate matches the fee of the same execution in the genesis block.
+    for transaction in genesis.transactions().iter() {
+        let fee = ledger.estimate_fee(transaction.transaction()).unwrap();
+        assert!(fee > 0);
+        assert_eq!(estimate, fee);
+    }
+}
+
+#[test]
+fn test_check_transactions_basic() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Ensure an empty batch is valid.
+    ledger.check_transactions_basic(&[], rng).unwrap();
+
+    // Ensure a batch of transactions that already exist in the ledger is invalid.
+    let transactions =
+        genesis.transactions().iter().map(|transaction| (transaction.transaction(), None)).collect::<Vec<_>>();
+    let error = ledger.check_transactions_basic(&transactions, rng).unwrap_err();
+    assert!(error.to_string().contains("already exists in the ledger"));
+}
+
+#[test]
+fn test_program_registry() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Ensure `credits.aleo` is resolved, without being deployed.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    assert!(ledger.contains_program(&credits).unwrap());
+    assert!(!ledger.contains_program_id(&credits).unwrap());
+    assert_eq!(ledger.get_program(credits).unwrap(), Program::credits().unwrap());
+
+    // Ensure an unknown program is not resolved.
+    let unknown = ProgramID::from_str("unknown.aleo").unwrap();
+    assert!(!ledger.contains_program(&unknown).unwrap());
+    assert!(ledger.get_program(unknown).is_err());
+
+    // Ensure the deployed programs are empty.
+    assert_eq!(ledger.program_ids().count(), 0);
+    assert_eq!(ledger.programs().count(), 0);
+}
+
+#[test]
+fn test_get_mapping_value() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Ensure the mappings of `credits.aleo` are initialized.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let committee = Identifier::from_str("committee").unwrap();
+    assert!(ledger.get_mapping_names(&credits).unwrap().contains(&committee));
+    assert!(ledger.get_mapping_names(&ProgramID::from_str("unknown.aleo").unwrap()).is_err());
+
+    // Ensure each committee member is in the `committee` mapping.
+    let entries = ledger.get_mapping(credits, committee).unwrap();
+    assert_eq!(entries.len(), ledger.latest_committee().unwrap().num_members());
+    for (key, value) in entries {
+        assert_eq!(ledger.get_mapping_value(credits, committee, &key).unwrap(), Some(value));
+    }
+
+    // Ensure a missing key has no value.
+    let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+    let key = Plaintext::from(Literal::Address(address));
+    assert_eq!(ledger.get_mapping_value(credits, committee, &key).unwrap(), None);
+}
+
+#[test]
+fn test_get_mapping_value_at() {
+    // Initialize the test environment.
+    let crate::test_helpers::MultiBlockTestEnv { ledger, accounts, .. } =
+        crate::test_helpers::sample_multi_block_test_env(7, 3);
+    let latest_height = ledger.latest_height();
+
+    // Prepare the `credits.aleo/account` mapping.
+    let credits = ProgramID::from_str("credits.aleo").unwrap();
+    let account = Identifier::from_str("account").unwrap();
+
+    for private_key in &accounts {
+        let key = Plaintext::from(Literal::Address(Address::try_from(private_key).unwrap()));
+        // Ensure the account had no public balance before it was funded.
+        assert_eq!(ledger.get_mapping_value_at(credits, account, &key, 0).unwrap(), None);
+        // Ensure the value as of the latest height matches the current value.
+        assert_eq!(
+            ledger.get_mapping_value_at(credits, account, &key, latest_height).unwrap(),
+            ledger.get_mapping_value(credits, account, &key).unwrap()
+        );
+        // Ensure a height beyond the latest height is rejected.
+        assert!(ledger.get_mapping_value_at(credits, account, &key, latest_height + 1).is_err());
+    }
+}
+
+#[test]
+fn test_get_block_chunks() {
+    // Initialize the test environment.
+    let crate::test_helpers::MultiBlockTestEnv { ledger, .. } = crate::test_helpers::sample_multi_block_test_env(7, 3);
+    let latest_height = ledger.latest_height();
+
+    // Ensure each block is in its own chunk when the blocks exceed the chunk size.
+    let chunks = ledger.get_block_chunks(0, 1).unwrap();
+    assert_eq!(chunks.len(), latest_height as usize + 1);
+    for (height, chunk) in (0u32..).zip(&chunks) {
+        assert_eq!(chunk.start_height(), height);
+        assert_eq!(chunk.to_blocks::<CurrentNetwork>().unwrap(), vec![ledger.get_block(height).unwrap()]);
+    }
+
+    // Ensure all blocks fit in a single chunk when the chunk size is unbounded.
+    let chunks = ledger.get_block_chunks(1, usize::MAX).unwrap();
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].start_height(), 1);
+    assert_eq!(chunks[0].end_height(), latest_height + 1);
+    assert_eq!(chunks[0].to_blocks::<CurrentNetwork>().unwrap(), ledger.get_blocks(1..latest_height + 1).unwrap());
+
+    // Ensure the serialized blocks match the blocks.
+    for height in 0..=latest_height {
+        assert_eq!(*ledger.get_block_bytes(height).unwrap(), ledger.get_block(height).unwrap().to_bytes_le().unwrap());
+    }
+
+    // Ensure a start height beyond the latest height is rejected.
+    assert!(ledger.get_block_chunks(latest_height + 1, usize::MAX).is_err());
+}
+
+#[test]
+fn test_dry_run() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Ensure the transactions in the genesis block would be aborted, as their inputs are already spent.
+    let transactions = genesis.transactions().iter().map(|tx| tx.transaction().clone()).collect::<Vec<_>>();
+    let outcomes = ledger.dry_run_transactions(&transactions).unwrap();
+    assert_eq!(outcomes.len(), transactions.len());
+    for ((transaction_id, outcome), transaction) in outcomes.into_iter().zip_eq(&transactions) {
+        assert_eq!(transaction_id, transaction.id());
+        assert_eq!(outcome, DryRunOutcome::Aborted);
+        assert!(!outcome.is_included());
+    }
+    assert_eq!(ledger.dry_run(&transactions[0]).unwrap(), DryRunOutcome::Aborted);
+
+    // Ensure the dry run does not modify the ledger.
+    assert_eq!(ledger.latest_height(), 0);
+    assert_eq!(ledger.latest_block(), genesis);
+}
+
+#[test]
+fn test_add_program_with_imports() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Initialize a program that imports a deployed program, and a program that imports an unknown program.
+    let program = |source: &str| Program::<CurrentNetwork>::from_str(source).unwrap();
+    let known = program(
+        r"
+import credits.aleo;
+
+program known.aleo;
+
+function foo:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+    );
+    let unknown = program(
+        r"
+import unknown_import.aleo;
+
+program unknown.aleo;
+
+function foo:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+    );
+
+    // Ensure the imports are resolved from the ledger.
+    let mut process = ledger.vm.process().read().clone();
+    ledger.add_program_with_imports(&mut process, &known).unwrap();
+    assert!(process.contains_program(known.id()));
+    let error = ledger.add_program_with_imports(&mut process, &unknown).unwrap_err();
+    assert!(error.to_string().contains("Missing program for ID unknown_import.aleo"));
+    assert!(!process.contains_program(unknown.id()));
+}
+
+#[test]
+fn test_replay() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Replay the genesis block, collecting the transitions.
+    let mut transition_ids = vec![];
+    let num_blocks = ledger
+        .replay(0..1, |block, transaction, transition| {
+            assert_eq!(block.hash(), genesis.hash());
+            assert!(transaction.transaction().contains_transition(transition.id()));
+            transition_ids.push(*transition.id());
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(num_blocks, 1);
+
+    // Ensure every transition was observed, in order.
+    let expected = genesis.transactions().iter().flat_map(|tx| tx.transaction().transition_ids().copied());
+    assert_eq!(transition_ids, expected.collect::<Vec<_>>());
+
+    // Ensure an empty range replays nothing, and a range beyond the latest block fails.
+    assert_eq!(ledger.replay(1..1, |_, _, _| Ok(())).unwrap(), 0);
+    assert!(ledger.replay(0..2, |_, _, _| Ok(())).is_err());
+
+    // Ensure an error from the observer stops the replay.
+    let error = ledger.replay(0..1, |_, _, _| bail!("Stop")).unwrap_err();
+    assert_eq!(error.to_string(), "Stop");
+}
+
+#[test]
+fn test_membership_filters() {
+    let rng = &mut TestRng::default();
+
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Ensure the genesis serial numbers, tags, and commitments are found.
+    for serial_number in genesis.serial_numbers() {
+        assert!(ledger.contains_serial_number(serial_number).unwrap());
+    }
+    for tag in genesis.tags() {
+        assert!(ledger.contains_tag(tag).unwrap());
+    }
+    for commitment in genesis.commitments() {
+        assert!(ledger.contains_commitment(commitment).unwrap());
+    }
+
+    // Ensure random serial numbers, tags, and commitments are not found.
+    for _ in 0..100 {
+        let field = Field::<CurrentNetwork>::rand(rng);
+        assert!(!ledger.contains_serial_number(&field).unwrap());
+        assert!(!ledger.contains_tag(&field).unwrap());
+        assert!(!ledger.contains_commitment(&field).unwrap());
+    }
+}
+
 #[test]
 fn test_state_path() {
     let rng = &mut TestRng::default();
@@ -325,12 +1036,10 @@ finalize foo:
         assert_eq!(**amount, 100)
     }
     // Ensure that we can't produce a transaction with a record that has insufficient balance to pay for fees.
-    assert!(
-        ledger
-            .vm
-            .execute(&private_key, ("dummy.aleo", "foo"), inputs.clone(), Some(insufficient_record), 0, None, rng)
-            .is_err()
-    );
+    assert!(ledger
+        .vm
+        .execute(&private_key, ("dummy.aleo", "foo"), inputs.clone(), Some(insufficient_record), 0, None, rng)
+        .is_err());
 
     let sufficient_record = records[1].clone();
     // Execute with enough fees.
@@ -637,6 +1346,74 @@ fn test_aborted_transaction_indexing() {
     ledger.advance_to_next_block(&block).unwrap();
 }
 
+#[test]
+fn test_transaction_expiration() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, address, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct a transfer that expires at the next block.
+    let next_height = ledger.latest_height() + 1;
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("1u64").unwrap()];
+    let transaction = ledger
+        .vm
+        .execute_with_expiration(
+            &private_key,
+            ("credits.aleo", "transfer_public"),
+            inputs.iter(),
+            None,
+            0,
+            Some(next_height),
+            None,
+            rng,
+        )
+        .unwrap();
+    assert_eq!(transaction.expiration(), Some(next_height));
+    assert!(!transaction.is_expired(next_height));
+    assert!(transaction.is_expired(next_height + 1));
+
+    // Ensure the transaction round trips with its expiration height.
+    let candidate = Transaction::<CurrentNetwork>::from_bytes_le(&transaction.to_bytes_le().unwrap()).unwrap();
+    assert_eq!(candidate, transaction);
+
+    // Ensure the transaction is valid for the next block.
+    ledger.check_transaction_basic(&transaction, None, rng).unwrap();
+
+    // Construct an expiring transfer to be included after it has expired.
+    let inputs = [Value::from_str(&format!("{address}")).unwrap(), Value::from_str("2u64").unwrap()];
+    let expired_transaction = ledger
+        .vm
+        .execute_with_expiration(
+            &private_key,
+            ("credits.aleo", "transfer_public"),
+            inputs.iter(),
+            None,
+            0,
+            Some(next_height),
+            None,
+            rng,
+        )
+        .unwrap();
+
+    // Add the first transaction in the next block.
+    let block =
+        ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![transaction], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Ensure the expired transaction is rejected by the basic check.
+    assert!(ledger.check_transaction_basic(&expired_transaction, None, rng).is_err());
+
+    // Ensure the expired transaction is aborted in the next block.
+    let expired_transaction_id = expired_transaction.id();
+    let block = ledger
+        .prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![expired_transaction], rng)
+        .unwrap();
+    assert_eq!(block.aborted_transaction_ids(), &[expired_transaction_id]);
+    ledger.check_next_block(&block, rng).unwrap();
+}
+
 #[test]
 fn test_execute_duplicate_input_ids() {
     let rng = &mut TestRng::default();
@@ -828,3 +1605,144 @@ finalize foo2:
     assert!(ledger.vm.transaction_store().contains_transaction_id(&deployment_1_id).unwrap());
     assert!(ledger.vm.block_store().contains_rejected_or_aborted_transaction_id(&deployment_2_id).unwrap());
 }
+
+#[test]
+fn test_export_import() {
+    let rng = &mut TestRng::default();
+
+    // Initialize the test environment.
+    let crate::test_helpers::TestEnv { ledger, private_key, .. } = crate::test_helpers::sample_test_env(rng);
+
+    // Construct the next block.
+    let block = ledger.prepare_advance_to_next_beacon_block(&private_key, vec![], vec![], vec![], rng).unwrap();
+    ledger.check_next_block(&block, rng).unwrap();
+    ledger.advance_to_next_block(&block).unwrap();
+
+    // Export the chain.
+    let mut dump = Vec::new();
+    assert_eq!(ledger.export(&mut dump, 0..2).unwrap(), 2);
+    // Ensure blocks beyond the latest height cannot be exported.
+    assert!(ledger.export(&mut Vec::new(), 0..3).is_err());
+
+    // Initialize a new ledger from the same genesis block, and import the chain.
+    let genesis = ledger.get_block(0).unwrap();
+    let other_ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+    assert_eq!(other_ledger.import(&dump[..]).unwrap(), 1);
+    assert_eq!(other_ledger.latest_block(), block);
+    // Ensure importing the chain again is a no-op.
+    assert_eq!(other_ledger.import(&dump[..]).unwrap(), 0);
+
+    // Ensure a corrupted chain dump is rejected.
+    let mut corrupted = dump.clone();
+    let index = corrupted.len() - 40;
+    corrupted[index] ^= 1;
+    let other_ledger = CurrentLedger::load(genesis, None).unwrap();
+    assert!(other_ledger.import(&corrupted[..]).is_err());
+    assert_eq!(other_ledger.latest_height(), 0);
+}
+
+#[test]
+fn test_import_malformed_dump() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger, and export the chain.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+    let mut dump = Vec::new();
+    assert_eq!(ledger.export(&mut dump, 0..1).unwrap(), 1);
+    assert_eq!(ledger.import(&dump[..]).unwrap(), 0);
+
+    // Ensure a chain dump that ends within a frame is rejected.
+    let mut truncated = dump.clone();
+    truncated.extend_from_slice(&[1, 0]);
+    assert!(ledger.import(&truncated[..]).is_err());
+
+    // Ensure a frame exceeding the maximum block size is rejected.
+    let mut oversized = dump;
+    oversized.extend_from_slice(&1u32.to_le_bytes());
+    oversized.extend_from_slice(&u32::MAX.to_le_bytes());
+    assert!(ledger.import(&oversized[..]).is_err());
+    assert_eq!(ledger.latest_height(), 0);
+}
+
+#[test]
+fn test_add_blocks() {
+    // Initialize the test environment.
+    let crate::test_helpers::MultiBlockTestEnv { ledger, .. } = crate::test_helpers::sample_multi_block_test_env(7, 3);
+    let latest_height = ledger.latest_height();
+    let blocks = ledger.get_blocks(1..latest_height + 1).unwrap();
+
+    // Initialize a new ledger from the same genesis block.
+    let genesis = ledger.get_block(0).unwrap();
+    let other_ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Ensure an empty batch is rejected.
+    assert!(other_ledger.add_blocks(vec![]).is_err());
+    // Ensure a batch that does not extend the latest block is rejected.
+    assert!(other_ledger.add_blocks(blocks[1..].to_vec()).is_err());
+    // Ensure a batch with a gap is rejected.
+    let gapped = blocks.iter().enumerate().filter(|(index, _)| *index != 1).map(|(_, block)| block.clone()).collect();
+    assert!(other_ledger.add_blocks(gapped).is_err());
+    assert_eq!(other_ledger.latest_height(), 0);
+
+    // Add the blocks in a single batch.
+    other_ledger.add_blocks(blocks.clone()).unwrap();
+    assert_eq!(other_ledger.latest_height(), latest_height);
+    assert_eq!(other_ledger.latest_block(), ledger.latest_block());
+    assert_eq!(other_ledger.latest_state_root(), ledger.latest_state_root());
+
+    // Ensure the blocks cannot be added again.
+    assert!(other_ledger.add_blocks(blocks).is_err());
+}
+
+#[test]
+fn test_multi_block_test_env() {
+    const NUM_BLOCKS: u32 = 5;
+
+    // Initialize the test environment.
+    let crate::test_helpers::MultiBlockTestEnv { ledger, accounts, .. } =
+        crate::test_helpers::sample_multi_block_test_env(7, NUM_BLOCKS);
+    assert_eq!(ledger.latest_height(), NUM_BLOCKS);
+    assert_eq!(accounts.len(), crate::test_helpers::NUM_TEST_ACCOUNTS);
+
+    // Ensure every block after genesis contains transactions.
+    for height in 1..=NUM_BLOCKS {
+        assert!(!ledger.get_transactions(height).unwrap().is_empty());
+    }
+    // Ensure the blocks after funding created records, and spent some of them.
+    let num_records: usize = accounts
+        .iter()
+        .map(|account| ledger.find_records(&ViewKey::try_from(account).unwrap(), RecordsFilter::All).unwrap().count())
+        .sum();
+    assert!(num_records > 0);
+    assert!(ledger.get_block(3).unwrap().serial_numbers().count() > 0);
+
+    // Ensure the accounts are determined by the seed.
+    let other = crate::test_helpers::sample_multi_block_test_env(7, 1);
+    assert_eq!(accounts[..], other.accounts[..]);
+}
+
+#[test]
+fn test_read_view() {
+    // Load the genesis block.
+    let genesis = crate::test_helpers::sample_genesis_block();
+
+    // Initialize the ledger.
+    let ledger = CurrentLedger::load(genesis.clone(), None).unwrap();
+
+    // Ensure the view is pinned at the latest block.
+    let view = ledger.read_view();
+    assert_eq!(view.height(), 0);
+    assert_eq!(view.hash(), genesis.hash());
+    assert_eq!(view.latest_block().unwrap(), genesis);
+
+    // Ensure the iterators cover the blocks in the view.
+    assert_eq!(view.blocks().collect::<Result<Vec<_>, _>>().unwrap(), vec![genesis.clone()]);
+    assert_eq!(view.transactions().count(), genesis.transactions().len());
+    let transitions = view.transitions().collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(transitions.iter().collect::<Vec<_>>(), genesis.transitions().collect::<Vec<_>>());
+    assert_eq!(view.records().count(), genesis.records().count());
+
+    // Ensure blocks beyond the view are not readable.
+    assert!(matches!(view.get_block(1), Err(crate::LedgerError::HeightExceedsTip { given: 1, tip: 0 })));
+}
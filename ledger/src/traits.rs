@@ -0,0 +1,369 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+/// The read-only query surface of a ledger.
+///
+/// This trait is implemented by `Ledger`, and allows code that only reads from the ledger
+/// to be written against alternate ledger implementations (e.g. in-memory mocks for testing).
+pub trait LedgerRead<N: Network> {
+    /// The error returned when a read from the ledger fails.
+    type Error: std::error::Error + Send + Sync + 'static;
+
+    /// Returns the latest block height.
+    fn latest_height(&self) -> u32;
+
+    /// Returns the latest block hash.
+    fn latest_hash(&self) -> N::BlockHash;
+
+    /// Returns the latest state root.
+    fn latest_state_root(&self) -> N::StateRoot;
+
+    /// Returns the latest block.
+    fn latest_block(&self) -> Result<Block<N>, Self::Error> {
+        self.get_block(self.latest_height())
+    }
+
+    /// Returns the block for the given block height.
+    fn get_block(&self, height: u32) -> Result<Block<N>, Self::Error>;
+
+    /// Returns the block hash for the given block height.
+    fn get_hash(&self, height: u32) -> Result<N::BlockHash, Self::Error>;
+
+    /// Returns the block header for the given block height.
+    fn get_header(&self, height: u32) -> Result<Header<N>, Self::Error>;
+
+    /// Returns the block transactions for the given block height.
+    fn get_transactions(&self, height: u32) -> Result<Transactions<N>, Self::Error>;
+
+    /// Returns the transaction for the given transaction ID.
+    fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>, Self::Error>;
+
+    /// Returns the transition for the given transition ID.
+    fn get_transition(&self, transition_id: N::TransitionID) -> Result<Transition<N>, Self::Error>;
+
+    /// Returns the program for the given program ID.
+    fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>, Self::Error>;
+
+    /// Returns the state path for the given commitment.
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>, Self::Error>;
+
+    /// Returns `true` if the given block height exists.
+    fn contains_block_height(&self, height: u32) -> Result<bool, Self::Error> {
+        Ok(height <= self.latest_height())
+    }
+
+    /// Returns `true` if the given block hash exists.
+    fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the given transaction ID exists.
+    fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the given transition ID exists.
+    fn contains_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the given serial number exists.
+    fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the given tag exists.
+    fn contains_tag(&self, tag: &Field<N>) -> Result<bool, Self::Error>;
+
+    /// Returns `true` if the given commitment exists.
+    fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool, Self::Error>;
+
+    /// Returns an iterator over the block heights, for all blocks in the ledger.
+    fn heights(&self) -> Box<dyn '_ + Iterator<Item = u32>> {
+        Box::new(0..=self.latest_height())
+    }
+
+    /// Returns an iterator over the transaction IDs, for all transactions in the ledger.
+    fn transaction_ids(&self) -> Box<dyn '_ + Iterator<Item = N::TransactionID>>;
+
+    /// Returns an iterator over the transition IDs, for all transitions in the ledger.
+    fn transition_ids(&self) -> Box<dyn '_ + Iterator<Item = N::TransitionID>>;
+}
+
+impl<N: Network, C: ConsensusStorage<N>> LedgerRead<N> for Ledger<N, C> {
+    type Error = LedgerError;
+
+    fn latest_height(&self) -> u32 {
+        self.latest_height()
+    }
+
+    fn latest_hash(&self) -> N::BlockHash {
+        self.latest_hash()
+    }
+
+    fn latest_state_root(&self) -> N::StateRoot {
+        self.latest_state_root()
+    }
+
+    fn latest_block(&self) -> Result<Block<N>, LedgerError> {
+        Ok(self.latest_block())
+    }
+
+    fn get_block(&self, height: u32) -> Result<Block<N>, LedgerError> {
+        self.get_block(height)
+    }
+
+    fn get_hash(&self, height: u32) -> Result<N::BlockHash, LedgerError> {
+        self.get_hash(height)
+    }
+
+    fn get_header(&self, height: u32) -> Result<Header<N>, LedgerError> {
+        self.get_header(height)
+    }
+
+    fn get_transactions(&self, height: u32) -> Result<Transactions<N>, LedgerError> {
+        self.get_transactions(height)
+    }
+
+    fn get_transaction(&self, transaction_id: N::TransactionID) -> Result<Transaction<N>, LedgerError> {
+        self.get_transaction(transaction_id)
+    }
+
+    fn get_transition(&self, transition_id: N::TransitionID) -> Result<Transition<N>, LedgerError> {
+        self.get_transition(transition_id)
+    }
+
+    fn get_program(&self, program_id: ProgramID<N>) -> Result<Program<N>, LedgerError> {
+        self.get_program(program_id)
+    }
+
+    fn get_state_path_for_commitment(&self, commitment: &Field<N>) -> Result<StatePath<N>, LedgerError> {
+        self.get_state_path_for_commitment(commitment)
+    }
+
+    fn contains_block_height(&self, height: u32) -> Result<bool, LedgerError> {
+        self.contains_block_height(height).map_err(LedgerError::StorageError)
+    }
+
+    fn contains_block_hash(&self, block_hash: &N::BlockHash) -> Result<bool, LedgerError> {
+        self.contains_block_hash(block_hash).map_err(LedgerError::StorageError)
+    }
+
+    fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool, LedgerError> {
+        self.contains_transaction_id(transaction_id).map_err(LedgerError::StorageError)
+    }
+
+    fn contains_transition_id(&self, transition_id: &N::TransitionID) -> Result<bool, LedgerError> {
+        self.contains_transition_id(transition_id).map_err(LedgerError::StorageError)
+    }
+
+    fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool, LedgerError> {
+        self.contains_serial_number(serial_number).map_err(LedgerError::StorageError)
+    }
+
+    fn contains_tag(&self, tag: &Field<N>) -> Result<bool, LedgerError> {
+        self.contains_tag(tag).map_err(LedgerError::StorageError)
+    }
+
+    fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool, LedgerError> {
+        self.contains_commitment(commitment).map_err(LedgerError::StorageError)
+    }
+
+    fn heights(&self) -> Box<dyn '_ + Iterator<Item = u32>> {
+        Box::new(self.heights().map(|height| height.into_owned()))
+    }
+
+    fn transaction_ids(&self) -> Box<dyn '_ + Iterator<Item = N::TransactionID>> {
+        Box::new(self.transaction_ids().map(|transaction_id| transaction_id.into_owned()))
+    }
+
+    fn transition_ids(&self) -> Box<dyn '_ + Iterator<Item = N::TransitionID>> {
+        Box::new(self.transition_ids().map(|transition_id| transition_id.into_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_helpers::CurrentNetwork;
+
+    /// A mock ledger, holding its blocks in memory.
+    struct MockLedger {
+        blocks: Vec<Block<CurrentNetwork>>,
+    }
+
+    impl MockLedger {
+        /// Returns the block at the given height.
+        fn block(&self, height: u32) -> Result<&Block<CurrentNetwork>, LedgerError> {
+            self.blocks.get(height as usize).ok_or(LedgerError::MissingBlock { height })
+        }
+
+        /// Returns an iterator over the transactions in the ledger.
+        fn transactions(&self) -> impl '_ + Iterator<Item = &Transaction<CurrentNetwork>> {
+            self.blocks
+                .iter()
+                .flat_map(|block| block.transactions().iter().map(|transaction| transaction.transaction()))
+        }
+
+        /// Returns an iterator over the transitions in the ledger.
+        fn transitions(&self) -> impl '_ + Iterator<Item = &Transition<CurrentNetwork>> {
+            self.blocks.iter().flat_map(|block| block.transitions())
+        }
+    }
+
+    impl LedgerRead<CurrentNetwork> for MockLedger {
+        type Error = LedgerError;
+
+        fn latest_height(&self) -> u32 {
+            self.blocks[self.blocks.len() - 1].height()
+        }
+
+        fn latest_hash(&self) -> <CurrentNetwork as Network>::BlockHash {
+            self.blocks[self.blocks.len() - 1].hash()
+        }
+
+        fn latest_state_root(&self) -> <CurrentNetwork as Network>::StateRoot {
+            Default::default()
+        }
+
+        fn get_block(&self, height: u32) -> Result<Block<CurrentNetwork>, LedgerError> {
+            self.block(height).cloned()
+        }
+
+        fn get_hash(&self, height: u32) -> Result<<CurrentNetwork as Network>::BlockHash, LedgerError> {
+            self.block(height).map(|block| block.hash())
+        }
+
+        fn get_header(&self, height: u32) -> Result<Header<CurrentNetwork>, LedgerError> {
+            self.block(height).map(|block| *block.header())
+        }
+
+        fn get_transactions(&self, height: u32) -> Result<Transactions<CurrentNetwork>, LedgerError> {
+            self.block(height).map(|block| block.transactions().clone())
+        }
+
+        fn get_transaction(
+            &self,
+            transaction_id: <CurrentNetwork as Network>::TransactionID,
+        ) -> Result<Transaction<CurrentNetwork>, LedgerError> {
+            self.transactions()
+                .find(|transaction| transaction.id() == transaction_id)
+                .cloned()
+                .ok_or_else(|| LedgerError::MissingTransaction { transaction_id: transaction_id.to_string() })
+        }
+
+        fn get_transition(
+            &self,
+            transition_id: <CurrentNetwork as Network>::TransitionID,
+        ) -> Result<Transition<CurrentNetwork>, LedgerError> {
+            self.transitions()
+                .find(|transition| *transition.id() == transition_id)
+                .cloned()
+                .ok_or_else(|| LedgerError::MissingTransition { transition_id: transition_id.to_string() })
+        }
+
+        fn get_program(&self, program_id: ProgramID<CurrentNetwork>) -> Result<Program<CurrentNetwork>, LedgerError> {
+            Err(LedgerError::MissingProgram { program_id: program_id.to_string() })
+        }
+
+        fn get_state_path_for_commitment(
+            &self,
+            commitment: &Field<CurrentNetwork>,
+        ) -> Result<StatePath<CurrentNetwork>, LedgerError> {
+            Err(LedgerError::StorageError(anyhow!("The mock ledger does not support state paths for {commitment}")))
+        }
+
+        fn contains_block_hash(
+            &self,
+            block_hash: &<CurrentNetwork as Network>::BlockHash,
+        ) -> Result<bool, LedgerError> {
+            Ok(self.blocks.iter().any(|block| block.hash() == *block_hash))
+        }
+
+        fn contains_transaction_id(
+            &self,
+            transaction_id: &<CurrentNetwork as Network>::TransactionID,
+        ) -> Result<bool, LedgerError> {
+            Ok(self.transactions().any(|transaction| transaction.id() == *transaction_id))
+        }
+
+        fn contains_transition_id(
+            &self,
+            transition_id: &<CurrentNetwork as Network>::TransitionID,
+        ) -> Result<bool, LedgerError> {
+            Ok(self.transitions().any(|transition| transition.id() == transition_id))
+        }
+
+        fn contains_serial_number(&self, serial_number: &Field<CurrentNetwork>) -> Result<bool, LedgerError> {
+            Ok(self.blocks.iter().any(|block| block.serial_numbers().any(|candidate| candidate == serial_number)))
+        }
+
+        fn contains_tag(&self, tag: &Field<CurrentNetwork>) -> Result<bool, LedgerError> {
+            Ok(self.blocks.iter().any(|block| block.tags().any(|candidate| candidate == tag)))
+        }
+
+        fn contains_commitment(&self, commitment: &Field<CurrentNetwork>) -> Result<bool, LedgerError> {
+            Ok(self.blocks.iter().any(|block| block.commitments().any(|candidate| candidate == commitment)))
+        }
+
+        fn transaction_ids(&self) -> Box<dyn '_ + Iterator<Item = <CurrentNetwork as Network>::TransactionID>> {
+            Box::new(self.transactions().map(|transaction| transaction.id()))
+        }
+
+        fn transition_ids(&self) -> Box<dyn '_ + Iterator<Item = <CurrentNetwork as Network>::TransitionID>> {
+            Box::new(self.transitions().map(|transition| *transition.id()))
+        }
+    }
+
+    /// Returns the number of transactions in the ledger, using only the ledger read interface.
+    fn num_transactions(ledger: &impl LedgerRead<CurrentNetwork>) -> Result<usize> {
+        let mut num_transactions = 0;
+        for height in ledger.heights() {
+            num_transactions += ledger.get_transactions(height)?.len();
+        }
+        // Ensure the transaction IDs match the transactions in each block.
+        ensure!(ledger.transaction_ids().count() == num_transactions);
+        for transaction_id in ledger.transaction_ids() {
+            ensure!(ledger.contains_transaction_id(&transaction_id)?);
+        }
+        Ok(num_transactions)
+    }
+
+    /// Returns the number of transitions in the latest block, using only the ledger read interface.
+    fn num_latest_transitions(ledger: &impl LedgerRead<CurrentNetwork>) -> Result<usize> {
+        let transactions = ledger.get_transactions(ledger.latest_height())?;
+        let mut num_transitions = 0;
+        for transition in transactions.transitions() {
+            // Ensure each transition can be retrieved from the ledger.
+            ensure!(ledger.contains_transition_id(transition.id())?);
+            ensure!(&ledger.get_transition(*transition.id())? == transition);
+            num_transitions += 1;
+        }
+        Ok(num_transitions)
+    }
+
+    #[test]
+    fn test_mock_ledger_read() {
+        // Load the genesis block.
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let ledger = MockLedger { blocks: vec![genesis.clone()] };
+
+        // Check the default methods.
+        assert_eq!(ledger.latest_block().unwrap(), genesis);
+        assert!(ledger.contains_block_height(0).unwrap());
+        assert!(!ledger.contains_block_height(1).unwrap());
+
+        // Check code written against the trait.
+        assert_eq!(num_latest_transitions(&ledger).unwrap(), genesis.transitions().count());
+        assert_eq!(num_transactions(&ledger).unwrap(), genesis.transactions().len());
+        assert_eq!(ledger.heights().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(ledger.transition_ids().count(), genesis.transitions().count());
+        assert!(ledger.contains_block_hash(&genesis.hash()).unwrap());
+        assert!(matches!(ledger.get_block(1), Err(LedgerError::MissingBlock { height: 1 })));
+    }
+}
@@ -16,15 +16,16 @@ use crate::{
     atomic_batch_scope,
     cow_to_cloned,
     cow_to_copied,
-    helpers::{Map, MapRead},
+    helpers::{LedgerStatistics, Map, MapRead, MembershipFilters, NestedMap, NestedMapRead, SupplyChange},
     TransactionStorage,
     TransactionStore,
     TransitionStorage,
     TransitionStore,
 };
 use console::{
+    account::Address,
     network::prelude::*,
-    program::{BlockTree, HeaderLeaf, ProgramID, StatePath},
+    program::{BlockTree, HeaderLeaf, Owner, ProgramID, StatePath},
     types::Field,
 };
 use ledger_authority::Authority;
@@ -44,7 +45,14 @@ use synthesizer_program::Program;
 
 use anyhow::Result;
 use parking_lot::RwLock;
-use std::{borrow::Cow, io::Cursor, sync::Arc};
+use std::{
+    borrow::Cow,
+    io::Cursor,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
 
 #[cfg(not(feature = "serial"))]
 use rayon::prelude::*;
@@ -167,6 +175,20 @@ fn to_confirmed_transaction<N: Network>(
     }
 }
 
+/// Returns the `(address, commitment)` pairs of the records with a public owner in the given transactions.
+fn public_record_owners<N: Network>(transactions: &Transactions<N>) -> Vec<(Address<N>, Field<N>)> {
+    transactions
+        .records()
+        .filter_map(|(commitment, record)| match record.owner() {
+            Owner::Public(address) => Some((*address, *commitment)),
+            Owner::Private(..) => None,
+        })
+        .collect()
+}
+
+/// The number of blocks between the stored snapshots of the membership filters.
+const FILTERS_SNAPSHOT_INTERVAL: u32 = 256;
+
 /// A trait for block storage.
 pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     /// The mapping of `block height` to `state root`.
@@ -201,6 +223,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     type ConfirmedTransactionsMap: for<'a> Map<'a, N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     /// The rejected deployment or execution map.
     type RejectedDeploymentOrExecutionMap: for<'a> Map<'a, Field<N>, Rejected<N>>;
+    /// The mapping of `0u8` to the latest snapshot of `(block height, membership filters)`.
+    type MembershipFiltersMap: for<'a> Map<'a, u8, (u32, MembershipFilters)>;
+    /// The mapping of `block height` to the ledger statistics after the block.
+    type StatisticsMap: for<'a> Map<'a, u32, LedgerStatistics>;
+    /// The mapping of `block height` to the change in the total supply from the block.
+    type SupplyChangesMap: for<'a> Map<'a, u32, SupplyChange>;
+    /// The mapping of `address` to the commitments of the records it publicly owns.
+    type OwnerCommitmentsMap: for<'a> NestedMap<'a, Address<N>, Field<N>, ()>;
     /// The transaction storage.
     type TransactionStorage: TransactionStorage<N, TransitionStorage = Self::TransitionStorage>;
     /// The transition storage.
@@ -239,6 +269,14 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
     fn confirmed_transactions_map(&self) -> &Self::ConfirmedTransactionsMap;
     /// Returns the rejected deployment or execution map.
     fn rejected_deployment_or_execution_map(&self) -> &Self::RejectedDeploymentOrExecutionMap;
+    /// Returns the membership filters map.
+    fn membership_filters_map(&self) -> &Self::MembershipFiltersMap;
+    /// Returns the statistics map.
+    fn statistics_map(&self) -> &Self::StatisticsMap;
+    /// Returns the supply changes map.
+    fn supply_changes_map(&self) -> &Self::SupplyChangesMap;
+    /// Returns the owner commitments map.
+    fn owner_commitments_map(&self) -> &Self::OwnerCommitmentsMap;
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage>;
 
@@ -269,6 +307,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().start_atomic();
         self.confirmed_transactions_map().start_atomic();
         self.rejected_deployment_or_execution_map().start_atomic();
+        self.membership_filters_map().start_atomic();
+        self.statistics_map().start_atomic();
+        self.supply_changes_map().start_atomic();
+        self.owner_commitments_map().start_atomic();
         self.transaction_store().start_atomic();
     }
 
@@ -289,6 +331,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             || self.rejected_or_aborted_transaction_id_map().is_atomic_in_progress()
             || self.confirmed_transactions_map().is_atomic_in_progress()
             || self.rejected_deployment_or_execution_map().is_atomic_in_progress()
+            || self.membership_filters_map().is_atomic_in_progress()
+            || self.statistics_map().is_atomic_in_progress()
+            || self.supply_changes_map().is_atomic_in_progress()
+            || self.owner_commitments_map().is_atomic_in_progress()
             || self.transaction_store().is_atomic_in_progress()
     }
 
@@ -309,6 +355,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_checkpoint();
         self.confirmed_transactions_map().atomic_checkpoint();
         self.rejected_deployment_or_execution_map().atomic_checkpoint();
+        self.membership_filters_map().atomic_checkpoint();
+        self.statistics_map().atomic_checkpoint();
+        self.supply_changes_map().atomic_checkpoint();
+        self.owner_commitments_map().atomic_checkpoint();
         self.transaction_store().atomic_checkpoint();
     }
 
@@ -329,6 +379,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().clear_latest_checkpoint();
         self.confirmed_transactions_map().clear_latest_checkpoint();
         self.rejected_deployment_or_execution_map().clear_latest_checkpoint();
+        self.membership_filters_map().clear_latest_checkpoint();
+        self.statistics_map().clear_latest_checkpoint();
+        self.supply_changes_map().clear_latest_checkpoint();
+        self.owner_commitments_map().clear_latest_checkpoint();
         self.transaction_store().clear_latest_checkpoint();
     }
 
@@ -349,6 +403,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().atomic_rewind();
         self.confirmed_transactions_map().atomic_rewind();
         self.rejected_deployment_or_execution_map().atomic_rewind();
+        self.membership_filters_map().atomic_rewind();
+        self.statistics_map().atomic_rewind();
+        self.supply_changes_map().atomic_rewind();
+        self.owner_commitments_map().atomic_rewind();
         self.transaction_store().atomic_rewind();
     }
 
@@ -369,6 +427,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().abort_atomic();
         self.confirmed_transactions_map().abort_atomic();
         self.rejected_deployment_or_execution_map().abort_atomic();
+        self.membership_filters_map().abort_atomic();
+        self.statistics_map().abort_atomic();
+        self.supply_changes_map().abort_atomic();
+        self.owner_commitments_map().abort_atomic();
         self.transaction_store().abort_atomic();
     }
 
@@ -389,6 +451,10 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
         self.rejected_or_aborted_transaction_id_map().finish_atomic()?;
         self.confirmed_transactions_map().finish_atomic()?;
         self.rejected_deployment_or_execution_map().finish_atomic()?;
+        self.membership_filters_map().finish_atomic()?;
+        self.statistics_map().finish_atomic()?;
+        self.supply_changes_map().finish_atomic()?;
+        self.owner_commitments_map().finish_atomic()?;
         self.transaction_store().finish_atomic()
     }
 
@@ -418,6 +484,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             .map(|tx| tx.to_unconfirmed_transaction_id())
             .collect::<Result<Vec<_>>>()?;
 
+        // Compute the ledger statistics after the block.
+        let statistics = self.next_statistics(block)?;
+        // Compute the change in the total supply from the block.
+        let supply_change = self.next_supply_change(block)?;
+
         atomic_batch_scope!(self, {
             // Store the (block height, state root) pair.
             self.state_root_map().insert(block.height(), state_root)?;
@@ -478,6 +549,15 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().insert(&transaction)?;
             }
 
+            // Store the ledger statistics.
+            self.statistics_map().insert(block.height(), statistics)?;
+            // Store the supply change.
+            self.supply_changes_map().insert(block.height(), supply_change)?;
+            // Store the commitments of the records with a public owner.
+            for (address, commitment) in public_record_owners(block.transactions()) {
+                self.owner_commitments_map().insert(address, commitment, ())?;
+            }
+
             Ok(())
         })
     }
@@ -513,8 +593,11 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Vec::new(),
         };
 
+        // Retrieve the block transactions.
+        let transactions = self.get_block_transactions(block_hash)?;
+
         // Retrieve the rejected transaction IDs, and the deployment or execution ID.
-        let rejected_transaction_ids_and_deployment_or_execution_id = match self.get_block_transactions(block_hash)? {
+        let rejected_transaction_ids_and_deployment_or_execution_id = match &transactions {
             Some(transactions) => transactions
                 .iter()
                 .filter(|tx| tx.is_rejected())
@@ -523,6 +606,12 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
             None => Vec::new(),
         };
 
+        // Retrieve the commitments of the records with a public owner.
+        let public_record_owners = match &transactions {
+            Some(transactions) => public_record_owners(transactions),
+            None => Vec::new(),
+        };
+
         // Determine the certificate IDs to remove.
         let certificate_ids_to_remove = match self.authority_map().get_confirmed(block_hash)? {
             Some(authority) => match authority {
@@ -595,10 +684,43 @@ pub trait BlockStorage<N: Network>: 'static + Clone + Send + Sync {
                 self.transaction_store().remove(transaction_id)?;
             }
 
+            // Remove the ledger statistics.
+            self.statistics_map().remove(&block_height)?;
+            // Remove the supply change.
+            self.supply_changes_map().remove(&block_height)?;
+            // Remove the commitments of the records with a public owner.
+            for (address, commitment) in public_record_owners.iter() {
+                self.owner_commitments_map().remove_key(address, commitment)?;
+            }
+
             Ok(())
         })
     }
 
+    /// Returns the ledger statistics after the given block, which must follow the latest block.
+    fn next_statistics(&self, block: &Block<N>) -> Result<LedgerStatistics> {
+        let mut statistics = match block.height().checked_sub(1) {
+            Some(previous_height) => match self.statistics_map().get_speculative(&previous_height)? {
+                Some(statistics) => cow_to_copied!(statistics),
+                None => bail!("Missing the ledger statistics for block {previous_height}"),
+            },
+            None => LedgerStatistics::default(),
+        };
+        statistics.add_block(block)?;
+        Ok(statistics)
+    }
+
+    /// Returns the change in the total supply from the given block, which must follow the latest block.
+    fn next_supply_change(&self, block: &Block<N>) -> Result<SupplyChange> {
+        match block.height().checked_sub(1) {
+            Some(previous_height) => match self.supply_changes_map().get_speculative(&previous_height)? {
+                Some(previous) => previous.next(block),
+                None => bail!("Missing the supply change for block {previous_height}"),
+            },
+            None => SupplyChange::genesis(block),
+        }
+    }
+
     /// Returns `true` if the given transaction ID exists.
     fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> Result<bool> {
         Ok(self.transaction_store().contains_transaction_id(transaction_id)?
@@ -1000,6 +1122,10 @@ pub struct BlockStore<N: Network, B: BlockStorage<N>> {
     storage: B,
     /// The block tree.
     tree: Arc<RwLock<BlockTree<N>>>,
+    /// The Bloom filters over the serial numbers, tags, and commitments.
+    filters: Arc<RwLock<MembershipFilters>>,
+    /// The number of membership checks that were answered by the membership filters alone.
+    num_filtered_lookups: Arc<AtomicU64>,
 }
 
 impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
@@ -1026,8 +1152,97 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             Arc::new(RwLock::new(N::merkle_tree_bhp(&hashes)?))
         };
 
+        // Load the membership filters.
+        let filters = Arc::new(RwLock::new(Self::load_filters(&storage)?));
+        // Store the ledger statistics, supply changes, and owner index for the blocks stored before they were tracked.
+        Self::backfill_block_metrics(&storage)?;
+
         // Return the block store.
-        Ok(Self { storage, tree })
+        Ok(Self { storage, tree, filters, num_filtered_lookups: Default::default() })
+    }
+
+    /// Loads the membership filters from their latest snapshot, and inserts the blocks stored after it.
+    /// If there is no snapshot, the filters are built from storage, and a snapshot is stored.
+    fn load_filters(storage: &B) -> Result<MembershipFilters> {
+        // Retrieve the latest block height.
+        let latest_height = storage.id_map().keys_confirmed().max().map(|height| cow_to_copied!(height));
+
+        match storage.membership_filters_map().get_confirmed(&0u8)? {
+            Some(snapshot) => {
+                let (snapshot_height, mut filters) = cow_to_cloned!(snapshot);
+                // Insert the blocks stored after the snapshot.
+                if let Some(latest_height) = latest_height {
+                    for height in snapshot_height.saturating_add(1)..=latest_height {
+                        let transactions = match storage.get_block_hash(height)? {
+                            Some(block_hash) => storage.get_block_transactions(&block_hash)?,
+                            None => None,
+                        };
+                        match transactions {
+                            Some(transactions) => filters.insert_transactions(&transactions),
+                            None => bail!("Failed to load the membership filters: missing block {height}"),
+                        }
+                    }
+                }
+                Ok(filters)
+            }
+            None => {
+                // Build the filters from storage.
+                let transition_store = storage.transition_store();
+                let mut filters = MembershipFilters::new(
+                    transition_store.serial_numbers().count(),
+                    transition_store.tags().count(),
+                    transition_store.commitments().count(),
+                );
+                transition_store
+                    .serial_numbers()
+                    .for_each(|serial_number| filters.serial_numbers.insert(&*serial_number));
+                transition_store.tags().for_each(|tag| filters.tags.insert(&*tag));
+                transition_store.commitments().for_each(|commitment| filters.commitments.insert(&*commitment));
+                // Store a snapshot of the filters.
+                if let Some(latest_height) = latest_height {
+                    storage.membership_filters_map().insert(0u8, (latest_height, filters.clone()))?;
+                }
+                Ok(filters)
+            }
+        }
+    }
+
+    /// Stores the ledger statistics, supply changes, and owner index for the blocks that are missing them.
+    fn backfill_block_metrics(storage: &B) -> Result<()> {
+        // Retrieve the latest block height.
+        let Some(latest_height) = storage.id_map().keys_confirmed().max().map(|height| cow_to_copied!(height)) else {
+            return Ok(());
+        };
+        // Determine the first block height without statistics, or without a supply change.
+        let next_height = |height: Option<Cow<'_, u32>>| match height {
+            Some(height) => cow_to_copied!(height).saturating_add(1),
+            None => 0,
+        };
+        let statistics_height = next_height(storage.statistics_map().keys_confirmed().max());
+        let supply_height = next_height(storage.supply_changes_map().keys_confirmed().max());
+
+        for height in statistics_height.min(supply_height)..=latest_height {
+            let block = match storage.get_block_hash(height)? {
+                Some(block_hash) => storage.get_block(&block_hash)?,
+                None => None,
+            };
+            let Some(block) = block else {
+                bail!("Failed to backfill the block metrics: missing block {height}");
+            };
+            if height >= statistics_height {
+                storage.statistics_map().insert(height, storage.next_statistics(&block)?)?;
+            }
+            if height >= supply_height {
+                storage.supply_changes_map().insert(height, storage.next_supply_change(&block)?)?;
+            }
+            // Note: Blocks without statistics predate the owner index as well.
+            if height >= statistics_height {
+                for (address, commitment) in public_record_owners(block.transactions()) {
+                    storage.owner_commitments_map().insert(address, commitment, ())?;
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Stores the given block into storage.
@@ -1040,14 +1255,73 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
             bail!("Attempted to insert a block at the incorrect height into storage")
         }
-        // Insert the (state root, block height) pair.
-        self.storage.insert((*updated_tree.root()).into(), block)?;
+        // Insert the serial numbers, tags, and commitments into the membership filters.
+        // Note: This is done before the block is stored, so a filter miss is never a false negative.
+        self.filters.write().insert_transactions(block.transactions());
+
+        atomic_batch_scope!(self, {
+            // Store a snapshot of the membership filters, in the same batch as the block.
+            if block.height() % FILTERS_SNAPSHOT_INTERVAL == 0 {
+                let filters = self.filters.read().clone();
+                self.storage.membership_filters_map().insert(0u8, (block.height(), filters))?;
+            }
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)
+        })?;
         // Update the block tree.
         *tree = updated_tree;
         // Return success.
         Ok(())
     }
 
+    /// Stores the given block into storage, and runs the given operations in the same atomic batch.
+    /// The block is only committed if the operations succeed, in which case both are committed together.
+    pub fn insert_with<T>(&self, block: &Block<N>, operations: impl FnOnce() -> Result<T>) -> Result<T> {
+        // Ensure that there is no atomic batch write in progress.
+        ensure!(!self.is_atomic_in_progress(), "Cannot insert a block while another atomic batch is in progress");
+
+        // Acquire the write lock on the block tree.
+        let mut tree = self.tree.write();
+        // Prepare an updated Merkle tree containing the new block hash.
+        let updated_tree = tree.prepare_append(&[block.hash().to_bits_le()])?;
+        // Ensure the next block height is correct.
+        if block.height() != u32::try_from(updated_tree.number_of_leaves())? - 1 {
+            bail!("Attempted to insert a block at the incorrect height into storage")
+        }
+        // Insert the serial numbers, tags, and commitments into the membership filters.
+        // Note: If the batch is aborted, the filters retain these entries, which only adds false positives.
+        self.filters.write().insert_transactions(block.transactions());
+
+        // Start the atomic batch.
+        self.start_atomic();
+        // Stage the block, and run the operations.
+        let result = || -> Result<T> {
+            // Store a snapshot of the membership filters, in the same batch as the block.
+            if block.height() % FILTERS_SNAPSHOT_INTERVAL == 0 {
+                let filters = self.filters.read().clone();
+                self.storage.membership_filters_map().insert(0u8, (block.height(), filters))?;
+            }
+            // Insert the (state root, block height) pair.
+            self.storage.insert((*updated_tree.root()).into(), block)?;
+            // Run the operations.
+            operations()
+        }();
+
+        match result {
+            // Commit the atomic batch, and update the block tree.
+            Ok(output) => {
+                self.finish_atomic()?;
+                *tree = updated_tree;
+                Ok(output)
+            }
+            // Abort the atomic batch.
+            Err(error) => {
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+
     /// Removes the last 'n' blocks from storage.
     pub fn remove_last_n(&self, n: u32) -> Result<()> {
         // Ensure 'n' is non-zero.
@@ -1073,6 +1347,9 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             None => bail!("Failed to remove last '{n}' blocks: no blocks in storage"),
         };
 
+        // Determine the latest block height after the removal.
+        let latest_height = heights.start().checked_sub(1);
+
         // Fetch the block hashes to remove.
         let hashes = cfg_into_iter!(heights)
             .map(|height| match self.storage.get_block_hash(height)? {
@@ -1089,6 +1366,15 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
             for block_hash in hashes.iter().rev() {
                 self.storage.remove(block_hash)?;
             }
+            // Store a snapshot of the membership filters at the new latest block height.
+            // Note: The removed items stay in the filters, which only causes false positives.
+            match latest_height {
+                Some(height) => {
+                    let filters = self.filters.read().clone();
+                    self.storage.membership_filters_map().insert(0u8, (height, filters))?;
+                }
+                None => self.storage.membership_filters_map().remove(&0u8)?,
+            }
             Ok(())
         })?;
 
@@ -1261,6 +1547,21 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.get_block(block_hash)
     }
 
+    /// Returns the ledger statistics after the block at the given height.
+    pub fn get_statistics(&self, height: u32) -> Result<Option<LedgerStatistics>> {
+        Ok(self.storage.statistics_map().get_confirmed(&height)?.map(|statistics| cow_to_copied!(statistics)))
+    }
+
+    /// Returns the change in the total supply from the block at the given height.
+    pub fn get_supply_change(&self, height: u32) -> Result<Option<SupplyChange>> {
+        Ok(self.storage.supply_changes_map().get_confirmed(&height)?.map(|change| cow_to_copied!(change)))
+    }
+
+    /// Returns the commitments of the records publicly owned by the given address.
+    pub fn get_commitments_for_address(&self, address: &Address<N>) -> Result<Vec<Field<N>>> {
+        Ok(self.storage.owner_commitments_map().get_map_confirmed(address)?.into_iter().map(|(c, _)| c).collect())
+    }
+
     /// Returns the program for the given `program ID`.
     pub fn get_program(&self, program_id: &ProgramID<N>) -> Result<Option<Program<N>>> {
         self.storage.transaction_store().get_program(program_id)
@@ -1308,6 +1609,42 @@ impl<N: Network, B: BlockStorage<N>> BlockStore<N, B> {
         self.storage.certificate_map().contains_key_confirmed(certificate_id)
     }
 
+    /// Returns `true` if the given serial number exists.
+    pub fn contains_serial_number(&self, serial_number: &Field<N>) -> Result<bool> {
+        // If the serial number is not in the filter, it does not exist.
+        if !self.filters.read().contains_serial_number(serial_number) {
+            self.num_filtered_lookups.fetch_add(1, Ordering::Relaxed);
+            return Ok(false);
+        }
+        self.transition_store().contains_serial_number(serial_number)
+    }
+
+    /// Returns `true` if the given tag exists.
+    pub fn contains_tag(&self, tag: &Field<N>) -> Result<bool> {
+        // If the tag is not in the filter, it does not exist.
+        if !self.filters.read().contains_tag(tag) {
+            self.num_filtered_lookups.fetch_add(1, Ordering::Relaxed);
+            return Ok(false);
+        }
+        self.transition_store().contains_tag(tag)
+    }
+
+    /// Returns `true` if the given commitment exists.
+    pub fn contains_commitment(&self, commitment: &Field<N>) -> Result<bool> {
+        // If the commitment is not in the filter, it does not exist.
+        if !self.filters.read().contains_commitment(commitment) {
+            self.num_filtered_lookups.fetch_add(1, Ordering::Relaxed);
+            return Ok(false);
+        }
+        self.transition_store().contains_commitment(commitment)
+    }
+
+    /// Returns the number of membership checks that were answered by the membership filters alone,
+    /// without querying the storage maps.
+    pub fn num_filtered_lookups(&self) -> u64 {
+        self.num_filtered_lookups.load(Ordering::Relaxed)
+    }
+
     /// Returns `true` if the given puzzle commitment exists.
     pub fn contains_puzzle_commitment(&self, puzzle_commitment: &PuzzleCommitment<N>) -> Result<bool> {
         self.storage.puzzle_commitments_map().contains_key_confirmed(puzzle_commitment)
@@ -1373,6 +1710,29 @@ mod tests {
         assert_eq!(None, candidate);
     }
 
+    #[test]
+    fn test_insert_with() {
+        // Load the genesis block.
+        let block = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        let block_hash = block.hash();
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        let state_root = block_store.current_state_root();
+
+        // Ensure the block is not stored if the operations fail.
+        assert!(block_store.insert_with(&block, || -> Result<()> { bail!("Failed") }).is_err());
+        assert_eq!(None, block_store.get_block(&block_hash).unwrap());
+        assert_eq!(state_root, block_store.current_state_root());
+        assert!(!block_store.is_atomic_in_progress());
+
+        // Ensure the block is stored if the operations succeed.
+        assert_eq!(block_store.insert_with(&block, || Ok(1u8)).unwrap(), 1);
+        assert_eq!(Some(block), block_store.get_block(&block_hash).unwrap());
+        assert_ne!(state_root, block_store.current_state_root());
+        assert!(!block_store.is_atomic_in_progress());
+    }
+
     #[test]
     fn test_find_block_hash() {
         let rng = &mut TestRng::default();
@@ -1414,6 +1774,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_membership_filters() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        assert!(block.commitments().count() > 0, "This test must be run with at least one commitment.");
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        for commitment in block.commitments() {
+            assert!(!block_store.contains_commitment(commitment).unwrap());
+        }
+
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the serial numbers, tags, and commitments are found.
+        for serial_number in block.serial_numbers() {
+            assert!(block_store.contains_serial_number(serial_number).unwrap());
+        }
+        for tag in block.tags() {
+            assert!(block_store.contains_tag(tag).unwrap());
+        }
+        for commitment in block.commitments() {
+            assert!(block_store.contains_commitment(commitment).unwrap());
+        }
+
+        // Ensure a snapshot of the filters is stored with the block.
+        let snapshot = block_store.storage.membership_filters_map().get_confirmed(&0u8).unwrap().unwrap();
+        assert_eq!(snapshot.0, block.height());
+        assert_eq!(snapshot.1, *block_store.filters.read());
+
+        // Ensure loading the filters from the snapshot preserves them.
+        let filters = BlockStore::<CurrentNetwork, BlockMemory<_>>::load_filters(&block_store.storage).unwrap();
+        assert_eq!(filters, *block_store.filters.read());
+
+        // Remove the block, and ensure the commitments are not found.
+        block_store.remove_last_n(1).unwrap();
+        for commitment in block.commitments() {
+            assert!(!block_store.contains_commitment(commitment).unwrap());
+        }
+        assert!(block_store.storage.membership_filters_map().get_confirmed(&0u8).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_owner_commitments() {
+        let rng = &mut TestRng::default();
+
+        // Sample the block.
+        let block = ledger_test_helpers::sample_genesis_block(rng);
+        let public_record_owners = public_record_owners(block.transactions());
+
+        // Initialize a new block store.
+        let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+        // Insert the block.
+        block_store.insert(&block).unwrap();
+
+        // Ensure the records with a public owner are indexed.
+        for (address, commitment) in public_record_owners.iter() {
+            assert!(block_store.get_commitments_for_address(address).unwrap().contains(commitment));
+        }
+
+        // Remove the block, and ensure the records are no longer indexed.
+        block_store.remove_last_n(1).unwrap();
+        for (address, _) in public_record_owners.iter() {
+            assert!(block_store.get_commitments_for_address(address).unwrap().is_empty());
+        }
+    }
+
     #[test]
     fn test_get_transaction() {
         let rng = &mut TestRng::default();
@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{network::prelude::*, types::Field};
+use ledger_block::Transactions;
+
+use sha2::{Digest, Sha256};
+
+/// The default false positive rate of the ledger Bloom filters.
+pub const DEFAULT_FALSE_POSITIVE_RATE: f64 = 0.001;
+/// The minimum capacity of each membership filter.
+pub const MIN_FILTER_CAPACITY: usize = 1 << 16;
+
+/// A Bloom filter, for fast negative membership checks.
+///
+/// A Bloom filter never returns a false negative: if `contains` returns `false`, the item was never inserted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BloomFilter {
+    /// The bits of the filter.
+    bits: Vec<u64>,
+    /// The number of bits in the filter.
+    num_bits: usize,
+    /// The number of hash functions.
+    num_hashes: u32,
+    /// The maximum number of items, before the false positive rate exceeds its target.
+    capacity: usize,
+    /// The number of inserted items.
+    len: usize,
+}
+
+impl BloomFilter {
+    /// Initializes a new Bloom filter for the given capacity and false positive rate.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(capacity: usize, false_positive_rate: f64) -> Self {
+        // Ensure the parameters are within bounds.
+        let capacity = capacity.max(1);
+        let false_positive_rate = false_positive_rate.clamp(f64::MIN_POSITIVE, 0.5);
+        // Compute the optimal number of bits, as `-n * ln(p) / ln(2)^2`.
+        let num_bits = (-(capacity as f64) * false_positive_rate.ln() / core::f64::consts::LN_2.powi(2)).ceil();
+        let num_bits = (num_bits as usize).max(64);
+        // Compute the optimal number of hash functions, as `(m / n) * ln(2)`.
+        let num_hashes = ((num_bits as f64 / capacity as f64) * core::f64::consts::LN_2).round();
+        let num_hashes = (num_hashes as u32).clamp(1, 32);
+
+        Self { bits: vec![0u64; (num_bits + 63) / 64], num_bits, num_hashes, capacity, len: 0 }
+    }
+
+    /// Inserts the given item into the filter.
+    pub fn insert(&mut self, item: &impl ToBytes) {
+        for index in self.indices(item) {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+        self.len = self.len.saturating_add(1);
+    }
+
+    /// Returns `false` if the given item was never inserted, and `true` if it may have been inserted.
+    pub fn contains(&self, item: &impl ToBytes) -> bool {
+        self.indices(item).all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Returns the maximum number of items, before the false positive rate exceeds its target.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of inserted items.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no items have been inserted.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the number of inserted items exceeds the capacity.
+    pub const fn is_saturated(&self) -> bool {
+        self.len > self.capacity
+    }
+
+    /// Returns the bit indices for the given item, using double hashing.
+    ///
+    /// Note: The hash must be stable across releases, as the filters are persisted in storage.
+    #[allow(clippy::cast_possible_truncation)]
+    fn indices(&self, item: &impl ToBytes) -> impl Iterator<Item = usize> {
+        // Serialize the item. Serialization of the ledger items is infallible.
+        let bytes = item.to_bytes_le().unwrap_or_default();
+        // Compute the two base hashes.
+        let digest = Sha256::digest(bytes);
+        let mut h1 = [0u8; 8];
+        let mut h2 = [0u8; 8];
+        h1.copy_from_slice(&digest[..8]);
+        h2.copy_from_slice(&digest[8..16]);
+        let (h1, h2) = (u64::from_le_bytes(h1), u64::from_le_bytes(h2) | 1);
+
+        // Note: The casts are safe, as each index is less than the number of bits.
+        let num_bits = self.num_bits as u64;
+        (0..self.num_hashes as u64).map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+}
+
+/// A Bloom filter that grows as items are inserted, so it never needs to be rebuilt.
+///
+/// Once the latest filter is saturated, a new filter with twice the capacity is appended.
+/// An item may have been inserted if any of the filters contains it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScalableBloomFilter {
+    /// The filters, in order of creation.
+    filters: Vec<BloomFilter>,
+}
+
+impl ScalableBloomFilter {
+    /// Initializes a new filter for the given initial capacity, with the default false positive rate.
+    pub fn new(capacity: usize) -> Self {
+        Self { filters: vec![BloomFilter::new(capacity, DEFAULT_FALSE_POSITIVE_RATE)] }
+    }
+
+    /// Inserts the given item into the filter.
+    pub fn insert(&mut self, item: &impl ToBytes) {
+        // If the latest filter is saturated, append a filter with twice the capacity.
+        if let Some(capacity) = self.filters.last().filter(|filter| filter.is_saturated()).map(BloomFilter::capacity) {
+            self.filters.push(BloomFilter::new(capacity.saturating_mul(2), DEFAULT_FALSE_POSITIVE_RATE));
+        }
+        if let Some(filter) = self.filters.last_mut() {
+            filter.insert(item);
+        }
+    }
+
+    /// Returns `false` if the given item was never inserted, and `true` if it may have been inserted.
+    pub fn contains(&self, item: &impl ToBytes) -> bool {
+        self.filters.iter().any(|filter| filter.contains(item))
+    }
+
+    /// Returns the number of inserted items.
+    pub fn len(&self) -> usize {
+        self.filters.iter().map(BloomFilter::len).sum()
+    }
+
+    /// Returns `true` if no items have been inserted.
+    pub fn is_empty(&self) -> bool {
+        self.filters.iter().all(BloomFilter::is_empty)
+    }
+
+    /// Returns the number of underlying filters.
+    pub fn num_filters(&self) -> usize {
+        self.filters.len()
+    }
+}
+
+/// The Bloom filters over the serial numbers, tags, and commitments in the ledger.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MembershipFilters {
+    /// The filter over the serial numbers.
+    pub serial_numbers: ScalableBloomFilter,
+    /// The filter over the tags.
+    pub tags: ScalableBloomFilter,
+    /// The filter over the commitments.
+    pub commitments: ScalableBloomFilter,
+}
+
+impl MembershipFilters {
+    /// Initializes new filters for the given number of items, leaving room for the filters to grow.
+    pub fn new(num_serial_numbers: usize, num_tags: usize, num_commitments: usize) -> Self {
+        let capacity = |num_items: usize| num_items.saturating_mul(2).max(MIN_FILTER_CAPACITY);
+        Self {
+            serial_numbers: ScalableBloomFilter::new(capacity(num_serial_numbers)),
+            tags: ScalableBloomFilter::new(capacity(num_tags)),
+            commitments: ScalableBloomFilter::new(capacity(num_commitments)),
+        }
+    }
+
+    /// Inserts the serial numbers, tags, and commitments of the given transactions into the filters.
+    pub fn insert_transactions<N: Network>(&mut self, transactions: &Transactions<N>) {
+        transactions.serial_numbers().for_each(|serial_number| self.serial_numbers.insert(serial_number));
+        transactions.tags().for_each(|tag| self.tags.insert(tag));
+        transactions.commitments().for_each(|commitment| self.commitments.insert(commitment));
+    }
+
+    /// Returns `false` if the given serial number was never inserted, and `true` if it may have been inserted.
+    pub fn contains_serial_number<N: Network>(&self, serial_number: &Field<N>) -> bool {
+        self.serial_numbers.contains(serial_number)
+    }
+
+    /// Returns `false` if the given tag was never inserted, and `true` if it may have been inserted.
+    pub fn contains_tag<N: Network>(&self, tag: &Field<N>) -> bool {
+        self.tags.contains(tag)
+    }
+
+    /// Returns `false` if the given commitment was never inserted, and `true` if it may have been inserted.
+    pub fn contains_commitment<N: Network>(&self, commitment: &Field<N>) -> bool {
+        self.commitments.contains(commitment)
+    }
+}
+
+impl Default for MembershipFilters {
+    /// Initializes new, empty filters.
+    fn default() -> Self {
+        Self::new(0, 0, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::types::Field;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    const ITERATIONS: usize = 1000;
+
+    #[test]
+    fn test_bloom_filter() {
+        let rng = &mut TestRng::default();
+
+        let mut filter = BloomFilter::new(ITERATIONS, DEFAULT_FALSE_POSITIVE_RATE);
+        assert!(filter.is_empty());
+
+        // Insert the items.
+        let items: Vec<Field<CurrentNetwork>> = (0..ITERATIONS).map(|_| Uniform::rand(rng)).collect();
+        for item in &items {
+            filter.insert(item);
+        }
+        assert_eq!(filter.len(), ITERATIONS);
+        assert!(!filter.is_saturated());
+
+        // Ensure there are no false negatives.
+        for item in &items {
+            assert!(filter.contains(item));
+        }
+
+        // Ensure the false positive rate is close to its target.
+        let num_false_positives =
+            (0..ITERATIONS).filter(|_| filter.contains(&Field::<CurrentNetwork>::rand(rng))).count();
+        assert!(num_false_positives < 10, "Found {num_false_positives} false positives");
+    }
+
+    #[test]
+    fn test_scalable_bloom_filter() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a filter that must grow to fit the items.
+        let mut filter = ScalableBloomFilter::new(ITERATIONS / 4);
+        assert!(filter.is_empty());
+
+        // Insert the items.
+        let items: Vec<Field<CurrentNetwork>> = (0..ITERATIONS).map(|_| Uniform::rand(rng)).collect();
+        for item in &items {
+            filter.insert(item);
+        }
+        assert_eq!(filter.len(), ITERATIONS);
+        assert!(filter.num_filters() > 1);
+
+        // Ensure there are no false negatives.
+        for item in &items {
+            assert!(filter.contains(item));
+        }
+
+        // Ensure the filter survives a round trip through storage.
+        let bytes = bincode::serialize(&filter).unwrap();
+        assert_eq!(filter, bincode::deserialize::<ScalableBloomFilter>(&bytes).unwrap());
+    }
+}
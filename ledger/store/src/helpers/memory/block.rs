@@ -13,13 +13,18 @@
 // limitations under the License.
 
 use crate::{
-    helpers::memory::{MemoryMap, TransactionMemory, TransitionMemory},
+    helpers::{
+        memory::{MemoryMap, NestedMemoryMap, TransactionMemory, TransitionMemory},
+        LedgerStatistics,
+        MembershipFilters,
+        SupplyChange,
+    },
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{account::Address, prelude::*, types::Field};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
@@ -57,6 +62,14 @@ pub struct BlockMemory<N: Network> {
     confirmed_transactions_map: MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: MemoryMap<Field<N>, Rejected<N>>,
+    /// The membership filters map.
+    membership_filters_map: MemoryMap<u8, (u32, MembershipFilters)>,
+    /// The statistics map.
+    statistics_map: MemoryMap<u32, LedgerStatistics>,
+    /// The supply changes map.
+    supply_changes_map: MemoryMap<u32, SupplyChange>,
+    /// The owner commitments map.
+    owner_commitments_map: NestedMemoryMap<Address<N>, Field<N>, ()>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionMemory<N>>,
 }
@@ -78,6 +91,10 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
     type RejectedOrAbortedTransactionIDMap = MemoryMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = MemoryMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     type RejectedDeploymentOrExecutionMap = MemoryMap<Field<N>, Rejected<N>>;
+    type MembershipFiltersMap = MemoryMap<u8, (u32, MembershipFilters)>;
+    type StatisticsMap = MemoryMap<u32, LedgerStatistics>;
+    type SupplyChangesMap = MemoryMap<u32, SupplyChange>;
+    type OwnerCommitmentsMap = NestedMemoryMap<Address<N>, Field<N>, ()>;
     type TransactionStorage = TransactionMemory<N>;
     type TransitionStorage = TransitionMemory<N>;
 
@@ -104,6 +121,10 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
             rejected_or_aborted_transaction_id_map: MemoryMap::default(),
             confirmed_transactions_map: MemoryMap::default(),
             rejected_deployment_or_execution_map: MemoryMap::default(),
+            membership_filters_map: MemoryMap::default(),
+            statistics_map: MemoryMap::default(),
+            supply_changes_map: MemoryMap::default(),
+            owner_commitments_map: NestedMemoryMap::default(),
             transaction_store,
         })
     }
@@ -183,6 +204,26 @@ impl<N: Network> BlockStorage<N> for BlockMemory<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the membership filters map.
+    fn membership_filters_map(&self) -> &Self::MembershipFiltersMap {
+        &self.membership_filters_map
+    }
+
+    /// Returns the statistics map.
+    fn statistics_map(&self) -> &Self::StatisticsMap {
+        &self.statistics_map
+    }
+
+    /// Returns the supply changes map.
+    fn supply_changes_map(&self) -> &Self::SupplyChangesMap {
+        &self.supply_changes_map
+    }
+
+    /// Returns the owner commitments map.
+    fn owner_commitments_map(&self) -> &Self::OwnerCommitmentsMap {
+        &self.owner_commitments_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
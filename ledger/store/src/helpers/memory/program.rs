@@ -19,6 +19,7 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    KeyValueHistoryEntry,
 };
 use console::{
     prelude::*,
@@ -37,6 +38,12 @@ pub struct FinalizeMemory<N: Network> {
     program_id_map: MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The key-value history map.
+    key_value_history_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, u32), KeyValueHistoryEntry<N>>,
+    /// The key-value history index map.
+    key_value_history_index_map: NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>,
+    /// The key-value history start map.
+    key_value_history_start_map: MemoryMap<u8, u32>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -46,6 +53,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
     type CommitteeStorage = CommitteeMemory<N>;
     type ProgramIDMap = MemoryMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type KeyValueHistoryMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, u32), KeyValueHistoryEntry<N>>;
+    type KeyValueHistoryIndexMap = NestedMemoryMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
+    type KeyValueHistoryStartMap = MemoryMap<u8, u32>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -56,6 +66,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
             committee_store,
             program_id_map: MemoryMap::default(),
             key_value_map: NestedMemoryMap::default(),
+            key_value_history_map: NestedMemoryMap::default(),
+            key_value_history_index_map: NestedMemoryMap::default(),
+            key_value_history_start_map: MemoryMap::default(),
             dev,
         })
     }
@@ -81,6 +94,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeMemory<N> {
         &self.key_value_map
     }
 
+    /// Returns the key-value history map.
+    fn key_value_history_map(&self) -> &Self::KeyValueHistoryMap {
+        &self.key_value_history_map
+    }
+
+    /// Returns the key-value history index map.
+    fn key_value_history_index_map(&self) -> &Self::KeyValueHistoryIndexMap {
+        &self.key_value_history_index_map
+    }
+
+    /// Returns the key-value history start map.
+    fn key_value_history_start_map(&self) -> &Self::KeyValueHistoryStartMap {
+        &self.key_value_history_start_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
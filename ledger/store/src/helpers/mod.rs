@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod bloom;
+pub use bloom::*;
+
 pub mod memory;
 #[cfg(feature = "rocks")]
 pub mod rocksdb;
@@ -19,6 +22,12 @@ pub mod rocksdb;
 #[cfg(test)]
 pub(crate) mod test_helpers;
 
+mod statistics;
+pub use statistics::*;
+
+mod supply;
+pub use supply::*;
+
 mod traits;
 pub use traits::*;
 
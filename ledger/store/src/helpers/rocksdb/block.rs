@@ -13,19 +13,24 @@
 // limitations under the License.
 
 use crate::{
-    helpers::rocksdb::{
-        internal::{self, DataMap, Database},
-        BlockMap,
-        MapID,
-        TransactionDB,
-        TransitionDB,
+    helpers::{
+        rocksdb::{
+            internal::{self, DataMap, Database, NestedDataMap},
+            BlockMap,
+            MapID,
+            TransactionDB,
+            TransitionDB,
+        },
+        LedgerStatistics,
+        MembershipFilters,
+        SupplyChange,
     },
     BlockStorage,
     ConfirmedTxType,
     TransactionStore,
     TransitionStore,
 };
-use console::{prelude::*, types::Field};
+use console::{account::Address, prelude::*, types::Field};
 use ledger_authority::Authority;
 use ledger_block::{Header, Ratifications, Rejected};
 use ledger_coinbase::{CoinbaseSolution, PuzzleCommitment};
@@ -63,6 +68,14 @@ pub struct BlockDB<N: Network> {
     confirmed_transactions_map: DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>,
     /// The rejected deployment or execution map.
     rejected_deployment_or_execution_map: DataMap<Field<N>, Rejected<N>>,
+    /// The membership filters map.
+    membership_filters_map: DataMap<u8, (u32, MembershipFilters)>,
+    /// The statistics map.
+    statistics_map: DataMap<u32, LedgerStatistics>,
+    /// The supply changes map.
+    supply_changes_map: DataMap<u32, SupplyChange>,
+    /// The owner commitments map.
+    owner_commitments_map: NestedDataMap<Address<N>, Field<N>, ()>,
     /// The transaction store.
     transaction_store: TransactionStore<N, TransactionDB<N>>,
 }
@@ -84,6 +97,10 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
     type RejectedOrAbortedTransactionIDMap = DataMap<N::TransactionID, N::BlockHash>;
     type ConfirmedTransactionsMap = DataMap<N::TransactionID, (N::BlockHash, ConfirmedTxType, Vec<u8>)>;
     type RejectedDeploymentOrExecutionMap = DataMap<Field<N>, Rejected<N>>;
+    type MembershipFiltersMap = DataMap<u8, (u32, MembershipFilters)>;
+    type StatisticsMap = DataMap<u32, LedgerStatistics>;
+    type SupplyChangesMap = DataMap<u32, SupplyChange>;
+    type OwnerCommitmentsMap = NestedDataMap<Address<N>, Field<N>, ()>;
     type TransactionStorage = TransactionDB<N>;
     type TransitionStorage = TransitionDB<N>;
 
@@ -110,6 +127,10 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
             rejected_or_aborted_transaction_id_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedOrAbortedTransactionID))?,
             confirmed_transactions_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::ConfirmedTransactions))?,
             rejected_deployment_or_execution_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::RejectedDeploymentOrExecution))?,
+            membership_filters_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::MembershipFilters))?,
+            statistics_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::Statistics))?,
+            supply_changes_map: internal::RocksDB::open_map(N::ID, dev, MapID::Block(BlockMap::SupplyChanges))?,
+            owner_commitments_map: internal::RocksDB::open_nested_map(N::ID, dev, MapID::Block(BlockMap::OwnerCommitments))?,
             transaction_store,
         })
     }
@@ -189,6 +210,26 @@ impl<N: Network> BlockStorage<N> for BlockDB<N> {
         &self.rejected_deployment_or_execution_map
     }
 
+    /// Returns the membership filters map.
+    fn membership_filters_map(&self) -> &Self::MembershipFiltersMap {
+        &self.membership_filters_map
+    }
+
+    /// Returns the statistics map.
+    fn statistics_map(&self) -> &Self::StatisticsMap {
+        &self.statistics_map
+    }
+
+    /// Returns the supply changes map.
+    fn supply_changes_map(&self) -> &Self::SupplyChangesMap {
+        &self.supply_changes_map
+    }
+
+    /// Returns the owner commitments map.
+    fn owner_commitments_map(&self) -> &Self::OwnerCommitmentsMap {
+        &self.owner_commitments_map
+    }
+
     /// Returns the transaction store.
     fn transaction_store(&self) -> &TransactionStore<N, Self::TransactionStorage> {
         &self.transaction_store
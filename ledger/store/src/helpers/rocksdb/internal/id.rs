@@ -82,6 +82,10 @@ pub enum BlockMap {
     RejectedOrAbortedTransactionID = DataID::BlockRejectedOrAbortedTransactionIDMap as u16,
     ConfirmedTransactions = DataID::BlockConfirmedTransactionsMap as u16,
     RejectedDeploymentOrExecution = DataID::BlockRejectedDeploymentOrExecutionMap as u16,
+    MembershipFilters = DataID::BlockMembershipFiltersMap as u16,
+    Statistics = DataID::BlockStatisticsMap as u16,
+    SupplyChanges = DataID::BlockSupplyChangesMap as u16,
+    OwnerCommitments = DataID::BlockOwnerCommitmentsMap as u16,
 }
 
 /// The RocksDB map prefix for committee-related entries.
@@ -119,6 +123,7 @@ pub enum ExecutionMap {
     ID = DataID::ExecutionIDMap as u16,
     ReverseID = DataID::ExecutionReverseIDMap as u16,
     Inclusion = DataID::ExecutionInclusionMap as u16,
+    Expiration = DataID::ExecutionExpirationMap as u16,
 }
 
 /// The RocksDB map prefix for fee-related entries.
@@ -194,6 +199,9 @@ pub enum TransitionMap {
 pub enum ProgramMap {
     ProgramID = DataID::ProgramIDMap as u16,
     KeyValueID = DataID::KeyValueMap as u16,
+    KeyValueHistory = DataID::KeyValueHistoryMap as u16,
+    KeyValueHistoryIndex = DataID::KeyValueHistoryIndexMap as u16,
+    KeyValueHistoryStart = DataID::KeyValueHistoryStartMap as u16,
 }
 
 /// The RocksDB map prefix for test-related entries.
@@ -285,6 +293,14 @@ enum DataID {
     // TODO (howardwu): For mainnet - Reorder this up above.
     BlockRejectedDeploymentOrExecutionMap,
     BFTTransmissionsMap,
+    ExecutionExpirationMap,
+    KeyValueHistoryMap,
+    KeyValueHistoryIndexMap,
+    KeyValueHistoryStartMap,
+    BlockMembershipFiltersMap,
+    BlockStatisticsMap,
+    BlockSupplyChangesMap,
+    BlockOwnerCommitmentsMap,
 
     // Testing
     #[cfg(test)]
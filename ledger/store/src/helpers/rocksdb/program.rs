@@ -19,6 +19,7 @@ use crate::{
     CommitteeStorage,
     CommitteeStore,
     FinalizeStorage,
+    KeyValueHistoryEntry,
 };
 use console::{
     prelude::*,
@@ -37,6 +38,12 @@ pub struct FinalizeDB<N: Network> {
     program_id_map: DataMap<ProgramID<N>, IndexSet<Identifier<N>>>,
     /// The key-value map.
     key_value_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>,
+    /// The key-value history map.
+    key_value_history_map: NestedDataMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, u32), KeyValueHistoryEntry<N>>,
+    /// The key-value history index map.
+    key_value_history_index_map: NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>,
+    /// The key-value history start map.
+    key_value_history_start_map: DataMap<u8, u32>,
     /// The optional development ID.
     dev: Option<u16>,
 }
@@ -46,6 +53,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
     type CommitteeStorage = CommitteeDB<N>;
     type ProgramIDMap = DataMap<ProgramID<N>, IndexSet<Identifier<N>>>;
     type KeyValueMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    type KeyValueHistoryMap = NestedDataMap<(ProgramID<N>, Identifier<N>), (Plaintext<N>, u32), KeyValueHistoryEntry<N>>;
+    type KeyValueHistoryIndexMap = NestedDataMap<(ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
+    type KeyValueHistoryStartMap = DataMap<u8, u32>;
 
     /// Initializes the finalize storage.
     fn open(dev: Option<u16>) -> Result<Self> {
@@ -56,6 +66,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_history_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueHistory))?,
+            key_value_history_index_map: rocksdb::RocksDB::open_nested_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueHistoryIndex))?,
+            key_value_history_start_map: rocksdb::RocksDB::open_map(N::ID, dev, MapID::Program(ProgramMap::KeyValueHistoryStart))?,
             dev,
         })
     }
@@ -70,6 +83,9 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
             committee_store,
             program_id_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::ProgramID))?,
             key_value_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueID))?,
+            key_value_history_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueHistory))?,
+            key_value_history_index_map: rocksdb::RocksDB::open_nested_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueHistoryIndex))?,
+            key_value_history_start_map: rocksdb::RocksDB::open_map_testing(temp_dir.clone(), dev, MapID::Program(ProgramMap::KeyValueHistoryStart))?,
             dev,
         })
     }
@@ -89,6 +105,21 @@ impl<N: Network> FinalizeStorage<N> for FinalizeDB<N> {
         &self.key_value_map
     }
 
+    /// Returns the key-value history map.
+    fn key_value_history_map(&self) -> &Self::KeyValueHistoryMap {
+        &self.key_value_history_map
+    }
+
+    /// Returns the key-value history index map.
+    fn key_value_history_index_map(&self) -> &Self::KeyValueHistoryIndexMap {
+        &self.key_value_history_index_map
+    }
+
+    /// Returns the key-value history start map.
+    fn key_value_history_start_map(&self) -> &Self::KeyValueHistoryStartMap {
+        &self.key_value_history_start_map
+    }
+
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16> {
         self.dev
@@ -0,0 +1,89 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::Block;
+
+/// The statistics of the ledger.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LedgerStatistics {
+    /// The latest block height.
+    latest_height: u32,
+    /// The number of blocks.
+    num_blocks: u64,
+    /// The number of confirmed transactions.
+    num_transactions: u64,
+    /// The number of transitions.
+    num_transitions: u64,
+    /// The number of record commitments.
+    num_commitments: u64,
+    /// The number of serial numbers.
+    num_serial_numbers: u64,
+    /// The number of bytes of the serialized blocks.
+    num_bytes: u64,
+}
+
+impl LedgerStatistics {
+    /// Updates the statistics with the given block.
+    pub fn add_block<N: Network>(&mut self, block: &Block<N>) -> Result<()> {
+        // Compute the statistics of the block.
+        let transactions = block.transactions();
+        let num_bytes = block.to_bytes_le()?.len();
+
+        // Update the statistics.
+        self.latest_height = block.height();
+        self.num_blocks = self.num_blocks.saturating_add(1);
+        self.num_transactions = self.num_transactions.saturating_add(transactions.len() as u64);
+        self.num_transitions = self.num_transitions.saturating_add(transactions.transitions().count() as u64);
+        self.num_commitments = self.num_commitments.saturating_add(transactions.commitments().count() as u64);
+        self.num_serial_numbers = self.num_serial_numbers.saturating_add(transactions.serial_numbers().count() as u64);
+        self.num_bytes = self.num_bytes.saturating_add(num_bytes as u64);
+        Ok(())
+    }
+
+    /// Returns the latest block height.
+    pub const fn latest_height(&self) -> u32 {
+        self.latest_height
+    }
+
+    /// Returns the number of blocks.
+    pub const fn num_blocks(&self) -> u64 {
+        self.num_blocks
+    }
+
+    /// Returns the number of confirmed transactions.
+    pub const fn num_transactions(&self) -> u64 {
+        self.num_transactions
+    }
+
+    /// Returns the number of transitions.
+    pub const fn num_transitions(&self) -> u64 {
+        self.num_transitions
+    }
+
+    /// Returns the number of record commitments.
+    pub const fn num_commitments(&self) -> u64 {
+        self.num_commitments
+    }
+
+    /// Returns the number of serial numbers.
+    pub const fn num_serial_numbers(&self) -> u64 {
+        self.num_serial_numbers
+    }
+
+    /// Returns an estimate of the storage size in bytes, as the total size of the serialized blocks.
+    pub const fn num_bytes(&self) -> u64 {
+        self.num_bytes
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::network::prelude::*;
+use ledger_block::{Block, Ratify, Transactions};
+
+/// The amount of microcredits burned by a call to `credits.aleo/split`.
+const SPLIT_FEE_IN_MICROCREDITS: u64 = 10_000u64;
+
+/// Returns the amount of microcredits burned by the given transactions, as the sum of their fees and splits.
+pub fn burned_supply<N: Network>(transactions: &Transactions<N>) -> Result<u64> {
+    // Initialize the burned microcredits.
+    let mut burned = 0u64;
+
+    // Iterate through the transactions to calculate the burned microcredits.
+    for confirmed in transactions.iter() {
+        // Add the fee to the burned microcredits.
+        burned = burned
+            .checked_add(*confirmed.fee_amount()?)
+            .ok_or_else(|| anyhow!("The fees overflow the burned supply of microcredits"))?;
+
+        // Iterate over the transitions in the transaction.
+        for transition in confirmed.transaction().transitions() {
+            // If the transition contains a split, add the amount to the burned microcredits.
+            if transition.is_split() {
+                // TODO (howardwu): Add a test that calls `split`, checks the output records - input records == 10_000u64.
+                burned = burned
+                    .checked_add(SPLIT_FEE_IN_MICROCREDITS)
+                    .ok_or_else(|| anyhow!("The splits overflow the burned supply of microcredits"))?;
+            }
+        }
+    }
+    Ok(burned)
+}
+
+/// The change in the total supply of microcredits from a block.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SupplyChange {
+    /// The block height.
+    height: u32,
+    /// The microcredits minted in the block, as the block reward and puzzle reward.
+    minted: u64,
+    /// The microcredits burned in the block, as the transaction fees and splits.
+    burned: u64,
+    /// The total supply in microcredits after the block.
+    total_supply: u64,
+}
+
+impl SupplyChange {
+    /// Returns the supply change of the genesis block, which sets the starting supply.
+    pub fn genesis<N: Network>(block: &Block<N>) -> Result<Self> {
+        // Ensure the block is the genesis block.
+        ensure!(block.height() == 0, "Block {} is not the genesis block", block.height());
+        Ok(Self { height: 0, minted: N::STARTING_SUPPLY, burned: 0, total_supply: N::STARTING_SUPPLY })
+    }
+
+    /// Returns the supply change of the given block, which must follow this one.
+    pub fn next<N: Network>(&self, block: &Block<N>) -> Result<Self> {
+        // Ensure the block follows this one.
+        ensure!(
+            block.height() == self.height.saturating_add(1),
+            "Block {} does not follow block {}",
+            block.height(),
+            self.height
+        );
+
+        // Compute the minted microcredits from the block reward and puzzle reward.
+        let mut minted = 0u64;
+        for ratification in block.ratifications().iter() {
+            match ratification {
+                Ratify::BlockReward(reward) | Ratify::PuzzleReward(reward) => {
+                    minted = minted
+                        .checked_add(*reward)
+                        .ok_or_else(|| anyhow!("The rewards overflow the minted supply of microcredits"))?
+                }
+                Ratify::Genesis(..) => bail!("Block {} contains a genesis ratification", block.height()),
+            }
+        }
+        // Compute the burned microcredits from the transactions.
+        let burned = burned_supply(block.transactions())?;
+        // Compute the next total supply.
+        let total_supply = self
+            .total_supply
+            .checked_add(minted)
+            .and_then(|supply| supply.checked_sub(burned))
+            .ok_or_else(|| anyhow!("Block {} has an invalid change in the total supply", block.height()))?;
+
+        Ok(Self { height: block.height(), minted, burned, total_supply })
+    }
+
+    /// Returns the block height.
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the microcredits minted in the block.
+    pub const fn minted(&self) -> u64 {
+        self.minted
+    }
+
+    /// Returns the microcredits burned in the block.
+    pub const fn burned(&self) -> u64 {
+        self.burned
+    }
+
+    /// Returns the total supply in microcredits after the block.
+    pub const fn total_supply(&self) -> u64 {
+        self.total_supply
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Map;
+use console::network::prelude::{anyhow, Deserialize, Result, Serialize};
+
+use async_trait::async_trait;
+use core::hash::Hash;
+use tokio::{sync::mpsc, task};
+
+/// The number of entries buffered ahead of the reader by an `AsyncMapIter`.
+const ITER_BUFFER_SIZE: usize = 1024;
+
+/// A trait representing map-like storage operations that may be performed asynchronously,
+/// e.g. by a networked or remote storage backend.
+///
+/// Note: The ledger stores are built on the blocking `Map` trait, and are not generic over this trait.
+/// It is intended for node components that access storage from async code (e.g. through `AsyncMapAdapter`).
+#[async_trait]
+pub trait AsyncMap<
+    K: Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+    V: Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+>: Send + Sync
+{
+    ///
+    /// Returns `true` if the given key exists in the map.
+    ///
+    async fn contains_key(&self, key: &K) -> Result<bool>;
+
+    ///
+    /// Returns the value for the given key from the map, if it exists.
+    ///
+    async fn get(&self, key: &K) -> Result<Option<V>>;
+
+    ///
+    /// Inserts the given key-value pair into the map.
+    ///
+    async fn insert(&self, key: K, value: V) -> Result<()>;
+
+    ///
+    /// Removes the key-value pair for the given key from the map.
+    ///
+    async fn remove(&self, key: &K) -> Result<()>;
+
+    ///
+    /// Returns the number of entries in the map.
+    ///
+    async fn len(&self) -> Result<usize>;
+
+    ///
+    /// Checks whether there are any entries in the map.
+    ///
+    async fn is_empty(&self) -> Result<bool> {
+        Ok(self.len().await? == 0)
+    }
+
+    ///
+    /// Returns an iterator over the key-value pairs in the map, in the order of the underlying storage.
+    /// The entries are yielded as they are read, so the map is never collected in memory.
+    ///
+    fn iter(&self) -> AsyncMapIter<K, V>;
+}
+
+/// An asynchronous iterator over the key-value pairs of an `AsyncMap`.
+pub struct AsyncMapIter<K, V> {
+    /// The receiver of the entries read from the map.
+    receiver: mpsc::Receiver<Result<(K, V)>>,
+}
+
+impl<K, V> AsyncMapIter<K, V> {
+    /// Initializes a new iterator over the entries sent to the given receiver.
+    pub const fn new(receiver: mpsc::Receiver<Result<(K, V)>>) -> Self {
+        Self { receiver }
+    }
+
+    /// Returns the next key-value pair, or `None` if the iteration is complete.
+    pub async fn next(&mut self) -> Option<Result<(K, V)>> {
+        self.receiver.recv().await
+    }
+}
+
+/// An adapter that exposes a (blocking) `Map` as an `AsyncMap`.
+///
+/// Every operation is run on the blocking thread pool of the current Tokio runtime,
+/// so that the executor threads are not blocked by storage reads and writes.
+#[derive(Clone)]
+pub struct AsyncMapAdapter<M> {
+    /// The underlying map.
+    map: M,
+}
+
+impl<M> AsyncMapAdapter<M> {
+    /// Initializes a new adapter over the given map.
+    pub const fn new(map: M) -> Self {
+        Self { map }
+    }
+
+    /// Returns the underlying map.
+    pub const fn inner(&self) -> &M {
+        &self.map
+    }
+
+    /// Runs the given operation on the underlying map, on the blocking thread pool.
+    async fn run<T: 'static + Send>(&self, operation: impl 'static + Send + FnOnce(M) -> Result<T>) -> Result<T>
+    where
+        M: 'static + Clone + Send,
+    {
+        let map = self.map.clone();
+        task::spawn_blocking(move || operation(map)).await.map_err(|e| anyhow!("Failed to run a map operation: {e}"))?
+    }
+}
+
+#[async_trait]
+impl<
+        K: 'static + Copy + Clone + PartialEq + Eq + Hash + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        V: 'static + Clone + PartialEq + Eq + Serialize + for<'de> Deserialize<'de> + Send + Sync,
+        M: 'static + for<'a> Map<'a, K, V>,
+    > AsyncMap<K, V> for AsyncMapAdapter<M>
+{
+    async fn contains_key(&self, key: &K) -> Result<bool> {
+        let key = *key;
+        self.run(move |map| map.contains_key_confirmed(&key)).await
+    }
+
+    async fn get(&self, key: &K) -> Result<Option<V>> {
+        let key = *key;
+        self.run(move |map| Ok(map.get_confirmed(&key)?.map(|value| value.into_owned()))).await
+    }
+
+    async fn insert(&self, key: K, value: V) -> Result<()> {
+        self.run(move |map| map.insert(key, value)).await
+    }
+
+    async fn remove(&self, key: &K) -> Result<()> {
+        let key = *key;
+        self.run(move |map| map.remove(&key)).await
+    }
+
+    async fn len(&self) -> Result<usize> {
+        self.run(|map| Ok(map.len_confirmed())).await
+    }
+
+    fn iter(&self) -> AsyncMapIter<K, V> {
+        let (sender, receiver) = mpsc::channel(ITER_BUFFER_SIZE);
+        let map = self.map.clone();
+        // Read the entries on the blocking thread pool, waiting whenever the buffer is full.
+        task::spawn_blocking(move || {
+            for (key, value) in map.iter_confirmed() {
+                // Stop reading if the iterator was dropped.
+                if sender.blocking_send(Ok((key.into_owned(), value.into_owned()))).is_err() {
+                    break;
+                }
+            }
+        });
+        AsyncMapIter::new(receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::{memory::MemoryMap, MapRead};
+
+    /// Runs the given future to completion on a new runtime.
+    fn block_on<F: core::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+    }
+
+    #[test]
+    fn test_async_map_adapter() {
+        block_on(async {
+            let map = AsyncMapAdapter::new(MemoryMap::<usize, String>::default());
+            assert!(map.is_empty().await.unwrap());
+
+            // Insert entries.
+            map.insert(1, "one".to_string()).await.unwrap();
+            map.insert(2, "two".to_string()).await.unwrap();
+            assert_eq!(map.len().await.unwrap(), 2);
+            assert!(map.contains_key(&1).await.unwrap());
+            assert_eq!(map.get(&2).await.unwrap(), Some("two".to_string()));
+            assert_eq!(map.get(&3).await.unwrap(), None);
+            let mut iter = map.iter();
+            assert_eq!(iter.next().await.unwrap().unwrap(), (1, "one".to_string()));
+            assert_eq!(iter.next().await.unwrap().unwrap(), (2, "two".to_string()));
+            assert!(iter.next().await.is_none());
+
+            // Ensure the writes are visible through the underlying map.
+            assert!(map.inner().contains_key_confirmed(&1).unwrap());
+
+            // Remove an entry.
+            map.remove(&1).await.unwrap();
+            assert!(!map.contains_key(&1).await.unwrap());
+            assert_eq!(map.len().await.unwrap(), 1);
+        });
+    }
+}
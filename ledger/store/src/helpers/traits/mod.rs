@@ -17,3 +17,8 @@ pub use map::*;
 
 mod nested_map;
 pub use nested_map::*;
+
+#[cfg(feature = "async")]
+mod async_map;
+#[cfg(feature = "async")]
+pub use async_map::*;
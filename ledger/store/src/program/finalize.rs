@@ -28,7 +28,9 @@ use synthesizer_program::{FinalizeOperation, FinalizeStoreTrait};
 
 use anyhow::Result;
 use core::marker::PhantomData;
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
+use parking_lot::RwLock;
+use std::sync::Arc;
 
 /// TODO (howardwu): Remove this.
 /// Returns the mapping ID for the given `program ID` and `mapping name`.
@@ -59,6 +61,13 @@ fn to_key_id<N: Network>(
     N::hash_bhp1024(&preimage)
 }
 
+/// An entry in the key-value history, as `(value, previous height, original value)`.
+///
+/// The `value` is the value of the key as of the block at the height of the entry, where `None` denotes a removed key.
+/// The `previous height` is the height of the previous entry for the key, if any. The `original value` is only set on
+/// the first entry for a key that existed before the history was recorded, and is the value of the key at that time.
+pub type KeyValueHistoryEntry<N> = (Option<Value<N>>, Option<u32>, Option<Value<N>>);
+
 /// A trait for program state storage. Note: For the program logic, see `DeploymentStorage`.
 ///
 /// We define the `key ID := Hash ( program ID || mapping name || Hash(key) )`
@@ -76,6 +85,17 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     type ProgramIDMap: for<'a> Map<'a, ProgramID<N>, IndexSet<Identifier<N>>>;
     /// The mapping of `(program ID, mapping name)` to `[(key, value)]`.
     type KeyValueMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, Value<N>>;
+    /// The mapping of `(program ID, mapping name)` to `[((key, block height), history entry)]`.
+    type KeyValueHistoryMap: for<'a> NestedMap<
+        'a,
+        (ProgramID<N>, Identifier<N>),
+        (Plaintext<N>, u32),
+        KeyValueHistoryEntry<N>,
+    >;
+    /// The mapping of `(program ID, mapping name)` to `[(key, latest block height in the history)]`.
+    type KeyValueHistoryIndexMap: for<'a> NestedMap<'a, (ProgramID<N>, Identifier<N>), Plaintext<N>, u32>;
+    /// The mapping of `0` to the block height at which the key-value history was first recorded.
+    type KeyValueHistoryStartMap: for<'a> Map<'a, u8, u32>;
 
     /// Initializes the program state storage.
     fn open(dev: Option<u16>) -> Result<Self>;
@@ -90,6 +110,12 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
     fn program_id_map(&self) -> &Self::ProgramIDMap;
     /// Returns the key-value map.
     fn key_value_map(&self) -> &Self::KeyValueMap;
+    /// Returns the key-value history map.
+    fn key_value_history_map(&self) -> &Self::KeyValueHistoryMap;
+    /// Returns the key-value history index map.
+    fn key_value_history_index_map(&self) -> &Self::KeyValueHistoryIndexMap;
+    /// Returns the key-value history start map.
+    fn key_value_history_start_map(&self) -> &Self::KeyValueHistoryStartMap;
 
     /// Returns the optional development ID.
     fn dev(&self) -> Option<u16>;
@@ -99,6 +125,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().start_atomic();
         self.program_id_map().start_atomic();
         self.key_value_map().start_atomic();
+        self.key_value_history_map().start_atomic();
+        self.key_value_history_index_map().start_atomic();
+        self.key_value_history_start_map().start_atomic();
     }
 
     /// Checks if an atomic batch is in progress.
@@ -106,6 +135,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().is_atomic_in_progress()
             || self.program_id_map().is_atomic_in_progress()
             || self.key_value_map().is_atomic_in_progress()
+            || self.key_value_history_map().is_atomic_in_progress()
+            || self.key_value_history_index_map().is_atomic_in_progress()
+            || self.key_value_history_start_map().is_atomic_in_progress()
     }
 
     /// Checkpoints the atomic batch.
@@ -113,6 +145,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_checkpoint();
         self.program_id_map().atomic_checkpoint();
         self.key_value_map().atomic_checkpoint();
+        self.key_value_history_map().atomic_checkpoint();
+        self.key_value_history_index_map().atomic_checkpoint();
+        self.key_value_history_start_map().atomic_checkpoint();
     }
 
     /// Clears the latest atomic batch checkpoint.
@@ -120,6 +155,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().clear_latest_checkpoint();
         self.program_id_map().clear_latest_checkpoint();
         self.key_value_map().clear_latest_checkpoint();
+        self.key_value_history_map().clear_latest_checkpoint();
+        self.key_value_history_index_map().clear_latest_checkpoint();
+        self.key_value_history_start_map().clear_latest_checkpoint();
     }
 
     /// Rewinds the atomic batch to the previous checkpoint.
@@ -127,6 +165,9 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().atomic_rewind();
         self.program_id_map().atomic_rewind();
         self.key_value_map().atomic_rewind();
+        self.key_value_history_map().atomic_rewind();
+        self.key_value_history_index_map().atomic_rewind();
+        self.key_value_history_start_map().atomic_rewind();
     }
 
     /// Aborts an atomic batch write operation.
@@ -134,13 +175,19 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         self.committee_store().abort_atomic();
         self.program_id_map().abort_atomic();
         self.key_value_map().abort_atomic();
+        self.key_value_history_map().abort_atomic();
+        self.key_value_history_index_map().abort_atomic();
+        self.key_value_history_start_map().abort_atomic();
     }
 
     /// Finishes an atomic batch write operation.
     fn finish_atomic(&self) -> Result<()> {
         self.committee_store().finish_atomic()?;
         self.program_id_map().finish_atomic()?;
-        self.key_value_map().finish_atomic()
+        self.key_value_map().finish_atomic()?;
+        self.key_value_history_map().finish_atomic()?;
+        self.key_value_history_index_map().finish_atomic()?;
+        self.key_value_history_start_map().finish_atomic()
     }
 
     /// Initializes the given `program ID` and `mapping name` in storage.
@@ -351,6 +398,54 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         })
     }
 
+    /// Records the value of the given key at the given block height in the key-value history,
+    /// given the value of the key before the write. A value of `None` records that the key was removed.
+    fn record_key_value_history(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        height: u32,
+        previous_value: Option<Value<N>>,
+        value: Option<Value<N>>,
+    ) -> Result<()> {
+        let map = (program_id, mapping_name);
+        // Retrieve the height of the latest entry for the key.
+        let latest_height = self.key_value_history_index_map().get_value_speculative(&map, &key)?;
+        // Construct the history entry.
+        let entry = match latest_height.map(|latest_height| cow_to_copied!(latest_height)) {
+            // If the key was already written at this height, overwrite the value of the entry.
+            Some(latest_height) if latest_height == height => {
+                match self.key_value_history_map().get_value_speculative(&map, &(key.clone(), height))? {
+                    Some(entry) => {
+                        let (_, previous_height, original_value) = cow_to_cloned!(entry);
+                        (value, previous_height, original_value)
+                    }
+                    None => bail!("Missing the history of '{program_id}/{mapping_name}' at block {height}"),
+                }
+            }
+            Some(latest_height) if latest_height > height => {
+                bail!("Cannot record the history of '{program_id}/{mapping_name}' at block {height} (after {latest_height})")
+            }
+            // Otherwise, link the entry to the latest entry.
+            Some(latest_height) => (value, Some(latest_height), None),
+            // If this is the first entry for the key, retain the value the key had before the history was recorded.
+            None => (value, None, previous_value),
+        };
+
+        atomic_batch_scope!(self, {
+            // If this is the first entry in the history, record the height at which the history begins.
+            if !self.key_value_history_start_map().contains_key_speculative(&0u8)? {
+                self.key_value_history_start_map().insert(0u8, height)?;
+            }
+            // Insert the history entry, and update the latest height for the key.
+            self.key_value_history_map().insert(map, (key.clone(), height), entry)?;
+            self.key_value_history_index_map().insert(map, key, height)?;
+
+            Ok(())
+        })
+    }
+
     /// Returns `true` if the given `program ID` exist.
     fn contains_program_confirmed(&self, program_id: &ProgramID<N>) -> Result<bool> {
         self.program_id_map().contains_key_confirmed(program_id)
@@ -450,6 +545,49 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
         }
     }
 
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`,
+    /// as of the block at the given height.
+    ///
+    /// If the key-value history was not recorded at the given height, an error is returned.
+    fn get_value_at_height_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        // Ensure the key-value history was recorded at the given height.
+        match self.key_value_history_start_map().get_confirmed(&0u8)? {
+            Some(start_height) if height >= *start_height => (),
+            Some(start_height) => bail!("The mapping history begins at block {}, not block {height}", *start_height),
+            None => bail!("The mapping history has not been recorded yet"),
+        }
+
+        let map = (program_id, mapping_name);
+        // Retrieve the height of the latest entry for the key.
+        let mut entry_height = match self.key_value_history_index_map().get_value_confirmed(&map, key)? {
+            Some(latest_height) => cow_to_copied!(latest_height),
+            // If the key has not been written since the history began, return the current value.
+            None => return self.get_value_confirmed(program_id, mapping_name, key),
+        };
+        // Walk back from the latest entry to the last entry at or before the given height.
+        loop {
+            let (value, previous_height, original_value) =
+                match self.key_value_history_map().get_value_confirmed(&map, &(key.clone(), entry_height))? {
+                    Some(entry) => cow_to_cloned!(entry),
+                    None => bail!("Missing the history of '{program_id}/{mapping_name}' at block {entry_height}"),
+                };
+            if entry_height <= height {
+                return Ok(value);
+            }
+            match previous_height {
+                Some(previous_height) => entry_height = previous_height,
+                // If the given height precedes the first entry, return the value before the first entry.
+                None => return Ok(original_value),
+            }
+        }
+    }
+
     /// Returns the confirmed checksum of the finalize storage.
     fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         // Compute all mapping checksums.
@@ -524,6 +662,8 @@ pub trait FinalizeStorage<N: Network>: 'static + Clone + Send + Sync {
 pub struct FinalizeStore<N: Network, P: FinalizeStorage<N>> {
     /// The finalize storage.
     storage: P,
+    /// The block height at which writes are recorded in the key-value history, if any.
+    history_height: Arc<RwLock<Option<u32>>>,
     /// PhantomData.
     _phantom: PhantomData<N>,
 }
@@ -543,7 +683,7 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     /// Initializes a finalize store from storage.
     pub fn from(storage: P) -> Result<Self> {
         // Return the finalize store.
-        Ok(Self { storage, _phantom: PhantomData })
+        Ok(Self { storage, history_height: Default::default(), _phantom: PhantomData })
     }
 
     /// Starts an atomic batch write operation.
@@ -585,6 +725,55 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
     pub fn dev(&self) -> Option<u16> {
         self.storage.dev()
     }
+
+    /// Sets the block height at which subsequent writes are recorded in the key-value history.
+    /// If `None`, writes are not recorded.
+    ///
+    /// Note: The history is written as part of the current atomic batch, so writes that are
+    /// aborted (e.g. during speculation) are also discarded from the history.
+    pub fn set_history_height(&self, height: Option<u32>) {
+        *self.history_height.write() = height;
+    }
+
+    /// Records the given value of the key in the key-value history, if the history height is set
+    /// and the value differs from the previous value.
+    fn record_key_value_history(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: Plaintext<N>,
+        previous_value: Option<Value<N>>,
+        value: Option<Value<N>>,
+    ) -> Result<()> {
+        match *self.history_height.read() {
+            Some(height) if previous_value != value => {
+                self.storage.record_key_value_history(program_id, mapping_name, key, height, previous_value, value)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Runs the given operations, which write to storage and to the key-value history, as one atomic batch.
+    fn atomic_history_scope<T>(&self, operations: impl FnOnce() -> Result<T>) -> Result<T> {
+        // If this is part of a larger atomic batch, rewind to the checkpoint on failure.
+        if self.is_atomic_in_progress() {
+            return atomic_batch_scope!(self, { operations() });
+        }
+        // Otherwise, run the operations as a new atomic batch, and abort it on failure.
+        self.start_atomic();
+        match operations() {
+            Ok(result) => self.finish_atomic().map(|_| result),
+            Err(error) => {
+                self.abort_atomic();
+                Err(error)
+            }
+        }
+    }
+
+    /// Returns `true` if writes are currently recorded in the key-value history.
+    fn is_history_recorded(&self) -> bool {
+        self.history_height.read().is_some()
+    }
 }
 
 impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
@@ -630,7 +819,14 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.insert_key_value(program_id, mapping_name, key, value)
+        if !self.is_history_recorded() {
+            return self.storage.insert_key_value(program_id, mapping_name, key, value);
+        }
+        self.atomic_history_scope(|| {
+            let operation = self.storage.insert_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+            self.record_key_value_history(program_id, mapping_name, key, None, Some(value))?;
+            Ok(operation)
+        })
     }
 
     /// Stores the given `(key, value)` pair at the given `program ID` and `mapping name` in storage.
@@ -644,7 +840,16 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         key: Plaintext<N>,
         value: Value<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.update_key_value(program_id, mapping_name, key, value)
+        if !self.is_history_recorded() {
+            return self.storage.update_key_value(program_id, mapping_name, key, value);
+        }
+        // Retrieve the value before the update.
+        let previous_value = self.storage.get_value_speculative(program_id, mapping_name, &key)?;
+        self.atomic_history_scope(|| {
+            let operation = self.storage.update_key_value(program_id, mapping_name, key.clone(), value.clone())?;
+            self.record_key_value_history(program_id, mapping_name, key, previous_value, Some(value))?;
+            Ok(operation)
+        })
     }
 
     /// Removes the key-value pair for the given `program ID`, `mapping name`, and `key` from storage.
@@ -654,7 +859,16 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStoreTrait<N> for FinalizeStore<
         mapping_name: Identifier<N>,
         key: &Plaintext<N>,
     ) -> Result<Option<FinalizeOperation<N>>> {
-        self.storage.remove_key_value(program_id, mapping_name, key)
+        if !self.is_history_recorded() {
+            return self.storage.remove_key_value(program_id, mapping_name, key);
+        }
+        // Retrieve the value before the removal.
+        let previous_value = self.storage.get_value_speculative(program_id, mapping_name, key)?;
+        self.atomic_history_scope(|| {
+            let operation = self.storage.remove_key_value(program_id, mapping_name, key)?;
+            self.record_key_value_history(program_id, mapping_name, key.clone(), previous_value, None)?;
+            Ok(operation)
+        })
     }
 }
 
@@ -677,7 +891,29 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         mapping_name: Identifier<N>,
         entries: Vec<(Plaintext<N>, Value<N>)>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.replace_mapping(program_id, mapping_name, entries)
+        if !self.is_history_recorded() {
+            return self.storage.replace_mapping(program_id, mapping_name, entries);
+        }
+        // Retrieve the entries that are currently in the mapping, indexed by the key bytes.
+        let mut previous_entries = self
+            .storage
+            .get_mapping_speculative(program_id, mapping_name)?
+            .into_iter()
+            .map(|(key, value)| Ok((key.to_bytes_le()?, (key, value))))
+            .collect::<Result<IndexMap<_, _>>>()?;
+        self.atomic_history_scope(|| {
+            let operation = self.storage.replace_mapping(program_id, mapping_name, entries.clone())?;
+            // Record the entries of the mapping that changed.
+            for (key, value) in entries {
+                let previous_value = previous_entries.swap_remove(&key.to_bytes_le()?).map(|(_, value)| value);
+                self.record_key_value_history(program_id, mapping_name, key, previous_value, Some(value))?;
+            }
+            // Record the removal of the keys that are no longer in the mapping.
+            for (key, previous_value) in previous_entries.into_values() {
+                self.record_key_value_history(program_id, mapping_name, key, Some(previous_value), None)?;
+            }
+            Ok(operation)
+        })
     }
 
     /// Removes the mapping for the given `program ID` and `mapping name` from storage,
@@ -687,7 +923,19 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         program_id: ProgramID<N>,
         mapping_name: Identifier<N>,
     ) -> Result<FinalizeOperation<N>> {
-        self.storage.remove_mapping(program_id, mapping_name)
+        if !self.is_history_recorded() {
+            return self.storage.remove_mapping(program_id, mapping_name);
+        }
+        // Retrieve the entries that are currently in the mapping.
+        let previous_entries = self.storage.get_mapping_speculative(program_id, mapping_name)?;
+        self.atomic_history_scope(|| {
+            let operation = self.storage.remove_mapping(program_id, mapping_name)?;
+            // Record the removal of every key in the mapping.
+            for (key, previous_value) in previous_entries {
+                self.record_key_value_history(program_id, mapping_name, key, Some(previous_value), None)?;
+            }
+            Ok(operation)
+        })
     }
 
     /// Removes the program for the given `program ID` from storage,
@@ -758,6 +1006,21 @@ impl<N: Network, P: FinalizeStorage<N>> FinalizeStore<N, P> {
         self.storage.get_value_speculative(program_id, mapping_name, key)
     }
 
+    /// Returns the confirmed value for the given `program ID`, `mapping name`, and `key`,
+    /// as of the block at the given height.
+    ///
+    /// Note: Only writes made while a history height was set are recorded in the history,
+    /// and an error is returned for heights before the history was first recorded.
+    pub fn get_value_at_height_confirmed(
+        &self,
+        program_id: ProgramID<N>,
+        mapping_name: Identifier<N>,
+        key: &Plaintext<N>,
+        height: u32,
+    ) -> Result<Option<Value<N>>> {
+        self.storage.get_value_at_height_confirmed(program_id, mapping_name, key, height)
+    }
+
     /// Returns the confirmed checksum of the finalize store.
     pub fn get_checksum_confirmed(&self) -> Result<Field<N>> {
         self.storage.get_checksum_confirmed()
@@ -1204,6 +1467,83 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_key_value_history() {
+        // Initialize a program ID and mapping name.
+        let program_id = ProgramID::<CurrentNetwork>::from_str("hello.aleo").unwrap();
+        let mapping_name = Identifier::from_str("account").unwrap();
+        let key = Plaintext::from_str("123456789field").unwrap();
+        let other_key = Plaintext::from_str("987654321field").unwrap();
+        let value = |item: u64| Value::from_str(&format!("{item}u64")).unwrap();
+
+        // Initialize a new finalize store.
+        let program_memory = FinalizeMemory::open(None).unwrap();
+        let finalize_store = FinalizeStore::from(program_memory).unwrap();
+        finalize_store.initialize_mapping(program_id, mapping_name).unwrap();
+
+        // Ensure writes are not recorded without a history height.
+        finalize_store.insert_key_value(program_id, mapping_name, key.clone(), value(0)).unwrap();
+        finalize_store.insert_key_value(program_id, mapping_name, other_key.clone(), value(0)).unwrap();
+        assert!(finalize_store.get_value_at_height_confirmed(program_id, mapping_name, &key, 0).is_err());
+
+        // Write the key at heights 1, 2 (twice), and 4, and remove it at height 5.
+        finalize_store.set_history_height(Some(1));
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(1)).unwrap();
+        finalize_store.set_history_height(Some(2));
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(20)).unwrap();
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(2)).unwrap();
+        // Ensure an unchanged value is not recorded.
+        finalize_store.set_history_height(Some(3));
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(2)).unwrap();
+        finalize_store.set_history_height(Some(4));
+        finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(4)).unwrap();
+        finalize_store.set_history_height(Some(5));
+        finalize_store.remove_key_value(program_id, mapping_name, &key).unwrap();
+
+        // Ensure the history cannot be rewritten at an earlier height.
+        finalize_store.set_history_height(Some(3));
+        assert!(finalize_store.update_key_value(program_id, mapping_name, key.clone(), value(3)).is_err());
+        // Ensure the failed write was reverted.
+        assert_eq!(finalize_store.get_value_confirmed(program_id, mapping_name, &key).unwrap(), None);
+        finalize_store.set_history_height(None);
+
+        // Ensure only the changes were recorded.
+        let history = finalize_store.storage.key_value_history_map().get_map_confirmed(&(program_id, mapping_name));
+        assert_eq!(history.unwrap().len(), 4);
+
+        // Ensure the history before the first recorded height is unavailable.
+        assert!(finalize_store.get_value_at_height_confirmed(program_id, mapping_name, &key, 0).is_err());
+        // Check the value of the key at each height.
+        let expected = [Some(value(1)), Some(value(2)), Some(value(2)), Some(value(4)), None, None];
+        for (height, expected) in (1u32..).zip(expected) {
+            let candidate =
+                finalize_store.get_value_at_height_confirmed(program_id, mapping_name, &key, height).unwrap();
+            assert_eq!(candidate, expected, "Mismatching value at height {height}");
+            // Ensure the unchanged key has its original value.
+            let candidate =
+                finalize_store.get_value_at_height_confirmed(program_id, mapping_name, &other_key, height).unwrap();
+            assert_eq!(candidate, Some(value(0)), "Mismatching original value at height {height}");
+        }
+
+        // Ensure replacing and removing the mapping are recorded.
+        finalize_store.set_history_height(Some(6));
+        let entries = vec![(key.clone(), value(6)), (other_key.clone(), value(0))];
+        finalize_store.replace_mapping(program_id, mapping_name, entries).unwrap();
+        finalize_store.set_history_height(Some(7));
+        finalize_store.remove_mapping(program_id, mapping_name).unwrap();
+        finalize_store.set_history_height(None);
+        for (height, expected, other_expected) in
+            [(5, None, Some(value(0))), (6, Some(value(6)), Some(value(0))), (7, None, None)]
+        {
+            let candidate =
+                finalize_store.get_value_at_height_confirmed(program_id, mapping_name, &key, height).unwrap();
+            assert_eq!(candidate, expected, "Mismatching value at height {height}");
+            let candidate =
+                finalize_store.get_value_at_height_confirmed(program_id, mapping_name, &other_key, height).unwrap();
+            assert_eq!(candidate, other_expected, "Mismatching other value at height {height}");
+        }
+    }
+
     #[test]
     fn test_must_initialize_first() {
         // Initialize a program ID and mapping name.
@@ -37,6 +37,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     type ReverseIDMap: for<'a> Map<'a, N::TransitionID, N::TransactionID>;
     /// The mapping of `transaction ID` to `(global state root, (optional) proof)`.
     type InclusionMap: for<'a> Map<'a, N::TransactionID, (N::StateRoot, Option<Proof<N>>)>;
+    /// The mapping of `transaction ID` to `expiration height`.
+    type ExpirationMap: for<'a> Map<'a, N::TransactionID, u32>;
     /// The fee storage.
     type FeeStorage: FeeStorage<N>;
 
@@ -49,6 +51,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
     fn reverse_id_map(&self) -> &Self::ReverseIDMap;
     /// Returns the inclusion map.
     fn inclusion_map(&self) -> &Self::InclusionMap;
+    /// Returns the expiration map.
+    fn expiration_map(&self) -> &Self::ExpirationMap;
     /// Returns the fee store.
     fn fee_store(&self) -> &FeeStore<N, Self::FeeStorage>;
     /// Returns the transition store.
@@ -66,6 +70,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().start_atomic();
         self.reverse_id_map().start_atomic();
         self.inclusion_map().start_atomic();
+        self.expiration_map().start_atomic();
         self.fee_store().start_atomic();
     }
 
@@ -74,6 +79,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().is_atomic_in_progress()
             || self.reverse_id_map().is_atomic_in_progress()
             || self.inclusion_map().is_atomic_in_progress()
+            || self.expiration_map().is_atomic_in_progress()
             || self.fee_store().is_atomic_in_progress()
     }
 
@@ -82,6 +88,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_checkpoint();
         self.reverse_id_map().atomic_checkpoint();
         self.inclusion_map().atomic_checkpoint();
+        self.expiration_map().atomic_checkpoint();
         self.fee_store().atomic_checkpoint();
     }
 
@@ -90,6 +97,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().clear_latest_checkpoint();
         self.reverse_id_map().clear_latest_checkpoint();
         self.inclusion_map().clear_latest_checkpoint();
+        self.expiration_map().clear_latest_checkpoint();
         self.fee_store().clear_latest_checkpoint();
     }
 
@@ -98,6 +106,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().atomic_rewind();
         self.reverse_id_map().atomic_rewind();
         self.inclusion_map().atomic_rewind();
+        self.expiration_map().atomic_rewind();
         self.fee_store().atomic_rewind();
     }
 
@@ -106,6 +115,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().abort_atomic();
         self.reverse_id_map().abort_atomic();
         self.inclusion_map().abort_atomic();
+        self.expiration_map().abort_atomic();
         self.fee_store().abort_atomic();
     }
 
@@ -114,6 +124,7 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         self.id_map().finish_atomic()?;
         self.reverse_id_map().finish_atomic()?;
         self.inclusion_map().finish_atomic()?;
+        self.expiration_map().finish_atomic()?;
         self.fee_store().finish_atomic()
     }
 
@@ -134,6 +145,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         let global_state_root = execution.global_state_root();
         // Retrieve the proof.
         let proof = execution.proof().cloned();
+        // Retrieve the expiration height.
+        let expiration = execution.expiration();
 
         atomic_batch_scope!(self, {
             // Store the transition IDs.
@@ -150,6 +163,11 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             // Store the global state root and proof.
             self.inclusion_map().insert(*transaction_id, (global_state_root, proof))?;
 
+            // Store the expiration height.
+            if let Some(expiration) = expiration {
+                self.expiration_map().insert(*transaction_id, expiration)?;
+            }
+
             // Store the fee.
             if let Some(fee) = fee {
                 // Store the fee.
@@ -182,6 +200,8 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
 
             // Remove the global state root and proof.
             self.inclusion_map().remove(transaction_id)?;
+            // Remove the expiration height.
+            self.expiration_map().remove(transaction_id)?;
 
             // Remove the fee.
             if has_fee {
@@ -234,8 +254,13 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
             };
         }
 
+        // Construct the execution.
+        let mut execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+        // Set the expiration height.
+        execution.set_expiration(self.expiration_map().get_confirmed(transaction_id)?.map(|e| cow_to_copied!(e)));
+
         // Return the execution.
-        Ok(Some(Execution::from(transitions.into_iter(), global_state_root, proof)?))
+        Ok(Some(execution))
     }
 
     /// Returns the transaction for the given `transaction ID`.
@@ -264,7 +289,9 @@ pub trait ExecutionStorage<N: Network>: Clone + Send + Sync {
         }
 
         // Construct the execution.
-        let execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+        let mut execution = Execution::from(transitions.into_iter(), global_state_root, proof)?;
+        // Set the expiration height.
+        execution.set_expiration(self.expiration_map().get_confirmed(transaction_id)?.map(|e| cow_to_copied!(e)));
 
         // Construct the transaction.
         let transaction = match has_fee {
@@ -14,6 +14,7 @@
 
 use crate::{
     atomic_batch_scope,
+    cow_to_copied,
     helpers::{Map, MapRead},
 };
 use console::{
@@ -401,6 +402,14 @@ impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
     pub fn find_transition_id(&self, input_id: &Field<N>) -> Result<Option<N::TransitionID>> {
         self.storage.find_transition_id(input_id)
     }
+
+    /// Returns the serial number for the given `tag`.
+    pub fn find_serial_number_from_tag(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        match self.record_tag.get_confirmed(tag)? {
+            Some(serial_number) => Ok(Some(cow_to_copied!(serial_number))),
+            None => Ok(None),
+        }
+    }
 }
 
 impl<N: Network, I: InputStorage<N>> InputStore<N, I> {
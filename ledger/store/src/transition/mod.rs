@@ -365,6 +365,21 @@ impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
         // Throw an error.
         bail!("Failed to find the transition ID for the given input or output ID '{id}'")
     }
+
+    /// Returns the transition ID that contains the given `commitment` as an output, if it exists.
+    pub fn find_transition_id_from_commitment(&self, commitment: &Field<N>) -> Result<Option<N::TransitionID>> {
+        self.outputs.find_transition_id(commitment)
+    }
+
+    /// Returns the transition ID that contains the given `serial number` as an input, if it exists.
+    pub fn find_transition_id_from_serial_number(&self, serial_number: &Field<N>) -> Result<Option<N::TransitionID>> {
+        self.inputs.find_transition_id(serial_number)
+    }
+
+    /// Returns the serial number for the given `tag`, if it exists.
+    pub fn find_serial_number_from_tag(&self, tag: &Field<N>) -> Result<Option<Field<N>>> {
+        self.inputs.find_serial_number_from_tag(tag)
+    }
 }
 
 impl<N: Network, T: TransitionStorage<N>> TransitionStore<N, T> {
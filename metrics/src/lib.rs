@@ -14,17 +14,48 @@
 
 #![forbid(unsafe_code)]
 
-const GAUGE_NAMES: [&str; 1] = [committee::TOTAL_STAKE];
+const COUNTER_NAMES: [&str; 6] = [
+    ledger::BLOCKS_ADDED,
+    ledger::TRANSACTIONS_ADDED,
+    ledger::RECORDS_SCANNED,
+    ledger::CACHE_HITS,
+    ledger::CACHE_MISSES,
+    prover::PROOFS,
+];
+const GAUGE_NAMES: [&str; 2] = [committee::TOTAL_STAKE, ledger::HEIGHT];
+const HISTOGRAM_NAMES: [&str; 3] = [ledger::BLOCK_ADD_TIME, prover::PROOF_TIME, prover::CONSTRAINTS];
 
 pub mod committee {
     pub const TOTAL_STAKE: &str = "snarkvm_ledger_committee_total_stake";
 }
 
+pub mod ledger {
+    pub const BLOCKS_ADDED: &str = "snarkvm_ledger_blocks_added_total";
+    pub const TRANSACTIONS_ADDED: &str = "snarkvm_ledger_transactions_added_total";
+    pub const HEIGHT: &str = "snarkvm_ledger_height";
+    pub const BLOCK_ADD_TIME: &str = "snarkvm_ledger_block_add_time_secs";
+    pub const RECORDS_SCANNED: &str = "snarkvm_ledger_records_scanned_total";
+    pub const CACHE_HITS: &str = "snarkvm_ledger_cache_hits_total";
+    pub const CACHE_MISSES: &str = "snarkvm_ledger_cache_misses_total";
+}
+
+pub mod prover {
+    pub const PROOFS: &str = "snarkvm_prover_proofs_total";
+    pub const PROOF_TIME: &str = "snarkvm_prover_proof_time_secs";
+    pub const CONSTRAINTS: &str = "snarkvm_prover_constraints";
+}
+
 /// Registers all snarkVM metrics.
 pub fn register_metrics() {
+    for name in COUNTER_NAMES {
+        register_counter(name);
+    }
     for name in GAUGE_NAMES {
         register_gauge(name);
     }
+    for name in HISTOGRAM_NAMES {
+        register_histogram(name);
+    }
 }
 
 /******** Counter ********/
@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use parking_lot::RwLock;
+use std::path::PathBuf;
+
+/// The environment variable that overrides the directory in which parameters are cached.
+pub const PARAMETERS_DIR_ENV_VAR: &str = "SNARKVM_PARAMETERS_DIR";
+
+lazy_static! {
+    /// The directory in which parameters are cached, if it was set at runtime.
+    static ref PARAMETERS_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+}
+
+/// Sets the directory in which downloaded parameters are cached.
+/// This takes precedence over the `SNARKVM_PARAMETERS_DIR` environment variable.
+pub fn set_parameters_dir<P: Into<PathBuf>>(path: P) {
+    *PARAMETERS_DIR.write() = Some(path.into());
+}
+
+/// Returns the directory in which downloaded parameters are cached.
+/// This is the directory set with `set_parameters_dir`, otherwise the `SNARKVM_PARAMETERS_DIR`
+/// environment variable, and otherwise the Aleo directory (i.e. `~/.aleo`).
+pub fn parameters_dir() -> PathBuf {
+    if let Some(path) = PARAMETERS_DIR.read().as_ref() {
+        return path.clone();
+    }
+    match std::env::var_os(PARAMETERS_DIR_ENV_VAR) {
+        Some(path) if !path.is_empty() => PathBuf::from(path),
+        _ => aleo_std::aleo_dir(),
+    }
+}
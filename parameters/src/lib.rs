@@ -27,6 +27,9 @@ extern crate thiserror;
 #[macro_use]
 pub mod macros;
 
+pub mod directory;
+pub use directory::*;
+
 pub mod errors;
 pub use errors::*;
 
@@ -169,7 +169,7 @@ macro_rules! impl_load_bytes_logic_local {
 macro_rules! impl_load_bytes_logic_remote {
     ($remote_url: expr, $local_dir: expr, $filename: expr, $metadata: expr, $expected_checksum: expr, $expected_size: expr) => {
         // Compose the correct file path for the parameter file.
-        let mut file_path = aleo_std::aleo_dir();
+        let mut file_path = $crate::parameters_dir();
         file_path.push($local_dir);
         file_path.push($filename);
 
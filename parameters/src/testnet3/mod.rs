@@ -188,4 +188,14 @@ mod tests {
         InclusionProver::load_bytes().expect("Failed to load inclusion prover");
         InclusionVerifier::load_bytes().expect("Failed to load inclusion verifier");
     }
+
+    #[test]
+    fn test_ensure_parameters() {
+        // The powers up to degree 2^16 are bundled locally.
+        ensure_parameters(0).expect("Failed to ensure the parameters for degree 0");
+        ensure_parameters((1 << 16) - 1).expect("Failed to ensure the parameters for degree 2^16 - 1");
+        // The SRS does not support degrees beyond 2^28 - 1.
+        assert!(ensure_parameters(1 << 28).is_err());
+        assert!(ensure_parameters(usize::MAX).is_err());
+    }
 }
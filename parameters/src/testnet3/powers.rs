@@ -49,6 +49,47 @@ const NUM_POWERS_28: usize = 1 << 28;
 /// The maximum degree supported by the SRS.
 const MAX_NUM_POWERS: usize = NUM_POWERS_28;
 
+/// Ensures the universal SRS powers of beta G that are needed for the given degree are available locally.
+/// Any missing parameter files are downloaded, verified against their expected checksums,
+/// and cached in the parameters directory (see `parameters_dir`).
+pub fn ensure_parameters(degree: usize) -> Result<()> {
+    // Determine the number of powers needed for the given degree.
+    let num_powers = degree
+        .checked_add(1)
+        .and_then(|num_powers| num_powers.checked_next_power_of_two())
+        .ok_or_else(|| anyhow!("Requesting too many powers"))?;
+    ensure!(num_powers <= MAX_NUM_POWERS, "Requesting more powers than exist in the SRS");
+
+    // Load each of the parameter files, which downloads and caches any that are missing.
+    let mut next_num_powers = NUM_POWERS_16;
+    while next_num_powers <= num_powers {
+        load_powers_bytes(next_num_powers)?;
+        next_num_powers *= 2;
+    }
+    Ok(())
+}
+
+/// Loads the universal SRS powers of beta G from `num_powers / 2` up to `num_powers`,
+/// downloading them if they're not already on disk.
+fn load_powers_bytes(num_powers: usize) -> Result<Vec<u8>> {
+    Ok(match num_powers {
+        NUM_POWERS_16 => Degree16::load_bytes()?,
+        NUM_POWERS_17 => Degree17::load_bytes()?,
+        NUM_POWERS_18 => Degree18::load_bytes()?,
+        NUM_POWERS_19 => Degree19::load_bytes()?,
+        NUM_POWERS_20 => Degree20::load_bytes()?,
+        NUM_POWERS_21 => Degree21::load_bytes()?,
+        NUM_POWERS_22 => Degree22::load_bytes()?,
+        NUM_POWERS_23 => Degree23::load_bytes()?,
+        NUM_POWERS_24 => Degree24::load_bytes()?,
+        NUM_POWERS_25 => Degree25::load_bytes()?,
+        NUM_POWERS_26 => Degree26::load_bytes()?,
+        NUM_POWERS_27 => Degree27::load_bytes()?,
+        NUM_POWERS_28 => Degree28::load_bytes()?,
+        _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
+    })
+}
+
 lazy_static::lazy_static! {
     static ref POWERS_OF_BETA_G_15: Vec<u8> = Degree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
     static ref SHIFTED_POWERS_OF_BETA_G_15: Vec<u8> = ShiftedDegree15::load_bytes().expect("Failed to load powers of beta in universal SRS");
@@ -401,22 +442,7 @@ impl<E: PairingEngine> PowersOfBetaG<E> {
             println!("Loading {num_powers} powers");
 
             // Download the universal SRS powers if they're not already on disk.
-            let additional_bytes = match *num_powers {
-                NUM_POWERS_16 => Degree16::load_bytes()?,
-                NUM_POWERS_17 => Degree17::load_bytes()?,
-                NUM_POWERS_18 => Degree18::load_bytes()?,
-                NUM_POWERS_19 => Degree19::load_bytes()?,
-                NUM_POWERS_20 => Degree20::load_bytes()?,
-                NUM_POWERS_21 => Degree21::load_bytes()?,
-                NUM_POWERS_22 => Degree22::load_bytes()?,
-                NUM_POWERS_23 => Degree23::load_bytes()?,
-                NUM_POWERS_24 => Degree24::load_bytes()?,
-                NUM_POWERS_25 => Degree25::load_bytes()?,
-                NUM_POWERS_26 => Degree26::load_bytes()?,
-                NUM_POWERS_27 => Degree27::load_bytes()?,
-                NUM_POWERS_28 => Degree28::load_bytes()?,
-                _ => bail!("Cannot download an invalid degree of '{num_powers}'"),
-            };
+            let additional_bytes = load_powers_bytes(*num_powers)?;
 
             // Deserialize the group elements.
             let additional_powers = Vec::deserialize_uncompressed_unchecked(&*additional_bytes)?;
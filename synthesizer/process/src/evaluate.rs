@@ -36,4 +36,24 @@ impl<N: Network> Process<N> {
 
         response
     }
+
+    /// Evaluates a program function on the given request, and returns the response,
+    /// along with a trace of the instructions evaluated in the top-level function.
+    /// The trace is returned even if the evaluation fails, and ends at the failed instruction.
+    #[inline]
+    pub fn evaluate_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        authorization: Authorization<N>,
+    ) -> (Result<Response<N>>, ExecutionTrace<N>) {
+        // Initialize the trace.
+        let mut trace = ExecutionTrace::new();
+        // Evaluate the function, recording the trace.
+        let response = authorization.peek_next().and_then(|request| {
+            // Retrieve the stack.
+            let stack = self.get_stack(request.program_id())?;
+            // Evaluate the function.
+            stack.evaluate_function_with_trace::<A>(CallStack::evaluate(authorization)?, None, Some(&mut trace))
+        });
+        (response, trace)
+    }
 }
@@ -16,7 +16,13 @@ use super::*;
 
 impl<N: Network> Process<N> {
     /// Executes the given authorization.
+    /// Note: The transitions are proven together in a single batch proof when the trace is proven.
+    /// There is no mode to aggregate the proofs further, as recursive proving is out of scope.
     #[inline]
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "Process::execute", skip_all, fields(transitions = authorization.len()))
+    )]
     pub fn execute<A: circuit::Aleo<Network = N>, R: CryptoRng + Rng>(
         &self,
         authorization: Authorization<N>,
@@ -63,7 +63,7 @@ use synthesizer_program::{
 use synthesizer_snark::{ProvingKey, UniversalSRS, VerifyingKey};
 
 use aleo_std::prelude::{finish, lap, timer};
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
 use std::{collections::HashMap, sync::Arc};
 
@@ -124,6 +124,47 @@ impl<N: Network> Process<N> {
         Ok(())
     }
 
+    /// Adds a new program to the process, along with any of its imports that are not yet in the process.
+    /// The missing imports are retrieved with the given `get_program` function (e.g. from the ledger),
+    /// and added in dependency order. If the imports contain a cycle, this method returns an error.
+    /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
+    #[inline]
+    pub fn add_program_with_imports(
+        &mut self,
+        program: &Program<N>,
+        mut get_program: impl FnMut(&ProgramID<N>) -> Result<Program<N>>,
+    ) -> Result<()> {
+        // Adds the given program, after adding its missing imports.
+        // Note: `path` is the list of programs currently being added, which is used to detect cycles.
+        fn add<N: Network>(
+            process: &mut Process<N>,
+            program: &Program<N>,
+            get_program: &mut impl FnMut(&ProgramID<N>) -> Result<Program<N>>,
+            path: &mut IndexSet<ProgramID<N>>,
+        ) -> Result<()> {
+            // Ensure the program is not already being added.
+            if !path.insert(*program.id()) {
+                let cycle = path.iter().chain([program.id()]).map(ToString::to_string).collect::<Vec<_>>();
+                bail!("Found a cycle in the program imports: {}", cycle.join(" -> "))
+            }
+            // Add the missing imports.
+            for import_id in program.imports().keys() {
+                if !process.contains_program(import_id) {
+                    let import = get_program(import_id).map_err(|e| {
+                        anyhow!("Failed to resolve the import '{import_id}' of '{}' - {e}", program.id())
+                    })?;
+                    ensure!(import.id() == import_id, "Expected the program '{import_id}', found '{}'", import.id());
+                    add(process, &import, get_program, path)?;
+                }
+            }
+            // Add the program.
+            path.pop();
+            process.add_program(program)
+        }
+
+        add(self, program, &mut get_program, &mut IndexSet::new())
+    }
+
     /// Adds a new stack to the process.
     /// If you intend to `execute` the program, use `deploy` and `finalize_deployment` instead.
     #[inline]
@@ -267,6 +308,29 @@ impl<N: Network> Process<N> {
         self.get_stack(program_id)?.insert_verifying_key(function_name, verifying_key)
     }
 
+    /// Warms up the given function, by synthesizing and caching its proving and verifying key.
+    /// Otherwise, the circuit keys of a function are synthesized lazily, the first time the function is executed.
+    #[inline]
+    pub fn warm_up<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        self.get_stack(program_id)?.warm_up::<A, R>(function_name, rng)
+    }
+
+    /// Returns the circuit metrics of the given function, by synthesizing its circuit without computing a proof.
+    #[inline]
+    pub fn circuit_metrics<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        program_id: &ProgramID<N>,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<CircuitMetrics<N>> {
+        self.get_stack(program_id)?.circuit_metrics::<A, R>(function_name, rng)
+    }
+
     /// Synthesizes the proving and verifying key for the given program ID and function name.
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -100,6 +100,23 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
         &self,
         call_stack: CallStack<N>,
         caller: Option<ProgramID<N>>,
+    ) -> Result<Response<N>> {
+        self.evaluate_function_with_trace::<A>(call_stack, caller, None)
+    }
+}
+
+impl<N: Network> Stack<N> {
+    /// Evaluates a program function on the given inputs.
+    /// If a trace is given, each evaluated instruction is recorded in the trace.
+    /// Note: A `call` instruction is recorded as a single step, without the instructions of the callee.
+    ///
+    /// # Errors
+    /// This method will halt if the given inputs are not the same length as the input statements.
+    pub(crate) fn evaluate_function_with_trace<A: circuit::Aleo<Network = N>>(
+        &self,
+        call_stack: CallStack<N>,
+        caller: Option<ProgramID<N>>,
+        mut trace: Option<&mut ExecutionTrace<N>>,
     ) -> Result<Response<N>> {
         let timer = timer!("Stack::evaluate_function");
 
@@ -175,14 +192,43 @@ impl<N: Network> StackEvaluate<N> for Stack<N> {
 
         // Evaluate the instructions.
         // Note: We handle the `call` instruction separately, as it requires special handling.
-        for instruction in function.instructions() {
-            // Evaluate the instruction.
-            let result = match instruction {
-                // If the instruction is a `call` instruction, we need to handle it separately.
-                Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
-                // Otherwise, evaluate the instruction normally.
-                _ => instruction.evaluate(self, &mut registers),
+        for (index, instruction) in function.instructions().iter().enumerate() {
+            // If tracing, load the operands before the instruction is evaluated.
+            let operands = match trace {
+                Some(_) => instruction.operands().iter().map(|operand| registers.load(self, operand)).collect(),
+                None => Ok(vec![]),
             };
+            // Evaluate the instruction.
+            let result = operands.and_then(|operands| {
+                let result = match instruction {
+                    // If the instruction is a `call` instruction, we need to handle it separately.
+                    Instruction::Call(call) => CallTrait::evaluate(call, self, &mut registers),
+                    // Otherwise, evaluate the instruction normally.
+                    _ => instruction.evaluate(self, &mut registers),
+                };
+                // If tracing, record the instruction, and the values of its destination registers.
+                if let Some(trace) = trace.as_deref_mut() {
+                    let destinations = result.as_ref().ok().map(|_| {
+                        instruction
+                            .destinations()
+                            .into_iter()
+                            .filter_map(|register| {
+                                let value = registers.load(self, &Operand::Register(register.clone())).ok()?;
+                                Some((register, value))
+                            })
+                            .collect()
+                    });
+                    trace.push(TraceStep {
+                        program_id: *self.program.id(),
+                        function_name: *function.name(),
+                        index,
+                        instruction: instruction.clone(),
+                        operands,
+                        destinations,
+                    });
+                }
+                result
+            });
             // If the evaluation fails, bail and return the error.
             if let Err(error) = result {
                 bail!("Failed to evaluate instruction ({instruction}): {error}");
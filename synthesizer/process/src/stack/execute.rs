@@ -368,6 +368,8 @@ impl<N: Network> StackExecute<N> for Stack<N> {
         // Retrieve the number of constraints for verifying the response in the circuit.
         let num_response_constraints =
             A::num_constraints().saturating_sub(num_request_constraints).saturating_sub(num_function_constraints);
+        // Retrieve the number of commands in the finalize scope of the function.
+        let num_finalize_commands = function.finalize_logic().map_or(0, |finalize| finalize.commands().len());
 
         #[cfg(debug_assertions)]
         Self::log_circuit::<A, _>("Complete");
@@ -425,6 +427,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                num_finalize_commands,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
@@ -447,6 +450,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                num_finalize_commands,
             };
 
             // Add the transition to the trace.
@@ -467,6 +471,7 @@ impl<N: Network> StackExecute<N> for Stack<N> {
                 num_request_constraints,
                 num_function_constraints,
                 num_response_constraints,
+                num_finalize_commands,
             };
             // Add the assignment to the assignments.
             assignments.write().push((assignment, metrics));
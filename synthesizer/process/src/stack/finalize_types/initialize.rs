@@ -598,6 +598,13 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::Array(opcode) => match opcode {
+                "array.get" => ensure!(
+                    matches!(instruction, Instruction::ArrayGet(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
             Opcode::Assert(opcode) => match opcode {
                 "assert.eq" => ensure!(
                     matches!(instruction, Instruction::AssertEq(..)),
@@ -686,6 +693,21 @@ impl<N: Network> FinalizeTypes<N> {
                     "Instruction '{instruction}' has multiple destinations."
                 );
             }
+            Opcode::String(opcode) => match opcode {
+                "string.len" => ensure!(
+                    matches!(instruction, Instruction::StringLen(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.concat" => ensure!(
+                    matches!(instruction, Instruction::StringConcat(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                "string.slice" => ensure!(
+                    matches!(instruction, Instruction::StringSlice(..)),
+                    "Instruction '{instruction}' is not for opcode '{opcode}'."
+                ),
+                _ => bail!("Instruction '{instruction}' is not for opcode '{opcode}'."),
+            },
         }
         Ok(())
     }
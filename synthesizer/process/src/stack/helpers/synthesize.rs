@@ -15,6 +15,17 @@
 use super::*;
 
 impl<N: Network> Stack<N> {
+    /// Warms up the given function, by synthesizing and caching its proving and verifying key.
+    /// Otherwise, the circuit keys of a function are synthesized lazily, the first time the function is executed.
+    #[inline]
+    pub fn warm_up<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<()> {
+        self.synthesize_key::<A, R>(function_name, rng)
+    }
+
     /// Synthesizes the proving key and verifying key for the given function name.
     #[inline]
     pub fn synthesize_key<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
@@ -27,32 +38,8 @@ impl<N: Network> Stack<N> {
             return Ok(());
         }
 
-        // Retrieve the program ID.
-        let program_id = self.program_id();
-        // Retrieve the function input types.
-        let input_types = self.get_function(function_name)?.input_types();
-
-        // Initialize a burner private key.
-        let burner_private_key = PrivateKey::new(rng)?;
-        // Compute the burner address.
-        let burner_address = Address::try_from(&burner_private_key)?;
-        // Sample the inputs.
-        let inputs = input_types
-            .iter()
-            .map(|input_type| match input_type {
-                ValueType::ExternalRecord(locator) => {
-                    // Retrieve the external stack.
-                    let stack = self.get_external_stack(locator.program_id())?;
-                    // Sample the input.
-                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
-                }
-                _ => self.sample_value(&burner_address, input_type, rng),
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        // Compute the request, with a burner private key.
-        let request =
-            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        // Sample a request, with a burner private key.
+        let (burner_private_key, request) = self.sample_request(function_name, rng)?;
         // Initialize the authorization.
         let authorization = Authorization::new(request.clone());
         // Initialize the call stack.
@@ -67,6 +54,30 @@ impl<N: Network> Stack<N> {
         Ok(())
     }
 
+    /// Returns the circuit metrics of the given function, by synthesizing its circuit without computing its circuit keys.
+    #[inline]
+    pub fn circuit_metrics<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<CircuitMetrics<N>> {
+        // Sample a request, with a burner private key.
+        let (burner_private_key, request) = self.sample_request(function_name, rng)?;
+        // Initialize the assignments.
+        let assignments = Assignments::<N>::default();
+        // Initialize the call stack.
+        let call_stack = CallStack::CheckDeployment(vec![request], burner_private_key, assignments.clone());
+        // Synthesize the circuit.
+        let _response = self.execute_function::<A, R>(call_stack, None, rng)?;
+
+        // Retrieve the assignment of the function, which is saved after those of any external calls.
+        let assignments = assignments.read();
+        let Some((assignment, _metrics)) = assignments.last() else {
+            bail!("The assignment for function '{function_name}' is missing in '{}'", self.program_id())
+        };
+        Ok(CircuitMetrics::new(*self.program_id(), *function_name, assignment))
+    }
+
     /// Synthesizes and stores the `(proving_key, verifying_key)` for the given function name and assignment.
     #[inline]
     pub fn synthesize_from_assignment(
@@ -86,4 +97,39 @@ impl<N: Network> Stack<N> {
         // Insert the verifying key.
         self.insert_verifying_key(function_name, verifying_key)
     }
+
+    /// Samples a request for the given function name, with randomly-sampled inputs and a burner private key.
+    fn sample_request<R: Rng + CryptoRng>(
+        &self,
+        function_name: &Identifier<N>,
+        rng: &mut R,
+    ) -> Result<(PrivateKey<N>, Request<N>)> {
+        // Retrieve the program ID.
+        let program_id = self.program_id();
+        // Retrieve the function input types.
+        let input_types = self.get_function(function_name)?.input_types();
+
+        // Initialize a burner private key.
+        let burner_private_key = PrivateKey::new(rng)?;
+        // Compute the burner address.
+        let burner_address = Address::try_from(&burner_private_key)?;
+        // Sample the inputs.
+        let inputs = input_types
+            .iter()
+            .map(|input_type| match input_type {
+                ValueType::ExternalRecord(locator) => {
+                    // Retrieve the external stack.
+                    let stack = self.get_external_stack(locator.program_id())?;
+                    // Sample the input.
+                    stack.sample_value(&burner_address, &ValueType::Record(*locator.resource()), rng)
+                }
+                _ => self.sample_value(&burner_address, input_type, rng),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Compute the request, with the burner private key.
+        let request =
+            Request::sign(&burner_private_key, *program_id, *function_name, inputs.into_iter(), &input_types, rng)?;
+        Ok((burner_private_key, request))
+    }
 }
@@ -36,7 +36,7 @@ mod evaluate;
 mod execute;
 mod helpers;
 
-use crate::{traits::*, CallMetrics, Process, Trace};
+use crate::{traits::*, CallMetrics, CircuitMetrics, ExecutionTrace, Process, Trace, TraceStep};
 use console::{
     account::{Address, PrivateKey},
     network::prelude::*,
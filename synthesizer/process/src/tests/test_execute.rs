@@ -22,10 +22,10 @@ use circuit::{network::AleoV0, Aleo};
 use console::{
     account::{Address, PrivateKey, ViewKey},
     network::{prelude::*, Testnet3},
-    program::{Identifier, Literal, Plaintext, ProgramID, Record, Value},
+    program::{Identifier, Literal, Plaintext, ProgramID, Record, Register, Value},
     types::{Field, U64},
 };
-use ledger_block::Fee;
+use ledger_block::{Execution, Fee};
 use ledger_query::Query;
 use ledger_store::{
     helpers::memory::{BlockMemory, FinalizeMemory},
@@ -154,6 +154,249 @@ function foo:
     assert_eq!(expected, candidate[0]);
 }
 
+#[test]
+fn test_program_evaluate_with_trace() {
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program example.aleo;
+
+function foo:
+    input r0 as field.public;
+    input r1 as field.private;
+    add r0 r1 into r2;
+    mul r2 r1 into r3;
+    assert.eq r3 15field;
+    output r3 as field.private;
+",
+    )
+    .unwrap();
+
+    // Declare the function name.
+    let function_name = Identifier::from_str("foo").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+
+    // Initialize an RNG.
+    let rng = &mut TestRng::default();
+    // Initialize caller private key.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+
+    // Evaluate the function with inputs that pass the assertion.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            ["2field", "3field"].into_iter(),
+            rng,
+        )
+        .unwrap();
+    let (response, trace) = process.evaluate_with_trace::<CurrentAleo>(authorization);
+    assert_eq!(response.unwrap().outputs(), [Value::from_str("15field").unwrap()]);
+
+    // Ensure each instruction is recorded, with its operands and destinations.
+    assert_eq!(trace.steps().len(), 3);
+    assert!(trace.failed_step().is_none());
+    let step = &trace.steps()[1];
+    assert_eq!(step.program_id, *program.id());
+    assert_eq!(step.function_name, function_name);
+    assert_eq!(step.index, 1);
+    assert_eq!(step.instruction.to_string(), "mul r2 r1 into r3;");
+    assert_eq!(step.operands, [Value::from_str("5field").unwrap(), Value::from_str("3field").unwrap()]);
+    assert_eq!(step.destinations, Some(vec![(Register::Locator(3), Value::from_str("15field").unwrap())]));
+    assert!(trace.to_string().contains("r3 <- 15field"));
+
+    // Evaluate the function with inputs that fail the assertion.
+    let authorization = process
+        .authorize::<CurrentAleo, _>(
+            &caller_private_key,
+            program.id(),
+            function_name,
+            ["1field", "1field"].into_iter(),
+            rng,
+        )
+        .unwrap();
+    let (response, trace) = process.evaluate_with_trace::<CurrentAleo>(authorization);
+    assert!(response.is_err());
+
+    // Ensure the trace ends at the failed instruction.
+    assert_eq!(trace.steps().len(), 3);
+    let step = trace.failed_step().unwrap();
+    assert_eq!(step.index, 2);
+    assert_eq!(step.operands, [Value::from_str("2field").unwrap(), Value::from_str("15field").unwrap()]);
+    assert!(trace.to_string().contains("(failed)"));
+}
+
+#[test]
+fn test_process_add_program_with_imports() {
+    // Initialize the programs, where `a.aleo` imports `b.aleo`, which imports `c.aleo`.
+    let program = |source: &str| Program::<CurrentNetwork>::from_str(source).unwrap();
+    let c = program(
+        r"
+program c.aleo;
+
+function f:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+    );
+    let b = program(
+        r"
+import c.aleo;
+
+program b.aleo;
+
+function g:
+    input r0 as u8.public;
+    call c.aleo/f r0 into r1;
+    output r1 as u8.public;",
+    );
+    let a = program(
+        r"
+import b.aleo;
+import c.aleo;
+
+program a.aleo;
+
+function h:
+    input r0 as u8.public;
+    call b.aleo/g r0 into r1;
+    call c.aleo/f r1 into r2;
+    output r2 as u8.public;",
+    );
+
+    // Initialize the lookup of programs, tracking the number of lookups.
+    let lookup = [b.clone(), c.clone()].into_iter().map(|program| (*program.id(), program)).collect::<IndexMap<_, _>>();
+    let mut num_lookups = 0;
+    let get_program = |program_id: &ProgramID<CurrentNetwork>| {
+        num_lookups += 1;
+        lookup.get(program_id).cloned().ok_or_else(|| anyhow!("Missing program '{program_id}'"))
+    };
+
+    // Ensure adding the program without its imports fails.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    assert!(process.add_program(&a).is_err());
+
+    // Ensure the imports are resolved, and each is looked up once.
+    process.add_program_with_imports(&a, get_program).unwrap();
+    assert!(process.contains_program(a.id()));
+    assert!(process.contains_program(b.id()));
+    assert!(process.contains_program(c.id()));
+    assert_eq!(num_lookups, 2);
+
+    // Ensure a missing import is reported.
+    let mut process = Process::<CurrentNetwork>::load().unwrap();
+    let error = process.add_program_with_imports(&a, |program_id| bail!("Missing program '{program_id}'")).unwrap_err();
+    assert!(error.to_string().contains("Failed to resolve the import 'b.aleo' of 'a.aleo'"));
+
+    // Initialize the programs, where `x.aleo` and `y.aleo` import each other.
+    let x = program(
+        r"
+import y.aleo;
+
+program x.aleo;
+
+function f:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+    );
+    let y = program(
+        r"
+import x.aleo;
+
+program y.aleo;
+
+function f:
+    input r0 as u8.public;
+    output r0 as u8.public;",
+    );
+    let lookup = [x.clone(), y].into_iter().map(|program| (*program.id(), program)).collect::<IndexMap<_, _>>();
+
+    // Ensure the cycle is detected.
+    let error = process
+        .add_program_with_imports(&x, |program_id| {
+            lookup.get(program_id).cloned().ok_or_else(|| anyhow!("Missing program '{program_id}'"))
+        })
+        .unwrap_err();
+    assert!(error.to_string().contains("x.aleo -> y.aleo -> x.aleo"));
+    assert!(!process.contains_program(x.id()));
+}
+
+#[test]
+fn test_process_warm_up() {
+    // Initialize a new program with two functions.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program lazy.aleo;
+
+function foo:
+    input r0 as u8.public;
+    output r0 as u8.public;
+
+function bar:
+    input r0 as u8.public;
+    add r0 r0 into r1;
+    output r1 as u8.public;",
+    )
+    .unwrap();
+    let foo = Identifier::from_str("foo").unwrap();
+    let bar = Identifier::from_str("bar").unwrap();
+
+    // Construct the process, and ensure no circuit keys are synthesized up front.
+    let process = crate::test_helpers::sample_process(&program);
+    let stack = process.get_stack(program.id()).unwrap();
+    assert!(!stack.contains_proving_key(&foo) && !stack.contains_verifying_key(&foo));
+    assert!(!stack.contains_proving_key(&bar) && !stack.contains_verifying_key(&bar));
+
+    // Warm up 'foo', and ensure only its circuit keys are synthesized.
+    process.warm_up::<CurrentAleo, _>(program.id(), &foo, &mut TestRng::default()).unwrap();
+    assert!(stack.contains_proving_key(&foo) && stack.contains_verifying_key(&foo));
+    assert!(!stack.contains_proving_key(&bar) && !stack.contains_verifying_key(&bar));
+}
+
+#[test]
+fn test_process_circuit_metrics() {
+    // Initialize a new program with two functions.
+    let program = Program::<CurrentNetwork>::from_str(
+        r"
+program metrics.aleo;
+
+function foo:
+    input r0 as u8.public;
+    output r0 as u8.public;
+
+function bar:
+    input r0 as u8.public;
+    mul r0 r0 into r1;
+    mul r1 r1 into r2;
+    output r2 as u8.public;",
+    )
+    .unwrap();
+    let foo = Identifier::from_str("foo").unwrap();
+    let bar = Identifier::from_str("bar").unwrap();
+
+    // Construct the process.
+    let process = crate::test_helpers::sample_process(&program);
+    let rng = &mut TestRng::default();
+
+    // Compute the circuit metrics.
+    let foo_metrics = process.circuit_metrics::<CurrentAleo, _>(program.id(), &foo, rng).unwrap();
+    let bar_metrics = process.circuit_metrics::<CurrentAleo, _>(program.id(), &bar, rng).unwrap();
+    assert_eq!(&foo_metrics.function_name, &foo);
+    assert_eq!(&bar_metrics.function_name, &bar);
+    assert!(foo_metrics.num_constraints > 0 && foo_metrics.num_public > 0 && foo_metrics.num_private > 0);
+    assert!(foo_metrics.num_nonzeros.0 > 0 && foo_metrics.num_nonzeros.1 > 0 && foo_metrics.num_nonzeros.2 > 0);
+
+    // Ensure the additional instructions are reflected in the metrics.
+    assert!(bar_metrics.num_constraints > foo_metrics.num_constraints);
+    assert!(bar_metrics.num_private > foo_metrics.num_private);
+
+    // Ensure the metrics are deterministic, and no circuit keys are synthesized.
+    assert_eq!(foo_metrics, process.circuit_metrics::<CurrentAleo, _>(program.id(), &foo, rng).unwrap());
+    let stack = process.get_stack(program.id()).unwrap();
+    assert!(!stack.contains_proving_key(&foo) && !stack.contains_verifying_key(&foo));
+}
+
 #[test]
 fn test_program_evaluate_struct_and_function() {
     // Initialize a new program.
@@ -2483,3 +2726,58 @@ function {function_name}:
     assert_ne!(execution_1.peek().unwrap().id(), execution_2.peek().unwrap().id());
     assert_ne!(execution_1.to_execution_id().unwrap(), execution_2.to_execution_id().unwrap());
 }
+
+#[test]
+fn test_process_verify_executions() {
+    // Initialize the RNG.
+    let rng = &mut TestRng::default();
+
+    // Construct the process.
+    let process = Process::<CurrentNetwork>::load().unwrap();
+    // Initialize a new block store.
+    let block_store = BlockStore::<CurrentNetwork, BlockMemory<_>>::open(None).unwrap();
+
+    // Initialize a new caller account.
+    let caller_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let caller = Address::try_from(&caller_private_key).unwrap();
+
+    // Compute a few executions of `credits.aleo/transfer_public`.
+    let mut executions = Vec::new();
+    for amount in 1..=3u64 {
+        // Declare the input values.
+        let inputs = [
+            Value::<CurrentNetwork>::from_str(&caller.to_string()).unwrap(),
+            Value::from_str(&format!("{amount}u64")).unwrap(),
+        ];
+        // Authorize the function call.
+        let authorization = process
+            .authorize::<CurrentAleo, _>(&caller_private_key, "credits.aleo", "transfer_public", inputs.iter(), rng)
+            .unwrap();
+        // Execute the request.
+        let (_, mut trace) = process.execute::<CurrentAleo, _>(authorization, rng).unwrap();
+        // Prepare the trace.
+        trace.prepare(Query::from(&block_store)).unwrap();
+        // Prove the execution.
+        executions.push(trace.prove_execution::<CurrentAleo, _>("credits.aleo/transfer_public", rng).unwrap());
+    }
+
+    // Ensure the executions verify together.
+    let batch = executions.iter().collect::<Vec<_>>();
+    process.verify_executions(&batch, rng).unwrap();
+
+    // Construct an execution with a bad proof, by taking the proof of another execution.
+    let bad_execution = Execution::from(
+        executions[1].transitions().cloned(),
+        executions[1].global_state_root(),
+        executions[0].proof().cloned(),
+    )
+    .unwrap();
+    assert!(process.verify_execution(&bad_execution).is_err());
+
+    // Ensure a batch with a single bad proof is rejected.
+    let batch = vec![&executions[0], &bad_execution, &executions[2]];
+    assert!(process.verify_executions(&batch, rng).is_err());
+
+    // Ensure an empty batch is rejected.
+    assert!(process.verify_executions(&[], rng).is_err());
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use console::{
-    network::Network,
+    network::prelude::*,
     program::{Identifier, ProgramID},
 };
 
@@ -25,4 +25,36 @@ pub struct CallMetrics<N: Network> {
     pub num_request_constraints: u64,
     pub num_function_constraints: u64,
     pub num_response_constraints: u64,
+    pub num_finalize_commands: usize,
+}
+
+impl<N: Network> CallMetrics<N> {
+    /// Returns the total number of constraints synthesized for the call.
+    pub fn num_constraints(&self) -> u64 {
+        self.num_request_constraints
+            .saturating_add(self.num_function_constraints)
+            .saturating_add(self.num_response_constraints)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_num_constraints() {
+        let metrics = CallMetrics::<CurrentNetwork> {
+            program_id: ProgramID::from_str("example.aleo").unwrap(),
+            function_name: Identifier::from_str("foo").unwrap(),
+            num_instructions: 3,
+            num_request_constraints: 100,
+            num_function_constraints: 20,
+            num_response_constraints: 3,
+            num_finalize_commands: 2,
+        };
+        assert_eq!(metrics.num_constraints(), 123);
+    }
 }
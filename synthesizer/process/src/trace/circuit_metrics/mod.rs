@@ -0,0 +1,45 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use circuit::Assignment;
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID},
+};
+
+/// The shape of a synthesized function circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CircuitMetrics<N: Network> {
+    pub program_id: ProgramID<N>,
+    pub function_name: Identifier<N>,
+    pub num_constraints: u64,
+    pub num_public: u64,
+    pub num_private: u64,
+    /// The number of nonzero entries in the `A`, `B`, and `C` matrices.
+    pub num_nonzeros: (u64, u64, u64),
+}
+
+impl<N: Network> CircuitMetrics<N> {
+    /// Initializes the circuit metrics of the given function from its assignment.
+    pub fn new(program_id: ProgramID<N>, function_name: Identifier<N>, assignment: &Assignment<N::Field>) -> Self {
+        Self {
+            program_id,
+            function_name,
+            num_constraints: assignment.num_constraints(),
+            num_public: assignment.num_public(),
+            num_private: assignment.num_private(),
+            num_nonzeros: assignment.num_nonzeros(),
+        }
+    }
+}
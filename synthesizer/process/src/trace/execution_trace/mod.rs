@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use console::{
+    network::prelude::*,
+    program::{Identifier, ProgramID, Register, Value},
+};
+use synthesizer_program::Instruction;
+
+/// A record of the instructions evaluated in a function call, for debugging.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionTrace<N: Network> {
+    /// The evaluated steps, in order.
+    steps: Vec<TraceStep<N>>,
+}
+
+/// A single instruction evaluated in a function call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceStep<N: Network> {
+    /// The program ID.
+    pub program_id: ProgramID<N>,
+    /// The function name.
+    pub function_name: Identifier<N>,
+    /// The index of the instruction in the function.
+    pub index: usize,
+    /// The instruction.
+    pub instruction: Instruction<N>,
+    /// The values of the operands, before the instruction was evaluated.
+    pub operands: Vec<Value<N>>,
+    /// The values stored in the destination registers, or `None` if the instruction failed.
+    pub destinations: Option<Vec<(Register<N>, Value<N>)>>,
+}
+
+impl<N: Network> ExecutionTrace<N> {
+    /// Initializes a new, empty execution trace.
+    pub const fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Returns the evaluated steps, in order.
+    pub fn steps(&self) -> &[TraceStep<N>] {
+        &self.steps
+    }
+
+    /// Returns the step that failed, if any.
+    pub fn failed_step(&self) -> Option<&TraceStep<N>> {
+        self.steps.iter().find(|step| step.destinations.is_none())
+    }
+
+    /// Appends the given step to the trace.
+    pub(crate) fn push(&mut self, step: TraceStep<N>) {
+        self.steps.push(step);
+    }
+}
+
+impl<N: Network> Display for ExecutionTrace<N> {
+    /// Prints the trace, with one instruction per step, followed by its operand and destination values.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for step in &self.steps {
+            writeln!(f, "{}/{} #{}: {}", step.program_id, step.function_name, step.index, step.instruction)?;
+            for (operand, value) in step.instruction.operands().iter().zip_eq(&step.operands) {
+                writeln!(f, "    {operand} = {value}")?;
+            }
+            match &step.destinations {
+                Some(destinations) => {
+                    for (register, value) in destinations {
+                        writeln!(f, "    {register} <- {value}")?;
+                    }
+                }
+                None => writeln!(f, "    (failed)")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -15,6 +15,12 @@
 mod call_metrics;
 pub use call_metrics::*;
 
+mod circuit_metrics;
+pub use circuit_metrics::*;
+
+mod execution_trace;
+pub use execution_trace::*;
+
 mod inclusion;
 pub use inclusion::*;
 
@@ -65,10 +71,26 @@ impl<N: Network> Trace<N> {
         &self.transitions
     }
 
+    /// Returns the transition tasks, as a map of locators to (proving key, assignments) pairs.
+    /// These may be exported, to delegate the proving of the transitions to another machine.
+    pub fn transition_tasks(&self) -> &HashMap<Locator<N>, (ProvingKey<N>, Vec<Assignment<N::Field>>)> {
+        &self.transition_tasks
+    }
+
     /// Returns the call metrics.
     pub fn call_metrics(&self) -> &[CallMetrics<N>] {
         &self.call_metrics
     }
+
+    /// Returns the total number of constraints synthesized, for all calls in the trace.
+    pub fn num_constraints(&self) -> u64 {
+        self.call_metrics.iter().fold(0u64, |total, metrics| total.saturating_add(metrics.num_constraints()))
+    }
+
+    /// Returns the total number of finalize commands, for all calls in the trace.
+    pub fn num_finalize_commands(&self) -> usize {
+        self.call_metrics.iter().fold(0usize, |total, metrics| total.saturating_add(metrics.num_finalize_commands))
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -147,6 +169,14 @@ impl<N: Network> Trace<N> {
     }
 
     /// Returns a new execution with a proof, for the current inclusion assignments and global state root.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            name = "Trace::prove_execution",
+            skip_all,
+            fields(locator = locator, transitions = self.transitions.len())
+        )
+    )]
     pub fn prove_execution<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(
         &self,
         locator: &str,
@@ -175,6 +205,7 @@ impl<N: Network> Trace<N> {
     }
 
     /// Returns a new fee with a proof, for the current inclusion assignment and global state root.
+    #[cfg_attr(feature = "instrument", tracing::instrument(name = "Trace::prove_fee", skip_all))]
     pub fn prove_fee<A: circuit::Aleo<Network = N>, R: Rng + CryptoRng>(&self, rng: &mut R) -> Result<Fee<N>> {
         // Ensure this is a fee.
         let is_fee_public = self.is_fee_public();
@@ -214,14 +245,8 @@ impl<N: Network> Trace<N> {
         verifier_inputs: Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
         execution: &Execution<N>,
     ) -> Result<()> {
-        // Retrieve the global state root.
-        let global_state_root = execution.global_state_root();
-        // Ensure the global state root is not zero.
-        if global_state_root == N::StateRoot::default() {
-            bail!("Inclusion expected the global state root in the execution to *not* be zero")
-        }
-        // Retrieve the proof.
-        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+        // Retrieve the global state root and proof.
+        let (global_state_root, proof) = Self::execution_state_root_and_proof(execution)?;
         // Verify the execution proof.
         match Self::verify_batch(locator, verifier_inputs, global_state_root, execution.transitions(), proof) {
             Ok(()) => Ok(()),
@@ -252,6 +277,28 @@ impl<N: Network> Trace<N> {
             Err(e) => bail!("Fee is invalid - {e}"),
         }
     }
+
+    /// Checks the proofs for the given executions together, by combining their final pairing checks.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    pub fn verify_execution_proofs<R: Rng + CryptoRng>(
+        executions: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Execution<N>)>,
+        rng: &mut R,
+    ) -> Result<()> {
+        // Construct the verifier inputs and proof for each execution.
+        let mut batches = Vec::with_capacity(executions.len());
+        for (mut verifier_inputs, execution) in executions {
+            // Retrieve the global state root and proof.
+            let (global_state_root, proof) = Self::execution_state_root_and_proof(execution)?;
+            // Insert the inclusion verifier inputs.
+            Self::append_inclusion_verifier_inputs(&mut verifier_inputs, global_state_root, execution.transitions())?;
+            batches.push((verifier_inputs, proof));
+        }
+        // Verify the execution proofs.
+        match VerifyingKey::verify_batch_proofs("executions", batches, rng) {
+            true => Ok(()),
+            false => bail!("Executions are invalid - Failed to verify proofs"),
+        }
+    }
 }
 
 impl<N: Network> Trace<N> {
@@ -303,6 +350,34 @@ impl<N: Network> Trace<N> {
         global_state_root: N::StateRoot,
         transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
         proof: &Proof<N>,
+    ) -> Result<()> {
+        // Insert the inclusion verifier inputs.
+        Self::append_inclusion_verifier_inputs(&mut verifier_inputs, global_state_root, transitions)?;
+        // Verify the proof.
+        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
+            true => Ok(()),
+            false => bail!("Failed to verify proof"),
+        }
+    }
+
+    /// Returns the global state root and proof of the given execution.
+    fn execution_state_root_and_proof(execution: &Execution<N>) -> Result<(N::StateRoot, &Proof<N>)> {
+        // Retrieve the global state root.
+        let global_state_root = execution.global_state_root();
+        // Ensure the global state root is not zero.
+        if global_state_root == N::StateRoot::default() {
+            bail!("Inclusion expected the global state root in the execution to *not* be zero")
+        }
+        // Retrieve the proof.
+        let Some(proof) = execution.proof() else { bail!("Expected the execution to contain a proof") };
+        Ok((global_state_root, proof))
+    }
+
+    /// Appends the batch of inclusion verifier inputs for the given transitions to the verifier inputs.
+    fn append_inclusion_verifier_inputs<'a>(
+        verifier_inputs: &mut Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>,
+        global_state_root: N::StateRoot,
+        transitions: impl ExactSizeIterator<Item = &'a Transition<N>>,
     ) -> Result<()> {
         // Construct the batch of inclusion verifier inputs.
         let batch_inclusion_inputs = Inclusion::prepare_verifier_inputs(global_state_root, transitions)?;
@@ -313,10 +388,6 @@ impl<N: Network> Trace<N> {
             // Insert the inclusion verifier inputs.
             verifier_inputs.push((verifying_key, batch_inclusion_inputs));
         }
-        // Verify the proof.
-        match VerifyingKey::verify_batch(locator, verifier_inputs, proof) {
-            true => Ok(()),
-            false => bail!("Failed to verify proof"),
-        }
+        Ok(())
     }
 }
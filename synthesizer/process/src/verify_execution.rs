@@ -18,9 +18,60 @@ impl<N: Network> Process<N> {
     /// Verifies the given execution is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "Process::verify_execution", skip_all, fields(transitions = execution.len()))
+    )]
     pub fn verify_execution(&self, execution: &Execution<N>) -> Result<()> {
         let timer = timer!("Process::verify_execution");
 
+        // Verify the execution, and construct the verifier inputs for the proof.
+        let (locator, verifier_inputs) = self.prepare_execution_verifier_inputs(execution)?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proof.
+        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
+        lap!(timer, "Verify the proof");
+
+        finish!(timer);
+        Ok(())
+    }
+
+    /// Verifies the given executions are valid.
+    /// The execution proofs are verified together, by combining their final pairing checks.
+    /// Note: This does *not* check that the global state roots exist in the ledger.
+    #[inline]
+    pub fn verify_executions<R: Rng + CryptoRng>(&self, executions: &[&Execution<N>], rng: &mut R) -> Result<()> {
+        let timer = timer!("Process::verify_executions");
+
+        // Ensure there are executions to verify.
+        ensure!(!executions.is_empty(), "There are no executions to verify");
+
+        // Verify each execution, and construct the verifier inputs for its proof.
+        let batches = executions
+            .iter()
+            .map(|execution| Ok((self.prepare_execution_verifier_inputs(execution)?.1, *execution)))
+            .collect::<Result<Vec<_>>>()?;
+        lap!(timer, "Construct the verifier inputs");
+
+        // Verify the execution proofs.
+        Trace::verify_execution_proofs(batches, rng)?;
+        lap!(timer, "Verify the proofs");
+
+        finish!(timer);
+        Ok(())
+    }
+}
+
+impl<N: Network> Process<N> {
+    /// Verifies the given execution, except for its proof, and returns the locator of the main function
+    /// and the verifier inputs for the proof.
+    fn prepare_execution_verifier_inputs(
+        &self,
+        execution: &Execution<N>,
+    ) -> Result<(String, Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>)> {
+        let timer = timer!("Process::prepare_execution_verifier_inputs");
+
         // Ensure the execution contains transitions.
         ensure!(!execution.is_empty(), "There are no transitions in the execution");
 
@@ -140,13 +191,9 @@ impl<N: Network> Process<N> {
 
         // Construct the list of verifier inputs.
         let verifier_inputs: Vec<_> = verifier_inputs.values().cloned().collect();
-        // Verify the execution proof.
-        Trace::verify_execution_proof(&locator, verifier_inputs, execution)?;
-
-        lap!(timer, "Verify the proof");
 
         finish!(timer);
-        Ok(())
+        Ok((locator, verifier_inputs))
     }
 }
 
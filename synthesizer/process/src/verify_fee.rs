@@ -18,6 +18,7 @@ impl<N: Network> Process<N> {
     /// Verifies the given fee is valid.
     /// Note: This does *not* check that the global state root exists in the ledger.
     #[inline]
+    #[cfg_attr(feature = "instrument", tracing::instrument(name = "Process::verify_fee", skip_all))]
     pub fn verify_fee(&self, fee: &Fee<N>, deployment_or_execution_id: Field<N>) -> Result<()> {
         let timer = timer!("Process::verify_fee");
 
@@ -354,6 +354,7 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
     /// This method will halt if the struct name is already in use in the program.
     /// This method will halt if the struct name is a reserved opcode or keyword.
     /// This method will halt if any structs in the struct's members are not already defined.
+    /// This method returns an error if the struct exceeds the maximum nesting depth.
     #[inline]
     fn add_struct(&mut self, struct_: StructType<N>) -> Result<()> {
         // Retrieve the struct name.
@@ -394,6 +395,17 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
             }
         }
 
+        // Ensure the struct does not exceed the maximum nesting depth.
+        let mut depths = IndexMap::new();
+        for plaintext_type in struct_.members().values() {
+            let depth = 1 + self.plaintext_type_depth(plaintext_type, &mut depths)?;
+            ensure!(
+                depth <= N::MAX_DATA_DEPTH,
+                "Struct '{struct_name}' exceeds the maximum nesting depth of {}",
+                N::MAX_DATA_DEPTH
+            );
+        }
+
         // Add the struct name to the identifiers.
         if self.identifiers.insert(struct_name, ProgramDefinition::Struct).is_some() {
             bail!("'{}' already exists in the program.", struct_name)
@@ -627,6 +639,35 @@ impl<N: Network, Instruction: InstructionTrait<N>, Command: CommandTrait<N>> Pro
         "future",
     ];
 
+    /// Returns the nesting depth of the given plaintext type, where a literal has a depth of zero.
+    /// Note: The depths of previously visited structs are cached in `depths`, as struct members may be shared.
+    fn plaintext_type_depth(
+        &self,
+        plaintext_type: &PlaintextType<N>,
+        depths: &mut IndexMap<Identifier<N>, usize>,
+    ) -> Result<usize> {
+        match plaintext_type {
+            PlaintextType::Literal(_) => Ok(0),
+            PlaintextType::Struct(struct_name) => {
+                // Return the cached depth, if it exists.
+                if let Some(depth) = depths.get(struct_name) {
+                    return Ok(*depth);
+                }
+                // Compute the depth of the struct from its members.
+                // Note: This recursion terminates, as a struct may only contain previously-defined structs.
+                let mut depth = 0;
+                for member_type in self.get_struct(struct_name)?.members().values() {
+                    depth = depth.max(1 + self.plaintext_type_depth(member_type, depths)?);
+                }
+                depths.insert(*struct_name, depth);
+                Ok(depth)
+            }
+            PlaintextType::Array(array_type) => {
+                Ok(1 + self.plaintext_type_depth(array_type.next_element_type(), depths)?)
+            }
+        }
+    }
+
     /// Returns `true` if the given name does not already exist in the program.
     fn is_unique_name(&self, name: &Identifier<N>) -> bool {
         !self.identifiers.contains_key(name)
@@ -706,6 +747,43 @@ struct message:
         Ok(())
     }
 
+    #[test]
+    fn test_program_nested_struct() -> Result<()> {
+        // Initialize a program with nested structs.
+        let program = Program::<CurrentNetwork>::from_str(
+            r"
+program unknown.aleo;
+
+struct point:
+    x as field;
+    y as field;
+
+struct line:
+    start as point;
+    end as point;
+
+struct shape:
+    edges as [line; 4u32];
+    label as u8;",
+        )?;
+        // Ensure the structs were added.
+        assert!(program.contains_struct(&Identifier::from_str("line")?));
+        assert!(program.contains_struct(&Identifier::from_str("shape")?));
+
+        // Construct a chain of structs that is one level deeper than the maximum depth.
+        let mut string = "program unknown.aleo; struct s0: x as field;".to_string();
+        for i in 1..=CurrentNetwork::MAX_DATA_DEPTH {
+            string.push_str(&format!(" struct s{i}: x as s{};", i - 1));
+        }
+        // Ensure the struct exceeding the maximum depth is rejected.
+        assert!(Program::<CurrentNetwork>::from_str(&string).is_err());
+        // Ensure the struct at the maximum depth is accepted.
+        string.truncate(string.rfind(" struct").unwrap());
+        assert!(Program::<CurrentNetwork>::from_str(&string).is_ok());
+
+        Ok(())
+    }
+
     #[test]
     fn test_program_record() -> Result<()> {
         // Create a new record.
@@ -201,6 +201,14 @@ pub enum Instruction<N: Network> {
     Ternary(Ternary<N>),
     /// Performs a bitwise `xor` on `first` and `second`, storing the outcome in `destination`.
     Xor(Xor<N>),
+    /// Loads the element of the array `first` at the index `second`, storing the outcome in `destination`.
+    ArrayGet(ArrayGet<N>),
+    /// Computes the length of the string `first` in bytes, storing the outcome in `destination`.
+    StringLen(StringLen<N>),
+    /// Concatenates the strings `first` and `second`, storing the outcome in `destination`.
+    StringConcat(StringConcat<N>),
+    /// Slices the string `first` from the constant byte index `second` to `third`, storing the outcome in `destination`.
+    StringSlice(StringSlice<N>),
 }
 
 /// Creates a match statement that applies the given operation for each instruction.
@@ -300,6 +308,10 @@ macro_rules! instruction {
             SubWrapped,
             Ternary,
             Xor,
+            ArrayGet,
+            StringLen,
+            StringConcat,
+            StringSlice,
         }}
     };
     // A variant **without** curly braces:
@@ -17,6 +17,8 @@ use console::network::prelude::*;
 /// The `Opcode` enum stores the mnemonic for the instruction.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Opcode {
+    /// The opcode is for an array operation (i.e. `array.get`).
+    Array(&'static str),
     /// The opcode is for a assert operation (i.e. `assert`).
     Assert(&'static str),
     /// The opcode is for an async call operation (i.e. `async`).
@@ -37,6 +39,8 @@ pub enum Opcode {
     Literal(&'static str),
     /// The opcode is for signature verification (i.e. `sign.verify`).
     Sign,
+    /// The opcode is for a string operation (i.e. `string.len`).
+    String(&'static str),
 }
 
 impl Deref for Opcode {
@@ -45,6 +49,7 @@ impl Deref for Opcode {
     /// Returns the opcode as a string.
     fn deref(&self) -> &Self::Target {
         match self {
+            Opcode::Array(opcode) => opcode,
             Opcode::Assert(opcode) => opcode,
             Opcode::Async => &"async",
             Opcode::Call => &"call",
@@ -55,6 +60,7 @@ impl Deref for Opcode {
             Opcode::Is(opcode) => opcode,
             Opcode::Literal(opcode) => opcode,
             Opcode::Sign => &"sign.verify",
+            Opcode::String(opcode) => opcode,
         }
     }
 }
@@ -70,6 +76,7 @@ impl Display for Opcode {
     /// Prints the opcode as a string, i.e. `add`.
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
+            Self::Array(opcode) => write!(f, "{opcode}"),
             Self::Assert(opcode) => write!(f, "{opcode}"),
             Self::Async => write!(f, "{}", self.deref()),
             Self::Call => write!(f, "{}", self.deref()),
@@ -80,6 +87,7 @@ impl Display for Opcode {
             Self::Is(opcode) => write!(f, "{opcode}"),
             Self::Literal(opcode) => write!(f, "{opcode}"),
             Self::Sign => write!(f, "{}", self.deref()),
+            Self::String(opcode) => write!(f, "{opcode}"),
         }
     }
 }
@@ -0,0 +1,323 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{
+    traits::{RegistersLoad, RegistersLoadCircuit, RegistersStore, RegistersStoreCircuit, StackMatches, StackProgram},
+    Opcode,
+    Operand,
+};
+use circuit::prelude::{Compare, Eject, Equal, FromBits as CircuitFromBits, Inject, Ternary, ToBits as CircuitToBits};
+use console::{
+    network::prelude::*,
+    program::{Literal, LiteralType, Plaintext, PlaintextType, Register, RegisterType, Value},
+    types::U32,
+};
+
+/// Loads the element of the array `first` at the index `second`, storing the outcome in `destination`.
+///
+/// The index may be a constant or a register. A register index is bounds-checked in the circuit,
+/// and selects the element with a linear scan over the array.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ArrayGet<N: Network> {
+    /// The operands.
+    operands: Vec<Operand<N>>,
+    /// The destination register.
+    destination: Register<N>,
+}
+
+impl<N: Network> ArrayGet<N> {
+    /// Initializes a new `array.get` instruction.
+    #[inline]
+    pub fn new(operands: Vec<Operand<N>>, destination: Register<N>) -> Result<Self> {
+        // Sanity check the number of operands.
+        ensure!(operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the instruction.
+        Ok(Self { operands, destination })
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn opcode() -> Opcode {
+        Opcode::Array("array.get")
+    }
+
+    /// Returns the operands in the operation.
+    #[inline]
+    pub fn operands(&self) -> &[Operand<N>] {
+        // Sanity check that the operands is exactly two inputs.
+        debug_assert!(self.operands.len() == 2, "Instruction '{}' must have two operands", Self::opcode());
+        // Return the operands.
+        &self.operands
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub fn destinations(&self) -> Vec<Register<N>> {
+        vec![self.destination.clone()]
+    }
+}
+
+impl<N: Network> ArrayGet<N> {
+    /// Evaluates the instruction.
+    #[inline]
+    pub fn evaluate(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let elements = match registers.load(stack, &self.operands[0])? {
+            Value::Plaintext(Plaintext::Array(elements, _)) => elements,
+            _ => bail!("Expected the first operand to be an array."),
+        };
+        let index = match registers.load_literal(stack, &self.operands[1])? {
+            Literal::U32(index) => *index as usize,
+            _ => bail!("Expected the second operand to be a u32."),
+        };
+
+        // Retrieve the element.
+        let output = match elements.get(index) {
+            Some(element) => element.clone(),
+            None => bail!("Index '{index}' is out of bounds for an array of length {}", elements.len()),
+        };
+        // Store the output.
+        registers.store(stack, &self.destination, Value::Plaintext(output))
+    }
+
+    /// Executes the instruction.
+    #[inline]
+    pub fn execute<A: circuit::Aleo<Network = N>>(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
+    ) -> Result<()> {
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Retrieve the inputs.
+        let elements = match registers.load_circuit(stack, &self.operands[0])? {
+            circuit::Value::Plaintext(circuit::Plaintext::Array(elements, _)) => elements,
+            _ => bail!("Expected the first operand to be an array."),
+        };
+        let index = match registers.load_literal_circuit(stack, &self.operands[1])? {
+            circuit::Literal::U32(index) => index,
+            _ => bail!("Expected the second operand to be a u32."),
+        };
+
+        // If the index is a constant, retrieve the element directly.
+        if index.is_constant() {
+            let index = *index.eject_value() as usize;
+            let output = match elements.get(index) {
+                Some(element) => element.clone(),
+                None => bail!("Index '{index}' is out of bounds for an array of length {}", elements.len()),
+            };
+            return registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output));
+        }
+
+        // Ensure the index is less than the length of the array.
+        let length = circuit::U32::constant(U32::new(u32::try_from(elements.len())?));
+        A::assert(index.is_less_than(&length));
+
+        // Select the bits of the element at the index, by scanning over every element in the array.
+        let mut elements_bits = elements.iter().map(|element| element.to_bits_le());
+        let mut output_bits = match elements_bits.next() {
+            Some(bits) => bits,
+            None => bail!("Cannot index into an empty array."),
+        };
+        for (i, bits) in (1u32..).zip(elements_bits) {
+            // Ensure the elements are of the same size.
+            ensure!(bits.len() == output_bits.len(), "Array elements must be of the same size to index by a register");
+            // Select the element bits, if this is the element at the index.
+            let is_index = index.is_equal(&circuit::U32::constant(U32::new(i)));
+            output_bits =
+                bits.iter().zip(&output_bits).map(|(a, b)| circuit::Boolean::ternary(&is_index, a, b)).collect();
+        }
+        let output = circuit::Plaintext::from_bits_le(&output_bits);
+
+        // Store the output.
+        registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output))
+    }
+
+    /// Finalizes the instruction.
+    #[inline]
+    pub fn finalize(
+        &self,
+        stack: &(impl StackMatches<N> + StackProgram<N>),
+        registers: &mut (impl RegistersLoad<N> + RegistersStore<N>),
+    ) -> Result<()> {
+        self.evaluate(stack, registers)
+    }
+
+    /// Returns the output type from the given program and input types.
+    #[inline]
+    pub fn output_types(
+        &self,
+        _stack: &impl StackProgram<N>,
+        input_types: &[RegisterType<N>],
+    ) -> Result<Vec<RegisterType<N>>> {
+        // Ensure the number of input types is correct.
+        if input_types.len() != 2 {
+            bail!("Instruction '{}' expects 2 inputs, found {} inputs", Self::opcode(), input_types.len())
+        }
+        // Ensure the number of operands is correct.
+        if self.operands.len() != 2 {
+            bail!("Instruction '{}' expects 2 operands, found {} operands", Self::opcode(), self.operands.len())
+        }
+
+        // Ensure the first input is an array.
+        let array_type = match &input_types[0] {
+            RegisterType::Plaintext(PlaintextType::Array(array_type)) => array_type,
+            _ => bail!("Instruction '{}' expects the first input to be an array", Self::opcode()),
+        };
+        // Ensure the second input is a u32.
+        if input_types[1] != RegisterType::Plaintext(PlaintextType::Literal(LiteralType::U32)) {
+            bail!("Instruction '{}' expects the second input to be a u32", Self::opcode())
+        }
+        // If the index is a constant, ensure it is within the bounds of the array.
+        if let Operand::Literal(Literal::U32(index)) = &self.operands[1] {
+            if index >= array_type.length() {
+                bail!("Index '{index}' is out of bounds for the array '{array_type}'")
+            }
+        }
+
+        Ok(vec![RegisterType::Plaintext(array_type.next_element_type().clone())])
+    }
+}
+
+impl<N: Network> Parser for ArrayGet<N> {
+    /// Parses a string into an operation.
+    #[inline]
+    fn parse(string: &str) -> ParserResult<Self> {
+        // Parse the opcode from the string.
+        let (string, _) = tag(*Self::opcode())(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the first operand from the string.
+        let (string, first) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the second operand from the string.
+        let (string, second) = Operand::parse(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the "into" from the string.
+        let (string, _) = tag("into")(string)?;
+        // Parse the whitespace from the string.
+        let (string, _) = Sanitizer::parse_whitespaces(string)?;
+        // Parse the destination register from the string.
+        let (string, destination) = Register::parse(string)?;
+
+        Ok((string, Self { operands: vec![first, second], destination }))
+    }
+}
+
+impl<N: Network> FromStr for ArrayGet<N> {
+    type Err = Error;
+
+    /// Parses a string into an operation.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        match Self::parse(string) {
+            Ok((remainder, object)) => {
+                // Ensure the remainder is empty.
+                ensure!(remainder.is_empty(), "Failed to parse string. Found invalid character in: \"{remainder}\"");
+                // Return the object.
+                Ok(object)
+            }
+            Err(error) => bail!("Failed to parse string. {error}"),
+        }
+    }
+}
+
+impl<N: Network> Debug for ArrayGet<N> {
+    /// Prints the operation as a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<N: Network> Display for ArrayGet<N> {
+    /// Prints the operation to a string.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(fmt::Error);
+        }
+        // Print the operation.
+        write!(f, "{} ", Self::opcode())?;
+        self.operands.iter().try_for_each(|operand| write!(f, "{operand} "))?;
+        write!(f, "into {}", self.destination)
+    }
+}
+
+impl<N: Network> FromBytes for ArrayGet<N> {
+    /// Reads the operation from a buffer.
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        // Initialize the vector for the operands.
+        let mut operands = Vec::with_capacity(2);
+        // Read the operands.
+        for _ in 0..2 {
+            operands.push(Operand::read_le(&mut reader)?);
+        }
+        // Read the destination register.
+        let destination = Register::read_le(&mut reader)?;
+
+        // Return the operation.
+        Ok(Self { operands, destination })
+    }
+}
+
+impl<N: Network> ToBytes for ArrayGet<N> {
+    /// Writes the operation to a buffer.
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        // Ensure the number of operands is 2.
+        if self.operands.len() != 2 {
+            return Err(error(format!("The number of operands must be 2, found {}", self.operands.len())));
+        }
+        // Write the operands.
+        self.operands.iter().try_for_each(|operand| operand.write_le(&mut writer))?;
+        // Write the destination register.
+        self.destination.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::network::Testnet3;
+
+    type CurrentNetwork = Testnet3;
+
+    #[test]
+    fn test_parse() {
+        let (string, get) = ArrayGet::<CurrentNetwork>::parse("array.get r0 r1 into r2").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.operands.len(), 2, "The number of operands is incorrect");
+        assert_eq!(get.operands[0], Operand::Register(Register::Locator(0)), "The first operand is incorrect");
+        assert_eq!(get.operands[1], Operand::Register(Register::Locator(1)), "The second operand is incorrect");
+        assert_eq!(get.destination, Register::Locator(2), "The destination register is incorrect");
+
+        let (string, get) = ArrayGet::<CurrentNetwork>::parse("array.get r0 3u32 into r1").unwrap();
+        assert!(string.is_empty(), "Parser did not consume all of the string: '{string}'");
+        assert_eq!(get.operands[1], Operand::Literal(Literal::U32(U32::new(3))), "The second operand is incorrect");
+        assert_eq!(get.to_string(), "array.get r0 3u32 into r1");
+    }
+}
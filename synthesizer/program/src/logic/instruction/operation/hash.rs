@@ -103,15 +103,51 @@ fn check_number_of_operands(variant: u8, opcode: Opcode, num_operands: usize) ->
     Ok(())
 }
 
+/// Returns the size of the raw digest in bits, if the variant is able to output its raw digest.
+const fn raw_digest_size_in_bits(variant: u8) -> Option<usize> {
+    match variant {
+        4 | 12 => Some(256),
+        5 | 13 => Some(384),
+        6 | 14 => Some(512),
+        _ => None,
+    }
+}
+
+/// Returns the element type and its size in bits, if the destination type is a raw digest of the variant.
+/// A raw digest is an array of unsigned integers whose total size matches the digest size, e.g. `[u8; 32u32]`.
+fn raw_digest_element_type<N: Network>(
+    variant: u8,
+    destination_type: &PlaintextType<N>,
+) -> Option<(LiteralType, usize)> {
+    let (Some(digest_size_in_bits), PlaintextType::Array(array_type)) =
+        (raw_digest_size_in_bits(variant), destination_type)
+    else {
+        return None;
+    };
+    let (element_type, element_size_in_bits) = match array_type.next_element_type() {
+        PlaintextType::Literal(LiteralType::U8) => (LiteralType::U8, 8),
+        PlaintextType::Literal(LiteralType::U16) => (LiteralType::U16, 16),
+        PlaintextType::Literal(LiteralType::U32) => (LiteralType::U32, 32),
+        PlaintextType::Literal(LiteralType::U64) => (LiteralType::U64, 64),
+        PlaintextType::Literal(LiteralType::U128) => (LiteralType::U128, 128),
+        _ => return None,
+    };
+    match **array_type.length() as usize * element_size_in_bits == digest_size_in_bits {
+        true => Some((element_type, element_size_in_bits)),
+        false => None,
+    }
+}
+
 /// Returns 'true' if the destination type is valid.
-fn is_valid_destination_type<N: Network>(destination_type: &PlaintextType<N>) -> bool {
-    !matches!(
-        destination_type,
-        PlaintextType::Literal(LiteralType::Boolean)
-            | PlaintextType::Literal(LiteralType::String)
-            | PlaintextType::Struct(..)
-            | PlaintextType::Array(..)
-    )
+fn is_valid_destination_type<N: Network>(variant: u8, destination_type: &PlaintextType<N>) -> bool {
+    raw_digest_element_type(variant, destination_type).is_some()
+        || !matches!(
+            destination_type,
+            PlaintextType::Literal(LiteralType::Boolean)
+                | PlaintextType::Literal(LiteralType::String)
+                | PlaintextType::Struct(..)
+                | PlaintextType::Array(..)
+        )
 }
 
 /// Hashes the operand into the declared type.
@@ -136,7 +172,7 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Sanity check the number of operands.
         check_number_of_operands(VARIANT, Self::opcode(), operands.len())?;
         // Sanity check the destination type.
-        if !is_valid_destination_type(&destination_type) {
+        if !is_valid_destination_type(VARIANT, &destination_type) {
             bail!("Invalid destination type for 'hash' instruction")
         }
         // Return the instruction.
@@ -206,10 +242,46 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load(stack, &self.operands[0])?;
+
+        // If the destination is a raw digest, hash the raw bits of the input, and output the digest as an array.
+        if let Some((element_type, element_size_in_bits)) = raw_digest_element_type(VARIANT, &self.destination_type) {
+            // Retrieve the raw bits of the input.
+            let input_bits = match &input {
+                Value::Plaintext(plaintext) => plaintext.to_bits_raw_le(),
+                Value::Record(..) | Value::Future(..) => bail!("Cannot hash a record or future into a raw digest"),
+            };
+            // Ensure the input is a non-empty sequence of bytes.
+            ensure!(
+                !input_bits.is_empty() && input_bits.len() % 8 == 0,
+                "The input to a raw digest must be a non-empty sequence of bytes"
+            );
+            // Hash the input.
+            let digest = match VARIANT {
+                4 => N::hash_keccak256(&input_bits)?,
+                5 => N::hash_keccak384(&input_bits)?,
+                6 => N::hash_keccak512(&input_bits)?,
+                12 => N::hash_sha3_256(&input_bits)?,
+                13 => N::hash_sha3_384(&input_bits)?,
+                14 => N::hash_sha3_512(&input_bits)?,
+                _ => bail!("Invalid 'hash' variant for a raw digest: {VARIANT}"),
+            };
+            // Convert the digest into an array of the element type.
+            let elements = digest
+                .chunks(element_size_in_bits)
+                .map(|bits| Ok(Plaintext::from(Literal::from_bits_le(element_type.type_id(), bits)?)))
+                .collect::<Result<Vec<_>>>()?;
+            let output = Plaintext::Array(elements, Default::default());
+            // Store the output.
+            return registers.store(stack, &self.destination, Value::Plaintext(output));
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => Literal::Group(N::hash_to_group_bhp256(&input.to_bits_le())?),
@@ -272,15 +344,57 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         stack: &(impl StackMatches<N> + StackProgram<N>),
         registers: &mut (impl RegistersLoadCircuit<N, A> + RegistersStoreCircuit<N, A>),
     ) -> Result<()> {
-        use circuit::traits::{ToBits, ToFields};
+        use circuit::{
+            traits::{ToBits, ToFields},
+            Inject,
+        };
 
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // Load the operand.
         let input = registers.load_circuit(stack, &self.operands[0])?;
+
+        // If the destination is a raw digest, hash the raw bits of the input, and output the digest as an array.
+        if let Some((element_type, element_size_in_bits)) = raw_digest_element_type(VARIANT, &self.destination_type) {
+            // Retrieve the raw bits of the input.
+            let input_bits = match &input {
+                circuit::Value::Plaintext(plaintext) => plaintext.to_bits_raw_le(),
+                circuit::Value::Record(..) | circuit::Value::Future(..) => {
+                    bail!("Cannot hash a record or future into a raw digest")
+                }
+            };
+            // Ensure the input is a non-empty sequence of bytes.
+            ensure!(
+                !input_bits.is_empty() && input_bits.len() % 8 == 0,
+                "The input to a raw digest must be a non-empty sequence of bytes"
+            );
+            // Hash the input.
+            let digest = match VARIANT {
+                4 => A::hash_keccak256(&input_bits),
+                5 => A::hash_keccak384(&input_bits),
+                6 => A::hash_keccak512(&input_bits),
+                12 => A::hash_sha3_256(&input_bits),
+                13 => A::hash_sha3_384(&input_bits),
+                14 => A::hash_sha3_512(&input_bits),
+                _ => bail!("Invalid 'hash' variant for a raw digest: {VARIANT}"),
+            };
+            // Convert the digest into an array of the element type.
+            let variant = circuit::U8::constant(console::types::U8::new(element_type.type_id()));
+            let elements = digest
+                .chunks(element_size_in_bits)
+                .map(|bits| circuit::Plaintext::from(circuit::Literal::from_bits_le(&variant, bits)))
+                .collect();
+            let output = circuit::Plaintext::Array(elements, Default::default());
+            // Store the output.
+            return registers.store_circuit(stack, &self.destination, circuit::Value::Plaintext(output));
+        }
+
         // Hash the input.
         let output = match (VARIANT, &self.destination_type) {
             (0, PlaintextType::Literal(..)) => circuit::Literal::Group(A::hash_to_group_bhp256(&input.to_bits_le())),
@@ -360,7 +474,10 @@ impl<N: Network, const VARIANT: u8> HashInstruction<N, VARIANT> {
         // Ensure the number of operands is correct.
         check_number_of_operands(VARIANT, Self::opcode(), self.operands.len())?;
         // Ensure the destination type is valid.
-        ensure!(is_valid_destination_type(&self.destination_type), "Invalid destination type in 'hash' instruction");
+        ensure!(
+            is_valid_destination_type(VARIANT, &self.destination_type),
+            "Invalid destination type in 'hash' instruction"
+        );
 
         // TODO (howardwu): If the operation is Pedersen, check that it is within the number of bits.
 
@@ -533,4 +650,27 @@ mod tests {
             assert_eq!(&hash.destination_type, destination_type, "The destination type is incorrect");
         }
     }
+
+    #[test]
+    fn test_raw_digest_destination_type() {
+        let raw_256 = PlaintextType::<CurrentNetwork>::from_str("[u8; 32u32]").unwrap();
+        let raw_384 = PlaintextType::<CurrentNetwork>::from_str("[u16; 24u32]").unwrap();
+        let raw_512 = PlaintextType::<CurrentNetwork>::from_str("[u128; 4u32]").unwrap();
+        let invalid = PlaintextType::<CurrentNetwork>::from_str("[i8; 32u32]").unwrap();
+
+        // Ensure the raw digest is only valid for the Keccak and SHA-3 variants with the matching digest size.
+        assert!(HashKeccak256::<CurrentNetwork>::from_str(&format!("hash.keccak256 r0 into r1 as {raw_256}")).is_ok());
+        assert!(HashKeccak384::<CurrentNetwork>::from_str(&format!("hash.keccak384 r0 into r1 as {raw_384}")).is_ok());
+        assert!(HashKeccak512::<CurrentNetwork>::from_str(&format!("hash.keccak512 r0 into r1 as {raw_512}")).is_ok());
+        assert!(HashSha3_256::<CurrentNetwork>::from_str(&format!("hash.sha3_256 r0 into r1 as {raw_256}")).is_ok());
+        assert!(HashSha3_384::<CurrentNetwork>::from_str(&format!("hash.sha3_384 r0 into r1 as {raw_384}")).is_ok());
+        assert!(HashSha3_512::<CurrentNetwork>::from_str(&format!("hash.sha3_512 r0 into r1 as {raw_512}")).is_ok());
+
+        let operands = vec![Operand::Register(Register::Locator(0))];
+        let destination = Register::Locator(1);
+        assert!(HashKeccak256::<CurrentNetwork>::new(operands.clone(), destination.clone(), raw_256.clone()).is_ok());
+        assert!(HashKeccak256::<CurrentNetwork>::new(operands.clone(), destination.clone(), raw_512).is_err());
+        assert!(HashKeccak256::<CurrentNetwork>::new(operands.clone(), destination.clone(), invalid).is_err());
+        assert!(HashBHP256::<CurrentNetwork>::new(operands, destination, raw_256).is_err());
+    }
 }
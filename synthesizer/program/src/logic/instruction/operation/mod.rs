@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod array;
+pub use array::*;
+
 mod assert;
 pub use assert::*;
 
@@ -41,6 +44,9 @@ mod macros;
 mod sign_verify;
 pub use sign_verify::*;
 
+mod string;
+pub use string::*;
+
 use crate::Opcode;
 use console::network::prelude::*;
 
@@ -330,3 +330,85 @@ fn test_hash_ped128_is_consistent() {
     }
     check_hash!(HashPED128);
 }
+
+#[test]
+fn test_hash_raw_digest_is_consistent() {
+    /// Returns the bytes of the given hex-encoded digest.
+    fn digest_bytes(digest: &str) -> Vec<u8> {
+        (0..digest.len()).step_by(2).map(|i| u8::from_str_radix(&digest[i..i + 2], 16).unwrap()).collect()
+    }
+
+    fn check_raw_digest<const VARIANT: u8>(
+        operation: impl FnOnce(
+            Vec<Operand<CurrentNetwork>>,
+            Register<CurrentNetwork>,
+            PlaintextType<CurrentNetwork>,
+        ) -> HashInstruction<CurrentNetwork, VARIANT>,
+        mode: circuit::Mode,
+        expected: &str,
+    ) {
+        // Initialize the opcode.
+        let opcode = HashInstruction::<CurrentNetwork, VARIANT>::opcode();
+        // Initialize the destination type.
+        let destination_type = PlaintextType::from_str("[u8; 32u32]").unwrap();
+        // Initialize the input, i.e. the ASCII character 'a'.
+        let literal = Literal::U8(console::types::U8::new(b'a'));
+
+        // Initialize the stack.
+        let (stack, operands, destination) =
+            sample_stack(opcode, LiteralType::U8, mode, destination_type.clone()).unwrap();
+        // Initialize the operation.
+        let operation = operation(operands, destination.clone(), destination_type);
+        // Initialize the function name.
+        let function_name = Identifier::from_str("run").unwrap();
+        // Initialize a destination operand.
+        let destination_operand = Operand::Register(destination);
+
+        // Evaluate, execute, and finalize the operation.
+        let mut evaluate_registers = sample_registers(&stack, &function_name, &[(&literal, None)]).unwrap();
+        operation.evaluate(&stack, &mut evaluate_registers).unwrap();
+        let mut execute_registers = sample_registers(&stack, &function_name, &[(&literal, Some(mode))]).unwrap();
+        operation.execute::<CurrentAleo>(&stack, &mut execute_registers).unwrap();
+        let mut finalize_registers = sample_finalize_registers(&stack, &function_name, &[&literal]).unwrap();
+        operation.finalize(&stack, &mut finalize_registers).unwrap();
+
+        // Retrieve the outputs.
+        let output_a = evaluate_registers.load(&stack, &destination_operand).unwrap();
+        let output_b = execute_registers.load_circuit(&stack, &destination_operand).unwrap();
+        let output_c = finalize_registers.load(&stack, &destination_operand).unwrap();
+
+        // Check that the outputs are consistent.
+        assert_eq!(output_a, output_b.eject_value(), "The results of the evaluation and execution are inconsistent");
+        assert_eq!(output_a, output_c, "The results of the evaluation and finalization are inconsistent");
+
+        // Check that the output is the expected digest.
+        let expected = Plaintext::Array(
+            digest_bytes(expected)
+                .into_iter()
+                .map(|byte| Plaintext::from(Literal::U8(console::types::U8::new(byte))))
+                .collect(),
+            Default::default(),
+        );
+        assert_eq!(output_a, Value::Plaintext(expected), "The digest of '{opcode}' is incorrect");
+
+        // Reset the circuit.
+        <CurrentAleo as circuit::Environment>::reset();
+    }
+
+    for mode in [circuit::Mode::Public, circuit::Mode::Private] {
+        check_raw_digest(
+            |operands, destination, destination_type| {
+                HashKeccak256::<CurrentNetwork>::new(operands, destination, destination_type).unwrap()
+            },
+            mode,
+            "3ac225168df54212a25c1c01fd35bebfea408fdac2e31ddd6f80a4bbf9a5f1cb",
+        );
+        check_raw_digest(
+            |operands, destination, destination_type| {
+                HashSha3_256::<CurrentNetwork>::new(operands, destination, destination_type).unwrap()
+            },
+            mode,
+            "80084bf2fba02475726feb2cab2d8215eab14bc6bdd8bfb2c8151257032ecd8b",
+        );
+    }
+}
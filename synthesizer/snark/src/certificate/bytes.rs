@@ -40,7 +40,7 @@ impl<N: Network> ToBytes for Certificate<N> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod tests {
     use super::*;
 
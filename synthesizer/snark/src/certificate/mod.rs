@@ -26,11 +26,13 @@ pub struct Certificate<N: Network> {
 
 impl<N: Network> Certificate<N> {
     /// Initializes a new certificate.
+    #[cfg(feature = "prover")]
     pub(super) const fn new(certificate: varuna::Certificate<N::PairingCurve>) -> Self {
         Self { certificate }
     }
 
     /// Returns the certificate from the proving and verifying key.
+    #[cfg(feature = "prover")]
     pub fn certify(
         function_name: &str,
         proving_key: &ProvingKey<N>,
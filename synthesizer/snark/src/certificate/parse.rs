@@ -71,7 +71,7 @@ impl<N: Network> Display for Certificate<N> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod tests {
     use super::*;
     use console::network::Testnet3;
@@ -34,7 +34,7 @@ impl<'de, N: Network> Deserialize<'de> for Certificate<N> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod tests {
     use super::*;
 
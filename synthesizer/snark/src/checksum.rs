@@ -0,0 +1,124 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+
+/// The version of the checksummed format.
+const CHECKSUMMED_VERSION: u8 = 1;
+/// The version of the checksummed format, with a zlib-compressed payload.
+#[cfg(feature = "compression")]
+const COMPRESSED_VERSION: u8 = 2;
+/// The size of the checksum in bytes.
+const CHECKSUM_SIZE_IN_BYTES: usize = 32;
+
+/// Returns the given bytes, prefixed with the format version and the SHA-256 checksum of the bytes.
+pub(crate) fn to_checksummed_bytes(bytes: &[u8]) -> Vec<u8> {
+    frame(CHECKSUMMED_VERSION, bytes)
+}
+
+/// Returns the given bytes compressed with zlib, prefixed with the format version and the SHA-256 checksum of the
+/// compressed bytes.
+#[cfg(feature = "compression")]
+pub(crate) fn to_compressed_checksummed_bytes(bytes: &[u8]) -> Result<Vec<u8>> {
+    use flate2::{write::ZlibEncoder, Compression};
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(bytes)?;
+    Ok(frame(COMPRESSED_VERSION, &encoder.finish()?))
+}
+
+/// Returns the bytes within the given checksummed bytes, after ensuring the format version and the checksum are valid.
+/// If the payload is compressed, it is decompressed.
+pub(crate) fn from_checksummed_bytes(bytes: &[u8]) -> Result<Cow<[u8]>> {
+    // Ensure the bytes contain the version and the checksum.
+    ensure!(bytes.len() > CHECKSUM_SIZE_IN_BYTES, "The checksummed bytes are too short");
+    // Ensure the checksum matches.
+    let (checksum, payload) = bytes[1..].split_at(CHECKSUM_SIZE_IN_BYTES);
+    ensure!(Sha256::digest(payload).as_slice() == checksum, "The checksum of the bytes does not match");
+    // Return the payload, based on the version.
+    match bytes[0] {
+        CHECKSUMMED_VERSION => Ok(Cow::Borrowed(payload)),
+        #[cfg(feature = "compression")]
+        COMPRESSED_VERSION => {
+            use flate2::read::ZlibDecoder;
+
+            let mut decompressed = Vec::new();
+            ZlibDecoder::new(payload).read_to_end(&mut decompressed)?;
+            Ok(Cow::Owned(decompressed))
+        }
+        version => bail!("Invalid checksummed bytes version ({version})"),
+    }
+}
+
+/// Returns the given payload, prefixed with the given format version and the SHA-256 checksum of the payload.
+fn frame(version: u8, payload: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(1 + CHECKSUM_SIZE_IN_BYTES + payload.len());
+    output.push(version);
+    output.extend_from_slice(&Sha256::digest(payload));
+    output.extend_from_slice(payload);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksummed_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_bytes in [0, 1, 32, 1000] {
+            let bytes = (0..num_bytes).map(|_| rng.gen()).collect::<Vec<u8>>();
+            let checksummed = to_checksummed_bytes(&bytes);
+            assert_eq!(bytes, *from_checksummed_bytes(&checksummed)?);
+
+            // Ensure tampering with the checksummed bytes is detected.
+            for index in [0, 1, checksummed.len() - 1] {
+                let mut tampered = checksummed.clone();
+                tampered[index] ^= 1;
+                assert!(from_checksummed_bytes(&tampered).is_err());
+            }
+            // Ensure truncated bytes are rejected.
+            assert!(from_checksummed_bytes(&checksummed[..checksummed.len() - 1]).is_err());
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "compression")]
+    #[test]
+    fn test_compressed_checksummed_bytes() -> Result<()> {
+        let mut rng = TestRng::default();
+
+        for num_bytes in [0, 1, 32, 1000] {
+            // Sample bytes with repetition, so that they compress.
+            let bytes = (0..num_bytes).map(|_| rng.gen_range(0..4)).collect::<Vec<u8>>();
+            let compressed = to_compressed_checksummed_bytes(&bytes)?;
+            assert_eq!(bytes, *from_checksummed_bytes(&compressed)?);
+            if num_bytes == 1000 {
+                assert!(compressed.len() < to_checksummed_bytes(&bytes).len());
+            }
+
+            // Ensure tampering with the compressed bytes is detected.
+            for index in [0, 1, compressed.len() - 1] {
+                let mut tampered = compressed.clone();
+                tampered[index] ^= 1;
+                assert!(from_checksummed_bytes(&tampered).is_err());
+            }
+        }
+        Ok(())
+    }
+}
@@ -20,6 +20,7 @@
 use console::network::{prelude::*, FiatShamir};
 use snarkvm_algorithms::{snark::varuna, traits::SNARK};
 
+#[cfg(feature = "prover")]
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
 
@@ -31,19 +32,28 @@ type Varuna<N> = varuna::VarunaSNARK<<N as Environment>::PairingCurve, FiatShami
 mod certificate;
 pub use certificate::Certificate;
 
+mod checksum;
+#[cfg(feature = "compression")]
+use checksum::to_compressed_checksummed_bytes;
+use checksum::{from_checksummed_bytes, to_checksummed_bytes};
+
 mod proof;
 pub use proof::Proof;
 
+#[cfg(feature = "prover")]
 mod proving_key;
+#[cfg(feature = "prover")]
 pub use proving_key::ProvingKey;
 
+#[cfg(feature = "prover")]
 mod universal_srs;
+#[cfg(feature = "prover")]
 pub use universal_srs::UniversalSRS;
 
 mod verifying_key;
 pub use verifying_key::VerifyingKey;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 pub(crate) mod test_helpers {
     use super::*;
     use circuit::{
@@ -134,7 +144,7 @@ pub(crate) mod test_helpers {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod test {
     use super::*;
     use circuit::environment::{Circuit, Environment};
@@ -160,4 +170,24 @@ mod test {
         println!("\nShould not verify (i.e. verifier messages should print below):");
         assert!(!verifying_key.verify("test", &[one, one + one], &proof));
     }
+
+    #[test]
+    fn test_delegated_proving() {
+        let assignment = crate::test_helpers::sample_assignment();
+        let (proving_key, verifying_key) = crate::test_helpers::sample_keys();
+
+        // Export the proving key and witness.
+        let proving_key_bytes = proving_key.to_bytes_le().unwrap();
+        let assignment_bytes = assignment.to_bytes_le().unwrap();
+
+        // Import the proving key and witness, and prove on the other machine.
+        let proving_key = ProvingKey::<CurrentNetwork>::from_bytes_le(&proving_key_bytes).unwrap();
+        let assignment =
+            circuit::Assignment::<<Circuit as Environment>::BaseField>::from_bytes_le(&assignment_bytes).unwrap();
+        let proof = proving_key.prove("test", &assignment, &mut TestRng::default()).unwrap();
+
+        // Ensure the proof is valid.
+        let one = <Circuit as Environment>::BaseField::one();
+        assert!(verifying_key.verify("test", &[one, one], &proof));
+    }
 }
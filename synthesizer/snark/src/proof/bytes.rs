@@ -40,7 +40,7 @@ impl<N: Network> ToBytes for Proof<N> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod tests {
     use super::*;
 
@@ -69,7 +69,7 @@ impl<N: Network> Display for Proof<N> {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "prover"))]
 mod tests {
     use super::*;
     use console::network::Testnet3;
@@ -39,3 +39,48 @@ impl<N: Network> ToBytes for ProvingKey<N> {
         self.proving_key.write_le(&mut writer)
     }
 }
+
+impl<N: Network> ProvingKey<N> {
+    /// Returns the proving key as bytes, prefixed with a format version and a SHA-256 checksum.
+    /// This is intended for persisting a synthesized proving key, e.g. between restarts.
+    pub fn to_bytes_with_checksum(&self) -> Result<Vec<u8>> {
+        Ok(to_checksummed_bytes(&self.to_bytes_le()?))
+    }
+
+    /// Returns the proving key as zlib-compressed bytes, prefixed with a format version and a SHA-256 checksum.
+    #[cfg(feature = "compression")]
+    pub fn to_compressed_bytes_with_checksum(&self) -> Result<Vec<u8>> {
+        to_compressed_checksummed_bytes(&self.to_bytes_le()?)
+    }
+
+    /// Reads the proving key from bytes produced by `to_bytes_with_checksum` (or `to_compressed_bytes_with_checksum`),
+    /// after ensuring the checksum matches.
+    pub fn from_bytes_with_checksum(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_le(&from_checksummed_bytes(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes_with_checksum() -> Result<()> {
+        // Sample the proving key.
+        let (expected, _) = crate::test_helpers::sample_keys();
+
+        // Ensure the proving key round trips.
+        let bytes = expected.to_bytes_with_checksum()?;
+        let candidate = ProvingKey::<CurrentNetwork>::from_bytes_with_checksum(&bytes)?;
+        assert_eq!(expected.to_bytes_le()?, candidate.to_bytes_le()?);
+
+        // Ensure a corrupted proving key is rejected.
+        let mut corrupted = bytes;
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 1;
+        assert!(ProvingKey::<CurrentNetwork>::from_bytes_with_checksum(&corrupted).is_err());
+        Ok(())
+    }
+}
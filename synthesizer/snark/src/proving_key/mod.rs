@@ -39,7 +39,7 @@ impl<N: Network> ProvingKey<N> {
         assignment: &circuit::Assignment<N::Field>,
         rng: &mut R,
     ) -> Result<Proof<N>> {
-        #[cfg(feature = "aleo-cli")]
+        #[cfg(any(feature = "aleo-cli", feature = "metrics"))]
         let timer = std::time::Instant::now();
 
         // Retrieve the proving parameters.
@@ -49,6 +49,9 @@ impl<N: Network> ProvingKey<N> {
         // Compute the proof.
         let proof = Proof::new(Varuna::<N>::prove(universal_prover, fiat_shamir, self, assignment, rng)?);
 
+        #[cfg(feature = "metrics")]
+        record_proof_metrics(timer.elapsed(), assignment.num_constraints());
+
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{function_name}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
         Ok(proof)
@@ -61,7 +64,7 @@ impl<N: Network> ProvingKey<N> {
         assignments: &[(ProvingKey<N>, Vec<circuit::Assignment<N::Field>>)],
         rng: &mut R,
     ) -> Result<Proof<N>> {
-        #[cfg(feature = "aleo-cli")]
+        #[cfg(any(feature = "aleo-cli", feature = "metrics"))]
         let timer = std::time::Instant::now();
 
         // Prepare the instances.
@@ -77,6 +80,12 @@ impl<N: Network> ProvingKey<N> {
         // Compute the proof.
         let batch_proof = Proof::new(Varuna::<N>::prove_batch(universal_prover, fiat_shamir, &instances, rng)?);
 
+        #[cfg(feature = "metrics")]
+        record_proof_metrics(
+            timer.elapsed(),
+            assignments.iter().flat_map(|(_, assignments)| assignments).map(|a| a.num_constraints()).sum(),
+        );
+
         #[cfg(feature = "aleo-cli")]
         println!("{}", format!(" • Executed '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
 
@@ -84,6 +93,14 @@ impl<N: Network> ProvingKey<N> {
     }
 }
 
+/// Records the elapsed time and number of constraints of a proof.
+#[cfg(feature = "metrics")]
+fn record_proof_metrics(elapsed: std::time::Duration, num_constraints: u64) {
+    metrics::increment_counter(metrics::prover::PROOFS);
+    metrics::histogram(metrics::prover::PROOF_TIME, elapsed.as_secs_f64());
+    metrics::histogram(metrics::prover::CONSTRAINTS, num_constraints as f64);
+}
+
 impl<N: Network> Deref for ProvingKey<N> {
     type Target = varuna::CircuitProvingKey<N::PairingCurve, varuna::VarunaHidingMode>;
 
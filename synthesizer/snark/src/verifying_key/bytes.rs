@@ -39,3 +39,48 @@ impl<N: Network> ToBytes for VerifyingKey<N> {
         self.verifying_key.write_le(&mut writer)
     }
 }
+
+impl<N: Network> VerifyingKey<N> {
+    /// Returns the verifying key as bytes, prefixed with a format version and a SHA-256 checksum.
+    /// This is intended for persisting a synthesized verifying key, e.g. between restarts.
+    pub fn to_bytes_with_checksum(&self) -> Result<Vec<u8>> {
+        Ok(to_checksummed_bytes(&self.to_bytes_le()?))
+    }
+
+    /// Returns the verifying key as zlib-compressed bytes, prefixed with a format version and a SHA-256 checksum.
+    #[cfg(feature = "compression")]
+    pub fn to_compressed_bytes_with_checksum(&self) -> Result<Vec<u8>> {
+        to_compressed_checksummed_bytes(&self.to_bytes_le()?)
+    }
+
+    /// Reads the verifying key from bytes produced by `to_bytes_with_checksum` (or `to_compressed_bytes_with_checksum`),
+    /// after ensuring the checksum matches.
+    pub fn from_bytes_with_checksum(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_le(&from_checksummed_bytes(bytes)?)
+    }
+}
+
+#[cfg(all(test, feature = "prover"))]
+mod tests {
+    use super::*;
+
+    type CurrentNetwork = console::network::Testnet3;
+
+    #[test]
+    fn test_bytes_with_checksum() -> Result<()> {
+        // Sample the verifying key.
+        let (_, expected) = crate::test_helpers::sample_keys();
+
+        // Ensure the verifying key round trips.
+        let bytes = expected.to_bytes_with_checksum()?;
+        let candidate = VerifyingKey::<CurrentNetwork>::from_bytes_with_checksum(&bytes)?;
+        assert_eq!(expected, candidate);
+
+        // Ensure a corrupted verifying key is rejected.
+        let mut corrupted = bytes;
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 1;
+        assert!(VerifyingKey::<CurrentNetwork>::from_bytes_with_checksum(&corrupted).is_err());
+        Ok(())
+    }
+}
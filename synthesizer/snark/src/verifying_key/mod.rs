@@ -87,6 +87,46 @@ impl<N: Network> VerifyingKey<N> {
             }
         }
     }
+
+    /// Returns `true` if all of the batch proofs are valid for their given public inputs.
+    /// The final pairing checks of the batch proofs are combined, and verified together.
+    #[allow(clippy::type_complexity)]
+    pub fn verify_batch_proofs<R: Rng + CryptoRng>(
+        locator: &str,
+        batches: Vec<(Vec<(VerifyingKey<N>, Vec<Vec<N::Field>>)>, &Proof<N>)>,
+        rng: &mut R,
+    ) -> bool {
+        #[cfg(feature = "aleo-cli")]
+        let timer = std::time::Instant::now();
+
+        // Convert the instances.
+        let instances: Vec<(BTreeMap<_, _>, _)> = batches
+            .iter()
+            .map(|(inputs, proof)| {
+                let keys_to_inputs =
+                    inputs.iter().map(|(verifying_key, inputs)| (verifying_key.deref(), inputs.as_slice())).collect();
+                (keys_to_inputs, (*proof).deref())
+            })
+            .collect();
+
+        // Retrieve the verification parameters.
+        let universal_verifier = N::varuna_universal_verifier();
+        let fiat_shamir = N::varuna_fs_parameters();
+
+        // Verify the batch proofs.
+        match Varuna::<N>::verify_batch_proofs(universal_verifier, fiat_shamir, &instances, rng) {
+            Ok(is_valid) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verified '{locator}' (in {} ms)", timer.elapsed().as_millis()).dimmed());
+                is_valid
+            }
+            Err(error) => {
+                #[cfg(feature = "aleo-cli")]
+                println!("{}", format!(" • Verifier failed: {error}").dimmed());
+                false
+            }
+        }
+    }
 }
 
 impl<N: Network> Deref for VerifyingKey<N> {
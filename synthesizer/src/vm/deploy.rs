@@ -21,6 +21,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// otherwise, a public fee will be included in the transaction.
     ///
     /// The `priority_fee_in_microcredits` is an additional fee **on top** of the deployment fee.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "VM::deploy", skip_all, fields(program_id = %program.id()))
+    )]
     pub fn deploy<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
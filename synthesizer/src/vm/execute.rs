@@ -23,6 +23,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// otherwise, a public fee will be included in the transaction.
     ///
     /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[cfg_attr(feature = "instrument", tracing::instrument(name = "VM::execute", skip_all))]
     pub fn execute<R: Rng + CryptoRng>(
         &self,
         private_key: &PrivateKey<N>,
@@ -32,6 +33,36 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         priority_fee_in_microcredits: u64,
         query: Option<Query<N, C::BlockStorage>>,
         rng: &mut R,
+    ) -> Result<Transaction<N>> {
+        self.execute_with_expiration(
+            private_key,
+            (program_id, function_name),
+            inputs,
+            fee_record,
+            priority_fee_in_microcredits,
+            None,
+            query,
+            rng,
+        )
+    }
+
+    /// Returns a new execute transaction, which may only be included in blocks up to the given `expiration` height.
+    ///
+    /// If a `fee_record` is provided, then a private fee will be included in the transaction;
+    /// otherwise, a public fee will be included in the transaction.
+    ///
+    /// The `priority_fee_in_microcredits` is an additional fee **on top** of the execution fee.
+    #[cfg_attr(feature = "instrument", tracing::instrument(name = "VM::execute_with_expiration", skip_all))]
+    pub fn execute_with_expiration<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        (program_id, function_name): (impl TryInto<ProgramID<N>>, impl TryInto<Identifier<N>>),
+        inputs: impl ExactSizeIterator<Item = impl TryInto<Value<N>>>,
+        fee_record: Option<Record<N, Plaintext<N>>>,
+        priority_fee_in_microcredits: u64,
+        expiration: Option<u32>,
+        query: Option<Query<N, C::BlockStorage>>,
+        rng: &mut R,
     ) -> Result<Transaction<N>> {
         // Compute the authorization.
         let authorization = self.authorize(private_key, program_id, function_name, inputs, rng)?;
@@ -40,7 +71,10 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Determine if a priority fee is declared.
         let is_priority_fee_declared = priority_fee_in_microcredits > 0;
         // Compute the execution.
-        let execution = self.execute_authorization_raw(authorization, query.clone(), rng)?;
+        let mut execution = self.execute_authorization_raw(authorization, query.clone(), rng)?;
+        // Set the expiration height.
+        // Note: This must happen before the fee is authorized, as the fee is bound to the execution ID.
+        execution.set_expiration(expiration);
         // Compute the fee.
         let fee = match is_fee_required || is_priority_fee_declared {
             true => {
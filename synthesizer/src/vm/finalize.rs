@@ -26,6 +26,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///     `Ratify::BlockReward(block_reward)` and `Ratify::PuzzleReward(puzzle_reward)`
     ///     to the front of the `ratifications` list.
     #[inline]
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            name = "VM::speculate",
+            skip_all,
+            fields(height = state.block_height(), transactions = candidate_transactions.len())
+        )
+    )]
     pub fn speculate<'a>(
         &self,
         state: FinalizeGlobalState,
@@ -68,6 +76,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ///
     /// Returns the finalize operations from pre-ratify and post-ratify.
     #[inline]
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            name = "VM::check_speculate",
+            skip_all,
+            fields(height = state.block_height(), transactions = transactions.len())
+        )
+    )]
     pub fn check_speculate(
         &self,
         state: FinalizeGlobalState,
@@ -119,8 +135,13 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     ) -> Result<Vec<FinalizeOperation<N>>> {
         let timer = timer!("VM::finalize");
 
+        // Record the writes to the finalize store in the key-value history, at the height of this block.
+        self.finalize_store().set_history_height(Some(state.block_height()));
         // Performs a **real-run** of finalize over the list of ratifications, solutions, and transactions.
-        let ratified_finalize_operations = self.atomic_finalize(state, ratifications, solutions, transactions)?;
+        let result = self.atomic_finalize(state, ratifications, solutions, transactions);
+        // Stop recording the writes to the finalize store.
+        self.finalize_store().set_history_height(None);
+        let ratified_finalize_operations = result?;
 
         finish!(timer, "Finished real-run of finalize");
         Ok(ratified_finalize_operations)
@@ -223,6 +244,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             let mut counter = 0u32;
             // Initialize a list of spent input IDs.
             let mut input_ids: IndexSet<Field<N>> = IndexSet::new();
+            // Initialize a counter for the weight of the confirmed transactions.
+            let mut weight = 0u64;
 
             // Finalize the transactions.
             'outer: for transaction in transactions {
@@ -235,6 +258,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                     continue 'outer;
                 }
 
+                // Ensure the transaction has not expired.
+                if transaction.is_expired(state.block_height()) {
+                    // Store the aborted transaction.
+                    aborted.push((transaction.clone(), "Transaction has expired".to_string()));
+                    // Continue to the next transaction.
+                    continue 'outer;
+                }
+
+                // Ensure the weight of the confirmed transactions does not exceed the maximum.
+                // Transactions that would exceed the maximum weight are aborted.
+                let next_weight = match transaction.size_in_bytes() {
+                    Ok(size) => weight.saturating_add(size),
+                    Err(error) => {
+                        // Store the aborted transaction.
+                        aborted.push((transaction.clone(), error.to_string()));
+                        // Continue to the next transaction.
+                        continue 'outer;
+                    }
+                };
+                if next_weight > Transactions::<N>::MAX_WEIGHT {
+                    // Store the aborted transaction.
+                    aborted.push((transaction.clone(), "Exceeds block weight limit".to_string()));
+                    // Continue to the next transaction.
+                    continue 'outer;
+                }
+
                 // Ensure that the transaction is not double-spending an input.
                 for input_id in transaction.input_ids() {
                     // If the input ID is already spent in this block or previous blocks, abort the transaction.
@@ -365,6 +414,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                         input_ids.extend(confirmed_transaction.transaction().input_ids());
                         // Store the confirmed transaction.
                         confirmed.push(confirmed_transaction);
+                        // Update the weight of the confirmed transactions.
+                        weight = next_weight;
                         // Increment the transaction index counter.
                         counter = counter.saturating_add(1);
                     }
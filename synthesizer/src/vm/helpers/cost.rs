@@ -187,6 +187,10 @@ pub fn cost_in_microcredits<N: Network>(finalize: &Finalize<N>) -> Result<u64> {
         Command::Instruction(Instruction::SubWrapped(_)) => Ok(2_000),
         Command::Instruction(Instruction::Ternary(_)) => Ok(2_000),
         Command::Instruction(Instruction::Xor(_)) => Ok(2_000),
+        Command::Instruction(Instruction::ArrayGet(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StringLen(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StringConcat(_)) => Ok(2_000),
+        Command::Instruction(Instruction::StringSlice(_)) => Ok(2_000),
         // TODO: The following 'finalize' commands are currently priced higher than expected.
         //  Expect these numbers to change as their usage is stabilized.
         Command::Await(_) => Ok(2_000),
@@ -278,7 +278,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         let ratifications = vec![Ratify::Genesis(committee, public_balances)];
         // Prepare the solutions.
         let solutions = None; // The genesis block does not require solutions.
-        // Prepare the transactions.
+                              // Prepare the transactions.
         let transactions = (0..Block::<N>::NUM_GENESIS_TRANSACTIONS)
             .map(|_| self.execute(private_key, locator, inputs.iter(), None, 0, None, rng))
             .collect::<Result<Vec<_>, _>>()?;
@@ -318,6 +318,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
 
     /// Adds the given block into the VM.
     #[inline]
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(
+            name = "VM::add_next_block",
+            skip_all,
+            fields(height = block.height(), transactions = block.transactions().len())
+        )
+    )]
     pub fn add_next_block(&self, block: &Block<N>) -> Result<()> {
         // Acquire the block lock, which is needed to ensure this function is not called concurrently.
         // Note: This lock must be held for the entire scope of this function.
@@ -332,26 +340,11 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
             block.previous_hash(),
         )?;
 
-        // Attention: The following order is crucial because if 'finalize' fails, we can rollback the block.
-        // If one first calls 'finalize', then calls 'insert(block)' and it fails, there is no way to rollback 'finalize'.
-
-        // First, insert the block.
-        self.block_store().insert(block)?;
-        // Next, finalize the transactions.
-        match self.finalize(state, block.ratifications(), block.solutions(), block.transactions()) {
-            Ok(_ratified_finalize_operations) => Ok(()),
-            Err(finalize_error) => {
-                // Rollback the block.
-                self.block_store().remove_last_n(1).map_err(|removal_error| {
-                    // Log the finalize error.
-                    error!("Failed to finalize block {} - {finalize_error}", block.height());
-                    // Return the removal error.
-                    removal_error
-                })?;
-                // Return the finalize error.
-                Err(finalize_error)
-            }
-        }
+        // Stage the block and its finalize operations in a single atomic batch, so that they are committed together.
+        // Note: If 'finalize' fails, the batch is aborted, and neither the block nor the finalize operations are stored.
+        self.block_store()
+            .insert_with(block, || self.finalize(state, block.ratifications(), block.solutions(), block.transactions()))
+            .map(|_ratified_finalize_operations| ())
     }
 }
 
@@ -14,17 +14,24 @@
 
 use super::*;
 
+use rand::{rngs::StdRng, SeedableRng};
+
 /// Ensures the given iterator has no duplicate elements, and that the ledger
 /// does not already contain a given item.
+///
+/// The membership check is performed by the transition store, unless another store is given.
 macro_rules! ensure_is_unique {
     ($name:expr, $self:expr, $method:ident, $iter:expr) => {
+        ensure_is_unique!($name, $self, transition_store, $method, $iter)
+    };
+    ($name:expr, $self:expr, $store:ident, $method:ident, $iter:expr) => {
         // Ensure there are no duplicate items in the transaction.
         if has_duplicates($iter) {
             bail!("Found a duplicate {} in the transaction", $name);
         }
         // Ensure the ledger does not already contain a given item.
         for item in $iter {
-            if $self.transition_store().$method(item)? {
+            if $self.$store().$method(item)? {
                 bail!("The {} '{}' already exists in the ledger", $name, item)
             }
         }
@@ -34,12 +41,79 @@ macro_rules! ensure_is_unique {
 impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
     /// Verifies the transaction in the VM. On failure, returns an error.
     #[inline]
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "VM::check_transaction", skip_all, fields(transaction_id = %transaction.id()))
+    )]
     pub fn check_transaction<R: CryptoRng + Rng>(
         &self,
         transaction: &Transaction<N>,
         rejected_id: Option<Field<N>>,
         rng: &mut R,
     ) -> Result<()> {
+        // Verify the transaction, except for the execution.
+        if let Some(execution) = self.check_transaction_internal(transaction, rejected_id, rng)? {
+            // Verify the execution.
+            self.check_execution_internal(execution)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies the given transactions in the VM, in parallel. On failure, returns an error.
+    ///
+    /// Each transaction is paired with its optional rejected ID, and is verified with its own RNG,
+    /// which is seeded from the given RNG. The execution proofs are verified together, as a single batch.
+    #[cfg_attr(
+        feature = "instrument",
+        tracing::instrument(name = "VM::check_transactions", skip_all, fields(transactions = transactions.len()))
+    )]
+    pub fn check_transactions<R: CryptoRng + Rng>(
+        &self,
+        transactions: &[(&Transaction<N>, Option<Field<N>>)],
+        rng: &mut R,
+    ) -> Result<()> {
+        let timer = timer!("VM::check_transactions");
+
+        // Prepare an RNG for each transaction.
+        let rngs = (0..transactions.len()).map(|_| StdRng::from_seed(rng.gen())).collect::<Vec<_>>();
+        // Verify the transactions, except for the executions.
+        let executions = cfg_iter!(transactions)
+            .zip(rngs)
+            .map(|((transaction, rejected_id), mut rng)| {
+                self.check_transaction_internal(transaction, *rejected_id, &mut rng)
+                    .map(|execution| execution.map(|execution| (transaction.id(), execution)))
+                    .map_err(|e| anyhow!("Invalid transaction '{}': {e}", transaction.id()))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        lap!(timer, "Verify the transactions");
+
+        // Verify the executions together.
+        if !executions.is_empty() {
+            let batch = executions.iter().map(|(_, execution)| *execution).collect::<Vec<_>>();
+            if let Err(error) = self.check_executions_internal(&batch, rng) {
+                // Find the invalid execution, by verifying each execution individually.
+                cfg_iter!(executions).try_for_each(|(id, execution)| {
+                    self.check_execution_internal(execution).map_err(|e| anyhow!("Invalid transaction '{id}': {e}"))
+                })?;
+                return Err(error);
+            }
+        }
+
+        finish!(timer, "Verify the executions");
+        Ok(())
+    }
+
+    /// Verifies the transaction in the VM, except for its execution. On failure, returns an error.
+    /// On success, returns the execution of the transaction (if any), which remains to be verified.
+    fn check_transaction_internal<'a, R: CryptoRng + Rng>(
+        &self,
+        transaction: &'a Transaction<N>,
+        rejected_id: Option<Field<N>>,
+        rng: &mut R,
+    ) -> Result<Option<&'a Execution<N>>> {
         let timer = timer!("VM::check_transaction");
 
         /* Transaction */
@@ -70,16 +144,18 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // Ensure the input IDs are unique.
         ensure_is_unique!("input ID", self, contains_input_id, transaction.input_ids());
         // Ensure the serial numbers are unique.
-        ensure_is_unique!("serial number", self, contains_serial_number, transaction.serial_numbers());
+        // Note: The serial numbers, tags, and commitments are checked by the block store, which consults
+        // its membership filters before the storage maps.
+        ensure_is_unique!("serial number", self, block_store, contains_serial_number, transaction.serial_numbers());
         // Ensure the tags are unique.
-        ensure_is_unique!("tag", self, contains_tag, transaction.tags());
+        ensure_is_unique!("tag", self, block_store, contains_tag, transaction.tags());
 
         /* Output */
 
         // Ensure the output IDs are unique.
         ensure_is_unique!("output ID", self, contains_output_id, transaction.output_ids());
         // Ensure the commitments are unique.
-        ensure_is_unique!("commitment", self, contains_commitment, transaction.commitments());
+        ensure_is_unique!("commitment", self, block_store, contains_commitment, transaction.commitments());
         // Ensure the nonces are unique.
         ensure_is_unique!("nonce", self, contains_nonce, transaction.nonces());
 
@@ -95,8 +171,8 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         // First, verify the fee.
         self.check_fee(transaction, rejected_id)?;
 
-        // Next, verify the deployment or execution.
-        match transaction {
+        // Next, verify the deployment, and retrieve the execution.
+        let execution = match transaction {
             Transaction::Deploy(id, owner, deployment, _) => {
                 // Compute the deployment ID.
                 let Ok(deployment_id) = deployment.to_deployment_id() else {
@@ -114,6 +190,7 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 }
                 // Verify the deployment.
                 self.check_deployment_internal(deployment, rng)?;
+                None
             }
             Transaction::Execute(id, execution, _) => {
                 // Compute the execution ID.
@@ -124,14 +201,14 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
                 if self.block_store().contains_rejected_deployment_or_execution_id(&execution_id)? {
                     bail!("Transaction '{id}' contains a previously rejected execution")
                 }
-                // Verify the execution.
-                self.check_execution_internal(execution)?;
+                // Note: The execution itself is verified by the caller.
+                Some(execution)
             }
-            Transaction::Fee(..) => { /* no-op */ }
-        }
+            Transaction::Fee(..) => None,
+        };
 
         finish!(timer, "Verify the transaction");
-        Ok(())
+        Ok(execution)
     }
 
     /// Verifies the `fee` in the given transaction. On failure, returns an error.
@@ -250,6 +327,32 @@ impl<N: Network, C: ConsensusStorage<N>> VM<N, C> {
         result
     }
 
+    /// Verifies the given executions together. On failure, returns an error.
+    ///
+    /// Note: This is an internal check only. To ensure all components of the executions are checked,
+    /// use `VM::check_transactions` instead.
+    #[inline]
+    fn check_executions_internal<R: CryptoRng + Rng>(&self, executions: &[&Execution<N>], rng: &mut R) -> Result<()> {
+        let timer = timer!("VM::check_executions");
+
+        // Verify the executions.
+        if let Err(error) = self.process.read().verify_executions(executions, rng) {
+            bail!("Execution verification failed: {error}")
+        }
+        lap!(timer, "Verify the executions");
+
+        // Ensure the global state roots exist in the block store.
+        for execution in executions {
+            match self.block_store().contains_state_root(&execution.global_state_root()) {
+                Ok(true) => (),
+                Ok(false) => bail!("Execution verification failed: global state root not found"),
+                Err(error) => bail!("Execution verification failed: {error}"),
+            }
+        }
+        finish!(timer, "Check the global state roots");
+        Ok(())
+    }
+
     /// Verifies the given fee. On failure, returns an error.
     ///
     /// Note: This is an internal check only. To ensure all components of the fee are checked,
@@ -336,6 +439,22 @@ mod tests {
         vm.check_transaction(&execution_transaction, None, rng).unwrap();
     }
 
+    #[test]
+    fn test_check_transaction_uses_membership_filters() {
+        let rng = &mut TestRng::default();
+        let vm = crate::vm::test_helpers::sample_vm_with_genesis_block(rng);
+
+        // Fetch an execution transaction, which spends a record and creates new records.
+        let transaction = crate::vm::test_helpers::sample_execution_transaction_with_private_fee(rng);
+        assert!(transaction.serial_numbers().count() > 0);
+        assert!(transaction.commitments().count() > 0);
+
+        // Ensure the transaction verifies, and the uniqueness checks consult the membership filters.
+        let num_filtered_lookups = vm.block_store().num_filtered_lookups();
+        vm.check_transaction(&transaction, None, rng).unwrap();
+        assert!(vm.block_store().num_filtered_lookups() > num_filtered_lookups);
+    }
+
     #[test]
     fn test_verify_deployment() {
         let rng = &mut TestRng::default();
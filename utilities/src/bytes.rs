@@ -80,6 +80,30 @@ pub trait FromBytes {
     }
 }
 
+/// A writer that counts the bytes written to it, without storing them.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ByteCounter(u64);
+
+impl ByteCounter {
+    /// Returns the number of bytes written.
+    pub const fn num_bytes(&self) -> u64 {
+        self.0
+    }
+}
+
+impl std::io::Write for ByteCounter {
+    #[inline]
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.0 = self.0.saturating_add(data.len() as u64);
+        Ok(data.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 pub struct ToBytesSerializer<T: ToBytes>(String, Option<usize>, PhantomData<T>);
 
 impl<T: ToBytes> ToBytesSerializer<T> {
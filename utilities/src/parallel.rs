@@ -79,6 +79,10 @@ pub fn execute_with_max_available_threads<T>(f: impl FnOnce() -> T + Send) -> T
 #[cfg(not(any(feature = "serial", feature = "wasm")))]
 #[inline(always)]
 fn execute_with_threads<T: Sync + Send>(f: impl FnOnce() -> T + Send, num_threads: usize) -> T {
+    // If the current thread is a worker of a pool within the limit, reuse that pool rather than spawning another.
+    if rayon::current_thread_index().is_some() && rayon::current_num_threads() <= num_threads {
+        return f();
+    }
     let pool = rayon::ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap();
     pool.install(f)
 }
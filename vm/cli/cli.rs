@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::cli::commands::{Build, Clean, Execute, New, Run, Update};
+use crate::cli::commands::{Build, Clean, Execute, Ledger, New, Run, Scan, Update};
 
 use anstyle::{AnsiColor, Color, Style};
 use anyhow::Result;
@@ -44,10 +44,14 @@ pub enum Command {
     Clean(Clean),
     #[clap(name = "execute")]
     Execute(Execute),
+    #[clap(name = "ledger")]
+    Ledger(Ledger),
     #[clap(name = "new")]
     New(New),
     #[clap(name = "run")]
     Run(Run),
+    #[clap(name = "scan")]
+    Scan(Scan),
     #[clap(name = "update")]
     Update(Update),
 }
@@ -59,8 +63,10 @@ impl Command {
             Self::Build(command) => command.parse(),
             Self::Clean(command) => command.parse(),
             Self::Execute(command) => command.parse(),
+            Self::Ledger(command) => command.parse(),
             Self::New(command) => command.parse(),
             Self::Run(command) => command.parse(),
+            Self::Scan(command) => command.parse(),
             Self::Update(command) => command.parse(),
         }
     }
@@ -27,6 +27,9 @@ pub struct Execute {
     /// Toggles offline mode.
     #[clap(long)]
     offline: bool,
+    /// Seeds the RNG with the given value, to make the output reproducible.
+    #[clap(long)]
+    seed: Option<u64>,
 }
 
 impl Execute {
@@ -42,7 +45,7 @@ impl Execute {
         let private_key = crate::cli::helpers::dotenv_private_key()?;
 
         // Initialize an RNG.
-        let rng = &mut rand::thread_rng();
+        let rng = &mut crate::cli::helpers::initialize_rng(self.seed);
 
         // Execute the request.
         let (response, execution, metrics) =
@@ -89,6 +92,15 @@ impl Execute {
             println!(" •  {function_constraints} {counter_string}",)
         }
 
+        // Log the total number of constraints and finalize commands.
+        let num_constraints = metrics.iter().fold(0u64, |total, metric| total.saturating_add(metric.num_constraints()));
+        let num_finalize_commands = metrics.iter().map(|metric| metric.num_finalize_commands).sum::<usize>();
+        println!(
+            " •  {} {}",
+            format!("Total - {} constraints", num_constraints.to_formatted_string(LOCALE)).bold(),
+            format!("({num_finalize_commands} finalize commands)").dimmed()
+        );
+
         // Log the outputs.
         match response.outputs().len() {
             0 => (),
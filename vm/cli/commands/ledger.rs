@@ -0,0 +1,180 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::network::Network,
+    ledger::{store::ConsensusStorage, Ledger as LedgerDB},
+};
+
+use anyhow::{bail, Context};
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+/// The identifier of a block, as a block height or block hash.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlockID {
+    Height(u32),
+    Hash(<CurrentNetwork as Network>::BlockHash),
+}
+
+impl FromStr for BlockID {
+    type Err = anyhow::Error;
+
+    /// Parses a block height or block hash.
+    fn from_str(id: &str) -> Result<Self> {
+        match id.parse::<u32>() {
+            Ok(height) => Ok(Self::Height(height)),
+            Err(_) => Ok(Self::Hash(id.parse().with_context(|| format!("Invalid block height or hash '{id}'"))?)),
+        }
+    }
+}
+
+/// Inspects a ledger database
+#[derive(Debug, Parser)]
+pub struct Ledger {
+    /// Opens the ledger of the development node with the given ID.
+    #[clap(long)]
+    dev: Option<u16>,
+    /// Specify a ledger subcommand.
+    #[clap(subcommand)]
+    command: LedgerCommand,
+}
+
+#[derive(Debug, Parser)]
+pub enum LedgerCommand {
+    /// Prints the block with the given height or hash.
+    #[clap(name = "block")]
+    Block { id: BlockID },
+    /// Prints the block header with the given height or hash.
+    #[clap(name = "header")]
+    Header { id: BlockID },
+    /// Prints the transaction with the given ID.
+    #[clap(name = "transaction")]
+    Transaction { id: <CurrentNetwork as Network>::TransactionID },
+    /// Checks the consistency of the last `depth` blocks, or of the whole chain.
+    #[clap(name = "verify")]
+    Verify {
+        #[clap(long)]
+        depth: Option<u32>,
+    },
+    /// Exports the blocks in the given range to a dump file.
+    #[clap(name = "export")]
+    Export {
+        /// The path of the dump file.
+        path: PathBuf,
+        /// The starting block height (inclusive).
+        #[clap(default_value = "0", long)]
+        start: u32,
+        /// The ending block height (exclusive). Defaults to the latest block height.
+        #[clap(long)]
+        end: Option<u32>,
+    },
+}
+
+impl Ledger {
+    /// Opens the ledger database and runs the ledger subcommand.
+    #[cfg(feature = "rocks")]
+    pub fn parse(self) -> Result<String> {
+        use crate::{
+            ledger::{block::Block, store::helpers::rocksdb::ConsensusDB},
+            utilities::FromBytes,
+        };
+
+        // Load the genesis block.
+        let genesis = Block::from_bytes_le(CurrentNetwork::genesis_bytes())?;
+        // Load the ledger.
+        let ledger = LedgerDB::<CurrentNetwork, ConsensusDB<CurrentNetwork>>::load(genesis, self.dev)?;
+        self.command.parse(&ledger)
+    }
+
+    /// Opens the ledger database and runs the ledger subcommand.
+    #[cfg(not(feature = "rocks"))]
+    pub fn parse(self) -> Result<String> {
+        bail!("The 'ledger' command requires snarkVM to be built with the 'rocks' feature")
+    }
+}
+
+impl LedgerCommand {
+    /// Runs the ledger subcommand on the given ledger.
+    pub fn parse<C: ConsensusStorage<CurrentNetwork>>(self, ledger: &LedgerDB<CurrentNetwork, C>) -> Result<String> {
+        match self {
+            Self::Block { id } => {
+                let block = match id {
+                    BlockID::Height(height) => ledger.get_block(height)?,
+                    BlockID::Hash(hash) => ledger.get_block_by_hash(&hash)?,
+                };
+                Ok(serde_json::to_string_pretty(&block)?)
+            }
+            Self::Header { id } => {
+                let height = match id {
+                    BlockID::Height(height) => height,
+                    BlockID::Hash(hash) => ledger.get_height(&hash)?,
+                };
+                Ok(serde_json::to_string_pretty(&ledger.get_header(height)?)?)
+            }
+            Self::Transaction { id } => Ok(serde_json::to_string_pretty(&ledger.get_transaction(id)?)?),
+            Self::Verify { depth } => {
+                let report = ledger.check_consistency(depth)?;
+                for issue in report.issues() {
+                    println!(" • {}", issue.to_string().red());
+                }
+                let range = format!("(blocks {} to {})", report.start_height(), report.end_height()).dimmed();
+                match report.is_consistent() {
+                    true => Ok(format!("✅ Verified {} blocks {range}", report.num_blocks())),
+                    false => bail!("Found {} issues in {} blocks {range}", report.issues().len(), report.num_blocks()),
+                }
+            }
+            Self::Export { path, start, end } => {
+                let end = end.unwrap_or_else(|| ledger.latest_height().saturating_add(1));
+                let writer = BufWriter::new(File::create(&path)?);
+                let num_blocks = ledger.export(writer, start..end)?;
+                let path_string = format!("(in \"{}\")", path.display());
+                Ok(format!("✅ Exported {num_blocks} blocks {}", path_string.dimmed()))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_ledger() {
+        let cli = CLI::parse_from(["snarkvm", "ledger", "--dev", "1", "block", "5"]);
+        if let Command::Ledger(ledger) = cli.command {
+            assert_eq!(ledger.dev, Some(1));
+            assert!(matches!(ledger.command, LedgerCommand::Block { id: BlockID::Height(5) }));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+
+        let cli = CLI::parse_from(["snarkvm", "ledger", "export", "chain.dump", "--end", "10"]);
+        if let Command::Ledger(ledger) = cli.command {
+            assert_eq!(ledger.dev, None);
+            assert!(matches!(ledger.command, LedgerCommand::Export { start: 0, end: Some(10), .. }));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+
+    #[test]
+    fn test_block_id() {
+        assert_eq!(BlockID::from_str("42").unwrap(), BlockID::Height(42));
+        let hash = <CurrentNetwork as Network>::BlockHash::default();
+        assert_eq!(BlockID::from_str(&hash.to_string()).unwrap(), BlockID::Hash(hash));
+        assert!(BlockID::from_str("abc").is_err());
+    }
+}
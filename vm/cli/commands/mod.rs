@@ -21,12 +21,18 @@ pub use clean::*;
 pub mod execute;
 pub use execute::*;
 
+pub mod ledger;
+pub use ledger::*;
+
 pub mod new;
 pub use new::*;
 
 pub mod run;
 pub use run::*;
 
+pub mod scan;
+pub use scan::*;
+
 pub mod update;
 pub use update::*;
 
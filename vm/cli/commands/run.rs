@@ -21,6 +21,9 @@ pub struct Run {
     function: Identifier<CurrentNetwork>,
     /// The function inputs.
     inputs: Vec<Value<CurrentNetwork>>,
+    /// Seeds the RNG with the given value, to make the output reproducible.
+    #[clap(long)]
+    seed: Option<u64>,
 }
 
 impl Run {
@@ -36,7 +39,7 @@ impl Run {
         let private_key = crate::cli::helpers::dotenv_private_key()?;
 
         // Initialize an RNG.
-        let rng = &mut rand::thread_rng();
+        let rng = &mut crate::cli::helpers::initialize_rng(self.seed);
 
         // Execute the request.
         let (response, metrics) = package.run::<Aleo, _>(&private_key, self.function, &self.inputs, rng)?;
@@ -120,4 +123,18 @@ mod tests {
             panic!("Unexpected result of clap parsing!");
         }
     }
+
+    #[test]
+    fn clap_snarkvm_run_with_seed() {
+        let arg_vec = vec!["snarkvm", "run", "hello", "1u32", "--seed", "42"];
+        let cli = CLI::parse_from(&arg_vec);
+
+        if let Command::Run(run) = cli.command {
+            assert_eq!(run.function, Identifier::try_from(arg_vec[2]).unwrap());
+            assert_eq!(run.inputs, vec![Value::try_from(arg_vec[3]).unwrap()]);
+            assert_eq!(run.seed, Some(42));
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
 }
@@ -0,0 +1,138 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use crate::{
+    console::{
+        account::{GraphKey, ViewKey},
+        program::{Plaintext, Record},
+        types::Field,
+    },
+    ledger::block::Block,
+};
+
+use anyhow::{ensure, Context};
+use std::collections::HashSet;
+
+/// The maximum number of blocks requested from the endpoint at once.
+const MAX_BLOCKS_PER_REQUEST: u32 = 50;
+
+/// Scans the ledger for the records owned by a view key
+#[derive(Debug, Parser)]
+pub struct Scan {
+    /// The view key used to decrypt the records.
+    #[clap(short, long)]
+    view_key: ViewKey<CurrentNetwork>,
+    /// The starting block height (inclusive).
+    #[clap(default_value = "0", long)]
+    start: u32,
+    /// The ending block height (exclusive). Defaults to the latest block height.
+    #[clap(long)]
+    end: Option<u32>,
+    /// Uses the specified endpoint.
+    #[clap(default_value = "https://api.explorer.aleo.org/v1", long)]
+    endpoint: String,
+}
+
+impl Scan {
+    /// Prints the records owned by the view key, along with their spent status.
+    #[allow(clippy::format_in_format_args)]
+    pub fn parse(self) -> Result<String> {
+        // Derive the `sk_tag` from the graph key.
+        let sk_tag = GraphKey::try_from(&self.view_key)?.sk_tag();
+
+        // Determine the ending block height.
+        let end = match self.end {
+            Some(end) => end,
+            None => self.get_latest_height()?.saturating_add(1),
+        };
+        ensure!(self.start < end, "The starting block height must be less than the ending block height");
+
+        // Scan the blocks for the owned records and the tags of the spent records.
+        let mut records = Vec::new();
+        let mut spent_tags = HashSet::new();
+        for start in (self.start..end).step_by(MAX_BLOCKS_PER_REQUEST as usize) {
+            let blocks = self.get_blocks(start, end.min(start.saturating_add(MAX_BLOCKS_PER_REQUEST)))?;
+            for block in &blocks {
+                spent_tags.extend(block.tags().copied());
+                records.extend(get_output_records(&self.view_key, block)?);
+            }
+        }
+
+        // Log the records.
+        println!("🔎 Records\n");
+        for (commitment, record) in &records {
+            // Determine whether the record is spent.
+            let tag = Record::<CurrentNetwork, Plaintext<CurrentNetwork>>::tag(sk_tag, *commitment)?;
+            let status = match spent_tags.contains(&tag) {
+                true => "spent".red(),
+                false => "unspent".green(),
+            };
+            println!(" • {} ({status})\n{record}\n", commitment.to_string().bold());
+        }
+
+        Ok(format!(
+            "✅ Found {} records {}",
+            records.len(),
+            format!("(in blocks {} to {}, from \"{}\")", self.start, end - 1, self.endpoint).dimmed()
+        ))
+    }
+
+    /// Returns the latest block height from the endpoint.
+    fn get_latest_height(&self) -> Result<u32> {
+        let url = format!("{}/testnet3/latest/height", self.endpoint);
+        ureq::get(&url).call()?.into_json().with_context(|| format!("Failed to fetch the latest height from '{url}'"))
+    }
+
+    /// Returns the blocks in the given range from the endpoint.
+    fn get_blocks(&self, start: u32, end: u32) -> Result<Vec<Block<CurrentNetwork>>> {
+        let url = format!("{}/testnet3/blocks?start={start}&end={end}", self.endpoint);
+        ureq::get(&url).call()?.into_json().with_context(|| format!("Failed to fetch the blocks from '{url}'"))
+    }
+}
+
+/// Returns the decrypted records in the given block that are owned by the given view key.
+#[allow(clippy::type_complexity)]
+fn get_output_records(
+    view_key: &ViewKey<CurrentNetwork>,
+    block: &Block<CurrentNetwork>,
+) -> Result<Vec<(Field<CurrentNetwork>, Record<CurrentNetwork, Plaintext<CurrentNetwork>>)>> {
+    block
+        .records()
+        .filter(|(_, record)| record.is_owner(view_key))
+        .map(|(commitment, record)| Ok((*commitment, record.decrypt(view_key)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{Command, CLI};
+
+    #[test]
+    fn clap_snarkvm_scan() {
+        let view_key = "AViewKey1n1n3ZbnVEtXVe3La2xWkUvY3EY7XaCG6RZJJ3tbvrrrD";
+        let arg_vec = vec!["snarkvm", "scan", "--view-key", view_key, "--start", "5", "--end", "10"];
+        let cli = CLI::parse_from(arg_vec);
+
+        if let Command::Scan(scan) = cli.command {
+            assert_eq!(scan.view_key, ViewKey::from_str(view_key).unwrap());
+            assert_eq!(scan.start, 5);
+            assert_eq!(scan.end, Some(10));
+            assert_eq!(scan.endpoint, "https://api.explorer.aleo.org/v1");
+        } else {
+            panic!("Unexpected result of clap parsing!");
+        }
+    }
+}
@@ -15,5 +15,8 @@
 pub mod env;
 pub use env::*;
 
+pub mod rng;
+pub use rng::*;
+
 pub mod updater;
 pub use updater::*;
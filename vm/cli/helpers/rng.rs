@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Returns the RNG to use for a command.
+///
+/// If a seed is given, the RNG is deterministic, so that the requests and transitions produced by
+/// the command are reproducible (e.g. for tests or audits). Otherwise, the RNG is seeded from OS entropy.
+///
+/// Note: Never use a fixed seed to produce transactions for a live network, as anyone who
+/// knows the seed can derive the randomness of the resulting transitions.
+pub fn initialize_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn test_initialize_rng() {
+        // Ensure a seeded RNG is deterministic.
+        let first: [u64; 4] = initialize_rng(Some(1)).gen();
+        let second: [u64; 4] = initialize_rng(Some(1)).gen();
+        assert_eq!(first, second);
+        // Ensure different seeds produce different RNGs.
+        let third: [u64; 4] = initialize_rng(Some(2)).gen();
+        assert_ne!(first, third);
+    }
+}
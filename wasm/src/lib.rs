@@ -33,5 +33,10 @@ pub use snarkvm_synthesizer as synthesizer;
 #[cfg(feature = "utilities")]
 pub use snarkvm_utilities as utilities;
 
+#[cfg(feature = "console")]
+mod record;
+#[cfg(feature = "console")]
+pub use record::*;
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,46 @@
+// Copyright (C) 2019-2023 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at:
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use snarkvm_console::{
+    account::ViewKey,
+    network::Testnet3,
+    program::{Ciphertext, Record},
+};
+
+use core::str::FromStr;
+use wasm_bindgen::prelude::wasm_bindgen;
+
+type CurrentNetwork = Testnet3;
+type RecordCiphertext = Record<CurrentNetwork, Ciphertext<CurrentNetwork>>;
+
+/// Returns the plaintext of the given record ciphertext, if it is owned by the given view key.
+#[wasm_bindgen(js_name = decryptRecord)]
+pub fn decrypt_record(view_key: &str, record: &str) -> Result<String, String> {
+    let (view_key, record) = parse(view_key, record)?;
+    record.decrypt(&view_key).map(|record| record.to_string()).map_err(|error| error.to_string())
+}
+
+/// Returns `true` if the given record ciphertext is owned by the given view key.
+#[wasm_bindgen(js_name = isRecordOwner)]
+pub fn is_record_owner(view_key: &str, record: &str) -> Result<bool, String> {
+    let (view_key, record) = parse(view_key, record)?;
+    Ok(record.is_owner(&view_key))
+}
+
+/// Parses the given view key and record ciphertext.
+fn parse(view_key: &str, record: &str) -> Result<(ViewKey<CurrentNetwork>, RecordCiphertext), String> {
+    let view_key = ViewKey::from_str(view_key).map_err(|error| format!("Invalid view key: {error}"))?;
+    let record = Record::from_str(record).map_err(|error| format!("Invalid record ciphertext: {error}"))?;
+    Ok((view_key, record))
+}
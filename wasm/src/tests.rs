@@ -14,9 +14,11 @@
 
 use snarkvm_console::{
     account::{Address, PrivateKey, ViewKey},
-    network::Testnet3,
+    network::{Network, Testnet3},
+    program::{Plaintext, Record},
+    types::Scalar,
 };
-use snarkvm_utilities::TestRng;
+use snarkvm_utilities::{TestRng, Uniform};
 
 use core::str::FromStr;
 use wasm_bindgen_test::*;
@@ -58,3 +60,36 @@ fn test_account_sign() {
         assert!(result, "Failed to execute signature verification");
     }
 }
+
+#[wasm_bindgen_test]
+fn test_decrypt_record() {
+    let mut rng = TestRng::default();
+
+    // Sample a new account.
+    let private_key = PrivateKey::<Testnet3>::new(&mut rng).unwrap();
+    let view_key = ViewKey::try_from(&private_key).unwrap();
+    let address = Address::try_from(&view_key).unwrap();
+
+    // Construct and encrypt a record owned by the account.
+    let randomizer = Scalar::rand(&mut rng);
+    let nonce = Testnet3::g_scalar_multiply(&randomizer);
+    let expected = Record::<Testnet3, Plaintext<Testnet3>>::from_str(&format!(
+        "{{ owner: {address}.private, amount: 5u64.private, _nonce: {nonce}.public }}"
+    ))
+    .unwrap();
+    let ciphertext = expected.encrypt(randomizer).unwrap().to_string();
+
+    // Ensure the owner can decrypt the record.
+    assert!(crate::is_record_owner(&view_key.to_string(), &ciphertext).unwrap());
+    let candidate = crate::decrypt_record(&view_key.to_string(), &ciphertext).unwrap();
+    assert_eq!(expected.to_string(), candidate);
+
+    // Ensure a different account cannot decrypt the record.
+    let other = ViewKey::try_from(&PrivateKey::<Testnet3>::new(&mut rng).unwrap()).unwrap();
+    assert!(!crate::is_record_owner(&other.to_string(), &ciphertext).unwrap());
+    assert!(crate::decrypt_record(&other.to_string(), &ciphertext).is_err());
+
+    // Ensure malformed inputs are rejected.
+    assert!(crate::decrypt_record("AViewKey1invalid", &ciphertext).is_err());
+    assert!(crate::is_record_owner(&view_key.to_string(), "record1invalid").is_err());
+}